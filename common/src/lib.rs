@@ -1,5 +1,6 @@
 pub mod db;
 pub mod solana;
+pub mod swaps;
 use substreams::{hex, log, pb::substreams::Clock, scalar::BigInt};
 
 pub type Address = Vec<u8>;
@@ -1,7 +1,7 @@
 use base64::Engine;
 use prost_types::Timestamp;
 use substreams::pb::substreams::Clock;
-use substreams_solana::{base58, pb::sf::solana::r#type::v1::ConfirmedTransaction};
+use substreams_solana::{base58, block_view::InstructionView, pb::sf::solana::r#type::v1::ConfirmedTransaction};
 
 const GENESIS_TIMESTAMP: u64 = 1584332940; // Genesis timestamp in seconds
 const SLOT_DURATION_MS: u64 = 400; // Slot duration in milliseconds
@@ -53,6 +53,30 @@ pub fn get_signers(tx: &ConfirmedTransaction) -> Option<Vec<Vec<u8>>> {
         })
 }
 
+/// Returns the program that invoked `instruction` through CPI,
+/// or an empty vector for top-level instructions.
+pub fn get_parent_program_id(instruction: &InstructionView) -> Vec<u8> {
+    instruction
+        .parent_instruction()
+        .map(|parent| parent.program_id().0.to_vec())
+        .unwrap_or_default()
+}
+
+/// Returns the program of the top-level (outermost) instruction containing `instruction`,
+/// or the program of `instruction` itself when it is top-level.
+pub fn get_outer_program_id(instruction: &InstructionView) -> Vec<u8> {
+    match instruction.top_instruction() {
+        Some(top) => top.program_id().0.to_vec(),
+        None => instruction.program_id().0.to_vec(),
+    }
+}
+
+/// Returns the account at `idx` of the instruction,
+/// or `None` when the instruction has fewer accounts.
+pub fn account_bytes(instruction: &InstructionView, idx: usize) -> Option<Vec<u8>> {
+    instruction.accounts().get(idx).map(|account| account.0.to_vec())
+}
+
 pub fn parse_program_data(log_message: &str) -> Option<Vec<u8>> {
     if let Some(b64) = log_message.strip_prefix("Program data:") {
         // remove embedded whitespace, if any
@@ -130,6 +154,37 @@ pub fn is_invoke(log: &str) -> bool {
     log.contains(" invoke [")
 }
 
+/// Programs currently invoked while walking the log messages of a transaction,
+/// used to resolve the ancestry of the program emitting a log.
+#[derive(Default)]
+pub struct InvokeStack {
+    programs: Vec<Vec<u8>>,
+}
+
+impl InvokeStack {
+    /// Pushes the program on `Program <PK> invoke [n]` and pops it on `success` / `failed`.
+    pub fn update(&mut self, log: &str) {
+        if is_invoke(log) {
+            if let (Some(program_id), Some(depth)) = (parse_program_id(log), parse_invoke_depth(log)) {
+                self.programs.truncate(depth.saturating_sub(1) as usize);
+                self.programs.push(program_id);
+            }
+        } else if (is_success(log) || is_failed(log)) && parse_program_id(log).is_some() {
+            self.programs.pop();
+        }
+    }
+
+    /// Program invoking the running program, empty when it is top-level.
+    pub fn parent_program_id(&self) -> Vec<u8> {
+        self.programs.len().checked_sub(2).map(|i| self.programs[i].clone()).unwrap_or_default()
+    }
+
+    /// Program of the running top-level instruction.
+    pub fn outer_program_id(&self) -> Vec<u8> {
+        self.programs.first().cloned().unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,4 +272,27 @@ mod tests {
         let decoded = parse_raydium_log(&msg.to_string()).unwrap();
         assert_eq!(decoded, b"hello");
     }
+
+    #[test]
+    fn invoke_stack_tracks_ancestry() {
+        let outer = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+        let parent = "Minimox7jqQmMpF6Z34DTNwE9iJyNkruzvvYQRaHpAP";
+        let program = "11111111111111111111111111111111";
+        let mut stack = InvokeStack::default();
+        for log in [
+            format!("Program {outer} invoke [1]"),
+            format!("Program {parent} invoke [2]"),
+            format!("Program {program} invoke [3]"),
+            "Program log: Instruction: Swap".to_string(),
+        ] {
+            stack.update(&log);
+        }
+        assert_eq!(stack.parent_program_id(), base58::decode(parent).unwrap());
+        assert_eq!(stack.outer_program_id(), base58::decode(outer).unwrap());
+
+        stack.update(&format!("Program {program} success"));
+        stack.update(&format!("Program {parent} success"));
+        assert!(stack.parent_program_id().is_empty());
+        assert_eq!(stack.outer_program_id(), base58::decode(outer).unwrap());
+    }
 }
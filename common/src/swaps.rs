@@ -0,0 +1,463 @@
+use std::collections::HashMap;
+
+use substreams_solana::{base58, block_view::InstructionView, pb::sf::solana::r#type::v1::ConfirmedTransaction};
+
+use crate::solana::get_signers;
+
+// Token Program KEG (TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA)
+const SOLANA_TOKEN_PROGRAM_KEG: [u8; 32] = [
+    6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+];
+
+// Token-2022 Program (TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb)
+const SOLANA_TOKEN_PROGRAM_ZQB: [u8; 32] = [
+    6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77, 131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+];
+
+// SPL Token instruction discriminators (identical for Token-2022)
+const INITIALIZE_MINT: u8 = 0;
+const TRANSFER: u8 = 3;
+const TRANSFER_CHECKED: u8 = 12;
+const INITIALIZE_MINT2: u8 = 20;
+
+/// SPL Token `Transfer` / `TransferChecked` CPI executed by a swap instruction.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenTransfer {
+    pub source: Vec<u8>,
+    pub destination: Vec<u8>,
+    pub authority: Vec<u8>,
+    pub mint: Vec<u8>,
+    pub amount: u64,
+}
+
+/// Realized swap, inferred from the token transfers executed by a swap instruction.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SwapAmounts {
+    pub user: Vec<u8>,
+    pub input_mint: Vec<u8>,
+    pub input_amount: u64,
+    pub output_mint: Vec<u8>,
+    pub output_amount: u64,
+}
+
+impl SwapAmounts {
+    /// Infers the realized swap from the inner instructions of `instruction`.
+    ///
+    /// - `user`: first transfer authority that signed the transaction (falls back to the first transfer authority)
+    /// - `input`: first transfer sent by the user
+    /// - `output`: last transfer received by a token account owned by the user
+    ///
+    /// Missing legs (ex: orderbook orders settled later) are left empty.
+    pub fn from_instruction(instruction: &InstructionView) -> Self {
+        let tx = instruction.transaction();
+        let accounts = TokenAccounts::new(tx);
+        let transfers = get_inner_transfers(instruction, &accounts);
+
+        let signers = get_signers(tx).unwrap_or_default();
+        let Some(user) = transfers
+            .iter()
+            .find(|t| signers.contains(&t.authority))
+            .or(transfers.first())
+            .map(|t| t.authority.clone())
+        else {
+            return Self::default();
+        };
+
+        let input = transfers.iter().find(|t| t.authority == user);
+        let output = transfers
+            .iter()
+            .rev()
+            .find(|t| t.authority != user && accounts.owner(&t.destination) == Some(&user));
+
+        Self {
+            input_mint: input.map(|t| t.mint.clone()).unwrap_or_default(),
+            input_amount: input.map_or(0, |t| t.amount),
+            output_mint: output.map(|t| t.mint.clone()).unwrap_or_default(),
+            output_amount: output.map_or(0, |t| t.amount),
+            user,
+        }
+    }
+}
+
+/// Decoders spread the realized swap into their `SwapInstruction` (`..SwapAmounts::from_instruction(ix).into()`).
+macro_rules! impl_from_swap_amounts {
+    ($($instruction:ty),* $(,)?) => {
+        $(
+            impl From<SwapAmounts> for $instruction {
+                fn from(swap: SwapAmounts) -> Self {
+                    Self {
+                        user: swap.user,
+                        input_mint: swap.input_mint,
+                        input_amount: swap.input_amount,
+                        output_mint: swap.output_mint,
+                        output_amount: swap.output_amount,
+                        ..Default::default()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_swap_amounts!(
+    proto::pb::aldrin::v1::SwapInstruction,
+    proto::pb::bonk::swap::v1::SwapInstruction,
+    proto::pb::byreal::v1::SwapInstruction,
+    proto::pb::dflow::v1::SwapInstruction,
+    proto::pb::okx::dex::v1::SwapInstruction,
+    proto::pb::sanctum::v1::SwapInstruction,
+    proto::pb::saros::v1::SwapInstruction,
+    proto::pb::serum::v1::SwapInstruction,
+);
+
+/// Returns every SPL Token `Transfer` / `TransferChecked` executed below `instruction` (depth-first, in execution order).
+///
+/// `Transfer` does not reference the mint, it is resolved from the transaction token balances.
+pub fn get_inner_transfers(instruction: &InstructionView, accounts: &TokenAccounts) -> Vec<TokenTransfer> {
+    let mut transfers = Vec::new();
+    collect_transfers(instruction, accounts, &mut transfers);
+    transfers
+}
+
+/// Side of the token transfers summed by [`get_transferred_amount`], relative to the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// Tokens sent from the account
+    Sent,
+    /// Tokens received by the account
+    Received,
+}
+
+/// Total amount of the token transfers below `instruction` sent from or received by `account` (ex: a pool vault).
+///
+/// Only one side is summed, so that a refund or fee sent back to the same account does not inflate the amount.
+pub fn get_transferred_amount(instruction: &InstructionView, account: &[u8], direction: TransferDirection) -> u64 {
+    let accounts = TokenAccounts::new(instruction.transaction());
+    get_inner_transfers(instruction, &accounts)
+        .iter()
+        .filter(|t| match direction {
+            TransferDirection::Sent => t.source == account,
+            TransferDirection::Received => t.destination == account,
+        })
+        .map(|t| t.amount)
+        .sum()
+}
+
+/// Returns the mint & decimals of the first SPL Token `InitializeMint` / `InitializeMint2` executed below `instruction` (ex: a token launch).
+pub fn get_initialized_mint(instruction: &InstructionView) -> Option<(Vec<u8>, u32)> {
+    for inner in instruction.inner_instructions() {
+        let program_id = inner.program_id().0;
+        if program_id != &SOLANA_TOKEN_PROGRAM_KEG && program_id != &SOLANA_TOKEN_PROGRAM_ZQB {
+            continue;
+        }
+        let data = inner.data();
+        // accounts: [mint, (rent)], data: [discriminator, decimals, mint_authority, freeze_authority]
+        if matches!(data.first(), Some(&INITIALIZE_MINT) | Some(&INITIALIZE_MINT2)) {
+            return Some((inner.accounts().first()?.0.to_vec(), *data.get(1)? as u32));
+        }
+    }
+    None
+}
+
+fn collect_transfers(instruction: &InstructionView, accounts: &TokenAccounts, transfers: &mut Vec<TokenTransfer>) {
+    for inner in instruction.inner_instructions() {
+        // only direct CPIs, deeper instructions are visited through their own parent
+        if inner.stack_height() != instruction.stack_height() + 1 {
+            continue;
+        }
+        if let Some(transfer) = unpack_transfer(&inner, accounts) {
+            transfers.push(transfer);
+        }
+        collect_transfers(&inner, accounts, transfers);
+    }
+}
+
+fn unpack_transfer(instruction: &InstructionView, token_accounts: &TokenAccounts) -> Option<TokenTransfer> {
+    let program_id = instruction.program_id().0;
+    if program_id != &SOLANA_TOKEN_PROGRAM_KEG && program_id != &SOLANA_TOKEN_PROGRAM_ZQB {
+        return None;
+    }
+    let data = instruction.data();
+    let accounts = instruction.accounts();
+    let amount = u64::from_le_bytes(data.get(1..9)?.try_into().ok()?);
+
+    match data.first()? {
+        // accounts: [source, destination, authority, ..signers]
+        &TRANSFER if accounts.len() >= 3 => {
+            let source = accounts[0].0.to_vec();
+            let destination = accounts[1].0.to_vec();
+            let mint = token_accounts.mint(&source).or(token_accounts.mint(&destination)).cloned().unwrap_or_default();
+            Some(TokenTransfer {
+                source,
+                destination,
+                authority: accounts[2].0.to_vec(),
+                mint,
+                amount,
+            })
+        }
+        // accounts: [source, mint, destination, authority, ..signers]
+        &TRANSFER_CHECKED if accounts.len() >= 4 => Some(TokenTransfer {
+            source: accounts[0].0.to_vec(),
+            mint: accounts[1].0.to_vec(),
+            destination: accounts[2].0.to_vec(),
+            authority: accounts[3].0.to_vec(),
+            amount,
+        }),
+        _ => None,
+    }
+}
+
+/// Mint, owner & post balance of the token accounts referenced by the transaction token balances.
+pub struct TokenAccounts {
+    accounts: HashMap<Vec<u8>, (Vec<u8>, Vec<u8>)>,
+    post_amounts: HashMap<Vec<u8>, u64>,
+}
+
+impl TokenAccounts {
+    pub fn new(tx: &ConfirmedTransaction) -> Self {
+        let mut accounts = HashMap::new();
+        let mut post_amounts = HashMap::new();
+        if let Some(meta) = tx.meta.as_ref() {
+            for balance in meta.pre_token_balances.iter().chain(meta.post_token_balances.iter()) {
+                let account = tx.account_at(balance.account_index as u8).0.to_vec();
+                let mint = base58::decode(&balance.mint).unwrap_or_default();
+                let owner = base58::decode(&balance.owner).unwrap_or_default();
+                accounts.insert(account, (mint, owner));
+            }
+            for balance in meta.post_token_balances.iter() {
+                let account = tx.account_at(balance.account_index as u8).0.to_vec();
+                if let Some(amount) = balance.ui_token_amount.as_ref().and_then(|ui| ui.amount.parse::<u64>().ok()) {
+                    post_amounts.insert(account, amount);
+                }
+            }
+        }
+        Self { accounts, post_amounts }
+    }
+
+    pub fn mint(&self, account: &[u8]) -> Option<&Vec<u8>> {
+        self.accounts.get(account).map(|(mint, _)| mint)
+    }
+
+    pub fn owner(&self, account: &[u8]) -> Option<&Vec<u8>> {
+        self.accounts.get(account).map(|(_, owner)| owner)
+    }
+
+    /// Balance of the token account at the end of the transaction.
+    pub fn post_amount(&self, account: &[u8]) -> Option<u64> {
+        self.post_amounts.get(account).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use substreams_solana::pb::sf::solana::r#type::v1::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message, MessageHeader, TokenBalance, Transaction, TransactionStatusMeta, UiTokenAmount,
+    };
+
+    const USER: u8 = 0;
+    const USER_TOKEN_A: u8 = 1;
+    const VAULT_A: u8 = 2;
+    const VAULT_B: u8 = 3;
+    const USER_TOKEN_B: u8 = 4;
+    const POOL_AUTHORITY: u8 = 5;
+    const MINT_A: u8 = 6;
+    const MINT_B: u8 = 7;
+    const TOKEN_PROGRAM: u8 = 8;
+    const AMM_PROGRAM: u8 = 9;
+
+    fn key(index: u8) -> Vec<u8> {
+        match index {
+            TOKEN_PROGRAM => SOLANA_TOKEN_PROGRAM_KEG.to_vec(),
+            _ => vec![index + 1; 32],
+        }
+    }
+
+    fn token_data(discriminator: u8, amount: u64) -> Vec<u8> {
+        let mut data = vec![discriminator];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    fn token_balance(account: u8, mint: u8, owner: u8, amount: u64) -> TokenBalance {
+        TokenBalance {
+            account_index: account as u32,
+            mint: base58::encode(key(mint)),
+            owner: base58::encode(key(owner)),
+            ui_token_amount: Some(UiTokenAmount {
+                amount: amount.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn inner(accounts: Vec<u8>, data: Vec<u8>) -> InnerInstruction {
+        InnerInstruction {
+            program_id_index: TOKEN_PROGRAM as u32,
+            accounts,
+            data,
+            stack_height: Some(2),
+        }
+    }
+
+    // User swaps 1_000 of mint A for 250 of mint B:
+    // - `Transfer` user token A -> vault A (mint resolved from the token balances)
+    // - `TransferChecked` vault B -> user token B, signed by the pool authority
+    fn swap_transaction() -> ConfirmedTransaction {
+        ConfirmedTransaction {
+            transaction: Some(Transaction {
+                signatures: vec![vec![0; 64]],
+                message: Some(Message {
+                    header: Some(MessageHeader {
+                        num_required_signatures: 1,
+                        ..Default::default()
+                    }),
+                    account_keys: (USER..=AMM_PROGRAM).map(key).collect(),
+                    instructions: vec![CompiledInstruction {
+                        program_id_index: AMM_PROGRAM as u32,
+                        accounts: vec![USER, USER_TOKEN_A, VAULT_A, VAULT_B, USER_TOKEN_B, POOL_AUTHORITY],
+                        data: vec![9],
+                    }],
+                    ..Default::default()
+                }),
+            }),
+            meta: Some(TransactionStatusMeta {
+                inner_instructions: vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![
+                        inner(vec![USER_TOKEN_A, VAULT_A, USER], token_data(TRANSFER, 1_000)),
+                        inner(vec![VAULT_B, MINT_B, USER_TOKEN_B, POOL_AUTHORITY], token_data(TRANSFER_CHECKED, 250)),
+                    ],
+                }],
+                post_token_balances: vec![
+                    token_balance(USER_TOKEN_A, MINT_A, USER, 0),
+                    token_balance(VAULT_A, MINT_A, POOL_AUTHORITY, 11_000),
+                    token_balance(VAULT_B, MINT_B, POOL_AUTHORITY, 4_750),
+                    token_balance(USER_TOKEN_B, MINT_B, USER, 250),
+                ],
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn inner_transfers_resolve_mint() {
+        let tx = swap_transaction();
+        let instruction = tx.walk_instructions().next().unwrap();
+        let transfers = get_inner_transfers(&instruction, &TokenAccounts::new(&tx));
+
+        assert_eq!(
+            transfers,
+            vec![
+                TokenTransfer {
+                    source: key(USER_TOKEN_A),
+                    destination: key(VAULT_A),
+                    authority: key(USER),
+                    mint: key(MINT_A),
+                    amount: 1_000,
+                },
+                TokenTransfer {
+                    source: key(VAULT_B),
+                    destination: key(USER_TOKEN_B),
+                    authority: key(POOL_AUTHORITY),
+                    mint: key(MINT_B),
+                    amount: 250,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn swap_amounts_from_transfers() {
+        let tx = swap_transaction();
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(
+            SwapAmounts::from_instruction(&instruction),
+            SwapAmounts {
+                user: key(USER),
+                input_mint: key(MINT_A),
+                input_amount: 1_000,
+                output_mint: key(MINT_B),
+                output_amount: 250,
+            }
+        );
+    }
+
+    #[test]
+    fn swap_amounts_without_transfers() {
+        let mut tx = swap_transaction();
+        tx.meta.as_mut().unwrap().inner_instructions.clear();
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(SwapAmounts::from_instruction(&instruction), SwapAmounts::default());
+    }
+
+    #[test]
+    fn transferred_amount_per_vault() {
+        let tx = swap_transaction();
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_A), TransferDirection::Received), 1_000);
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_A), TransferDirection::Sent), 0);
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_B), TransferDirection::Sent), 250);
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_B), TransferDirection::Received), 0);
+        assert_eq!(get_transferred_amount(&instruction, &key(POOL_AUTHORITY), TransferDirection::Sent), 0);
+    }
+
+    #[test]
+    fn transferred_amount_sums_transfers() {
+        let mut tx = swap_transaction();
+        let instructions = &mut tx.meta.as_mut().unwrap().inner_instructions[0].instructions;
+        instructions.push(inner(vec![USER_TOKEN_A, VAULT_A, USER], token_data(TRANSFER, 500)));
+        // refund sent back from the vault, not part of the received amount
+        instructions.push(inner(vec![VAULT_A, USER_TOKEN_A, POOL_AUTHORITY], token_data(TRANSFER, 100)));
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_A), TransferDirection::Received), 1_500);
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_A), TransferDirection::Sent), 100);
+    }
+
+    #[test]
+    fn transferred_amount_ignores_other_programs() {
+        let mut tx = swap_transaction();
+        for instruction in tx.meta.as_mut().unwrap().inner_instructions[0].instructions.iter_mut() {
+            instruction.program_id_index = AMM_PROGRAM as u32;
+        }
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(get_transferred_amount(&instruction, &key(VAULT_A), TransferDirection::Received), 0);
+    }
+
+    #[test]
+    fn token_accounts_post_amount() {
+        let tx = swap_transaction();
+        let accounts = TokenAccounts::new(&tx);
+
+        assert_eq!(accounts.owner(&key(VAULT_B)), Some(&key(POOL_AUTHORITY)));
+        assert_eq!(accounts.post_amount(&key(VAULT_B)), Some(4_750));
+        assert_eq!(accounts.post_amount(&key(MINT_A)), None);
+    }
+
+    #[test]
+    fn initialized_mint_from_inner_instructions() {
+        let mut tx = swap_transaction();
+        let instructions = &mut tx.meta.as_mut().unwrap().inner_instructions[0].instructions;
+        let mut data = vec![INITIALIZE_MINT2, 6];
+        data.extend_from_slice(&key(POOL_AUTHORITY));
+        data.push(0);
+        instructions.push(inner(vec![MINT_A], data));
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(get_initialized_mint(&instruction), Some((key(MINT_A), 6)));
+    }
+
+    #[test]
+    fn initialized_mint_without_initialize() {
+        let tx = swap_transaction();
+        let instruction = tx.walk_instructions().next().unwrap();
+
+        assert_eq!(get_initialized_mint(&instruction), None);
+    }
+}
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::aldrin::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -34,18 +35,73 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     if program_id != &aldrin::PROGRAM_ID { return None; }
 
     // Aldrin is an orderbook DEX — capture NewOrder instructions as swap-like events
-    match aldrin::instructions::unpack(ix.data()) {
+    let accounts = match aldrin::instructions::unpack(ix.data()) {
         Ok(aldrin::instructions::AldrinInstruction::NewOrder(_)) |
-        Ok(aldrin::instructions::AldrinInstruction::NewOrderV2(_)) |
-        Ok(aldrin::instructions::AldrinInstruction::NewOrderV3(_)) |
-        Ok(aldrin::instructions::AldrinInstruction::SendTake(_)) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
-                amount_in: 0,
-                minimum_amount_out: 0,
-            })),
-        }),
-        _ => None,
+        Ok(aldrin::instructions::AldrinInstruction::NewOrderV2(_)) => get_new_order_accounts(ix),
+        Ok(aldrin::instructions::AldrinInstruction::NewOrderV3(_)) => get_new_order_v3_accounts(ix),
+        Ok(aldrin::instructions::AldrinInstruction::SendTake(_)) => get_send_take_accounts(ix),
+        _ => return None,
+    };
+
+    Some(pb::Instruction {
+        program_id: program_id.to_vec(),
+        stack_height: ix.stack_height(),
+        parent_program_id: get_parent_program_id(ix),
+        outer_program_id: get_outer_program_id(ix),
+        instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
+            amount_in: 0,
+            minimum_amount_out: 0,
+            accounts: Some(accounts),
+            ..SwapAmounts::from_instruction(ix).into()
+        })),
+    })
+}
+
+// NewOrder / NewOrderV2
+// [market, open_orders, request_queue, order_payer, owner, coin_vault, pc_vault, token_program, rent, (fee_discount)]
+fn get_new_order_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        market: account_bytes(ix, 0).unwrap_or_default(),
+        open_orders: account_bytes(ix, 1).unwrap_or_default(),
+        order_payer: account_bytes(ix, 3).unwrap_or_default(),
+        owner: account_bytes(ix, 4).unwrap_or_default(),
+        coin_vault: account_bytes(ix, 5).unwrap_or_default(),
+        pc_vault: account_bytes(ix, 6).unwrap_or_default(),
+        token_program: account_bytes(ix, 7).unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+// NewOrderV3
+// [market, open_orders, request_queue, event_queue, bids, asks, order_payer, owner, coin_vault, pc_vault, token_program, rent, (fee_discount)]
+fn get_new_order_v3_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        market: account_bytes(ix, 0).unwrap_or_default(),
+        open_orders: account_bytes(ix, 1).unwrap_or_default(),
+        bids: account_bytes(ix, 4).unwrap_or_default(),
+        asks: account_bytes(ix, 5).unwrap_or_default(),
+        order_payer: account_bytes(ix, 6).unwrap_or_default(),
+        owner: account_bytes(ix, 7).unwrap_or_default(),
+        coin_vault: account_bytes(ix, 8).unwrap_or_default(),
+        pc_vault: account_bytes(ix, 9).unwrap_or_default(),
+        token_program: account_bytes(ix, 10).unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+// SendTake
+// [market, request_queue, event_queue, bids, asks, coin_wallet, pc_wallet, owner, coin_vault, pc_vault, token_program, vault_signer, (fee_discount)]
+fn get_send_take_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        market: account_bytes(ix, 0).unwrap_or_default(),
+        bids: account_bytes(ix, 3).unwrap_or_default(),
+        asks: account_bytes(ix, 4).unwrap_or_default(),
+        coin_wallet: account_bytes(ix, 5).unwrap_or_default(),
+        pc_wallet: account_bytes(ix, 6).unwrap_or_default(),
+        owner: account_bytes(ix, 7).unwrap_or_default(),
+        coin_vault: account_bytes(ix, 8).unwrap_or_default(),
+        pc_vault: account_bytes(ix, 9).unwrap_or_default(),
+        token_program: account_bytes(ix, 10).unwrap_or_default(),
+        ..Default::default()
     }
 }
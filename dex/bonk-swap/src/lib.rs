@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::bonk::swap::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -43,11 +44,14 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
     decode_swap_instruction(instruction.data()).map(|swap| pb::Instruction {
         program_id: BONKSWAP_PROGRAM_ID.to_vec(),
         stack_height: instruction.stack_height(),
+        parent_program_id: get_parent_program_id(instruction),
+        outer_program_id: get_outer_program_id(instruction),
         instruction: Some(pb::instruction::Instruction::SwapInstruction(pb::SwapInstruction {
             accounts: Some(get_swap_accounts(instruction)),
             delta_in: swap.delta_in,
             price_limit: swap.price_limit.to_string(),
             x_to_y: swap.x_to_y,
+            ..SwapAmounts::from_instruction(instruction).into()
         })),
     })
 }
@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
 use proto::pb::boop::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +7,10 @@ use substreams_solana::{
 };
 use substreams_solana_idls::boop;
 
+// Events which are not part of the IDL crate
+const TOKEN_CREATED_EVENT: [u8; 8] = [96, 122, 113, 138, 50, 227, 149, 57];
+const TOKEN_GRADUATED_EVENT: [u8; 8] = [73, 116, 111, 26, 92, 217, 146, 141];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -38,6 +42,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(boop::instructions::BoopInstruction::BuyToken(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Buy(pb::BuyTokenInstruction {
                 buy_amount: event.buy_amount,
                 amount_out_min: event.amount_out_min,
@@ -46,6 +52,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(boop::instructions::BoopInstruction::SellToken(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Sell(pb::SellTokenInstruction {
                 sell_amount: event.sell_amount,
                 amount_out_min: event.amount_out_min,
@@ -105,6 +113,39 @@ fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32)
                 recipient: event.recipient.to_bytes().to_vec(),
             })),
         }),
+        _ => parse_launch_event(data.as_slice()).map(|log| pb::Log {
+            program_id: program_id_bytes.to_vec(),
+            invoke_depth,
+            log: Some(log),
+        }),
+    }
+}
+
+fn parse_launch_event(data: &[u8]) -> Option<pb::log::Log> {
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+    match discriminator {
+        // TokenCreatedEvent { mint: Pubkey, name: String, symbol: String, uri: String }
+        TOKEN_CREATED_EVENT => {
+            let mut offset = 40;
+            Some(pb::log::Log::Created(pb::TokenCreatedEvent {
+                mint: data.get(8..40)?.to_vec(),
+                name: read_string(data, &mut offset)?,
+                symbol: read_string(data, &mut offset)?,
+                uri: read_string(data, &mut offset)?,
+            }))
+        }
+        // TokenGraduatedEvent { mint: Pubkey, .. }
+        TOKEN_GRADUATED_EVENT => Some(pb::log::Log::Graduated(pb::TokenGraduatedEvent {
+            mint: data.get(8..40)?.to_vec(),
+        })),
         _ => None,
     }
 }
+
+/// Borsh string: u32 length followed by the UTF-8 bytes.
+fn read_string(data: &[u8], offset: &mut usize) -> Option<String> {
+    let len = u32::from_le_bytes(data.get(*offset..*offset + 4)?.try_into().ok()?) as usize;
+    let bytes = data.get(*offset + 4..*offset + 4 + len)?;
+    *offset += 4 + len;
+    Some(String::from_utf8_lossy(bytes).to_string())
+}
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_id};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_id};
+use common::swaps::SwapAmounts;
 use proto::pb::byreal::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -38,9 +39,13 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(byreal::clmm::instructions::ByrealClmmInstruction::Swap(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                 amount_in: event.amount,
                 minimum_amount_out: event.other_amount_threshold,
+                accounts: Some(get_swap_accounts(ix)),
+                ..SwapAmounts::from_instruction(ix).into()
             })),
         }),
         _ => None,
@@ -62,3 +67,20 @@ fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec
     }
     logs
 }
+
+// Swap (Raydium CLMM layout)
+// [payer, amm_config, pool_state, input_token_account, output_token_account, input_vault, output_vault, observation_state, token_program, tick_array, ..remaining tick arrays]
+fn get_swap_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        payer: account_bytes(ix, 0).unwrap_or_default(),
+        amm_config: account_bytes(ix, 1).unwrap_or_default(),
+        pool_state: account_bytes(ix, 2).unwrap_or_default(),
+        input_token_account: account_bytes(ix, 3).unwrap_or_default(),
+        output_token_account: account_bytes(ix, 4).unwrap_or_default(),
+        input_vault: account_bytes(ix, 5).unwrap_or_default(),
+        output_vault: account_bytes(ix, 6).unwrap_or_default(),
+        observation_state: account_bytes(ix, 7).unwrap_or_default(),
+        token_program: account_bytes(ix, 8).unwrap_or_default(),
+        tick_array: account_bytes(ix, 9).unwrap_or_default(),
+    }
+}
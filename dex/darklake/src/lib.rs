@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
 use proto::pb::darklake::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -47,6 +47,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     amount_in: event.amount_in,
                     is_swap_x_to_y: event.is_swap_x_to_y,
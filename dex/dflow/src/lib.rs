@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::dflow::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -37,9 +38,12 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     Some(pb::Instruction {
         program_id: program_id.to_vec(),
         stack_height: ix.stack_height(),
+        parent_program_id: get_parent_program_id(ix),
+        outer_program_id: get_outer_program_id(ix),
         instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
             amount_in: 0,
             minimum_amount_out: 0,
+            ..SwapAmounts::from_instruction(ix).into()
         })),
     })
 }
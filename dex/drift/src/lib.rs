@@ -7,6 +7,13 @@ use substreams_solana::{
 };
 use substreams_solana_idls::drift;
 
+// Anchor event discriminators, sha256("event:<Name>")[..8]
+const ORDER_ACTION_RECORD: [u8; 8] = [224, 52, 67, 71, 194, 237, 109, 1];
+const FUNDING_RATE_RECORD: [u8; 8] = [68, 3, 255, 26, 133, 91, 147, 254];
+const LIQUIDATION_RECORD: [u8; 8] = [127, 17, 0, 108, 182, 13, 231, 53];
+const DEPOSIT_RECORD: [u8; 8] = [180, 241, 218, 207, 102, 135, 44, 134];
+const SETTLE_PNL_RECORD: [u8; 8] = [57, 68, 105, 26, 119, 198, 213, 89];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -78,6 +85,13 @@ fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec
 
 fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32) -> Option<pb::Log> {
     let data = parse_program_data(log_message)?;
+    if let Some(log) = parse_record(&data) {
+        return Some(pb::Log {
+            program_id: program_id_bytes.to_vec(),
+            invoke_depth,
+            log: Some(log),
+        });
+    }
     match drift::v2::events::unpack(data.as_slice()) {
         Ok(drift::v2::events::DriftEvent::SwapRecord(event)) => Some(pb::Log {
             program_id: program_id_bytes.to_vec(),
@@ -91,3 +105,575 @@ fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32)
         _ => None,
     }
 }
+
+fn parse_record(data: &[u8]) -> Option<pb::log::Log> {
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+    let mut r = Reader::new(&data[8..]);
+    match discriminator {
+        ORDER_ACTION_RECORD => Some(pb::log::Log::OrderAction(pb::OrderActionEvent {
+            ts: r.i64()?,
+            action: r.u8()? as u32,
+            action_explanation: r.u8()? as u32,
+            market_index: r.u16()? as u32,
+            market_type: r.u8()? as u32,
+            filler: r.option(Reader::pubkey)?,
+            filler_reward: r.option(Reader::u64)?,
+            fill_record_id: r.option(Reader::u64)?,
+            base_asset_amount_filled: r.option(Reader::u64)?,
+            quote_asset_amount_filled: r.option(Reader::u64)?,
+            taker_fee: r.option(Reader::u64)?,
+            maker_fee: r.option(Reader::i64)?,
+            referrer_reward: r.option(Reader::u32)?,
+            quote_asset_amount_surplus: r.option(Reader::i64)?,
+            spot_fulfillment_method_fee: r.option(Reader::u64)?,
+            taker: r.option(Reader::pubkey)?,
+            taker_order_id: r.option(Reader::u32)?,
+            taker_order_direction: r.option(|r| r.u8().map(u32::from))?,
+            taker_order_base_asset_amount: r.option(Reader::u64)?,
+            taker_order_cumulative_base_asset_amount_filled: r.option(Reader::u64)?,
+            taker_order_cumulative_quote_asset_amount_filled: r.option(Reader::u64)?,
+            maker: r.option(Reader::pubkey)?,
+            maker_order_id: r.option(Reader::u32)?,
+            maker_order_direction: r.option(|r| r.u8().map(u32::from))?,
+            maker_order_base_asset_amount: r.option(Reader::u64)?,
+            maker_order_cumulative_base_asset_amount_filled: r.option(Reader::u64)?,
+            maker_order_cumulative_quote_asset_amount_filled: r.option(Reader::u64)?,
+            oracle_price: r.i64()?,
+        })),
+        FUNDING_RATE_RECORD => Some(pb::log::Log::FundingRate(pb::FundingRateEvent {
+            ts: r.i64()?,
+            record_id: r.u64()?,
+            market_index: r.u16()? as u32,
+            funding_rate: r.i64()?,
+            funding_rate_long: r.i128()?.to_string(),
+            funding_rate_short: r.i128()?.to_string(),
+            cumulative_funding_rate_long: r.i128()?.to_string(),
+            cumulative_funding_rate_short: r.i128()?.to_string(),
+            oracle_price_twap: r.i64()?,
+            mark_price_twap: r.u64()?,
+            period_revenue: r.i64()?,
+            base_asset_amount_with_amm: r.i128()?.to_string(),
+            base_asset_amount_with_unsettled_lp: r.i128()?.to_string(),
+        })),
+        LIQUIDATION_RECORD => Some(pb::log::Log::Liquidation(pb::LiquidationEvent {
+            ts: r.i64()?,
+            liquidation_type: r.u8()? as u32,
+            user: r.pubkey()?,
+            liquidator: r.pubkey()?,
+            margin_requirement: r.u128()?.to_string(),
+            total_collateral: r.i128()?.to_string(),
+            margin_freed: r.u64()?,
+            liquidation_id: r.u16()? as u32,
+            bankrupt: r.u8()? != 0,
+            canceled_order_ids: (0..r.u32()?).map(|_| r.u32()).collect::<Option<_>>()?,
+            // LiquidatePerpRecord
+            liquidate_perp: Some(pb::LiquidatePerp {
+                market_index: r.u16()? as u32,
+                oracle_price: r.i64()?,
+                base_asset_amount: r.i64()?,
+                quote_asset_amount: r.i64()?,
+                lp_shares: r.u64()?,
+                fill_record_id: r.u64()?,
+                user_order_id: r.u32()?,
+                liquidator_order_id: r.u32()?,
+                liquidator_fee: r.u64()?,
+                if_fee: r.u64()?,
+            }),
+            // LiquidateSpotRecord
+            liquidate_spot: Some(pb::LiquidateSpot {
+                asset_market_index: r.u16()? as u32,
+                asset_price: r.i64()?,
+                asset_transfer: r.u128()?.to_string(),
+                liability_market_index: r.u16()? as u32,
+                liability_price: r.i64()?,
+                liability_transfer: r.u128()?.to_string(),
+                if_fee: r.u64()?,
+            }),
+            // LiquidateBorrowForPerpPnlRecord
+            liquidate_borrow_for_perp_pnl: Some(pb::LiquidateBorrowForPerpPnl {
+                perp_market_index: r.u16()? as u32,
+                market_oracle_price: r.i64()?,
+                pnl_transfer: r.u128()?.to_string(),
+                liability_market_index: r.u16()? as u32,
+                liability_price: r.i64()?,
+                liability_transfer: r.u128()?.to_string(),
+            }),
+            // LiquidatePerpPnlForDepositRecord
+            liquidate_perp_pnl_for_deposit: Some(pb::LiquidatePerpPnlForDeposit {
+                perp_market_index: r.u16()? as u32,
+                market_oracle_price: r.i64()?,
+                pnl_transfer: r.u128()?.to_string(),
+                asset_market_index: r.u16()? as u32,
+                asset_price: r.i64()?,
+                asset_transfer: r.u128()?.to_string(),
+            }),
+            // PerpBankruptcyRecord
+            perp_bankruptcy: Some(pb::PerpBankruptcy {
+                market_index: r.u16()? as u32,
+                pnl: r.i128()?.to_string(),
+                if_payment: r.u128()?.to_string(),
+                clawback_user: r.option(Reader::pubkey)?,
+                clawback_user_payment: r.option(Reader::u128)?.map(|payment| payment.to_string()),
+                cumulative_funding_rate_delta: r.i128()?.to_string(),
+            }),
+            // SpotBankruptcyRecord
+            spot_bankruptcy: Some(pb::SpotBankruptcy {
+                market_index: r.u16()? as u32,
+                borrow_amount: r.u128()?.to_string(),
+                if_payment: r.u128()?.to_string(),
+                cumulative_deposit_interest_delta: r.u128()?.to_string(),
+            }),
+        })),
+        DEPOSIT_RECORD => Some(pb::log::Log::Deposit(pb::DepositEvent {
+            ts: r.i64()?,
+            user_authority: r.pubkey()?,
+            user: r.pubkey()?,
+            direction: r.u8()? as u32,
+            deposit_record_id: r.u64()?,
+            amount: r.u64()?,
+            market_index: r.u16()? as u32,
+            oracle_price: r.i64()?,
+            market_deposit_balance: r.u128()?.to_string(),
+            market_withdraw_balance: r.u128()?.to_string(),
+            market_cumulative_deposit_interest: r.u128()?.to_string(),
+            market_cumulative_borrow_interest: r.u128()?.to_string(),
+            total_deposits_after: r.u64()?,
+            total_withdraws_after: r.u64()?,
+            explanation: r.u8()? as u32,
+            // missing from older records
+            transfer_user: match r.is_empty() {
+                true => None,
+                false => r.option(Reader::pubkey)?,
+            },
+        })),
+        SETTLE_PNL_RECORD => Some(pb::log::Log::SettlePnl(pb::SettlePnlEvent {
+            ts: r.i64()?,
+            user: r.pubkey()?,
+            market_index: r.u16()? as u32,
+            pnl: r.i128()?.to_string(),
+            base_asset_amount: r.i64()?,
+            quote_asset_amount_after: r.i64()?,
+            quote_entry_amount: r.i64()?,
+            settle_price: r.i64()?,
+            explanation: r.u8()? as u32,
+        })),
+        _ => None,
+    }
+}
+
+/// Sequential Borsh reader, the Drift records are too long and have too many optional fields for fixed offsets.
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// No bytes left, ex: fields appended to the record after it was emitted.
+    fn is_empty(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let bytes = self.data.get(self.offset..self.offset + N)?.try_into().ok()?;
+        self.offset += N;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|bytes| bytes[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take().map(u64::from_le_bytes)
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        self.take().map(i64::from_le_bytes)
+    }
+
+    fn u128(&mut self) -> Option<u128> {
+        self.take().map(u128::from_le_bytes)
+    }
+
+    fn i128(&mut self) -> Option<i128> {
+        self.take().map(i128::from_le_bytes)
+    }
+
+    fn pubkey(&mut self) -> Option<Vec<u8>> {
+        self.take::<32>().map(|bytes| bytes.to_vec())
+    }
+
+    /// Borsh `Option<T>`: `None` when truncated, `Some(None)` when the value is absent.
+    fn option<T>(&mut self, read: impl FnOnce(&mut Self) -> Option<T>) -> Option<Option<T>> {
+        match self.u8()? {
+            0 => Some(None),
+            _ => read(self).map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: [u8; 32] = [1; 32];
+    const AUTHORITY: [u8; 32] = [2; 32];
+    const COUNTERPARTY: [u8; 32] = [3; 32];
+    const NONE: &[u8] = &[0];
+
+    fn bytes(parts: &[&[u8]]) -> Vec<u8> {
+        parts.concat()
+    }
+
+    // Borsh `Some(value)`
+    fn some(value: &[u8]) -> Vec<u8> {
+        bytes(&[&[1], value])
+    }
+
+    #[test]
+    fn reader_primitives() {
+        let data = bytes(&[&[7], &513u16.to_le_bytes(), &(-5i64).to_le_bytes(), &u128::MAX.to_le_bytes(), &USER]);
+        let mut r = Reader::new(&data);
+        assert_eq!(r.u8(), Some(7));
+        assert_eq!(r.u16(), Some(513));
+        assert_eq!(r.i64(), Some(-5));
+        assert_eq!(r.u128(), Some(u128::MAX));
+        assert!(!r.is_empty());
+        assert_eq!(r.pubkey(), Some(USER.to_vec()));
+        assert!(r.is_empty());
+        assert_eq!(r.u8(), None);
+    }
+
+    #[test]
+    fn reader_truncated() {
+        let mut r = Reader::new(&[1, 2, 3]);
+        assert_eq!(r.u32(), None);
+        // a failed read does not consume the bytes
+        assert_eq!(r.u16(), Some(513));
+    }
+
+    #[test]
+    fn reader_option() {
+        let data = bytes(&[NONE, &some(&42u64.to_le_bytes()), &some(&[9, 9])]);
+        let mut r = Reader::new(&data);
+        assert_eq!(r.option(Reader::u64), Some(None));
+        assert_eq!(r.option(Reader::u64), Some(Some(42)));
+        // tag present, value truncated
+        assert_eq!(r.option(Reader::u64), None);
+        // no tag left
+        assert_eq!(Reader::new(&[]).option(Reader::u64), None);
+    }
+
+    #[test]
+    fn order_action_record() {
+        // taker fill of 1 SOL-PERP at 150 USDC, without filler
+        let data = bytes(&[
+            &ORDER_ACTION_RECORD,
+            &1_700_000_000i64.to_le_bytes(),
+            &[2, 0],
+            &0u16.to_le_bytes(),
+            &[1],
+            NONE,
+            NONE,
+            &some(&77u64.to_le_bytes()),
+            &some(&1_000_000_000u64.to_le_bytes()),
+            &some(&150_000_000u64.to_le_bytes()),
+            &some(&52_500u64.to_le_bytes()),
+            &some(&(-3_000i64).to_le_bytes()),
+            NONE,
+            NONE,
+            NONE,
+            &some(&USER),
+            &some(&12u32.to_le_bytes()),
+            &some(&[0]),
+            &some(&1_000_000_000u64.to_le_bytes()),
+            &some(&1_000_000_000u64.to_le_bytes()),
+            &some(&150_000_000u64.to_le_bytes()),
+            &some(&COUNTERPARTY),
+            &some(&34u32.to_le_bytes()),
+            &some(&[1]),
+            &some(&2_000_000_000u64.to_le_bytes()),
+            &some(&1_000_000_000u64.to_le_bytes()),
+            &some(&150_000_000u64.to_le_bytes()),
+            &150_000_000i64.to_le_bytes(),
+        ]);
+        assert_eq!(
+            parse_record(&data),
+            Some(pb::log::Log::OrderAction(pb::OrderActionEvent {
+                ts: 1_700_000_000,
+                action: 2,
+                action_explanation: 0,
+                market_index: 0,
+                market_type: 1,
+                filler: None,
+                filler_reward: None,
+                fill_record_id: Some(77),
+                base_asset_amount_filled: Some(1_000_000_000),
+                quote_asset_amount_filled: Some(150_000_000),
+                taker_fee: Some(52_500),
+                maker_fee: Some(-3_000),
+                referrer_reward: None,
+                quote_asset_amount_surplus: None,
+                spot_fulfillment_method_fee: None,
+                taker: Some(USER.to_vec()),
+                taker_order_id: Some(12),
+                taker_order_direction: Some(0),
+                taker_order_base_asset_amount: Some(1_000_000_000),
+                taker_order_cumulative_base_asset_amount_filled: Some(1_000_000_000),
+                taker_order_cumulative_quote_asset_amount_filled: Some(150_000_000),
+                maker: Some(COUNTERPARTY.to_vec()),
+                maker_order_id: Some(34),
+                maker_order_direction: Some(1),
+                maker_order_base_asset_amount: Some(2_000_000_000),
+                maker_order_cumulative_base_asset_amount_filled: Some(1_000_000_000),
+                maker_order_cumulative_quote_asset_amount_filled: Some(150_000_000),
+                oracle_price: 150_000_000,
+            }))
+        );
+        // truncated oracle price
+        assert_eq!(parse_record(&data[..data.len() - 4]), None);
+    }
+
+    #[test]
+    fn funding_rate_record() {
+        let data = bytes(&[
+            &FUNDING_RATE_RECORD,
+            &1_700_000_000i64.to_le_bytes(),
+            &501u64.to_le_bytes(),
+            &1u16.to_le_bytes(),
+            &(-12_000i64).to_le_bytes(),
+            &(-12_000i128).to_le_bytes(),
+            &(-11_500i128).to_le_bytes(),
+            &1_234_567_890i128.to_le_bytes(),
+            &(-1_234_000_000i128).to_le_bytes(),
+            &2_500_000_000i64.to_le_bytes(),
+            &2_499_000_000u64.to_le_bytes(),
+            &(-42i64).to_le_bytes(),
+            &(-8_000_000_000i128).to_le_bytes(),
+            &0i128.to_le_bytes(),
+        ]);
+        assert_eq!(
+            parse_record(&data),
+            Some(pb::log::Log::FundingRate(pb::FundingRateEvent {
+                ts: 1_700_000_000,
+                record_id: 501,
+                market_index: 1,
+                funding_rate: -12_000,
+                funding_rate_long: "-12000".to_string(),
+                funding_rate_short: "-11500".to_string(),
+                cumulative_funding_rate_long: "1234567890".to_string(),
+                cumulative_funding_rate_short: "-1234000000".to_string(),
+                oracle_price_twap: 2_500_000_000,
+                mark_price_twap: 2_499_000_000,
+                period_revenue: -42,
+                base_asset_amount_with_amm: "-8000000000".to_string(),
+                base_asset_amount_with_unsettled_lp: "0".to_string(),
+            }))
+        );
+    }
+
+    fn liquidation_record(liquidation_type: u8) -> Vec<u8> {
+        bytes(&[
+            &LIQUIDATION_RECORD,
+            &1_700_000_000i64.to_le_bytes(),
+            &[liquidation_type],
+            &USER,
+            &COUNTERPARTY,
+            &5_000_000u128.to_le_bytes(),
+            &(-1_000_000i128).to_le_bytes(),
+            &250_000u64.to_le_bytes(),
+            &9u16.to_le_bytes(),
+            &[0],
+            &2u32.to_le_bytes(),
+            &12u32.to_le_bytes(),
+            &13u32.to_le_bytes(),
+            // LiquidatePerpRecord
+            &0u16.to_le_bytes(),
+            &150_000_000i64.to_le_bytes(),
+            &(-1_000_000_000i64).to_le_bytes(),
+            &150_000_000i64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &88u64.to_le_bytes(),
+            &12u32.to_le_bytes(),
+            &0u32.to_le_bytes(),
+            &1_500_000u64.to_le_bytes(),
+            &750_000u64.to_le_bytes(),
+            // LiquidateSpotRecord
+            &1u16.to_le_bytes(),
+            &150_000_000i64.to_le_bytes(),
+            &2_000_000_000u128.to_le_bytes(),
+            &0u16.to_le_bytes(),
+            &1_000_000i64.to_le_bytes(),
+            &300_000_000u128.to_le_bytes(),
+            &4_000u64.to_le_bytes(),
+            // following records are not decoded
+            &[0; 64],
+        ])
+    }
+
+    #[test]
+    fn liquidation_record_perp() {
+        let Some(pb::log::Log::Liquidation(event)) = parse_record(&liquidation_record(0)) else {
+            panic!("expected a liquidation");
+        };
+        assert_eq!(event.liquidation_type, 0);
+        assert_eq!(event.user, USER.to_vec());
+        assert_eq!(event.liquidator, COUNTERPARTY.to_vec());
+        assert_eq!(event.margin_requirement, "5000000");
+        assert_eq!(event.total_collateral, "-1000000");
+        assert_eq!(event.margin_freed, 250_000);
+        assert_eq!(event.liquidation_id, 9);
+        assert!(!event.bankrupt);
+        assert_eq!(event.canceled_order_ids, vec![12, 13]);
+        assert_eq!(
+            event.liquidate_perp,
+            Some(pb::LiquidatePerp {
+                market_index: 0,
+                oracle_price: 150_000_000,
+                base_asset_amount: -1_000_000_000,
+                quote_asset_amount: 150_000_000,
+                lp_shares: 0,
+                fill_record_id: 88,
+                user_order_id: 12,
+                liquidator_order_id: 0,
+                liquidator_fee: 1_500_000,
+                if_fee: 750_000,
+            })
+        );
+    }
+
+    #[test]
+    fn liquidation_record_spot() {
+        let Some(pb::log::Log::Liquidation(event)) = parse_record(&liquidation_record(1)) else {
+            panic!("expected a liquidation");
+        };
+        assert_eq!(event.liquidation_type, 1);
+        assert_eq!(
+            event.liquidate_spot,
+            Some(pb::LiquidateSpot {
+                asset_market_index: 1,
+                asset_price: 150_000_000,
+                asset_transfer: "2000000000".to_string(),
+                liability_market_index: 0,
+                liability_price: 1_000_000,
+                liability_transfer: "300000000".to_string(),
+                if_fee: 4_000,
+            })
+        );
+    }
+
+    fn deposit_record() -> Vec<u8> {
+        bytes(&[
+            &DEPOSIT_RECORD,
+            &1_700_000_000i64.to_le_bytes(),
+            &AUTHORITY,
+            &USER,
+            &[1],
+            &310u64.to_le_bytes(),
+            &100_000_000u64.to_le_bytes(),
+            &0u16.to_le_bytes(),
+            &1_000_000i64.to_le_bytes(),
+            &7_000_000_000u128.to_le_bytes(),
+            &3_000_000_000u128.to_le_bytes(),
+            &10_100_000_000u128.to_le_bytes(),
+            &10_300_000_000u128.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &100_000_000u64.to_le_bytes(),
+            &[0],
+        ])
+    }
+
+    #[test]
+    fn deposit_record_without_transfer_user() {
+        // older records end with the explanation
+        assert_eq!(
+            parse_record(&deposit_record()),
+            Some(pb::log::Log::Deposit(pb::DepositEvent {
+                ts: 1_700_000_000,
+                user_authority: AUTHORITY.to_vec(),
+                user: USER.to_vec(),
+                direction: 1,
+                deposit_record_id: 310,
+                amount: 100_000_000,
+                market_index: 0,
+                oracle_price: 1_000_000,
+                market_deposit_balance: "7000000000".to_string(),
+                market_withdraw_balance: "3000000000".to_string(),
+                market_cumulative_deposit_interest: "10100000000".to_string(),
+                market_cumulative_borrow_interest: "10300000000".to_string(),
+                total_deposits_after: 0,
+                total_withdraws_after: 100_000_000,
+                explanation: 0,
+                transfer_user: None,
+            }))
+        );
+        let data = bytes(&[&deposit_record(), NONE]);
+        let Some(pb::log::Log::Deposit(event)) = parse_record(&data) else {
+            panic!("expected a deposit");
+        };
+        assert_eq!(event.transfer_user, None);
+    }
+
+    #[test]
+    fn deposit_record_with_transfer_user() {
+        let data = bytes(&[&deposit_record(), &some(&COUNTERPARTY)]);
+        let Some(pb::log::Log::Deposit(event)) = parse_record(&data) else {
+            panic!("expected a deposit");
+        };
+        assert_eq!(event.transfer_user, Some(COUNTERPARTY.to_vec()));
+    }
+
+    #[test]
+    fn deposit_record_truncated_transfer_user() {
+        let data = bytes(&[&deposit_record(), &some(&COUNTERPARTY[..16])]);
+        assert_eq!(parse_record(&data), None);
+    }
+
+    #[test]
+    fn settle_pnl_record() {
+        let data = bytes(&[
+            &SETTLE_PNL_RECORD,
+            &1_700_000_000i64.to_le_bytes(),
+            &USER,
+            &0u16.to_le_bytes(),
+            &(-25_000_000i128).to_le_bytes(),
+            &1_000_000_000i64.to_le_bytes(),
+            &(-150_000_000i64).to_le_bytes(),
+            &(-125_000_000i64).to_le_bytes(),
+            &150_000_000i64.to_le_bytes(),
+            &[0],
+        ]);
+        assert_eq!(
+            parse_record(&data),
+            Some(pb::log::Log::SettlePnl(pb::SettlePnlEvent {
+                ts: 1_700_000_000,
+                user: USER.to_vec(),
+                market_index: 0,
+                pnl: "-25000000".to_string(),
+                base_asset_amount: 1_000_000_000,
+                quote_asset_amount_after: -150_000_000,
+                quote_entry_amount: -125_000_000,
+                settle_price: 150_000_000,
+                explanation: 0,
+            }))
+        );
+    }
+
+    #[test]
+    fn unknown_record() {
+        assert_eq!(parse_record(&[0; 16]), None);
+        assert_eq!(parse_record(&DEPOSIT_RECORD[..4]), None);
+    }
+}
@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
 use proto::pb::dumpfun::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -46,6 +46,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(dumpfun::instructions::DumpfunInstruction::BuyExactTokens(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::BuyExactTokens(pb::BuyExactTokensInstruction {
                 token_out: event.token_out,
                 max_sol_in: event.max_sol_in,
@@ -54,6 +56,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(dumpfun::instructions::DumpfunInstruction::BuyTokensWithExactSol(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::BuyTokensWithExactSol(pb::BuyTokensWithExactSolInstruction {
                 sol_in: event.sol_in,
                 min_token_out: event.min_token_out,
@@ -62,6 +66,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(dumpfun::instructions::DumpfunInstruction::SellExactTokens(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::SellExactTokens(pb::SellExactTokensInstruction {
                 token_in: event.token_in,
                 min_sol_out: event.min_sol_out,
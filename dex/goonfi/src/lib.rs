@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
 use proto::pb::goonfi::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -45,6 +45,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(goonfi::instructions::GoonFiInstruction::Swap(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                 is_bid: event.is_bid,
             })),
@@ -1,4 +1,8 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{
+    get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data,
+    parse_program_id, InvokeStack,
+};
+use common::swaps::get_initialized_mint;
 use proto::pb::heaven::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -42,38 +46,48 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
-    match heaven::instructions::unpack(ix.data()) {
-        Ok(heaven::instructions::HeavenInstruction::Sell(event)) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::Sell(pb::SellInstruction {
-                amount_in: event.amount_in,
-                minimum_amount_out: event.minimum_amount_out,
-                encoded_user_defined_event_data: event.encoded_user_defined_event_data,
-            })),
+    let instruction = match heaven::instructions::unpack(ix.data()) {
+        Ok(heaven::instructions::HeavenInstruction::Sell(event)) => pb::instruction::Instruction::Sell(pb::SellInstruction {
+            amount_in: event.amount_in,
+            minimum_amount_out: event.minimum_amount_out,
+            encoded_user_defined_event_data: event.encoded_user_defined_event_data,
         }),
-        _ => None,
-    }
+        // launches are not decoded by the IDL crate, they are detected from the mint initialized by the instruction
+        _ => {
+            let (mint, decimals) = get_initialized_mint(ix)?;
+            pb::instruction::Instruction::Create(pb::CreateInstruction { mint, decimals })
+        }
+    };
+
+    Some(pb::Instruction {
+        program_id: program_id.to_vec(),
+        stack_height: ix.stack_height(),
+        parent_program_id: get_parent_program_id(ix),
+        outer_program_id: get_outer_program_id(ix),
+        instruction: Some(instruction),
+    })
 }
 
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
+    let mut invoke_stack = InvokeStack::default();
 
     for log_message in tx_meta.log_messages.iter() {
+        invoke_stack.update(log_message);
         let match_program_id = parse_program_id(log_message).map_or(false, |id| id == program_id_bytes.to_vec());
 
         if is_invoke(log_message) && match_program_id {
             if let Some(invoke_depth) = parse_invoke_depth(log_message) {
                 is_invoked = true;
-                if let Some(log_data) = parse_log_data(log_message, program_id_bytes, invoke_depth) {
+                if let Some(log_data) = parse_log_data(log_message, program_id_bytes, invoke_depth, &invoke_stack) {
                     logs.push(log_data);
                 }
             }
         } else if match_program_id && (is_success(log_message) || is_failed(log_message)) {
             is_invoked = false;
         } else if is_invoked {
-            if let Some(log_data) = parse_log_data(log_message, program_id_bytes, 0) {
+            if let Some(log_data) = parse_log_data(log_message, program_id_bytes, 0, &invoke_stack) {
                 logs.push(log_data);
             }
         }
@@ -82,12 +96,14 @@ fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec
     logs
 }
 
-fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32) -> Option<pb::Log> {
+fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32, invoke_stack: &InvokeStack) -> Option<pb::Log> {
     let data = parse_program_data(log_message)?;
     match heaven::logs::unpack(data.as_slice()) {
         Ok(heaven::logs::HeavenLog::Sell(event)) => Some(pb::Log {
             program_id: program_id_bytes.to_vec(),
             invoke_depth,
+            parent_program_id: invoke_stack.parent_program_id(),
+            outer_program_id: invoke_stack.outer_program_id(),
             log: Some(pb::log::Log::Sell(pb::SellLog {
                 user: event.user.to_bytes().to_vec(),
                 mint: event.mint.to_bytes().to_vec(),
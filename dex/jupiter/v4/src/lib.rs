@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id, InvokeStack};
 use proto::pb::jupiter::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, TransactionStatusMeta};
@@ -34,14 +34,19 @@ fn process_logs(tx_meta: &TransactionStatusMeta) -> Vec<pb::Instruction> {
     let mut instructions = Vec::new();
     let mut is_invoked = false;
     let mut current_stack_height = 0;
+    let mut invocation_index = 0; // V4 routes are not decoded, their events are grouped by invocation
+    let mut invoke_stack = InvokeStack::default(); // resolves the parent & outermost programs
 
     for log_message in &tx_meta.log_messages {
-        let is_jupiter_program = parse_program_id(log_message).map_or(false, |id| id == jupiter::v4::PROGRAM_ID.to_vec());
+        let program_id = parse_program_id(log_message);
+        let is_jupiter_program = program_id.as_ref().map_or(false, |id| id == &jupiter::v4::PROGRAM_ID.to_vec());
+        invoke_stack.update(log_message);
 
         // Track program invocation and stack height
         if is_invoke(log_message) && is_jupiter_program {
             if let Some(height) = parse_invoke_depth(log_message) {
                 current_stack_height = height - 1; // stack height is 1-based
+                invocation_index += 1;
                 is_invoked = true;
 
                 // Continue to next log message as invoke logs don't contain program data
@@ -58,7 +63,10 @@ fn process_logs(tx_meta: &TransactionStatusMeta) -> Vec<pb::Instruction> {
         }
 
         // Try to parse program data from log
-        if let Some(instruction) = parse_log_instruction(log_message, current_stack_height) {
+        if let Some(mut instruction) = parse_log_instruction(log_message, current_stack_height) {
+            instruction.parent_program_id = invoke_stack.parent_program_id();
+            instruction.outer_program_id = invoke_stack.outer_program_id();
+            instruction.invocation_index = invocation_index;
             instructions.push(instruction);
         }
     }
@@ -74,6 +82,9 @@ fn parse_log_instruction(log_message: &str, stack_height: u32) -> Option<pb::Ins
         Ok(jupiter::v4::events::JupiterV4Event::Swap(event)) => Some(pb::Instruction {
             program_id: jupiter::v4::PROGRAM_ID.to_vec(),
             stack_height,
+            parent_program_id: vec![],
+            outer_program_id: vec![],
+            invocation_index: 0,
             instruction: Some(pb::instruction::Instruction::SwapEvent(pb::SwapEvent {
                 amm: event.amm.to_bytes().to_vec(),
                 input_mint: event.input_mint.to_bytes().to_vec(),
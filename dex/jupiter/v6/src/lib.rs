@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
 use proto::pb::jupiter::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +7,8 @@ use substreams_solana::{
 };
 use substreams_solana_idls::jupiter;
 
+mod routes;
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -37,10 +39,24 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
         return None;
     }
 
+    if let Some(route) = process_route(instruction) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: instruction.stack_height(),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
+            invocation_index: 0,
+            instruction: Some(route),
+        });
+    }
+
     match jupiter::v6::events::unpack(instruction.data()) {
         Ok(jupiter::v6::events::JupiterV6Event::Swap(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: instruction.stack_height(),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
+            invocation_index: 0,
             instruction: Some(pb::instruction::Instruction::SwapEvent(pb::SwapEvent {
                 amm: event.amm.to_bytes().to_vec(),
                 input_mint: event.input_mint.to_bytes().to_vec(),
@@ -52,12 +68,82 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
         Ok(jupiter::v6::events::JupiterV6Event::Fee(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: instruction.stack_height(),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
+            invocation_index: 0,
             instruction: Some(pb::instruction::Instruction::FeeEvent(pb::FeeEvent {
                 account: event.account.to_bytes().to_vec(),
                 mint: event.mint.to_bytes().to_vec(),
                 amount: event.amount,
             })),
         }),
+        // Events are emitted through self CPI, any other Jupiter instruction is kept to delimit the routes
+        _ if get_parent_program_id(instruction) != jupiter::v6::PROGRAM_ID.to_vec() => Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: instruction.stack_height(),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
+            invocation_index: 0,
+            instruction: Some(process_other(instruction)),
+        }),
         _ => None,
     }
 }
+
+fn process_route(instruction: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = instruction.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        routes::ROUTE | routes::SHARED_ACCOUNTS_ROUTE => {
+            let shared_accounts = discriminator == routes::SHARED_ACCOUNTS_ROUTE;
+            let args = routes::decode_route_args(data, shared_accounts)?;
+            let trade = routes::get_trade(instruction, &args.route_plan);
+            let route = pb::RouteInstruction {
+                accounts: Some(routes::get_route_accounts(instruction)),
+                id: args.id,
+                route_plan: args.route_plan,
+                in_amount: args.amount,
+                quoted_out_amount: args.quoted_amount,
+                slippage_bps: args.slippage_bps,
+                platform_fee_bps: args.platform_fee_bps,
+                trade,
+            };
+            Some(match shared_accounts {
+                true => pb::instruction::Instruction::SharedAccountsRoute(route),
+                false => pb::instruction::Instruction::Route(route),
+            })
+        }
+        routes::EXACT_OUT_ROUTE | routes::SHARED_ACCOUNTS_EXACT_OUT_ROUTE => {
+            let shared_accounts = discriminator == routes::SHARED_ACCOUNTS_EXACT_OUT_ROUTE;
+            let args = routes::decode_route_args(data, shared_accounts)?;
+            let trade = routes::get_trade(instruction, &args.route_plan);
+            let route = pb::ExactOutRouteInstruction {
+                accounts: Some(routes::get_route_accounts(instruction)),
+                id: args.id,
+                route_plan: args.route_plan,
+                out_amount: args.amount,
+                quoted_in_amount: args.quoted_amount,
+                slippage_bps: args.slippage_bps,
+                platform_fee_bps: args.platform_fee_bps,
+                trade,
+            };
+            Some(match shared_accounts {
+                true => pb::instruction::Instruction::SharedAccountsExactOutRoute(route),
+                false => pb::instruction::Instruction::ExactOutRoute(route),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn process_other(instruction: &InstructionView) -> pb::instruction::Instruction {
+    let discriminator = instruction.data().get(0..8).unwrap_or_default();
+    let route = routes::undecoded_route_name(discriminator);
+
+    pb::instruction::Instruction::Other(pb::OtherInstruction {
+        discriminator: discriminator.to_vec(),
+        route: route.unwrap_or_default().to_string(),
+        trade: route.and_then(|_| routes::get_trade(instruction, &[])),
+    })
+}
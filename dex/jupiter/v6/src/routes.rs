@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use proto::pb::jupiter::v1 as pb;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana_idls::jupiter;
+
+pub const ROUTE: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+pub const SHARED_ACCOUNTS_ROUTE: [u8; 8] = [193, 32, 155, 51, 65, 214, 156, 129];
+pub const EXACT_OUT_ROUTE: [u8; 8] = [208, 51, 239, 151, 123, 43, 237, 92];
+pub const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: [u8; 8] = [176, 209, 105, 168, 154, 125, 69, 62];
+
+// Route instructions whose arguments are not decoded, only their trade is
+const UNDECODED_ROUTES: [([u8; 8], &str); 6] = [
+    ([150, 86, 71, 116, 167, 93, 14, 104], "route_with_token_ledger"),
+    ([230, 121, 143, 80, 119, 159, 106, 170], "shared_accounts_route_with_token_ledger"),
+    ([187, 100, 250, 204, 49, 196, 175, 20], "route_v2"),
+    ([209, 152, 83, 147, 124, 254, 216, 233], "shared_accounts_route_v2"),
+    ([157, 138, 184, 82, 21, 244, 243, 36], "exact_out_route_v2"),
+    ([53, 96, 229, 202, 216, 187, 250, 24], "shared_accounts_exact_out_route_v2"),
+];
+
+// amount (u64) + quoted amount (u64) + slippage_bps (u16) + platform_fee_bps (u8)
+const ROUTE_ARGS_LEN: usize = 19;
+
+/// Arguments shared by every route instruction, `amount` & `quoted_amount` depend on the route direction.
+pub struct RouteArgs {
+    pub id: u32,
+    pub route_plan: Vec<pb::RoutePlanStep>,
+    pub amount: u64,
+    pub quoted_amount: u64,
+    pub slippage_bps: u32,
+    pub platform_fee_bps: u32,
+}
+
+/// Decodes `[discriminator][id: u8 (shared accounts only)][route_plan: Vec<RoutePlanStep>][amount][quoted_amount][slippage_bps][platform_fee_bps]`.
+///
+/// The fixed size arguments are read from the end of the data, so that an unsupported swap variant
+/// in the route plan only drops the route plan.
+pub fn decode_route_args(data: &[u8], shared_accounts: bool) -> Option<RouteArgs> {
+    let mut offset = 8;
+    let id = if shared_accounts {
+        offset += 1;
+        *data.get(8)? as u32
+    } else {
+        0
+    };
+    let args = data.len().checked_sub(ROUTE_ARGS_LEN).filter(|args| *args >= offset + 4)?;
+
+    Some(RouteArgs {
+        id,
+        route_plan: decode_route_plan(&data[offset..args]).unwrap_or_default(),
+        amount: u64::from_le_bytes(data[args..args + 8].try_into().ok()?),
+        quoted_amount: u64::from_le_bytes(data[args + 8..args + 16].try_into().ok()?),
+        slippage_bps: u16::from_le_bytes(data[args + 16..args + 18].try_into().ok()?) as u32,
+        platform_fee_bps: data[args + 18] as u32,
+    })
+}
+
+fn decode_route_plan(data: &[u8]) -> Option<Vec<pb::RoutePlanStep>> {
+    let len = u32::from_le_bytes(data.get(0..4)?.try_into().ok()?) as usize;
+    let mut offset = 4;
+    let mut steps = Vec::with_capacity(len.min(data.len()));
+    for _ in 0..len {
+        let swap = *data.get(offset)?;
+        offset += 1 + swap_args_len(swap, data.get(offset + 1..)?)?;
+        let step = data.get(offset..offset + 3)?;
+        steps.push(pb::RoutePlanStep {
+            swap: swap as u32,
+            percent: step[0] as u32,
+            input_index: step[1] as u32,
+            output_index: step[2] as u32,
+        });
+        offset += 3;
+    }
+    // the route plan must be fully consumed, otherwise a variant size is wrong
+    (offset == data.len()).then_some(steps)
+}
+
+/// Size of the arguments of the `Swap` enum variant (borsh), `None` for unsupported variants.
+fn swap_args_len(swap: u8, data: &[u8]) -> Option<usize> {
+    match swap {
+        // Crema, Serum, Aldrin, AldrinV2, Whirlpool, Invariant, DeltaFi, MarcoPolo, Dradex, Openbook, Phoenix,
+        // OpenBookV2, Obric, FoxClaimPartial, SolFi, TokenMill, Plasma, TesseraV
+        8 | 12 | 15 | 16 | 17 | 18 | 21 | 23 | 24 | 27 | 28 | 39 | 58 | 60 | 61 | 64 | 85 | 89 => Some(1),
+        // Symmetry
+        29 => Some(16),
+        // StakeDexSwapViaStake, StakeDexPrefundWithdrawStakeAndDepositStake
+        33 | 41 => Some(4),
+        // Clone
+        42 => Some(3),
+        // SanctumS
+        43 => Some(10),
+        // SanctumSAddLiquidity, SanctumSRemoveLiquidity
+        44 | 45 => Some(5),
+        // WhirlpoolSwapV2 { a_to_b, remaining_accounts_info: Option<RemainingAccountsInfo> }
+        47 => match data.get(1)? {
+            0 => Some(2),
+            _ => Some(2 + remaining_accounts_info_len(data.get(2..)?)?),
+        },
+        // Perena, GoonFi
+        71 | 86 => Some(2),
+        // MeteoraDlmmSwapV2 { remaining_accounts_info }
+        75 => remaining_accounts_info_len(data),
+        // RaydiumLaunchlabBuy, RaydiumLaunchlabSell
+        81 | 82 => Some(8),
+        // HumidiFi
+        87 => Some(9),
+        0..=88 => Some(0),
+        _ => None,
+    }
+}
+
+// RemainingAccountsInfo { slices: Vec<RemainingAccountsSlice { accounts_type: u8, length: u8 }> }
+fn remaining_accounts_info_len(data: &[u8]) -> Option<usize> {
+    let slices = u32::from_le_bytes(data.get(0..4)?.try_into().ok()?) as usize;
+    Some(4 + slices * 2)
+}
+
+/// Source & destination of the user funds, account positions differ between the route instructions.
+pub fn get_route_accounts(instruction: &InstructionView) -> pb::RouteAccounts {
+    let discriminator = instruction.data().get(0..8).unwrap_or_default();
+    // (user_transfer_authority, source_token_account, destination_token_account, source_mint, destination_mint, platform_fee_account)
+    let indexes: [Option<usize>; 6] = if discriminator == ROUTE {
+        [Some(1), Some(2), Some(3), None, Some(5), Some(6)]
+    } else if discriminator == EXACT_OUT_ROUTE {
+        [Some(1), Some(2), Some(3), Some(5), Some(6), Some(7)]
+    } else {
+        [Some(2), Some(3), Some(6), Some(7), Some(8), Some(9)]
+    };
+    let account = |index: Option<usize>| {
+        index
+            .and_then(|index| instruction.accounts().get(index).map(|account| account.0.to_vec()))
+            .unwrap_or_default()
+    };
+
+    pb::RouteAccounts {
+        user_transfer_authority: account(indexes[0]),
+        source_token_account: account(indexes[1]),
+        destination_token_account: account(indexes[2]),
+        source_mint: account(indexes[3]),
+        destination_mint: account(indexes[4]),
+        platform_fee_account: account(indexes[5]),
+    }
+}
+
+/// Name of the undecoded route instruction matching `discriminator`, `None` if it is not a route.
+pub fn undecoded_route_name(discriminator: &[u8]) -> Option<&'static str> {
+    UNDECODED_ROUTES.iter().find(|(route, _)| route == discriminator).map(|(_, name)| *name)
+}
+
+/// Groups the `SwapEvent`s emitted by the route (self CPI) into a single trade.
+///
+/// Split routes emit several events for the same hop, amounts are summed over the events
+/// matching the input mint of the first hop & the output mint of the last hop.
+/// Hops follow the route plan, or the mints of the events when the route plan is not decoded.
+pub fn get_trade(instruction: &InstructionView, route_plan: &[pb::RoutePlanStep]) -> Option<pb::Trade> {
+    let events: Vec<_> = instruction
+        .inner_instructions()
+        .filter(|inner| inner.stack_height() == instruction.stack_height() + 1)
+        .filter(|inner| inner.program_id().0 == &jupiter::v6::PROGRAM_ID)
+        .filter_map(|inner| match jupiter::v6::events::unpack(inner.data()) {
+            Ok(jupiter::v6::events::JupiterV6Event::Swap(event)) => Some(event),
+            _ => None,
+        })
+        .collect();
+    let input_mint = events.first()?.input_mint;
+    let output_mint = events.last()?.output_mint;
+
+    Some(pb::Trade {
+        input_mint: input_mint.to_bytes().to_vec(),
+        input_amount: events.iter().filter(|e| e.input_mint == input_mint).map(|e| e.input_amount).sum(),
+        output_mint: output_mint.to_bytes().to_vec(),
+        output_amount: events.iter().filter(|e| e.output_mint == output_mint).map(|e| e.output_amount).sum(),
+        hops: match route_plan.is_empty() {
+            true => count_hops(events.iter().map(|e| (e.input_mint.to_bytes(), e.output_mint.to_bytes()))),
+            false => count_hops(route_plan.iter().map(|step| (step.input_index, step.output_index))),
+        },
+    })
+}
+
+/// Longest chain of steps from the input token to the output token,
+/// steps splitting the amount between the same tokens count as a single hop.
+fn count_hops<T: Ord>(steps: impl Iterator<Item = (T, T)>) -> u32 {
+    let mut hops: BTreeMap<T, u32> = BTreeMap::new();
+    for (input, output) in steps {
+        let depth = hops.get(&input).copied().unwrap_or_default() + 1;
+        let output_hops = hops.entry(output).or_default();
+        *output_hops = (*output_hops).max(depth);
+    }
+    hops.into_values().max().unwrap_or_default()
+}
@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
 use proto::pb::lifinity::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -46,6 +46,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         authority: accounts.authority.to_bytes().to_vec(),
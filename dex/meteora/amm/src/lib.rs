@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::swaps::TokenAccounts;
 use proto::pb::meteora::amm::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,12 @@ use substreams_solana::{
 };
 use substreams_solana_idls::meteora;
 
+const ADD_BALANCE_LIQUIDITY: [u8; 8] = [168, 227, 50, 62, 189, 171, 84, 176];
+const ADD_IMBALANCE_LIQUIDITY: [u8; 8] = [79, 35, 122, 84, 173, 15, 93, 191];
+const REMOVE_BALANCE_LIQUIDITY: [u8; 8] = [133, 109, 44, 179, 56, 238, 114, 33];
+const REMOVE_LIQUIDITY_SINGLE_SIDE: [u8; 8] = [84, 84, 177, 66, 254, 185, 10, 251];
+const BOOTSTRAP_LIQUIDITY: [u8; 8] = [4, 228, 215, 71, 225, 253, 119, 206];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -41,12 +48,24 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_liquidity_instruction(ix) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match meteora::amm::instructions::unpack(ix.data()) {
         Ok(meteora::amm::instructions::AmmInstruction::Swap(evt)) => {
             let accounts = meteora::amm::accounts::get_swap_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         pool: accounts.pool.to_bytes().to_vec(),
@@ -74,6 +93,36 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
+fn process_liquidity_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let discriminator: [u8; 8] = ix.data().get(0..8)?.try_into().ok()?;
+    let instruction = match discriminator {
+        ADD_BALANCE_LIQUIDITY => pb::instruction::Instruction::AddBalanceLiquidity,
+        ADD_IMBALANCE_LIQUIDITY => pb::instruction::Instruction::AddImbalanceLiquidity,
+        REMOVE_BALANCE_LIQUIDITY => pb::instruction::Instruction::RemoveBalanceLiquidity,
+        REMOVE_LIQUIDITY_SINGLE_SIDE => pb::instruction::Instruction::RemoveLiquiditySingleSide,
+        BOOTSTRAP_LIQUIDITY => pb::instruction::Instruction::BootstrapLiquidity,
+        _ => return None,
+    };
+    // `remove_liquidity_single_side` has a single user token account
+    let user_index = if discriminator == REMOVE_LIQUIDITY_SINGLE_SIDE { 12 } else { 13 };
+    let accounts = TokenAccounts::new(ix.transaction());
+    let a_token_vault = account_bytes(ix, 9)?;
+    let b_token_vault = account_bytes(ix, 10)?;
+
+    Some(instruction(pb::LiquidityInstruction {
+        accounts: Some(pb::LiquidityAccounts {
+            pool: account_bytes(ix, 0)?,
+            lp_mint: account_bytes(ix, 1)?,
+            user_pool_lp: account_bytes(ix, 2)?,
+            token_a_mint: accounts.mint(&a_token_vault).cloned().unwrap_or_default(),
+            token_b_mint: accounts.mint(&b_token_vault).cloned().unwrap_or_default(),
+            a_token_vault,
+            b_token_vault,
+            user: account_bytes(ix, user_index)?,
+        }),
+    }))
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::{get_transferred_amount, TransferDirection};
 use proto::pb::meteora::daam::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,16 @@ use substreams_solana::{
 };
 use substreams_solana_idls::meteora;
 
+const INITIALIZE_POOL: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+const INITIALIZE_CUSTOMIZABLE_POOL: [u8; 8] = [20, 161, 241, 24, 189, 221, 180, 2];
+const INITIALIZE_POOL_WITH_DYNAMIC_CONFIG: [u8; 8] = [149, 82, 72, 197, 253, 252, 68, 15];
+const CLAIM_POSITION_FEE: [u8; 8] = [180, 38, 154, 17, 133, 33, 162, 211];
+const CLAIM_REWARD: [u8; 8] = [149, 95, 181, 242, 94, 90, 158, 162];
+const LOCK_POSITION: [u8; 8] = [227, 62, 2, 252, 247, 10, 171, 185];
+const PERMANENT_LOCK_POSITION: [u8; 8] = [165, 176, 125, 6, 231, 171, 186, 213];
+const SPLIT_POSITION: [u8; 8] = [172, 241, 221, 138, 161, 29, 253, 42];
+const UPDATE_POOL_FEES: [u8; 8] = [118, 217, 203, 179, 60, 8, 70, 89];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -41,12 +52,24 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_initialize_pool_instruction(ix).or_else(|| process_position_instruction(ix)) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match meteora::daam::instructions::unpack(ix.data()) {
         Ok(meteora::daam::instructions::MeteoraDammInstruction::AddLiquidity(instr)) => {
             let accounts = meteora::daam::accounts::get_add_liquidity_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::AddLiquidity(pb::AddLiquidityInstruction {
                     accounts: Some(pb::AddLiquidityAccounts {
                         pool: accounts.pool.to_bytes().to_vec(),
@@ -77,6 +100,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::RemoveLiquidity(pb::RemoveLiquidityInstruction {
                     accounts: Some(pb::RemoveLiquidityAccounts {
                         pool_authority: accounts.pool_authority.to_bytes().to_vec(),
@@ -108,6 +133,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         pool_authority: accounts.pool_authority.to_bytes().to_vec(),
@@ -136,6 +163,181 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
+fn process_initialize_pool_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+    // (config, pool), the pool is followed by the position, token mints & token vaults
+    let (config, pool) = match discriminator {
+        INITIALIZE_POOL => (Some(4), 6),
+        INITIALIZE_CUSTOMIZABLE_POOL => (None, 5),
+        INITIALIZE_POOL_WITH_DYNAMIC_CONFIG => (Some(5), 7),
+        _ => return None,
+    };
+    // initialize_pool(params: { liquidity: u128, sqrt_price: u128, activation_point: Option<u64> })
+    let (liquidity, sqrt_price) = match discriminator {
+        INITIALIZE_POOL => (
+            u128::from_le_bytes(data.get(8..24)?.try_into().ok()?).to_string(),
+            u128::from_le_bytes(data.get(24..40)?.try_into().ok()?).to_string(),
+        ),
+        _ => (String::new(), String::new()),
+    };
+
+    let instruction = pb::InitializePoolInstruction {
+        accounts: Some(pb::InitializePoolAccounts {
+            creator: account_bytes(ix, 0)?,
+            payer: account_bytes(ix, 3)?,
+            config: config.and_then(|index| account_bytes(ix, index)).unwrap_or_default(),
+            pool: account_bytes(ix, pool)?,
+            position: account_bytes(ix, pool + 1)?,
+            token_a_mint: account_bytes(ix, pool + 2)?,
+            token_b_mint: account_bytes(ix, pool + 3)?,
+            token_a_vault: account_bytes(ix, pool + 4)?,
+            token_b_vault: account_bytes(ix, pool + 5)?,
+        }),
+        liquidity,
+        sqrt_price,
+    };
+    Some(match discriminator {
+        INITIALIZE_POOL => pb::instruction::Instruction::InitializePool(instruction),
+        INITIALIZE_CUSTOMIZABLE_POOL => pb::instruction::Instruction::InitializeCustomizablePool(instruction),
+        _ => pb::instruction::Instruction::InitializePoolWithDynamicConfig(instruction),
+    })
+}
+
+fn process_position_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        CLAIM_POSITION_FEE => {
+            let accounts = pb::ClaimPositionFeeAccounts {
+                pool: account_bytes(ix, 1)?,
+                position: account_bytes(ix, 2)?,
+                token_a_account: account_bytes(ix, 3)?,
+                token_b_account: account_bytes(ix, 4)?,
+                token_a_vault: account_bytes(ix, 5)?,
+                token_b_vault: account_bytes(ix, 6)?,
+                token_a_mint: account_bytes(ix, 7)?,
+                token_b_mint: account_bytes(ix, 8)?,
+                position_nft_account: account_bytes(ix, 9)?,
+                owner: account_bytes(ix, 10)?,
+            };
+            Some(pb::instruction::Instruction::ClaimPositionFee(pb::ClaimPositionFeeInstruction {
+                fee_a: get_transferred_amount(ix, &accounts.token_a_vault, TransferDirection::Sent),
+                fee_b: get_transferred_amount(ix, &accounts.token_b_vault, TransferDirection::Sent),
+                accounts: Some(accounts),
+            }))
+        }
+        // claim_reward(reward_index: u8)
+        CLAIM_REWARD => {
+            let accounts = pb::ClaimRewardAccounts {
+                pool: account_bytes(ix, 1)?,
+                position: account_bytes(ix, 2)?,
+                reward_vault: account_bytes(ix, 3)?,
+                reward_mint: account_bytes(ix, 4)?,
+                user_token_account: account_bytes(ix, 5)?,
+                position_nft_account: account_bytes(ix, 6)?,
+                owner: account_bytes(ix, 7)?,
+            };
+            Some(pb::instruction::Instruction::ClaimReward(pb::ClaimRewardInstruction {
+                reward_index: *data.get(8)? as u32,
+                amount: get_transferred_amount(ix, &accounts.reward_vault, TransferDirection::Sent),
+                accounts: Some(accounts),
+            }))
+        }
+        // lock_position(params: { cliff_point: Option<u64>, period_frequency: u64, cliff_unlock_liquidity: u128,
+        //                         liquidity_per_period: u128, number_of_period: u16 })
+        LOCK_POSITION => {
+            let (cliff_point, offset) = read_option_u64(data, 8)?;
+            Some(pb::instruction::Instruction::LockPosition(pb::LockPositionInstruction {
+                accounts: Some(pb::LockPositionAccounts {
+                    pool: account_bytes(ix, 0)?,
+                    position: account_bytes(ix, 1)?,
+                    vesting: account_bytes(ix, 2)?,
+                    position_nft_account: account_bytes(ix, 3)?,
+                    owner: account_bytes(ix, 4)?,
+                    payer: account_bytes(ix, 5)?,
+                }),
+                cliff_point,
+                period_frequency: u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?),
+                cliff_unlock_liquidity: u128::from_le_bytes(data.get(offset + 8..offset + 24)?.try_into().ok()?).to_string(),
+                liquidity_per_period: u128::from_le_bytes(data.get(offset + 24..offset + 40)?.try_into().ok()?).to_string(),
+                number_of_period: u16::from_le_bytes(data.get(offset + 40..offset + 42)?.try_into().ok()?) as u32,
+            }))
+        }
+        // permanent_lock_position(permanent_lock_liquidity: u128)
+        PERMANENT_LOCK_POSITION => Some(pb::instruction::Instruction::PermanentLockPosition(pb::PermanentLockPositionInstruction {
+            accounts: Some(pb::PermanentLockPositionAccounts {
+                pool: account_bytes(ix, 0)?,
+                position: account_bytes(ix, 1)?,
+                position_nft_account: account_bytes(ix, 2)?,
+                owner: account_bytes(ix, 3)?,
+            }),
+            permanent_lock_liquidity: u128::from_le_bytes(data.get(8..24)?.try_into().ok()?).to_string(),
+        })),
+        // split_position(params: { unlocked_liquidity_percentage: u8, permanent_locked_liquidity_percentage: u8, fee_a_percentage: u8,
+        //                          fee_b_percentage: u8, reward_0_percentage: u8, reward_1_percentage: u8, padding: [u8; 16] })
+        SPLIT_POSITION => {
+            let percentages = data.get(8..14)?;
+            Some(pb::instruction::Instruction::SplitPosition(pb::SplitPositionInstruction {
+                accounts: Some(pb::SplitPositionAccounts {
+                    pool: account_bytes(ix, 0)?,
+                    first_position: account_bytes(ix, 1)?,
+                    first_position_nft_account: account_bytes(ix, 2)?,
+                    second_position: account_bytes(ix, 3)?,
+                    second_position_nft_account: account_bytes(ix, 4)?,
+                    first_owner: account_bytes(ix, 5)?,
+                    second_owner: account_bytes(ix, 6)?,
+                }),
+                unlocked_liquidity_percentage: percentages[0] as u32,
+                permanent_locked_liquidity_percentage: percentages[1] as u32,
+                fee_a_percentage: percentages[2] as u32,
+                fee_b_percentage: percentages[3] as u32,
+                reward_0_percentage: percentages[4] as u32,
+                reward_1_percentage: percentages[5] as u32,
+            }))
+        }
+        // update_pool_fees(params: { cliff_fee_numerator: Option<u64>, dynamic_fee: Option<DynamicFeeParameters> })
+        UPDATE_POOL_FEES => {
+            let (cliff_fee_numerator, offset) = read_option_u64(data, 8)?;
+            Some(pb::instruction::Instruction::UpdatePoolFees(pb::UpdatePoolFeesInstruction {
+                accounts: Some(pb::UpdatePoolFeesAccounts {
+                    pool: account_bytes(ix, 0)?,
+                    operator: account_bytes(ix, 1)?,
+                }),
+                cliff_fee_numerator,
+                dynamic_fee: match *data.get(offset)? {
+                    0 => None,
+                    _ => Some(parse_dynamic_fee(data.get(offset + 1..)?)?),
+                },
+            }))
+        }
+        _ => None,
+    }
+}
+
+// DynamicFeeParameters { bin_step: u16, bin_step_u128: u128, filter_period: u16, decay_period: u16, reduction_factor: u16,
+//                        max_volatility_accumulator: u32, variable_fee_control: u32 }
+fn parse_dynamic_fee(data: &[u8]) -> Option<pb::DynamicFeeParameters> {
+    Some(pb::DynamicFeeParameters {
+        bin_step: u16::from_le_bytes(data.get(0..2)?.try_into().ok()?) as u32,
+        bin_step_u128: u128::from_le_bytes(data.get(2..18)?.try_into().ok()?).to_string(),
+        filter_period: u16::from_le_bytes(data.get(18..20)?.try_into().ok()?) as u32,
+        decay_period: u16::from_le_bytes(data.get(20..22)?.try_into().ok()?) as u32,
+        reduction_factor: u16::from_le_bytes(data.get(22..24)?.try_into().ok()?) as u32,
+        max_volatility_accumulator: u32::from_le_bytes(data.get(24..28)?.try_into().ok()?),
+        variable_fee_control: u32::from_le_bytes(data.get(28..32)?.try_into().ok()?),
+    })
+}
+
+/// Borsh `Option<u64>` at `offset`, with the offset of the following field.
+fn read_option_u64(data: &[u8], offset: usize) -> Option<(Option<u64>, usize)> {
+    match *data.get(offset)? {
+        0 => Some((None, offset + 1)),
+        _ => Some((Some(u64::from_le_bytes(data.get(offset + 1..offset + 9)?.try_into().ok()?)), offset + 9)),
+    }
+}
+
 fn process_event_instruction(ix: &InstructionView) -> Option<pb::Log> {
     let program_id = ix.program_id().0;
     if program_id != &meteora::daam::PROGRAM_ID {
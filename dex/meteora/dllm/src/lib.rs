@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::{get_transferred_amount, TransferDirection};
 use proto::pb::meteora::dllm::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,35 @@ use substreams_solana::{
 };
 use substreams_solana_idls::meteora::dllm;
 
+const INITIALIZE_LB_PAIR: [u8; 8] = [45, 154, 237, 210, 221, 15, 166, 92];
+const INITIALIZE_POSITION: [u8; 8] = [219, 192, 234, 71, 190, 191, 102, 80];
+const INITIALIZE_POSITION_PDA: [u8; 8] = [46, 82, 125, 146, 85, 141, 228, 153];
+const CLOSE_POSITION: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+const ADD_LIQUIDITY: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
+const ADD_LIQUIDITY_BY_WEIGHT: [u8; 8] = [28, 140, 238, 99, 231, 162, 21, 149];
+const ADD_LIQUIDITY_BY_STRATEGY: [u8; 8] = [7, 3, 150, 127, 148, 40, 61, 200];
+const ADD_LIQUIDITY_ONE_SIDE: [u8; 8] = [94, 155, 103, 151, 70, 95, 220, 165];
+const ADD_LIQUIDITY_BY_STRATEGY_ONE_SIDE: [u8; 8] = [41, 5, 238, 175, 100, 225, 6, 205];
+const ADD_LIQUIDITY_ONE_SIDE_PRECISE: [u8; 8] = [161, 194, 103, 84, 171, 71, 250, 154];
+const ADD_LIQUIDITY2: [u8; 8] = [228, 162, 78, 28, 70, 219, 116, 115];
+const ADD_LIQUIDITY_BY_STRATEGY2: [u8; 8] = [3, 221, 149, 218, 111, 141, 118, 213];
+const ADD_LIQUIDITY_ONE_SIDE_PRECISE2: [u8; 8] = [33, 51, 163, 201, 117, 98, 125, 231];
+const REMOVE_LIQUIDITY: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
+const REMOVE_LIQUIDITY2: [u8; 8] = [230, 215, 82, 127, 241, 101, 227, 146];
+const REMOVE_LIQUIDITY_BY_RANGE: [u8; 8] = [26, 82, 102, 152, 240, 74, 105, 26];
+const REMOVE_LIQUIDITY_BY_RANGE2: [u8; 8] = [204, 2, 195, 145, 53, 145, 145, 205];
+const REMOVE_ALL_LIQUIDITY: [u8; 8] = [10, 51, 61, 35, 112, 105, 24, 85];
+const CLAIM_FEE: [u8; 8] = [169, 32, 79, 137, 136, 232, 70, 137];
+const CLAIM_REWARD: [u8; 8] = [149, 95, 181, 242, 94, 90, 158, 162];
+
+// Anchor self-CPI event: `EVENT_IX_TAG` followed by the event discriminator
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+const ADD_LIQUIDITY_EVENT: [u8; 8] = [31, 94, 125, 90, 227, 52, 61, 186];
+const REMOVE_LIQUIDITY_EVENT: [u8; 8] = [116, 244, 97, 232, 103, 31, 152, 58];
+const CLAIM_FEE_EVENT: [u8; 8] = [75, 122, 154, 48, 140, 74, 123, 163];
+const CLAIM_REWARD_EVENT: [u8; 8] = [148, 116, 134, 204, 22, 171, 85, 95];
+const POSITION_CREATE_EVENT: [u8; 8] = [144, 142, 252, 84, 157, 53, 37, 121];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -39,11 +69,26 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_initialize_lb_pair_instruction(ix)
+        .or_else(|| process_position_instruction(ix))
+        .or_else(|| process_position_event(ix))
+    {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     // 1) Try to decode Anchor "event CPI" first and EARLY-RETURN if it matches.
     if let Ok(dllm::anchor_cpi_event::MeteoraDllmAnchorCpiEvent::Swap(event)) = dllm::anchor_cpi_event::unpack(ix.data()) {
         return Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::SwapEvent(pb::SwapEvent {
                 lb_pair: event.lb_pair.to_bytes().to_vec(),
                 from: event.from.to_bytes().to_vec(),
@@ -66,6 +111,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SwapInstruction(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         lb_pair: accounts.lb_pair.to_bytes().to_vec(),
@@ -92,3 +139,231 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         _ => None,
     }
 }
+
+// initialize_lb_pair(active_id: i32, bin_step: u16)
+fn process_initialize_lb_pair_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    if data.get(0..8)? != INITIALIZE_LB_PAIR {
+        return None;
+    }
+
+    Some(pb::instruction::Instruction::InitializeLbPair(pb::InitializeLbPairInstruction {
+        accounts: Some(pb::InitializeLbPairAccounts {
+            lb_pair: account_bytes(ix, 0)?,
+            token_mint_x: account_bytes(ix, 2)?,
+            token_mint_y: account_bytes(ix, 3)?,
+            reserve_x: account_bytes(ix, 4)?,
+            reserve_y: account_bytes(ix, 5)?,
+            preset_parameter: account_bytes(ix, 7)?,
+            funder: account_bytes(ix, 8)?,
+        }),
+        active_id: i32::from_le_bytes(data.get(8..12)?.try_into().ok()?),
+        bin_step: u16::from_le_bytes(data.get(12..14)?.try_into().ok()?) as u32,
+    }))
+}
+
+fn process_position_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        // initialize_position(lower_bin_id: i32, width: i32)
+        INITIALIZE_POSITION | INITIALIZE_POSITION_PDA => {
+            // initialize_position_pda inserts the base account after the payer
+            let offset = if discriminator == INITIALIZE_POSITION_PDA { 1 } else { 0 };
+            Some(pb::instruction::Instruction::InitializePosition(pb::InitializePositionInstruction {
+                accounts: Some(pb::InitializePositionAccounts {
+                    payer: account_bytes(ix, 0)?,
+                    position: account_bytes(ix, 1 + offset)?,
+                    lb_pair: account_bytes(ix, 2 + offset)?,
+                    owner: account_bytes(ix, 3 + offset)?,
+                }),
+                lower_bin_id: i32::from_le_bytes(data.get(8..12)?.try_into().ok()?),
+                width: i32::from_le_bytes(data.get(12..16)?.try_into().ok()?),
+            }))
+        }
+        CLOSE_POSITION => Some(pb::instruction::Instruction::ClosePosition(pb::ClosePositionInstruction {
+            accounts: Some(pb::ClosePositionAccounts {
+                position: account_bytes(ix, 0)?,
+                lb_pair: account_bytes(ix, 1)?,
+                sender: account_bytes(ix, 4)?,
+                rent_receiver: account_bytes(ix, 5)?,
+            }),
+        })),
+        // v1 instructions pass the lower & upper bin arrays before the sender, v2 instructions pass them as remaining accounts
+        ADD_LIQUIDITY | ADD_LIQUIDITY_BY_WEIGHT | ADD_LIQUIDITY_BY_STRATEGY => Some(pb::instruction::Instruction::AddLiquidity(
+            get_modify_liquidity_instruction(ix, 11, TransferDirection::Received)?,
+        )),
+        ADD_LIQUIDITY2 | ADD_LIQUIDITY_BY_STRATEGY2 => Some(pb::instruction::Instruction::AddLiquidity(get_modify_liquidity_instruction(
+            ix,
+            9,
+            TransferDirection::Received,
+        )?)),
+        ADD_LIQUIDITY_ONE_SIDE | ADD_LIQUIDITY_BY_STRATEGY_ONE_SIDE | ADD_LIQUIDITY_ONE_SIDE_PRECISE => {
+            Some(pb::instruction::Instruction::AddLiquidity(get_one_side_liquidity_instruction(ix, 8)?))
+        }
+        ADD_LIQUIDITY_ONE_SIDE_PRECISE2 => Some(pb::instruction::Instruction::AddLiquidity(get_one_side_liquidity_instruction(ix, 6)?)),
+        REMOVE_LIQUIDITY | REMOVE_LIQUIDITY_BY_RANGE | REMOVE_ALL_LIQUIDITY => Some(pb::instruction::Instruction::RemoveLiquidity(
+            get_modify_liquidity_instruction(ix, 11, TransferDirection::Sent)?,
+        )),
+        REMOVE_LIQUIDITY2 | REMOVE_LIQUIDITY_BY_RANGE2 => Some(pb::instruction::Instruction::RemoveLiquidity(get_modify_liquidity_instruction(
+            ix,
+            9,
+            TransferDirection::Sent,
+        )?)),
+        CLAIM_FEE => {
+            let accounts = pb::ClaimFeeAccounts {
+                lb_pair: account_bytes(ix, 0)?,
+                position: account_bytes(ix, 1)?,
+                sender: account_bytes(ix, 4)?,
+                reserve_x: account_bytes(ix, 5)?,
+                reserve_y: account_bytes(ix, 6)?,
+                user_token_x: account_bytes(ix, 7)?,
+                user_token_y: account_bytes(ix, 8)?,
+                token_x_mint: account_bytes(ix, 9)?,
+                token_y_mint: account_bytes(ix, 10)?,
+            };
+            Some(pb::instruction::Instruction::ClaimFee(pb::ClaimFeeInstruction {
+                fee_x: get_transferred_amount(ix, &accounts.reserve_x, TransferDirection::Sent),
+                fee_y: get_transferred_amount(ix, &accounts.reserve_y, TransferDirection::Sent),
+                accounts: Some(accounts),
+            }))
+        }
+        // claim_reward(reward_index: u64)
+        CLAIM_REWARD => {
+            let accounts = pb::ClaimRewardAccounts {
+                lb_pair: account_bytes(ix, 0)?,
+                position: account_bytes(ix, 1)?,
+                sender: account_bytes(ix, 4)?,
+                reward_vault: account_bytes(ix, 5)?,
+                reward_mint: account_bytes(ix, 6)?,
+                user_token_account: account_bytes(ix, 7)?,
+            };
+            Some(pb::instruction::Instruction::ClaimReward(pb::ClaimRewardInstruction {
+                reward_index: u64::from_le_bytes(data.get(8..16)?.try_into().ok()?),
+                amount: get_transferred_amount(ix, &accounts.reward_vault, TransferDirection::Sent),
+                accounts: Some(accounts),
+            }))
+        }
+        _ => None,
+    }
+}
+
+// Position events which are not decoded by the IDL crate
+fn process_position_event(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    if data.get(0..8)? != EVENT_IX_TAG {
+        return None;
+    }
+    let discriminator: [u8; 8] = data.get(8..16)?.try_into().ok()?;
+    match discriminator {
+        // {Add,Remove}Liquidity { lb_pair: Pubkey, from: Pubkey, position: Pubkey, amounts: [u64; 2], active_bin_id: i32 }
+        ADD_LIQUIDITY_EVENT | REMOVE_LIQUIDITY_EVENT => {
+            let event = pb::LiquidityEvent {
+                lb_pair: data.get(16..48)?.to_vec(),
+                from: data.get(48..80)?.to_vec(),
+                position: data.get(80..112)?.to_vec(),
+                amount_x: u64::from_le_bytes(data.get(112..120)?.try_into().ok()?),
+                amount_y: u64::from_le_bytes(data.get(120..128)?.try_into().ok()?),
+                active_bin_id: i32::from_le_bytes(data.get(128..132)?.try_into().ok()?),
+            };
+            if discriminator == ADD_LIQUIDITY_EVENT {
+                Some(pb::instruction::Instruction::AddLiquidityEvent(event))
+            } else {
+                Some(pb::instruction::Instruction::RemoveLiquidityEvent(event))
+            }
+        }
+        // ClaimFee { lb_pair: Pubkey, position: Pubkey, owner: Pubkey, fee_x: u64, fee_y: u64 }
+        CLAIM_FEE_EVENT => Some(pb::instruction::Instruction::ClaimFeeEvent(pb::ClaimFeeEvent {
+            lb_pair: data.get(16..48)?.to_vec(),
+            position: data.get(48..80)?.to_vec(),
+            owner: data.get(80..112)?.to_vec(),
+            fee_x: u64::from_le_bytes(data.get(112..120)?.try_into().ok()?),
+            fee_y: u64::from_le_bytes(data.get(120..128)?.try_into().ok()?),
+        })),
+        // ClaimReward { lb_pair: Pubkey, position: Pubkey, owner: Pubkey, reward_index: u64, total_reward: u64 }
+        CLAIM_REWARD_EVENT => Some(pb::instruction::Instruction::ClaimRewardEvent(pb::ClaimRewardEvent {
+            lb_pair: data.get(16..48)?.to_vec(),
+            position: data.get(48..80)?.to_vec(),
+            owner: data.get(80..112)?.to_vec(),
+            reward_index: u64::from_le_bytes(data.get(112..120)?.try_into().ok()?),
+            total_reward: u64::from_le_bytes(data.get(120..128)?.try_into().ok()?),
+        })),
+        // PositionCreate { lb_pair: Pubkey, position: Pubkey, owner: Pubkey }
+        POSITION_CREATE_EVENT => Some(pb::instruction::Instruction::PositionCreateEvent(pb::PositionCreateEvent {
+            lb_pair: data.get(16..48)?.to_vec(),
+            position: data.get(48..80)?.to_vec(),
+            owner: data.get(80..112)?.to_vec(),
+        })),
+        _ => None,
+    }
+}
+
+/// Add & remove liquidity instructions share the same leading accounts, only the position of the sender differs.
+fn get_modify_liquidity_instruction(ix: &InstructionView, sender_index: usize, direction: TransferDirection) -> Option<pb::ModifyLiquidityInstruction> {
+    let accounts = pb::ModifyLiquidityAccounts {
+        position: account_bytes(ix, 0)?,
+        lb_pair: account_bytes(ix, 1)?,
+        user_token_x: account_bytes(ix, 3)?,
+        user_token_y: account_bytes(ix, 4)?,
+        reserve_x: account_bytes(ix, 5)?,
+        reserve_y: account_bytes(ix, 6)?,
+        token_x_mint: account_bytes(ix, 7)?,
+        token_y_mint: account_bytes(ix, 8)?,
+        sender: account_bytes(ix, sender_index)?,
+    };
+    Some(pb::ModifyLiquidityInstruction {
+        amount_x: get_transferred_amount(ix, &accounts.reserve_x, direction),
+        amount_y: get_transferred_amount(ix, &accounts.reserve_y, direction),
+        accounts: Some(accounts),
+    })
+}
+
+/// One-sided deposits only pass the deposited token, its side is read from the `AddLiquidity` event emitted by the instruction.
+fn get_one_side_liquidity_instruction(ix: &InstructionView, sender_index: usize) -> Option<pb::ModifyLiquidityInstruction> {
+    let user_token = account_bytes(ix, 3)?;
+    let reserve = account_bytes(ix, 4)?;
+    let token_mint = account_bytes(ix, 5)?;
+    let sender = account_bytes(ix, sender_index)?;
+    let position = account_bytes(ix, 0)?;
+    let lb_pair = account_bytes(ix, 1)?;
+    let amount = get_transferred_amount(ix, &reserve, TransferDirection::Received);
+
+    let accounts = if is_token_y_deposit(ix)? {
+        pb::ModifyLiquidityAccounts {
+            position,
+            lb_pair,
+            user_token_y: user_token,
+            reserve_y: reserve,
+            token_y_mint: token_mint,
+            sender,
+            ..Default::default()
+        }
+    } else {
+        pb::ModifyLiquidityAccounts {
+            position,
+            lb_pair,
+            user_token_x: user_token,
+            reserve_x: reserve,
+            token_x_mint: token_mint,
+            sender,
+            ..Default::default()
+        }
+    };
+    Some(pb::ModifyLiquidityInstruction {
+        amount_x: if accounts.reserve_x.is_empty() { 0 } else { amount },
+        amount_y: if accounts.reserve_y.is_empty() { 0 } else { amount },
+        accounts: Some(accounts),
+    })
+}
+
+/// `AddLiquidity { amounts: [u64; 2] }` event CPI of the instruction, `true` when only token Y was deposited.
+fn is_token_y_deposit(ix: &InstructionView) -> Option<bool> {
+    let data = ix
+        .inner_instructions()
+        .map(|inner| inner.data().to_vec())
+        .find(|data| data.get(0..8) == Some(&EVENT_IX_TAG[..]) && data.get(8..16) == Some(&ADD_LIQUIDITY_EVENT[..]))?;
+    let amount_x = u64::from_le_bytes(data.get(112..120)?.try_into().ok()?);
+    let amount_y = u64::from_le_bytes(data.get(120..128)?.try_into().ok()?);
+    Some(amount_x == 0 && amount_y > 0)
+}
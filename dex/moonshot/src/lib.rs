@@ -1,4 +1,8 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{
+    account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth,
+    parse_program_data, parse_program_id,
+};
+use common::swaps::TokenAccounts;
 use proto::pb::moonshot::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +11,9 @@ use substreams_solana::{
 };
 use substreams_solana_idls::moonshot;
 
+const TOKEN_MINT: [u8; 8] = [3, 44, 164, 184, 123, 13, 245, 179];
+const MIGRATE_FUNDS: [u8; 8] = [42, 229, 10, 231, 189, 62, 193, 174];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -42,15 +49,29 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_launch_instruction(ix) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match moonshot::instructions::unpack(ix.data()) {
         Ok(moonshot::instructions::MoonshotInstruction::Buy(event)) => {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Buy(pb::BuyInstruction {
                     amount: event.amount,
                     collateral_amount: event.collateral_amount,
                     slippage_bps: event.slippage_bps,
+                    accounts: get_trade_accounts(ix),
+                    curve_token_reserves: get_curve_token_reserves(ix),
                 })),
             })
         }
@@ -58,10 +79,14 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Sell(pb::SellInstruction {
                     amount: event.amount,
                     collateral_amount: event.collateral_amount,
                     slippage_bps: event.slippage_bps,
+                    accounts: get_trade_accounts(ix),
+                    curve_token_reserves: get_curve_token_reserves(ix),
                 })),
             })
         }
@@ -69,6 +94,70 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
+fn process_launch_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        // token_mint(TokenMintParams { name: String, symbol: String, uri: String, decimals: u8, collateral_currency: u8,
+        //                              amount: u64, curve_type: u8, migration_target: u8 })
+        TOKEN_MINT => {
+            let mut offset = 8;
+            let name = read_string(data, &mut offset)?;
+            let symbol = read_string(data, &mut offset)?;
+            let uri = read_string(data, &mut offset)?;
+            let params = data.get(offset..offset + 12)?;
+            Some(pb::instruction::Instruction::TokenMint(pb::TokenMintInstruction {
+                accounts: Some(pb::TokenMintAccounts {
+                    sender: account_bytes(ix, 0)?,
+                    curve_account: account_bytes(ix, 2)?,
+                    mint: account_bytes(ix, 3)?,
+                }),
+                name,
+                symbol,
+                uri,
+                decimals: params[0] as u32,
+                collateral_currency: params[1] as u32,
+                amount: u64::from_le_bytes(params[2..10].try_into().ok()?),
+                curve_type: params[10] as u32,
+                migration_target: params[11] as u32,
+            }))
+        }
+        MIGRATE_FUNDS => Some(pb::instruction::Instruction::MigrateFunds(pb::MigrateFundsInstruction {
+            accounts: Some(pb::MigrateFundsAccounts {
+                migration_authority: account_bytes(ix, 1)?,
+                curve_account: account_bytes(ix, 2)?,
+                mint: account_bytes(ix, 5)?,
+            }),
+        })),
+        _ => None,
+    }
+}
+
+// buy & sell: [sender, sender_token_account, curve_account, curve_token_account, dex_fee, helio_fee, mint, config_account, ..]
+fn get_trade_accounts(ix: &InstructionView) -> Option<pb::TradeAccounts> {
+    Some(pb::TradeAccounts {
+        sender: account_bytes(ix, 0)?,
+        curve_account: account_bytes(ix, 2)?,
+        curve_token_account: account_bytes(ix, 3)?,
+        mint: account_bytes(ix, 6)?,
+    })
+}
+
+fn get_curve_token_reserves(ix: &InstructionView) -> u64 {
+    account_bytes(ix, 3)
+        .and_then(|curve_token_account| TokenAccounts::new(ix.transaction()).post_amount(&curve_token_account))
+        .unwrap_or(0)
+}
+
+/// Borsh string: u32 length followed by the UTF-8 bytes.
+fn read_string(data: &[u8], offset: &mut usize) -> Option<String> {
+    let len = u32::from_le_bytes(data.get(*offset..*offset + 4)?.try_into().ok()?) as usize;
+    let bytes = data.get(*offset + 4..*offset + 4 + len)?;
+    *offset += 4 + len;
+    Some(String::from_utf8_lossy(bytes).to_string())
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
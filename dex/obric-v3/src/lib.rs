@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::obric::v3::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -33,21 +34,38 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     let program_id = ix.program_id().0;
     if program_id != &obric::v3::PROGRAM_ID { return None; }
 
+    // realized swap, inferred from the inner SPL Token transfers
+    let swap = SwapAmounts::from_instruction(ix);
+
     match obric::v3::instructions::unpack(ix.data()) {
         Ok(obric::v3::instructions::ObricV3Instruction::SwapXToY(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::SwapXToY(pb::SwapXToYInstruction {
                 input_amount: event.input_x,
                 min_output_amount: event.min_output_amt,
+                user: swap.user,
+                input_mint: swap.input_mint,
+                realized_input_amount: swap.input_amount,
+                output_mint: swap.output_mint,
+                output_amount: swap.output_amount,
             })),
         }),
         Ok(obric::v3::instructions::ObricV3Instruction::SwapYToX(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::SwapYToX(pb::SwapYToXInstruction {
                 input_amount: event.input_y,
                 min_output_amount: event.min_output_amt,
+                user: swap.user,
+                input_mint: swap.input_mint,
+                realized_input_amount: swap.input_amount,
+                output_mint: swap.output_mint,
+                output_amount: swap.output_amount,
             })),
         }),
         _ => None,
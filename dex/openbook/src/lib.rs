@@ -1,4 +1,8 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{
+    account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth,
+    parse_program_data, parse_program_id,
+};
+use common::swaps::{get_transferred_amount, TransferDirection};
 use proto::pb::openbook::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +11,16 @@ use substreams_solana::{
 };
 use substreams_solana_idls::openbook;
 
+const PLACE_ORDER: [u8; 8] = [51, 194, 155, 175, 109, 130, 96, 106];
+const PLACE_ORDERS: [u8; 8] = [60, 63, 50, 123, 12, 197, 60, 190];
+const PLACE_TAKE_ORDER: [u8; 8] = [3, 44, 71, 3, 26, 199, 203, 85];
+const CANCEL_ALL_AND_PLACE_ORDERS: [u8; 8] = [128, 155, 222, 60, 186, 40, 225, 50];
+const EDIT_ORDER: [u8; 8] = [254, 208, 118, 29, 173, 248, 200, 70];
+const CANCEL_ORDER: [u8; 8] = [95, 129, 237, 240, 8, 49, 223, 132];
+const CANCEL_ORDER_BY_CLIENT_ORDER_ID: [u8; 8] = [115, 178, 201, 8, 175, 183, 123, 119];
+const CANCEL_ALL_ORDERS: [u8; 8] = [196, 83, 243, 171, 17, 100, 160, 143];
+const SETTLE_FUNDS: [u8; 8] = [238, 64, 163, 96, 75, 171, 16, 33];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -42,31 +56,190 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
-    match openbook::instructions::unpack(ix.data()) {
-        Ok(openbook::instructions::OpenbookInstruction::PlaceOrder) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::PlaceOrder(pb::PlaceOrderInstruction {})),
-        }),
-        Ok(openbook::instructions::OpenbookInstruction::PlaceOrders) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::PlaceOrders(pb::PlaceOrdersInstruction {})),
-        }),
-        Ok(openbook::instructions::OpenbookInstruction::PlaceTakeOrder) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::PlaceTakeOrder(pb::PlaceTakeOrderInstruction {})),
-        }),
-        Ok(openbook::instructions::OpenbookInstruction::CancelAllAndPlaceOrders) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::CancelAllAndPlaceOrders(pb::CancelAllAndPlaceOrdersInstruction {})),
-        }),
+    Some(pb::Instruction {
+        program_id: program_id.to_vec(),
+        stack_height: ix.stack_height(),
+        parent_program_id: get_parent_program_id(ix),
+        outer_program_id: get_outer_program_id(ix),
+        instruction: Some(process_order_instruction(ix)?),
+    })
+}
+
+fn process_order_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        // place_order(args: PlaceOrderArgs)
+        PLACE_ORDER => Some(pb::instruction::Instruction::PlaceOrder(pb::PlaceOrderInstruction {
+            accounts: Some(get_place_order_accounts(ix)?),
+            args: Some(parse_place_order_args(data, 8)?),
+        })),
+        // place_orders(orders_type: PlaceOrderType, bids: Vec<PlaceMultipleOrdersArgs>, asks: Vec<PlaceMultipleOrdersArgs>, limit: u8)
+        PLACE_ORDERS | CANCEL_ALL_AND_PLACE_ORDERS => {
+            let (bids, offset) = parse_multiple_orders_args(data, 9)?;
+            let (asks, offset) = parse_multiple_orders_args(data, offset)?;
+            let instruction = pb::PlaceOrdersInstruction {
+                accounts: Some(pb::PlaceOrdersAccounts {
+                    signer: account_bytes(ix, 0)?,
+                    open_orders_account: account_bytes(ix, 1)?,
+                    user_quote_account: account_bytes(ix, 3)?,
+                    user_base_account: account_bytes(ix, 4)?,
+                    market: account_bytes(ix, 5)?,
+                    bids: account_bytes(ix, 6)?,
+                    asks: account_bytes(ix, 7)?,
+                    event_heap: account_bytes(ix, 8)?,
+                    market_quote_vault: account_bytes(ix, 9)?,
+                    market_base_vault: account_bytes(ix, 10)?,
+                }),
+                orders_type: *data.get(8)? as u32,
+                bids,
+                asks,
+                limit: *data.get(offset)? as u32,
+            };
+            if discriminator == PLACE_ORDERS {
+                Some(pb::instruction::Instruction::PlaceOrders(instruction))
+            } else {
+                Some(pb::instruction::Instruction::CancelAllAndPlaceOrders(instruction))
+            }
+        }
+        // place_take_order(args: { side: Side, price_lots: i64, max_base_lots: i64, max_quote_lots_including_fees: i64,
+        //                           order_type: PlaceOrderType, limit: u8 })
+        PLACE_TAKE_ORDER => Some(pb::instruction::Instruction::PlaceTakeOrder(pb::PlaceTakeOrderInstruction {
+            accounts: Some(pb::PlaceTakeOrderAccounts {
+                signer: account_bytes(ix, 0)?,
+                market: account_bytes(ix, 2)?,
+                bids: account_bytes(ix, 4)?,
+                asks: account_bytes(ix, 5)?,
+                market_base_vault: account_bytes(ix, 6)?,
+                market_quote_vault: account_bytes(ix, 7)?,
+                event_heap: account_bytes(ix, 8)?,
+                user_base_account: account_bytes(ix, 9)?,
+                user_quote_account: account_bytes(ix, 10)?,
+            }),
+            side: *data.get(8)? as u32,
+            price_lots: read_i64(data, 9)?,
+            max_base_lots: read_i64(data, 17)?,
+            max_quote_lots_including_fees: read_i64(data, 25)?,
+            order_type: *data.get(33)? as u32,
+            limit: *data.get(34)? as u32,
+        })),
+        // edit_order(client_order_id: u64, expected_cancel_size: i64, place_order: PlaceOrderArgs)
+        EDIT_ORDER => Some(pb::instruction::Instruction::EditOrder(pb::EditOrderInstruction {
+            accounts: Some(get_place_order_accounts(ix)?),
+            client_order_id: read_u64(data, 8)?,
+            expected_cancel_size: read_i64(data, 16)?,
+            place_order: Some(parse_place_order_args(data, 24)?),
+        })),
+        // cancel_order(order_id: u128)
+        CANCEL_ORDER => Some(pb::instruction::Instruction::CancelOrder(pb::CancelOrderInstruction {
+            accounts: Some(get_cancel_order_accounts(ix)?),
+            order_id: u128::from_le_bytes(data.get(8..24)?.try_into().ok()?).to_string(),
+            client_order_id: 0,
+        })),
+        // cancel_order_by_client_order_id(client_order_id: u64)
+        CANCEL_ORDER_BY_CLIENT_ORDER_ID => Some(pb::instruction::Instruction::CancelOrderByClientOrderId(pb::CancelOrderInstruction {
+            accounts: Some(get_cancel_order_accounts(ix)?),
+            order_id: String::new(),
+            client_order_id: read_u64(data, 8)?,
+        })),
+        // cancel_all_orders(side_option: Option<Side>, limit: u8)
+        CANCEL_ALL_ORDERS => {
+            let (side, offset) = match *data.get(8)? {
+                0 => (None, 9),
+                _ => (Some(*data.get(9)? as u32), 10),
+            };
+            Some(pb::instruction::Instruction::CancelAllOrders(pb::CancelAllOrdersInstruction {
+                accounts: Some(get_cancel_order_accounts(ix)?),
+                side,
+                limit: *data.get(offset)? as u32,
+            }))
+        }
+        SETTLE_FUNDS => {
+            let accounts = pb::SettleFundsAccounts {
+                owner: account_bytes(ix, 0)?,
+                open_orders_account: account_bytes(ix, 2)?,
+                market: account_bytes(ix, 3)?,
+                market_base_vault: account_bytes(ix, 5)?,
+                market_quote_vault: account_bytes(ix, 6)?,
+                user_base_account: account_bytes(ix, 7)?,
+                user_quote_account: account_bytes(ix, 8)?,
+            };
+            Some(pb::instruction::Instruction::SettleFunds(pb::SettleFundsInstruction {
+                base_amount: get_transferred_amount(ix, &accounts.user_base_account, TransferDirection::Received),
+                quote_amount: get_transferred_amount(ix, &accounts.user_quote_account, TransferDirection::Received),
+                accounts: Some(accounts),
+            }))
+        }
         _ => None,
     }
 }
 
+// PlaceOrderArgs { side: Side, price_lots: i64, max_base_lots: i64, max_quote_lots_including_fees: i64, client_order_id: u64,
+//                  order_type: PlaceOrderType, expiry_timestamp: u64, self_trade_behavior: SelfTradeBehavior, limit: u8 }
+fn parse_place_order_args(data: &[u8], offset: usize) -> Option<pb::PlaceOrderArgs> {
+    Some(pb::PlaceOrderArgs {
+        side: *data.get(offset)? as u32,
+        price_lots: read_i64(data, offset + 1)?,
+        max_base_lots: read_i64(data, offset + 9)?,
+        max_quote_lots_including_fees: read_i64(data, offset + 17)?,
+        client_order_id: read_u64(data, offset + 25)?,
+        order_type: *data.get(offset + 33)? as u32,
+        expiry_timestamp: read_u64(data, offset + 34)?,
+        self_trade_behavior: *data.get(offset + 42)? as u32,
+        limit: *data.get(offset + 43)? as u32,
+    })
+}
+
+/// Borsh `Vec<PlaceMultipleOrdersArgs { price_lots: i64, max_quote_lots_including_fees: i64, expiry_timestamp: u64 }>` at `offset`,
+/// with the offset of the following field.
+fn parse_multiple_orders_args(data: &[u8], offset: usize) -> Option<(Vec<pb::PlaceMultipleOrdersArgs>, usize)> {
+    let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+    let mut orders = Vec::with_capacity(len);
+    let mut offset = offset + 4;
+    for _ in 0..len {
+        orders.push(pb::PlaceMultipleOrdersArgs {
+            price_lots: read_i64(data, offset)?,
+            max_quote_lots_including_fees: read_i64(data, offset + 8)?,
+            expiry_timestamp: read_u64(data, offset + 16)?,
+        });
+        offset += 24;
+    }
+    Some((orders, offset))
+}
+
+/// Accounts of `place_order` & `edit_order`, the optional `open_orders_admin` is the program ID when not set.
+fn get_place_order_accounts(ix: &InstructionView) -> Option<pb::PlaceOrderAccounts> {
+    Some(pb::PlaceOrderAccounts {
+        signer: account_bytes(ix, 0)?,
+        open_orders_account: account_bytes(ix, 1)?,
+        user_token_account: account_bytes(ix, 3)?,
+        market: account_bytes(ix, 4)?,
+        bids: account_bytes(ix, 5)?,
+        asks: account_bytes(ix, 6)?,
+        event_heap: account_bytes(ix, 7)?,
+        market_vault: account_bytes(ix, 8)?,
+    })
+}
+
+fn get_cancel_order_accounts(ix: &InstructionView) -> Option<pb::CancelOrderAccounts> {
+    Some(pb::CancelOrderAccounts {
+        signer: account_bytes(ix, 0)?,
+        open_orders_account: account_bytes(ix, 1)?,
+        market: account_bytes(ix, 2)?,
+        bids: account_bytes(ix, 3)?,
+        asks: account_bytes(ix, 4)?,
+    })
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+fn read_i64(data: &[u8], offset: usize) -> Option<i64> {
+    Some(i64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
@@ -131,3 +304,113 @@ fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32)
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(parts: &[&[u8]]) -> Vec<u8> {
+        parts.concat()
+    }
+
+    fn order(price_lots: i64, max_quote_lots_including_fees: i64, expiry_timestamp: u64) -> Vec<u8> {
+        bytes(&[
+            &price_lots.to_le_bytes(),
+            &max_quote_lots_including_fees.to_le_bytes(),
+            &expiry_timestamp.to_le_bytes(),
+        ])
+    }
+
+    #[test]
+    fn place_order_args() {
+        // place_order(PlaceOrderArgs { side: Ask, price_lots: 1_500, max_base_lots: 20, max_quote_lots_including_fees: 30_060,
+        //                              client_order_id: 42, order_type: PostOnly, expiry_timestamp: 0, self_trade_behavior: AbortTransaction, limit: 10 })
+        let data = bytes(&[
+            &PLACE_ORDER,
+            &[1],
+            &1_500i64.to_le_bytes(),
+            &20i64.to_le_bytes(),
+            &30_060i64.to_le_bytes(),
+            &42u64.to_le_bytes(),
+            &[2],
+            &0u64.to_le_bytes(),
+            &[2, 10],
+        ]);
+        assert_eq!(
+            parse_place_order_args(&data, 8),
+            Some(pb::PlaceOrderArgs {
+                side: 1,
+                price_lots: 1_500,
+                max_base_lots: 20,
+                max_quote_lots_including_fees: 30_060,
+                client_order_id: 42,
+                order_type: 2,
+                expiry_timestamp: 0,
+                self_trade_behavior: 2,
+                limit: 10,
+            })
+        );
+        // truncated limit
+        assert_eq!(parse_place_order_args(&data[..data.len() - 1], 8), None);
+    }
+
+    #[test]
+    fn place_orders_bids_and_asks() {
+        // place_orders(orders_type: Limit, bids: [2 orders], asks: [1 order], limit: 12)
+        let data = bytes(&[
+            &PLACE_ORDERS,
+            &[0],
+            &2u32.to_le_bytes(),
+            &order(1_490, 14_900, 0),
+            &order(1_480, 29_600, 1_700_000_000),
+            &1u32.to_le_bytes(),
+            &order(1_510, 15_100, 0),
+            &[12],
+        ]);
+
+        let (bids, offset) = parse_multiple_orders_args(&data, 9).unwrap();
+        assert_eq!(
+            bids,
+            vec![
+                pb::PlaceMultipleOrdersArgs {
+                    price_lots: 1_490,
+                    max_quote_lots_including_fees: 14_900,
+                    expiry_timestamp: 0,
+                },
+                pb::PlaceMultipleOrdersArgs {
+                    price_lots: 1_480,
+                    max_quote_lots_including_fees: 29_600,
+                    expiry_timestamp: 1_700_000_000,
+                },
+            ]
+        );
+
+        let (asks, offset) = parse_multiple_orders_args(&data, offset).unwrap();
+        assert_eq!(
+            asks,
+            vec![pb::PlaceMultipleOrdersArgs {
+                price_lots: 1_510,
+                max_quote_lots_including_fees: 15_100,
+                expiry_timestamp: 0,
+            }]
+        );
+        assert_eq!(data.get(offset), Some(&12));
+        assert_eq!(offset, data.len() - 1);
+    }
+
+    #[test]
+    fn place_orders_empty_side() {
+        let data = bytes(&[&PLACE_ORDERS, &[0], &0u32.to_le_bytes(), &1u32.to_le_bytes(), &order(1_510, 15_100, 0), &[12]]);
+        let (bids, offset) = parse_multiple_orders_args(&data, 9).unwrap();
+        assert!(bids.is_empty());
+        let (asks, _) = parse_multiple_orders_args(&data, offset).unwrap();
+        assert_eq!(asks.len(), 1);
+    }
+
+    #[test]
+    fn place_orders_truncated() {
+        // announces 2 bids, only 1 is serialized
+        let data = bytes(&[&PLACE_ORDERS, &[0], &2u32.to_le_bytes(), &order(1_490, 14_900, 0)]);
+        assert_eq!(parse_multiple_orders_args(&data, 9), None);
+    }
+}
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::swaps::{get_transferred_amount, TokenAccounts, TransferDirection};
 use proto::pb::orca::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,20 @@ use substreams_solana::{
 };
 use substreams_solana_idls::orca;
 
+const OPEN_POSITION: [u8; 8] = [135, 128, 47, 77, 15, 152, 240, 49];
+const OPEN_POSITION_WITH_METADATA: [u8; 8] = [242, 29, 134, 48, 58, 110, 14, 60];
+const OPEN_POSITION_WITH_TOKEN_EXTENSIONS: [u8; 8] = [212, 47, 95, 92, 114, 102, 131, 250];
+const CLOSE_POSITION: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+const CLOSE_POSITION_WITH_TOKEN_EXTENSIONS: [u8; 8] = [1, 182, 135, 59, 155, 25, 99, 223];
+const COLLECT_FEES: [u8; 8] = [164, 152, 207, 99, 30, 186, 19, 182];
+const COLLECT_FEES_V2: [u8; 8] = [207, 117, 95, 191, 229, 180, 226, 15];
+const COLLECT_REWARD: [u8; 8] = [70, 5, 132, 87, 86, 235, 177, 34];
+const COLLECT_REWARD_V2: [u8; 8] = [177, 107, 37, 180, 160, 19, 49, 209];
+const INCREASE_LIQUIDITY: [u8; 8] = [46, 156, 243, 118, 13, 205, 251, 178];
+const INCREASE_LIQUIDITY_V2: [u8; 8] = [133, 29, 89, 223, 69, 238, 176, 10];
+const DECREASE_LIQUIDITY: [u8; 8] = [160, 38, 208, 111, 104, 91, 44, 1];
+const DECREASE_LIQUIDITY_V2: [u8; 8] = [58, 127, 188, 62, 79, 82, 196, 96];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -42,12 +57,24 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_position_instruction(ix) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match orca::whirlpool::instructions::unpack(ix.data()) {
         Ok(orca::whirlpool::instructions::WhirlpoolInstruction::Swap(event)) => {
             let accounts = orca::whirlpool::accounts::get_swap_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         token_program: accounts.token_program.to_bytes().to_vec(),
@@ -67,6 +94,7 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                     sqrt_price_limit: event.sqrt_price_limit.to_string(),
                     amount_specified_is_input: event.amount_specified_is_input,
                     a_to_b: event.a_to_b,
+                    vault_balances: get_vault_balances(ix, [&accounts.token_vault_a.to_bytes(), &accounts.token_vault_b.to_bytes()]),
                 })),
             })
         }
@@ -75,6 +103,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SwapV2(pb::SwapV2Instruction {
                     accounts: Some(pb::SwapV2Accounts {
                         token_program_a: accounts.token_program_a.to_bytes().to_vec(),
@@ -98,6 +128,7 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                     sqrt_price_limit: event.sqrt_price_limit.to_string(),
                     amount_specified_is_input: event.amount_specified_is_input,
                     a_to_b: event.a_to_b,
+                    vault_balances: get_vault_balances(ix, [&accounts.token_vault_a.to_bytes(), &accounts.token_vault_b.to_bytes()]),
                 })),
             })
         }
@@ -105,6 +136,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::TwoHopSwap(pb::TwoHopSwapInstruction {
                     amount: event.amount,
                     other_amount_threshold: event.other_amount_threshold,
@@ -120,6 +153,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::TwoHopSwapV2(pb::TwoHopSwapV2Instruction {
                     amount: event.amount,
                     other_amount_threshold: event.other_amount_threshold,
@@ -135,6 +170,158 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
+fn process_position_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        // open_position(bumps: { position_bump: u8 }, tick_lower_index: i32, tick_upper_index: i32)
+        OPEN_POSITION => Some(pb::instruction::Instruction::OpenPosition(pb::OpenPositionInstruction {
+            accounts: Some(get_open_position_accounts(ix, 5)?),
+            tick_lower_index: i32::from_le_bytes(data.get(9..13)?.try_into().ok()?),
+            tick_upper_index: i32::from_le_bytes(data.get(13..17)?.try_into().ok()?),
+        })),
+        // open_position_with_metadata(bumps: { position_bump: u8, metadata_bump: u8 }, tick_lower_index: i32, tick_upper_index: i32)
+        OPEN_POSITION_WITH_METADATA => Some(pb::instruction::Instruction::OpenPosition(pb::OpenPositionInstruction {
+            accounts: Some(get_open_position_accounts(ix, 6)?),
+            tick_lower_index: i32::from_le_bytes(data.get(10..14)?.try_into().ok()?),
+            tick_upper_index: i32::from_le_bytes(data.get(14..18)?.try_into().ok()?),
+        })),
+        // open_position_with_token_extensions(tick_lower_index: i32, tick_upper_index: i32, with_token_metadata_extension: bool)
+        OPEN_POSITION_WITH_TOKEN_EXTENSIONS => Some(pb::instruction::Instruction::OpenPosition(pb::OpenPositionInstruction {
+            accounts: Some(get_open_position_accounts(ix, 5)?),
+            tick_lower_index: i32::from_le_bytes(data.get(8..12)?.try_into().ok()?),
+            tick_upper_index: i32::from_le_bytes(data.get(12..16)?.try_into().ok()?),
+        })),
+        CLOSE_POSITION | CLOSE_POSITION_WITH_TOKEN_EXTENSIONS => Some(pb::instruction::Instruction::ClosePosition(pb::ClosePositionInstruction {
+            accounts: Some(pb::ClosePositionAccounts {
+                position_authority: account_bytes(ix, 0)?,
+                receiver: account_bytes(ix, 1)?,
+                position: account_bytes(ix, 2)?,
+                position_mint: account_bytes(ix, 3)?,
+                position_token_account: account_bytes(ix, 4)?,
+            }),
+        })),
+        COLLECT_FEES | COLLECT_FEES_V2 => {
+            // collect_fees_v2 inserts token_mint_a & token_mint_b after the position token account
+            let offset = if discriminator == COLLECT_FEES_V2 { 2 } else { 0 };
+            let accounts = pb::CollectFeesAccounts {
+                whirlpool: account_bytes(ix, 0)?,
+                position_authority: account_bytes(ix, 1)?,
+                position: account_bytes(ix, 2)?,
+                position_token_account: account_bytes(ix, 3)?,
+                token_owner_account_a: account_bytes(ix, 4 + offset)?,
+                token_vault_a: account_bytes(ix, 5 + offset)?,
+                token_owner_account_b: account_bytes(ix, 6 + offset)?,
+                token_vault_b: account_bytes(ix, 7 + offset)?,
+            };
+            Some(pb::instruction::Instruction::CollectFees(pb::CollectFeesInstruction {
+                fee_a_amount: get_transferred_amount(ix, &accounts.token_vault_a, TransferDirection::Sent),
+                fee_b_amount: get_transferred_amount(ix, &accounts.token_vault_b, TransferDirection::Sent),
+                accounts: Some(accounts),
+            }))
+        }
+        // collect_reward(reward_index: u8)
+        COLLECT_REWARD | COLLECT_REWARD_V2 => {
+            // collect_reward_v2 inserts reward_mint before the reward vault
+            let reward_vault = account_bytes(ix, if discriminator == COLLECT_REWARD_V2 { 6 } else { 5 })?;
+            let reward_mint = TokenAccounts::new(ix.transaction()).mint(&reward_vault).cloned().unwrap_or_default();
+            Some(pb::instruction::Instruction::CollectReward(pb::CollectRewardInstruction {
+                reward_index: *data.get(8)? as u32,
+                reward_mint,
+                amount: get_transferred_amount(ix, &reward_vault, TransferDirection::Sent),
+                accounts: Some(pb::CollectRewardAccounts {
+                    whirlpool: account_bytes(ix, 0)?,
+                    position_authority: account_bytes(ix, 1)?,
+                    position: account_bytes(ix, 2)?,
+                    position_token_account: account_bytes(ix, 3)?,
+                    reward_owner_account: account_bytes(ix, 4)?,
+                    reward_vault,
+                }),
+            }))
+        }
+        INCREASE_LIQUIDITY | INCREASE_LIQUIDITY_V2 => {
+            let instruction = get_modify_liquidity_instruction(ix, discriminator == INCREASE_LIQUIDITY_V2)?;
+            Some(pb::instruction::Instruction::IncreaseLiquidity(instruction))
+        }
+        DECREASE_LIQUIDITY | DECREASE_LIQUIDITY_V2 => {
+            let instruction = get_modify_liquidity_instruction(ix, discriminator == DECREASE_LIQUIDITY_V2)?;
+            Some(pb::instruction::Instruction::DecreaseLiquidity(instruction))
+        }
+        _ => None,
+    }
+}
+
+/// {increase,decrease}_liquidity(liquidity_amount: u128, token_a_limit: u64, token_b_limit: u64)
+///
+/// v2 instructions insert the token programs & memo program after the whirlpool, and the token mints after the position token account.
+fn get_modify_liquidity_instruction(ix: &InstructionView, v2: bool) -> Option<pb::ModifyLiquidityInstruction> {
+    let data = ix.data();
+    let accounts = if v2 {
+        pb::ModifyLiquidityAccounts {
+            whirlpool: account_bytes(ix, 0)?,
+            position_authority: account_bytes(ix, 4)?,
+            position: account_bytes(ix, 5)?,
+            position_token_account: account_bytes(ix, 6)?,
+            token_mint_a: account_bytes(ix, 7)?,
+            token_mint_b: account_bytes(ix, 8)?,
+            token_owner_account_a: account_bytes(ix, 9)?,
+            token_owner_account_b: account_bytes(ix, 10)?,
+            token_vault_a: account_bytes(ix, 11)?,
+            token_vault_b: account_bytes(ix, 12)?,
+        }
+    } else {
+        let token_vault_a = account_bytes(ix, 7)?;
+        let token_vault_b = account_bytes(ix, 8)?;
+        let token_accounts = TokenAccounts::new(ix.transaction());
+        pb::ModifyLiquidityAccounts {
+            whirlpool: account_bytes(ix, 0)?,
+            position_authority: account_bytes(ix, 2)?,
+            position: account_bytes(ix, 3)?,
+            position_token_account: account_bytes(ix, 4)?,
+            token_mint_a: token_accounts.mint(&token_vault_a).cloned().unwrap_or_default(),
+            token_mint_b: token_accounts.mint(&token_vault_b).cloned().unwrap_or_default(),
+            token_owner_account_a: account_bytes(ix, 5)?,
+            token_owner_account_b: account_bytes(ix, 6)?,
+            token_vault_a,
+            token_vault_b,
+        }
+    };
+    Some(pb::ModifyLiquidityInstruction {
+        accounts: Some(accounts),
+        liquidity_amount: u128::from_le_bytes(data.get(8..24)?.try_into().ok()?).to_string(),
+        token_a_limit: u64::from_le_bytes(data.get(24..32)?.try_into().ok()?),
+        token_b_limit: u64::from_le_bytes(data.get(32..40)?.try_into().ok()?),
+    })
+}
+
+/// Open position accounts, `whirlpool_idx` accounts for the metadata account of `open_position_with_metadata`.
+fn get_open_position_accounts(ix: &InstructionView, whirlpool_idx: usize) -> Option<pb::OpenPositionAccounts> {
+    Some(pb::OpenPositionAccounts {
+        funder: account_bytes(ix, 0)?,
+        owner: account_bytes(ix, 1)?,
+        position: account_bytes(ix, 2)?,
+        position_mint: account_bytes(ix, 3)?,
+        position_token_account: account_bytes(ix, whirlpool_idx - 1)?,
+        whirlpool: account_bytes(ix, whirlpool_idx)?,
+    })
+}
+
+/// Post balances of the pool vaults, resolved from the transaction token balances.
+fn get_vault_balances(ix: &InstructionView, vaults: [&[u8]; 2]) -> Vec<pb::VaultBalance> {
+    let accounts = TokenAccounts::new(ix.transaction());
+    vaults
+        .into_iter()
+        .filter_map(|vault| {
+            Some(pb::VaultBalance {
+                vault: vault.to_vec(),
+                mint: accounts.mint(vault)?.clone(),
+                post_amount: accounts.post_amount(vault)?,
+            })
+        })
+        .collect()
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
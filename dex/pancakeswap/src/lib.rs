@@ -1,4 +1,7 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{
+    get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data,
+    parse_program_id,
+};
 use proto::pb::pancakeswap::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -48,6 +51,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -1,12 +1,43 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::{get_transferred_amount, TransferDirection};
 use proto::pb::phoenix::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
     block_view::InstructionView,
-    pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, TransactionStatusMeta},
+    pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction},
 };
 use substreams_solana_idls::phoenix;
 
+// Phoenix instructions are tagged by their first byte
+const SWAP: u8 = 0;
+const SWAP_WITH_FREE_FUNDS: u8 = 1;
+const PLACE_LIMIT_ORDER: u8 = 2;
+const PLACE_LIMIT_ORDER_WITH_FREE_FUNDS: u8 = 3;
+const REDUCE_ORDER: u8 = 4;
+const REDUCE_ORDER_WITH_FREE_FUNDS: u8 = 5;
+const CANCEL_ALL_ORDERS: u8 = 6;
+const CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS: u8 = 11;
+const WITHDRAW_FUNDS: u8 = 12;
+const LOG: u8 = 15;
+const PLACE_MULTIPLE_POST_ONLY_ORDERS: u8 = 16;
+const PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS: u8 = 17;
+
+// `PhoenixMarketEvent` variants
+const HEADER_EVENT: u8 = 1;
+const FILL_EVENT: u8 = 2;
+const PLACE_EVENT: u8 = 3;
+const REDUCE_EVENT: u8 = 4;
+const EVICT_EVENT: u8 = 5;
+const FILL_SUMMARY_EVENT: u8 = 6;
+const FEE_EVENT: u8 = 7;
+const TIME_IN_FORCE_EVENT: u8 = 8;
+const EXPIRED_ORDER_EVENT: u8 = 9;
+
+// `OrderPacket` variants
+const POST_ONLY: u8 = 0;
+const LIMIT: u8 = 1;
+const IMMEDIATE_OR_CANCEL: u8 = 2;
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -18,7 +49,7 @@ fn process_transaction(tx: ConfirmedTransaction) -> Option<pb::Transaction> {
     let tx_meta = tx.meta.as_ref()?;
 
     let instructions: Vec<pb::Instruction> = tx.walk_instructions().filter_map(|iview| process_instruction(&iview)).collect();
-    let logs = process_logs(tx_meta, &phoenix::PROGRAM_ID.to_vec());
+    let logs = process_market_events(&tx);
 
     if instructions.is_empty() && logs.is_empty() {
         return None;
@@ -42,12 +73,24 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_order_instruction(ix) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match phoenix::instructions::unpack(ix.data()) {
         Ok(phoenix::instructions::PhonenixInstruction::Swap(event)) => {
             let accounts = phoenix::accounts::get_swap_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::SwapAccounts {
                         phoenix_program: accounts.phoenix_program.to_bytes().to_vec(),
@@ -69,6 +112,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SwapWithFreeFunds(pb::SwapWithFreeFundsInstruction {
                     accounts: Some(pb::SwapAccounts {
                         phoenix_program: accounts.phoenix_program.to_bytes().to_vec(),
@@ -89,47 +134,534 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
-fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
-    let mut logs = Vec::new();
-    let mut is_invoked = false;
+fn process_order_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let tag = *data.first()?;
+    let accounts = || {
+        Some(pb::OrderAccounts {
+            market: account_bytes(ix, 2)?,
+            trader: account_bytes(ix, 3)?,
+        })
+    };
 
-    for log_message in tx_meta.log_messages.iter() {
-        let match_program_id = parse_program_id(log_message).map_or(false, |id| id == program_id_bytes.to_vec());
+    match tag {
+        PLACE_LIMIT_ORDER | PLACE_LIMIT_ORDER_WITH_FREE_FUNDS => Some(pb::instruction::Instruction::PlaceLimitOrder(pb::PlaceLimitOrderInstruction {
+            accounts: accounts(),
+            order_packet: Some(parse_order_packet(data.get(1..)?)?),
+            use_free_funds: tag == PLACE_LIMIT_ORDER_WITH_FREE_FUNDS,
+        })),
+        // ReduceOrderParams { base_params: { side: Side, price_in_ticks: u64, order_sequence_number: u64 }, size: u64 }
+        REDUCE_ORDER | REDUCE_ORDER_WITH_FREE_FUNDS => Some(pb::instruction::Instruction::ReduceOrder(pb::ReduceOrderInstruction {
+            accounts: accounts(),
+            side: *data.get(1)? as u32,
+            price_in_ticks: read_u64(data, 2)?,
+            order_sequence_number: read_u64(data, 10)?,
+            size: read_u64(data, 18)?,
+            use_free_funds: tag == REDUCE_ORDER_WITH_FREE_FUNDS,
+        })),
+        // the cancelled orders are logged as `Reduce` events
+        CANCEL_ALL_ORDERS..=CANCEL_MULTIPLE_ORDERS_BY_ID_WITH_FREE_FUNDS => Some(pb::instruction::Instruction::CancelOrders(pb::CancelOrdersInstruction {
+            accounts: accounts(),
+            // the "with free funds" variants have odd tags
+            use_free_funds: tag % 2 == 1,
+        })),
+        WITHDRAW_FUNDS => {
+            let accounts = pb::WithdrawFundsAccounts {
+                market: account_bytes(ix, 2)?,
+                trader: account_bytes(ix, 3)?,
+                base_account: account_bytes(ix, 4)?,
+                quote_account: account_bytes(ix, 5)?,
+                base_vault: account_bytes(ix, 6)?,
+                quote_vault: account_bytes(ix, 7)?,
+            };
+            Some(pb::instruction::Instruction::WithdrawFunds(pb::WithdrawFundsInstruction {
+                base_amount: get_transferred_amount(ix, &accounts.base_account, TransferDirection::Received),
+                quote_amount: get_transferred_amount(ix, &accounts.quote_account, TransferDirection::Received),
+                accounts: Some(accounts),
+            }))
+        }
+        _ => None,
+    }
+}
 
-        if is_invoke(log_message) && match_program_id {
-            if let Some(invoke_depth) = parse_invoke_depth(log_message) {
-                is_invoked = true;
-                if let Some(log_data) = parse_log_data(log_message, program_id_bytes, invoke_depth) {
-                    logs.push(log_data);
-                }
-            }
-        } else if match_program_id && (is_success(log_message) || is_failed(log_message)) {
-            is_invoked = false;
-        } else if is_invoked {
-            if let Some(log_data) = parse_log_data(log_message, program_id_bytes, 0) {
-                logs.push(log_data);
+/// Decodes the fields shared by the `OrderPacket` variants:
+///
+/// - PostOnly { side, price_in_ticks: u64, num_base_lots: u64, client_order_id: u128, .. }
+/// - Limit { side, price_in_ticks: u64, num_base_lots: u64, self_trade_behavior, match_limit: Option<u64>, client_order_id: u128, .. }
+/// - ImmediateOrCancel { side, price_in_ticks: Option<u64>, num_base_lots: u64, num_quote_lots: u64, min_base_lots_to_fill: u64,
+///   min_quote_lots_to_fill: u64, self_trade_behavior, match_limit: Option<u64>, client_order_id: u128, .. }
+fn parse_order_packet(data: &[u8]) -> Option<pb::OrderPacket> {
+    let order_type = *data.first()?;
+    let side = *data.get(1)? as u32;
+    let (price_in_ticks, num_base_lots, client_order_id) = match order_type {
+        POST_ONLY => (read_u64(data, 2)?, read_u64(data, 10)?, read_u128(data, 18)?),
+        LIMIT => {
+            let offset = option_end(data, 19)?;
+            (read_u64(data, 2)?, read_u64(data, 10)?, read_u128(data, offset)?)
+        }
+        IMMEDIATE_OR_CANCEL => {
+            let price_in_ticks = match *data.get(2)? {
+                0 => 0,
+                _ => read_u64(data, 3)?,
+            };
+            let offset = option_end(data, 2)?;
+            let num_base_lots = read_u64(data, offset)?;
+            // skips num_quote_lots, min_base_lots_to_fill, min_quote_lots_to_fill & self_trade_behavior
+            let offset = option_end(data, offset + 33)?;
+            (price_in_ticks, num_base_lots, read_u128(data, offset)?)
+        }
+        _ => return None,
+    };
+    Some(pb::OrderPacket {
+        order_type: order_type as u32,
+        side,
+        price_in_ticks,
+        num_base_lots,
+        client_order_id: client_order_id.to_string(),
+    })
+}
+
+/// Order packet type of the instruction emitting the events, for the instructions placing orders.
+fn get_order_type(ix: &InstructionView) -> Option<u32> {
+    let data = ix.data();
+    match *data.first()? {
+        SWAP | SWAP_WITH_FREE_FUNDS | PLACE_LIMIT_ORDER | PLACE_LIMIT_ORDER_WITH_FREE_FUNDS => Some(*data.get(1)? as u32),
+        PLACE_MULTIPLE_POST_ONLY_ORDERS | PLACE_MULTIPLE_POST_ONLY_ORDERS_WITH_FREE_FUNDS => Some(POST_ONLY as u32),
+        _ => None,
+    }
+}
+
+/// Market events of the transaction.
+///
+/// Phoenix does not log its events, they are the data of `log` self-CPIs: the instruction tag followed by
+/// Borsh-serialized `PhoenixMarketEvent`s. The first batch of an instruction starts with a `Header` event.
+fn process_market_events(tx: &ConfirmedTransaction) -> Vec<pb::Log> {
+    let mut logs = Vec::new();
+    let mut header = pb::Log::default();
+
+    for ix in tx.walk_instructions() {
+        if ix.program_id().0 != &phoenix::PROGRAM_ID || ix.data().first() != Some(&LOG) {
+            continue;
+        }
+        let data = &ix.data()[1..];
+        let order_type = ix.parent_instruction().and_then(|parent| get_order_type(&parent));
+        let mut offset = 0;
+        while let Some(&tag) = data.get(offset) {
+            let event = &data[offset + 1..];
+            let size = match tag {
+                HEADER_EVENT => 91,
+                FILL_EVENT => 66,
+                PLACE_EVENT => 42,
+                REDUCE_EVENT => 34,
+                EVICT_EVENT => 58,
+                FILL_SUMMARY_EVENT => 42,
+                FEE_EVENT => 10,
+                TIME_IN_FORCE_EVENT => 26,
+                EXPIRED_ORDER_EVENT => 58,
+                // unknown event, the size of the following events is unknown
+                _ => break,
+            };
+            if tag == HEADER_EVENT {
+                // AuditLogHeader { instruction: u8, sequence_number: u64, timestamp: i64, slot: u64, market: Pubkey, signer: Pubkey, total_events: u16 }
+                header = pb::Log {
+                    program_id: phoenix::PROGRAM_ID.to_vec(),
+                    invoke_depth: ix.stack_height(),
+                    instruction: *event.first().unwrap_or(&0) as u32,
+                    market: event.get(25..57).map(<[u8]>::to_vec).unwrap_or_default(),
+                    signer: event.get(57..89).map(<[u8]>::to_vec).unwrap_or_default(),
+                    order_type,
+                    log: None,
+                };
+            } else if let Some(log) = parse_market_event(tag, event) {
+                logs.push(pb::Log {
+                    log: Some(log),
+                    ..header.clone()
+                });
             }
+            offset += 1 + size;
         }
     }
 
     logs
 }
 
-fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32) -> Option<pb::Log> {
-    let data = parse_program_data(log_message)?;
-    match phoenix::events::unpack(data.as_slice()) {
-        Ok(phoenix::events::PhonenixEvent::Fill(event)) => Some(pb::Log {
-            program_id: program_id_bytes.to_vec(),
-            invoke_depth,
-            log: Some(pb::log::Log::Fill(pb::FillEvent {
-                index: event.index as u32,
-                maker_id: event.maker_id.to_bytes().to_vec(),
-                order_sequence_number: event.order_sequence_number,
-                price_in_ticks: event.price_in_ticks,
-                base_lots_filled: event.base_lots_filled,
-                base_lots_remaining: event.base_lots_remaining,
-            })),
-        }),
+/// Decodes the payload of a `PhoenixMarketEvent`, `None` when truncated or not decoded.
+fn parse_market_event(tag: u8, e: &[u8]) -> Option<pb::log::Log> {
+    match tag {
+        // FillEvent { index: u16, maker_id: Pubkey, order_sequence_number: u64, price_in_ticks: u64, base_lots_filled: u64, base_lots_remaining: u64 }
+        FILL_EVENT => Some(pb::log::Log::Fill(pb::FillEvent {
+            index: read_u16(e, 0)?,
+            maker_id: e.get(2..34)?.to_vec(),
+            order_sequence_number: read_u64(e, 34)?,
+            price_in_ticks: read_u64(e, 42)?,
+            base_lots_filled: read_u64(e, 50)?,
+            base_lots_remaining: read_u64(e, 58)?,
+        })),
+        // PlaceEvent { index: u16, order_sequence_number: u64, client_order_id: u128, price_in_ticks: u64, base_lots_placed: u64 }
+        PLACE_EVENT => Some(pb::log::Log::Place(pb::PlaceEvent {
+            index: read_u16(e, 0)?,
+            order_sequence_number: read_u64(e, 2)?,
+            client_order_id: read_u128(e, 10)?.to_string(),
+            price_in_ticks: read_u64(e, 26)?,
+            base_lots_placed: read_u64(e, 34)?,
+        })),
+        // ReduceEvent { index: u16, order_sequence_number: u64, price_in_ticks: u64, base_lots_removed: u64, base_lots_remaining: u64 }
+        REDUCE_EVENT => Some(pb::log::Log::Reduce(pb::ReduceEvent {
+            index: read_u16(e, 0)?,
+            order_sequence_number: read_u64(e, 2)?,
+            price_in_ticks: read_u64(e, 10)?,
+            base_lots_removed: read_u64(e, 18)?,
+            base_lots_remaining: read_u64(e, 26)?,
+        })),
+        // EvictEvent { index: u16, maker_id: Pubkey, order_sequence_number: u64, price_in_ticks: u64, base_lots_evicted: u64 }
+        EVICT_EVENT => Some(pb::log::Log::Evict(pb::EvictEvent {
+            index: read_u16(e, 0)?,
+            maker_id: e.get(2..34)?.to_vec(),
+            order_sequence_number: read_u64(e, 34)?,
+            price_in_ticks: read_u64(e, 42)?,
+            base_lots_evicted: read_u64(e, 50)?,
+        })),
+        // FillSummaryEvent { index: u16, client_order_id: u128, total_base_lots_filled: u64, total_quote_lots_filled: u64, total_fee_in_quote_lots: u64 }
+        FILL_SUMMARY_EVENT => Some(pb::log::Log::FillSummary(pb::FillSummaryEvent {
+            index: read_u16(e, 0)?,
+            client_order_id: read_u128(e, 2)?.to_string(),
+            total_base_lots_filled: read_u64(e, 18)?,
+            total_quote_lots_filled: read_u64(e, 26)?,
+            total_fee_in_quote_lots: read_u64(e, 34)?,
+        })),
+        // FeeEvent { index: u16, fees_collected_in_quote_lots: u64 }
+        FEE_EVENT => Some(pb::log::Log::Fee(pb::FeeEvent {
+            index: read_u16(e, 0)?,
+            fees_collected_in_quote_lots: read_u64(e, 2)?,
+        })),
+        // not decoded:
+        // - TimeInForce { index: u16, order_sequence_number: u64, last_valid_slot: u64, last_valid_unix_timestamp_in_seconds: u64 }
+        // - ExpiredOrder { index: u16, maker_id: Pubkey, order_sequence_number: u64, price_in_ticks: u64, base_lots_removed: u64 }
         _ => None,
     }
 }
+
+/// Offset following the Borsh `Option<u64>` at `offset`.
+fn option_end(data: &[u8], offset: usize) -> Option<usize> {
+    match *data.get(offset)? {
+        0 => Some(offset + 1),
+        _ => Some(offset + 9),
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?) as u32)
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+fn read_u128(data: &[u8], offset: usize) -> Option<u128> {
+    Some(u128::from_le_bytes(data.get(offset..offset + 16)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use substreams_solana::pb::sf::solana::r#type::v1::{
+        CompiledInstruction, InnerInstruction, InnerInstructions, Message, MessageHeader, Transaction, TransactionStatusMeta,
+    };
+
+    const MARKET: [u8; 32] = [3; 32];
+    const SIGNER: [u8; 32] = [1; 32];
+    const MAKER: [u8; 32] = [9; 32];
+
+    fn bytes(parts: &[&[u8]]) -> Vec<u8> {
+        parts.concat()
+    }
+
+    #[test]
+    fn post_only_packet() {
+        // PostOnly { side: Ask, price_in_ticks: 1_500, num_base_lots: 20, client_order_id: 42, reject_post_only: true, .. }
+        let data = bytes(&[
+            &[POST_ONLY, 1],
+            &1_500u64.to_le_bytes(),
+            &20u64.to_le_bytes(),
+            &42u128.to_le_bytes(),
+            &[1, 0, 0, 0, 0],
+        ]);
+        assert_eq!(
+            parse_order_packet(&data),
+            Some(pb::OrderPacket {
+                order_type: POST_ONLY as u32,
+                side: 1,
+                price_in_ticks: 1_500,
+                num_base_lots: 20,
+                client_order_id: "42".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn limit_packet() {
+        // Limit { side: Bid, price_in_ticks: 1_490, num_base_lots: 10, self_trade_behavior: CancelProvide, match_limit: Some(5), client_order_id: 7, .. }
+        let data = bytes(&[
+            &[LIMIT, 0],
+            &1_490u64.to_le_bytes(),
+            &10u64.to_le_bytes(),
+            &[1, 1],
+            &5u64.to_le_bytes(),
+            &7u128.to_le_bytes(),
+            &[0, 0, 0, 0],
+        ]);
+        assert_eq!(
+            parse_order_packet(&data),
+            Some(pb::OrderPacket {
+                order_type: LIMIT as u32,
+                side: 0,
+                price_in_ticks: 1_490,
+                num_base_lots: 10,
+                client_order_id: "7".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn limit_packet_without_match_limit() {
+        let data = bytes(&[
+            &[LIMIT, 0],
+            &1_490u64.to_le_bytes(),
+            &10u64.to_le_bytes(),
+            &[1, 0],
+            &7u128.to_le_bytes(),
+            &[0, 0, 0, 0],
+        ]);
+        assert_eq!(parse_order_packet(&data).map(|packet| packet.client_order_id), Some("7".to_string()));
+    }
+
+    #[test]
+    fn immediate_or_cancel_packet() {
+        // ImmediateOrCancel { side: Bid, price_in_ticks: None, num_base_lots: 30, num_quote_lots: 0, min_base_lots_to_fill: 25,
+        //                     min_quote_lots_to_fill: 0, self_trade_behavior: Abort, match_limit: Some(3), client_order_id: u128::MAX, .. }
+        let data = bytes(&[
+            &[IMMEDIATE_OR_CANCEL, 0, 0],
+            &30u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &25u64.to_le_bytes(),
+            &0u64.to_le_bytes(),
+            &[0, 1],
+            &3u64.to_le_bytes(),
+            &u128::MAX.to_le_bytes(),
+            &[0, 0, 0],
+        ]);
+        assert_eq!(
+            parse_order_packet(&data),
+            Some(pb::OrderPacket {
+                order_type: IMMEDIATE_OR_CANCEL as u32,
+                side: 0,
+                price_in_ticks: 0,
+                num_base_lots: 30,
+                client_order_id: u128::MAX.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn immediate_or_cancel_packet_with_price() {
+        let data = bytes(&[
+            &[IMMEDIATE_OR_CANCEL, 1, 1],
+            &1_480u64.to_le_bytes(),
+            &30u64.to_le_bytes(),
+            &[0; 24],
+            &[0, 0],
+            &11u128.to_le_bytes(),
+        ]);
+        let packet = parse_order_packet(&data).unwrap();
+        assert_eq!(packet.price_in_ticks, 1_480);
+        assert_eq!(packet.num_base_lots, 30);
+        assert_eq!(packet.client_order_id, "11");
+    }
+
+    #[test]
+    fn invalid_packets() {
+        assert_eq!(parse_order_packet(&[]), None);
+        assert_eq!(parse_order_packet(&[3, 0]), None);
+        // truncated client_order_id
+        assert_eq!(
+            parse_order_packet(&bytes(&[&[POST_ONLY, 1], &1_500u64.to_le_bytes(), &20u64.to_le_bytes(), &[0; 8]])),
+            None
+        );
+    }
+
+    fn header_event() -> Vec<u8> {
+        // AuditLogHeader { instruction: Swap, sequence_number: 7, timestamp, slot, market, signer, total_events: 8 }
+        bytes(&[
+            &[HEADER_EVENT, SWAP],
+            &7u64.to_le_bytes(),
+            &1_700_000_000i64.to_le_bytes(),
+            &250_000_000u64.to_le_bytes(),
+            &MARKET,
+            &SIGNER,
+            &8u16.to_le_bytes(),
+        ])
+    }
+
+    fn market_events() -> Vec<u8> {
+        [
+            header_event(),
+            bytes(&[
+                &[FILL_EVENT],
+                &1u16.to_le_bytes(),
+                &MAKER,
+                &100u64.to_le_bytes(),
+                &1_500u64.to_le_bytes(),
+                &10u64.to_le_bytes(),
+                &5u64.to_le_bytes(),
+            ]),
+            bytes(&[
+                &[PLACE_EVENT],
+                &2u16.to_le_bytes(),
+                &101u64.to_le_bytes(),
+                &42u128.to_le_bytes(),
+                &1_490u64.to_le_bytes(),
+                &20u64.to_le_bytes(),
+            ]),
+            bytes(&[
+                &[REDUCE_EVENT],
+                &3u16.to_le_bytes(),
+                &102u64.to_le_bytes(),
+                &1_480u64.to_le_bytes(),
+                &3u64.to_le_bytes(),
+                &0u64.to_le_bytes(),
+            ]),
+            bytes(&[
+                &[EVICT_EVENT],
+                &4u16.to_le_bytes(),
+                &MAKER,
+                &103u64.to_le_bytes(),
+                &1_470u64.to_le_bytes(),
+                &6u64.to_le_bytes(),
+            ]),
+            bytes(&[
+                &[FILL_SUMMARY_EVENT],
+                &5u16.to_le_bytes(),
+                &42u128.to_le_bytes(),
+                &10u64.to_le_bytes(),
+                &15_000u64.to_le_bytes(),
+                &15u64.to_le_bytes(),
+            ]),
+            bytes(&[&[FEE_EVENT], &6u16.to_le_bytes(), &15u64.to_le_bytes()]),
+            bytes(&[&[TIME_IN_FORCE_EVENT], &[0; 26]]),
+            bytes(&[&[EXPIRED_ORDER_EVENT], &[0; 58]]),
+        ]
+        .concat()
+    }
+
+    // Swap (immediate-or-cancel) emitting the `log` self-CPI with the given events batch
+    fn log_transaction(events: Vec<u8>) -> ConfirmedTransaction {
+        ConfirmedTransaction {
+            transaction: Some(Transaction {
+                signatures: vec![vec![0; 64]],
+                message: Some(Message {
+                    header: Some(MessageHeader {
+                        num_required_signatures: 1,
+                        ..Default::default()
+                    }),
+                    account_keys: vec![SIGNER.to_vec(), phoenix::PROGRAM_ID.to_vec(), vec![2; 32], MARKET.to_vec()],
+                    instructions: vec![CompiledInstruction {
+                        program_id_index: 1,
+                        accounts: vec![1, 2, 3, 0],
+                        data: vec![SWAP, IMMEDIATE_OR_CANCEL],
+                    }],
+                    ..Default::default()
+                }),
+            }),
+            meta: Some(TransactionStatusMeta {
+                inner_instructions: vec![InnerInstructions {
+                    index: 0,
+                    instructions: vec![InnerInstruction {
+                        program_id_index: 1,
+                        accounts: vec![2],
+                        data: [vec![LOG], events].concat(),
+                        stack_height: Some(2),
+                    }],
+                }],
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn log(log: pb::log::Log) -> pb::Log {
+        pb::Log {
+            program_id: phoenix::PROGRAM_ID.to_vec(),
+            invoke_depth: 2,
+            market: MARKET.to_vec(),
+            signer: SIGNER.to_vec(),
+            instruction: SWAP as u32,
+            order_type: Some(IMMEDIATE_OR_CANCEL as u32),
+            log: Some(log),
+        }
+    }
+
+    #[test]
+    fn market_events_batch() {
+        assert_eq!(
+            process_market_events(&log_transaction(market_events())),
+            vec![
+                log(pb::log::Log::Fill(pb::FillEvent {
+                    index: 1,
+                    maker_id: MAKER.to_vec(),
+                    order_sequence_number: 100,
+                    price_in_ticks: 1_500,
+                    base_lots_filled: 10,
+                    base_lots_remaining: 5,
+                })),
+                log(pb::log::Log::Place(pb::PlaceEvent {
+                    index: 2,
+                    order_sequence_number: 101,
+                    client_order_id: "42".to_string(),
+                    price_in_ticks: 1_490,
+                    base_lots_placed: 20,
+                })),
+                log(pb::log::Log::Reduce(pb::ReduceEvent {
+                    index: 3,
+                    order_sequence_number: 102,
+                    price_in_ticks: 1_480,
+                    base_lots_removed: 3,
+                    base_lots_remaining: 0,
+                })),
+                log(pb::log::Log::Evict(pb::EvictEvent {
+                    index: 4,
+                    maker_id: MAKER.to_vec(),
+                    order_sequence_number: 103,
+                    price_in_ticks: 1_470,
+                    base_lots_evicted: 6,
+                })),
+                log(pb::log::Log::FillSummary(pb::FillSummaryEvent {
+                    index: 5,
+                    client_order_id: "42".to_string(),
+                    total_base_lots_filled: 10,
+                    total_quote_lots_filled: 15_000,
+                    total_fee_in_quote_lots: 15,
+                })),
+                log(pb::log::Log::Fee(pb::FeeEvent {
+                    index: 6,
+                    fees_collected_in_quote_lots: 15,
+                })),
+            ]
+        );
+    }
+
+    #[test]
+    fn market_events_stop_at_unknown_event() {
+        let events = [
+            header_event(),
+            vec![42, 0, 0],
+            bytes(&[&[FEE_EVENT], &6u16.to_le_bytes(), &15u64.to_le_bytes()]),
+        ]
+        .concat();
+        assert!(process_market_events(&log_transaction(events)).is_empty());
+    }
+
+    #[test]
+    fn market_events_skip_truncated_event() {
+        let events = [header_event(), bytes(&[&[FEE_EVENT], &6u16.to_le_bytes(), &[0; 4]])].concat();
+        assert!(process_market_events(&log_transaction(events)).is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::TokenAccounts;
 use proto::pb::pumpfun::amm::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,12 @@ use substreams_solana::{
 };
 use substreams_solana_idls::pumpfun::amm as pumpfun;
 
+// Anchor self-CPI event: `EVENT_IX_TAG` followed by the event discriminator
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+const COLLECT_COIN_CREATOR_FEE_EVENT: [u8; 8] = [232, 245, 194, 238, 234, 218, 58, 89];
+const SET_BONDING_CURVE_COIN_CREATOR_EVENT: [u8; 8] = [242, 231, 235, 102, 65, 99, 189, 211];
+const SET_METAPLEX_COIN_CREATOR_EVENT: [u8; 8] = [150, 107, 199, 123, 124, 207, 102, 228];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -38,9 +45,12 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
 
     process_instruction1(instruction)
         .or_else(|| process_instruction2(instruction))
+        .or_else(|| process_instruction3(instruction))
         .map(|parsed_instruction| pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: instruction.stack_height(),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
             instruction: Some(parsed_instruction),
         })
 }
@@ -219,6 +229,8 @@ fn process_instruction2(instruction: &InstructionView) -> Option<pb::instruction
             user_base_token_account: event.user_base_token_account.to_bytes().to_vec(),
             user_quote_token_account: event.user_quote_token_account.to_bytes().to_vec(),
             coin_creator: None,
+            pool_base_token_account: parent_account_bytes(instruction, 9),
+            pool_quote_token_account: parent_account_bytes(instruction, 10),
         })),
         // -- CreatePool V2 --
         Ok(pumpfun::events::PumpFunAmmEvent::CreatePoolEventV2(event)) => Some(pb::instruction::Instruction::CreatePoolEvent(pb::CreatePoolEvent {
@@ -241,11 +253,56 @@ fn process_instruction2(instruction: &InstructionView) -> Option<pb::instruction
             user_base_token_account: event.user_base_token_account.to_bytes().to_vec(),
             user_quote_token_account: event.user_quote_token_account.to_bytes().to_vec(),
             coin_creator: Some(event.coin_creator.to_bytes().to_vec()),
+            pool_base_token_account: parent_account_bytes(instruction, 9),
+            pool_quote_token_account: parent_account_bytes(instruction, 10),
+        })),
+        _ => None,
+    }
+}
+
+// Creator fee events which are not part of the IDL
+fn process_instruction3(instruction: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = instruction.data();
+    if data.get(0..8)? != EVENT_IX_TAG {
+        return None;
+    }
+    let discriminator: [u8; 8] = data.get(8..16)?.try_into().ok()?;
+    match discriminator {
+        // CollectCoinCreatorFeeEvent { timestamp: i64, coin_creator: Pubkey, coin_creator_fee: u64,
+        //                              coin_creator_vault_ata: Pubkey, coin_creator_token_account: Pubkey }
+        COLLECT_COIN_CREATOR_FEE_EVENT => Some(pb::instruction::Instruction::CollectCoinCreatorFeeEvent(pb::CollectCoinCreatorFeeEvent {
+            timestamp: i64::from_le_bytes(data.get(16..24)?.try_into().ok()?),
+            coin_creator: data.get(24..56)?.to_vec(),
+            coin_creator_fee: u64::from_le_bytes(data.get(56..64)?.try_into().ok()?),
+            coin_creator_vault_ata: data.get(64..96)?.to_vec(),
+            coin_creator_token_account: data.get(96..128)?.to_vec(),
+            quote_mint: TokenAccounts::new(instruction.transaction())
+                .mint(data.get(64..96)?)
+                .cloned()
+                .unwrap_or_default(),
         })),
+        // Set{BondingCurve,Metaplex}CoinCreatorEvent { timestamp: i64, base_mint: Pubkey, pool: Pubkey,
+        //                                              bonding_curve | metadata: Pubkey, coin_creator: Pubkey }
+        SET_BONDING_CURVE_COIN_CREATOR_EVENT | SET_METAPLEX_COIN_CREATOR_EVENT => {
+            Some(pb::instruction::Instruction::SetCoinCreatorEvent(pb::SetCoinCreatorEvent {
+                timestamp: i64::from_le_bytes(data.get(16..24)?.try_into().ok()?),
+                base_mint: data.get(24..56)?.to_vec(),
+                pool: data.get(56..88)?.to_vec(),
+                coin_creator: data.get(120..152)?.to_vec(),
+            }))
+        }
         _ => None,
     }
 }
 
+/// Account of the `create_pool` instruction emitting the Anchor CPI event.
+fn parent_account_bytes(instruction: &InstructionView, idx: usize) -> Vec<u8> {
+    instruction
+        .parent_instruction()
+        .and_then(|parent| parent.accounts().get(idx).map(|account| account.0.to_vec()))
+        .unwrap_or_default()
+}
+
 pub fn get_trade_accounts(instruction: &InstructionView) -> pb::TradeAccounts {
     pb::TradeAccounts {
         pool: instruction.accounts()[1 - 1].0.to_vec(),
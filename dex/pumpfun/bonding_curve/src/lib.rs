@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
 use proto::pb::pumpfun::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +7,13 @@ use substreams_solana::{
 };
 use substreams_solana_idls::pumpfun::bonding_curve as pumpfun;
 
+const MIGRATE: [u8; 8] = [155, 234, 231, 146, 236, 158, 162, 30];
+// Anchor self-CPI event: `EVENT_IX_TAG` followed by the event discriminator
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+const COMPLETE_EVENT: [u8; 8] = [95, 114, 97, 156, 212, 46, 152, 8];
+const COLLECT_CREATOR_FEE_EVENT: [u8; 8] = [122, 2, 127, 1, 14, 191, 12, 175];
+const SET_CREATOR_EVENT: [u8; 8] = [237, 52, 123, 37, 245, 251, 72, 210];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -38,9 +45,12 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
     // try one algorithm, then another
     process_instruction1(instruction)
         .or_else(|| process_instruction2(instruction))
+        .or_else(|| process_instruction3(instruction))
         .map(|parsed_instruction| pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: instruction.stack_height(),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
             instruction: Some(parsed_instruction),
         })
 }
@@ -62,6 +72,7 @@ fn process_instruction1(instruction: &InstructionView) -> Option<pb::instruction
             symbol: event.symbol,
             uri: event.uri,
             creator: event.creator.to_bytes().to_vec(),
+            accounts: get_create_accounts(instruction),
         })),
         Ok(pumpfun::instructions::PumpFunInstruction::Initialize) => Some(pb::instruction::Instruction::Initialize(pb::InitializeInstruction {})),
         Ok(pumpfun::instructions::PumpFunInstruction::Withdraw) => Some(pb::instruction::Instruction::Withdraw(pb::WithdrawInstruction {})),
@@ -155,6 +166,63 @@ fn process_instruction2(instruction: &InstructionView) -> Option<pb::instruction
     }
 }
 
+// Instructions & events which are not part of the IDL
+fn process_instruction3(instruction: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = instruction.data();
+    if data.get(0..8)? == MIGRATE {
+        return Some(pb::instruction::Instruction::Migrate(pb::MigrateInstruction {
+            accounts: Some(pb::MigrateAccounts {
+                global: account_bytes(instruction, 0)?,
+                withdraw_authority: account_bytes(instruction, 1)?,
+                mint: account_bytes(instruction, 2)?,
+                bonding_curve: account_bytes(instruction, 3)?,
+                associated_bonding_curve: account_bytes(instruction, 4)?,
+                user: account_bytes(instruction, 5)?,
+                pump_amm: account_bytes(instruction, 8)?,
+                pool: account_bytes(instruction, 9)?,
+            }),
+        }));
+    }
+
+    if data.get(0..8)? != EVENT_IX_TAG {
+        return None;
+    }
+    let discriminator: [u8; 8] = data.get(8..16)?.try_into().ok()?;
+    match discriminator {
+        // CompleteEvent { user: Pubkey, mint: Pubkey, bonding_curve: Pubkey, timestamp: i64 }
+        COMPLETE_EVENT => Some(pb::instruction::Instruction::Complete(pb::CompleteEvent {
+            user: data.get(16..48)?.to_vec(),
+            mint: data.get(48..80)?.to_vec(),
+            bonding_curve: data.get(80..112)?.to_vec(),
+            timestamp: i64::from_le_bytes(data.get(112..120)?.try_into().ok()?),
+        })),
+        // CollectCreatorFeeEvent { timestamp: i64, creator: Pubkey, creator_fee: u64 }
+        COLLECT_CREATOR_FEE_EVENT => Some(pb::instruction::Instruction::CollectCreatorFee(pb::CollectCreatorFeeEvent {
+            timestamp: i64::from_le_bytes(data.get(16..24)?.try_into().ok()?),
+            creator: data.get(24..56)?.to_vec(),
+            creator_fee: u64::from_le_bytes(data.get(56..64)?.try_into().ok()?),
+        })),
+        // SetCreatorEvent { timestamp: i64, mint: Pubkey, bonding_curve: Pubkey, creator: Pubkey }
+        SET_CREATOR_EVENT => Some(pb::instruction::Instruction::SetCreator(pb::SetCreatorEvent {
+            timestamp: i64::from_le_bytes(data.get(16..24)?.try_into().ok()?),
+            mint: data.get(24..56)?.to_vec(),
+            bonding_curve: data.get(56..88)?.to_vec(),
+            creator: data.get(88..120)?.to_vec(),
+        })),
+        _ => None,
+    }
+}
+
+// create: [mint, mint_authority, bonding_curve, associated_bonding_curve, global, mpl_token_metadata, metadata, user, ..]
+fn get_create_accounts(instruction: &InstructionView) -> Option<pb::CreateAccounts> {
+    Some(pb::CreateAccounts {
+        mint: account_bytes(instruction, 0)?,
+        bonding_curve: account_bytes(instruction, 2)?,
+        associated_bonding_curve: account_bytes(instruction, 3)?,
+        user: account_bytes(instruction, 7)?,
+    })
+}
+
 pub fn get_trade_accounts(instruction: &InstructionView) -> pb::TradeAccounts {
     pb::TradeAccounts {
         global: instruction.accounts()[0].0.to_vec(),
@@ -1,4 +1,7 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{
+    get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data,
+    parse_program_id,
+};
 use proto::pb::pumpswap::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -47,6 +50,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Buy(pb::BuyInstruction {
                     base_amount_out: event.base_amount_out,
                     max_quote_amount_in: event.max_quote_amount_in,
@@ -57,6 +62,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::BuyExactQuoteIn(pb::BuyExactQuoteInInstruction {
                     spendable_quote_in: event.spendable_quote_in,
                     min_base_amount_out: event.min_base_amount_out,
@@ -67,6 +74,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Sell(pb::SellInstruction {
                     base_amount_in: event.base_amount_in,
                     min_quote_amount_out: event.min_quote_amount_out,
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_id, parse_raydium_log};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_id, parse_raydium_log};
+use common::swaps::{get_transferred_amount, TokenAccounts, TransferDirection};
 use proto::pb::raydium::amm::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,18 @@ use substreams_solana::{
 };
 use substreams_solana_idls::raydium;
 
+// Raydium AMM v4 instruction tags
+const INITIALIZE2: u8 = 1;
+const DEPOSIT: u8 = 3;
+const WITHDRAW: u8 = 4;
+const SET_PARAMS: u8 = 6;
+const WITHDRAW_PNL: u8 = 7;
+
+// Raydium AMM v4 `SetParams` params which do not carry a `u64` value
+const PARAM_FEES: u8 = 9;
+const PARAM_AMM_OWNER: u8 = 10;
+const PARAM_LAST_ORDER_DISTANCE: u8 = 12;
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -50,29 +63,162 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
     // Try to unpack the instruction data
     match raydium::amm::v4::instructions::unpack(instruction.data()) {
         // -- SwapBaseIn --
-        Ok(raydium::amm::v4::instructions::RaydiumV4Instruction::SwapBaseIn(event)) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: instruction.stack_height(),
-            instruction: Some(pb::instruction::Instruction::SwapBaseIn(pb::SwapBaseInInstruction {
-                accounts: Some(get_swap_accounts(instruction)),
-                amount_in: event.amount_in,
-                minimum_amount_out: event.minimum_amount_out,
-            })),
-        }),
+        Ok(raydium::amm::v4::instructions::RaydiumV4Instruction::SwapBaseIn(event)) => {
+            let accounts = get_swap_accounts(instruction);
+            Some(pb::Instruction {
+                program_id: program_id.to_vec(),
+                stack_height: instruction.stack_height(),
+                parent_program_id: get_parent_program_id(instruction),
+                outer_program_id: get_outer_program_id(instruction),
+                instruction: Some(pb::instruction::Instruction::SwapBaseIn(pb::SwapBaseInInstruction {
+                    vault_balances: get_vault_balances(instruction, [&accounts.amm_coin_vault, &accounts.amm_pc_vault]),
+                    accounts: Some(accounts),
+                    amount_in: event.amount_in,
+                    minimum_amount_out: event.minimum_amount_out,
+                })),
+            })
+        }
         // -- SwapBaseOut --
-        Ok(raydium::amm::v4::instructions::RaydiumV4Instruction::SwapBaseOut(event)) => Some(pb::Instruction {
+        Ok(raydium::amm::v4::instructions::RaydiumV4Instruction::SwapBaseOut(event)) => {
+            let accounts = get_swap_accounts(instruction);
+            Some(pb::Instruction {
+                program_id: program_id.to_vec(),
+                stack_height: instruction.stack_height(),
+                parent_program_id: get_parent_program_id(instruction),
+                outer_program_id: get_outer_program_id(instruction),
+                instruction: Some(pb::instruction::Instruction::SwapBaseOut(pb::SwapBaseOutInstruction {
+                    vault_balances: get_vault_balances(instruction, [&accounts.amm_coin_vault, &accounts.amm_pc_vault]),
+                    accounts: Some(accounts),
+                    amount_out: event.amount_out,
+                    max_amount_in: event.max_amount_in,
+                })),
+            })
+        }
+        _ => process_liquidity_instruction(instruction).map(|data| pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: instruction.stack_height(),
-            instruction: Some(pb::instruction::Instruction::SwapBaseOut(pb::SwapBaseOutInstruction {
-                accounts: Some(get_swap_accounts(instruction)),
-                amount_out: event.amount_out,
-                max_amount_in: event.max_amount_in,
-            })),
+            parent_program_id: get_parent_program_id(instruction),
+            outer_program_id: get_outer_program_id(instruction),
+            instruction: Some(data),
         }),
+    }
+}
+
+fn process_liquidity_instruction(instruction: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = instruction.data();
+    let read_u64 = |offset: usize| data.get(offset..offset + 8).and_then(|bytes| bytes.try_into().ok()).map(u64::from_le_bytes);
+
+    match *data.first()? {
+        // -- Initialize2 { nonce, open_time, init_pc_amount, init_coin_amount } --
+        INITIALIZE2 if instruction.accounts().len() >= 18 => Some(pb::instruction::Instruction::Initialize2(pb::Initialize2Instruction {
+            accounts: Some(pb::Initialize2Accounts {
+                amm: account_bytes(instruction, 4).unwrap_or_default(),
+                amm_authority: account_bytes(instruction, 5).unwrap_or_default(),
+                amm_open_orders: account_bytes(instruction, 6).unwrap_or_default(),
+                lp_mint: account_bytes(instruction, 7).unwrap_or_default(),
+                coin_mint: account_bytes(instruction, 8).unwrap_or_default(),
+                pc_mint: account_bytes(instruction, 9).unwrap_or_default(),
+                amm_coin_vault: account_bytes(instruction, 10).unwrap_or_default(),
+                amm_pc_vault: account_bytes(instruction, 11).unwrap_or_default(),
+                amm_target_orders: account_bytes(instruction, 12).unwrap_or_default(),
+                market_program: account_bytes(instruction, 15).unwrap_or_default(),
+                market: account_bytes(instruction, 16).unwrap_or_default(),
+                user_wallet: account_bytes(instruction, 17).unwrap_or_default(),
+            }),
+            nonce: *data.get(1)? as u32,
+            open_time: read_u64(2)?,
+            init_pc_amount: read_u64(10)?,
+            init_coin_amount: read_u64(18)?,
+        })),
+        // -- Deposit { max_coin_amount, max_pc_amount, base_side } --
+        DEPOSIT => Some(pb::instruction::Instruction::Deposit(pb::DepositInstruction {
+            accounts: Some(get_liquidity_accounts(instruction, [1, 5, 6, 7, 9, 10, 11, 12])?),
+            max_coin_amount: read_u64(1)?,
+            max_pc_amount: read_u64(9)?,
+            base_side: read_u64(17)?,
+        })),
+        // -- Withdraw { amount } --
+        WITHDRAW => {
+            // legacy layout = 22, `pool_withdraw_queue` & `pool_temp_lp` sit before the market accounts
+            let offset = if instruction.accounts().len() >= 22 { 2 } else { 0 };
+            Some(pb::instruction::Instruction::Withdraw(pb::WithdrawInstruction {
+                accounts: Some(get_liquidity_accounts(
+                    instruction,
+                    [1, 5, 6, 7, 14 + offset, 15 + offset, 13 + offset, 16 + offset],
+                )?),
+                amount: read_u64(1)?,
+            }))
+        }
+        // -- WithdrawPnl --
+        WITHDRAW_PNL if instruction.accounts().len() >= 10 => {
+            let accounts = pb::WithdrawPnlAccounts {
+                amm: account_bytes(instruction, 1).unwrap_or_default(),
+                amm_config: account_bytes(instruction, 2).unwrap_or_default(),
+                amm_coin_vault: account_bytes(instruction, 5).unwrap_or_default(),
+                amm_pc_vault: account_bytes(instruction, 6).unwrap_or_default(),
+                coin_pnl_token_account: account_bytes(instruction, 7).unwrap_or_default(),
+                pc_pnl_token_account: account_bytes(instruction, 8).unwrap_or_default(),
+                pnl_owner: account_bytes(instruction, 9).unwrap_or_default(),
+            };
+            let token_accounts = TokenAccounts::new(instruction.transaction());
+            Some(pb::instruction::Instruction::WithdrawPnl(pb::WithdrawPnlInstruction {
+                coin_mint: token_accounts.mint(&accounts.amm_coin_vault).cloned().unwrap_or_default(),
+                coin_amount: get_transferred_amount(instruction, &accounts.coin_pnl_token_account, TransferDirection::Received),
+                pc_mint: token_accounts.mint(&accounts.amm_pc_vault).cloned().unwrap_or_default(),
+                pc_amount: get_transferred_amount(instruction, &accounts.pc_pnl_token_account, TransferDirection::Received),
+                accounts: Some(accounts),
+            }))
+        }
+        // -- SetParams { param, value | new_pubkey | fees | last_order_distance } --
+        SET_PARAMS if instruction.accounts().len() >= 16 => {
+            let param = *data.get(1)?;
+            let (value, new_pubkey, fees) = match param {
+                PARAM_AMM_OWNER => (None, Some(data.get(2..34)?.to_vec()), None),
+                PARAM_FEES => (None, None, Some(parse_fees(|i| read_u64(2 + i * 8))?)),
+                PARAM_LAST_ORDER_DISTANCE => (None, None, None),
+                _ => (read_u64(2), None, None),
+            };
+            Some(pb::instruction::Instruction::SetParams(pb::SetParamsInstruction {
+                amm: account_bytes(instruction, 1).unwrap_or_default(),
+                amm_admin: account_bytes(instruction, 15).unwrap_or_default(),
+                param: param as u32,
+                value,
+                new_pubkey,
+                fees,
+            }))
+        }
         _ => None,
     }
 }
 
+fn parse_fees(read_u64: impl Fn(usize) -> Option<u64>) -> Option<pb::Fees> {
+    Some(pb::Fees {
+        min_separate_numerator: read_u64(0)?,
+        min_separate_denominator: read_u64(1)?,
+        trade_fee_numerator: read_u64(2)?,
+        trade_fee_denominator: read_u64(3)?,
+        pnl_numerator: read_u64(4)?,
+        pnl_denominator: read_u64(5)?,
+        swap_fee_numerator: read_u64(6)?,
+        swap_fee_denominator: read_u64(7)?,
+    })
+}
+
+/// Post balances of the pool vaults, resolved from the transaction token balances.
+fn get_vault_balances(instruction: &InstructionView, vaults: [&[u8]; 2]) -> Vec<pb::VaultBalance> {
+    let accounts = TokenAccounts::new(instruction.transaction());
+    vaults
+        .into_iter()
+        .filter_map(|vault| {
+            Some(pb::VaultBalance {
+                vault: vault.to_vec(),
+                mint: accounts.mint(vault)?.clone(),
+                post_amount: accounts.post_amount(vault)?,
+            })
+        })
+        .collect()
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
@@ -142,89 +288,103 @@ fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32)
             Some(log)
         }
 
-        // // -- InitLog --
-        // Ok(raydium::amm::v4::events::RaydiumV4Event::Init(event)) => {
-        //     base.log = Some(pb::log::Log::Init(pb::InitLog {
-        //         pc_decimals: event.pc_decimals as u32,
-        //         coin_decimals: event.coin_decimals as u32,
-        //         pc_lot_size: event.pc_lot_size,
-        //         coin_lot_size: event.coin_lot_size,
-        //         pc_amount: event.pc_amount,
-        //         coin_amount: event.coin_amount,
-        //         market: event.market.to_bytes().to_vec(),
-        //     }));
-        //     transaction.logs.push(base.clone());
-        // }
-        // // -- DepositLog --
-        // Ok(raydium::amm::v4::events::RaydiumV4Event::Deposit(event)) => {
-        //     base.log = Some(pb::log::Log::Deposit(pb::DepositLog {
-        //         max_coin: event.max_coin,
-        //         max_pc: event.max_pc,
-        //         base: event.base,
-        //         pool_coin: event.pool_coin,
-        //         pool_pc: event.pool_pc,
-        //         pool_lp: event.pool_lp,
-        //         calc_pnl_x: event.calc_pnl_x.to_string(),
-        //         calc_pnl_y: event.calc_pnl_y.to_string(),
-        //         deduct_coin: event.deduct_coin,
-        //         deduct_pc: event.deduct_pc,
-        //         mint_lp: event.mint_lp,
-        //     }));
-        //     transaction.logs.push(base.clone());
-        // }
-        // // -- WithdrawLog --
-        // Ok(raydium::amm::v4::events::RaydiumV4Event::Withdraw(event)) => {
-        //     base.log = Some(pb::log::Log::Withdraw(pb::WithdrawLog {
-        //         withdraw_lp: event.withdraw_lp,
-        //         user_lp: event.user_lp,
-        //         pool_coin: event.pool_coin,
-        //         pool_pc: event.pool_pc,
-        //         pool_lp: event.pool_lp,
-        //         calc_pnl_x: event.calc_pnl_x.to_string(),
-        //         calc_pnl_y: event.calc_pnl_y.to_string(),
-        //         out_coin: event.out_coin,
-        //         out_pc: event.out_pc,
-        //     }));
-        //     transaction.logs.push(base.clone());
-        // }
+        // -- InitLog --
+        Ok(raydium::amm::v4::logs::RaydiumV4Log::Init(event)) => {
+            log.log = Some(pb::log::Log::Init(pb::InitLog {
+                pc_decimals: event.pc_decimals as u32,
+                coin_decimals: event.coin_decimals as u32,
+                pc_lot_size: event.pc_lot_size,
+                coin_lot_size: event.coin_lot_size,
+                pc_amount: event.pc_amount,
+                coin_amount: event.coin_amount,
+                market: event.market.to_bytes().to_vec(),
+            }));
+            Some(log)
+        }
+        // -- DepositLog --
+        Ok(raydium::amm::v4::logs::RaydiumV4Log::Deposit(event)) => {
+            log.log = Some(pb::log::Log::Deposit(pb::DepositLog {
+                max_coin: event.max_coin,
+                max_pc: event.max_pc,
+                base: event.base,
+                pool_coin: event.pool_coin,
+                pool_pc: event.pool_pc,
+                pool_lp: event.pool_lp,
+                calc_pnl_x: event.calc_pnl_x.to_string(),
+                calc_pnl_y: event.calc_pnl_y.to_string(),
+                deduct_coin: event.deduct_coin,
+                deduct_pc: event.deduct_pc,
+                mint_lp: event.mint_lp,
+            }));
+            Some(log)
+        }
+        // -- WithdrawLog --
+        Ok(raydium::amm::v4::logs::RaydiumV4Log::Withdraw(event)) => {
+            log.log = Some(pb::log::Log::Withdraw(pb::WithdrawLog {
+                withdraw_lp: event.withdraw_lp,
+                user_lp: event.user_lp,
+                pool_coin: event.pool_coin,
+                pool_pc: event.pool_pc,
+                pool_lp: event.pool_lp,
+                calc_pnl_x: event.calc_pnl_x.to_string(),
+                calc_pnl_y: event.calc_pnl_y.to_string(),
+                out_coin: event.out_coin,
+                out_pc: event.out_pc,
+            }));
+            Some(log)
+        }
         _ => None,
     }
 }
 
-/// Safely get the `idx`-th account's pubkey bytes or empty vector if not found.
-///
-/// * `ix`   – the fully-parsed instruction (`InstructionView`)
-/// * `idx`  – the position in the account vector
-#[inline]
-fn account_bytes(ix: &InstructionView, idx: usize) -> Vec<u8> {
-    ix.accounts()[idx].0.to_vec()
-}
-
 pub fn get_swap_accounts(ix: &InstructionView) -> pb::SwapAccounts {
     let with_target_orders = ix.accounts().len() == 18; // v4 = 18, legacy = 17
     let offset = if with_target_orders { 1 } else { 0 }; // how many slots to shift after we pass index 3
 
     pb::SwapAccounts {
         // fixed positions
-        token_program: account_bytes(ix, 0),
-        amm: account_bytes(ix, 1),
-        amm_authority: account_bytes(ix, 2),
-        amm_open_orders: account_bytes(ix, 3),
+        token_program: account_bytes(ix, 0).unwrap_or_default(),
+        amm: account_bytes(ix, 1).unwrap_or_default(),
+        amm_authority: account_bytes(ix, 2).unwrap_or_default(),
+        amm_open_orders: account_bytes(ix, 3).unwrap_or_default(),
         // new in Raydium-v4
-        amm_target_orders: if with_target_orders { Some(account_bytes(ix, 4)) } else { None },
+        amm_target_orders: if with_target_orders { Some(account_bytes(ix, 4).unwrap_or_default()) } else { None },
         // everything after index 3 shifts by +1 when target-orders is present
-        amm_coin_vault: account_bytes(ix, 4 + offset),
-        amm_pc_vault: account_bytes(ix, 5 + offset),
-        market_program: account_bytes(ix, 6 + offset),
-        market: account_bytes(ix, 7 + offset),
-        market_bids: account_bytes(ix, 8 + offset),
-        market_asks: account_bytes(ix, 9 + offset),
-        market_event_queue: account_bytes(ix, 10 + offset),
-        market_coin_vault: account_bytes(ix, 11 + offset),
-        market_pc_vault: account_bytes(ix, 12 + offset),
-        market_vault_signer: account_bytes(ix, 13 + offset),
-        user_token_source: account_bytes(ix, 14 + offset),
-        user_token_destination: account_bytes(ix, 15 + offset),
-        user_source_owner: account_bytes(ix, 16 + offset),
+        amm_coin_vault: account_bytes(ix, 4 + offset).unwrap_or_default(),
+        amm_pc_vault: account_bytes(ix, 5 + offset).unwrap_or_default(),
+        market_program: account_bytes(ix, 6 + offset).unwrap_or_default(),
+        market: account_bytes(ix, 7 + offset).unwrap_or_default(),
+        market_bids: account_bytes(ix, 8 + offset).unwrap_or_default(),
+        market_asks: account_bytes(ix, 9 + offset).unwrap_or_default(),
+        market_event_queue: account_bytes(ix, 10 + offset).unwrap_or_default(),
+        market_coin_vault: account_bytes(ix, 11 + offset).unwrap_or_default(),
+        market_pc_vault: account_bytes(ix, 12 + offset).unwrap_or_default(),
+        market_vault_signer: account_bytes(ix, 13 + offset).unwrap_or_default(),
+        user_token_source: account_bytes(ix, 14 + offset).unwrap_or_default(),
+        user_token_destination: account_bytes(ix, 15 + offset).unwrap_or_default(),
+        user_source_owner: account_bytes(ix, 16 + offset).unwrap_or_default(),
     }
 }
+
+/// Deposit & Withdraw accounts, `indexes` are the positions of
+/// `[amm, lp_mint, amm_coin_vault, amm_pc_vault, user_coin_token, user_pc_token, user_lp_token, user_owner]`.
+fn get_liquidity_accounts(ix: &InstructionView, indexes: [usize; 8]) -> Option<pb::LiquidityAccounts> {
+    let accounts = ix.accounts();
+    let account = |i: usize| accounts.get(indexes[i]).map(|account| account.0.to_vec());
+    let token_accounts = TokenAccounts::new(ix.transaction());
+    let amm_coin_vault = account(2)?;
+    let amm_pc_vault = account(3)?;
+
+    Some(pb::LiquidityAccounts {
+        amm: account(0)?,
+        lp_mint: account(1)?,
+        coin_mint: token_accounts.mint(&amm_coin_vault).cloned().unwrap_or_default(),
+        pc_mint: token_accounts.mint(&amm_pc_vault).cloned().unwrap_or_default(),
+        amm_coin_vault,
+        amm_pc_vault,
+        user_coin_token: account(4)?,
+        user_pc_token: account(5)?,
+        user_lp_token: account(6)?,
+        user_owner: account(7)?,
+    })
+}
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::swaps::{get_transferred_amount, TokenAccounts, TransferDirection};
 use proto::pb::raydium::clmm::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,19 @@ use substreams_solana::{
 };
 use substreams_solana_idls::raydium;
 
+const CREATE_POOL: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+const OPEN_POSITION: [u8; 8] = [135, 128, 47, 77, 15, 152, 240, 49];
+const OPEN_POSITION_V2: [u8; 8] = [77, 184, 74, 214, 112, 86, 241, 199];
+const OPEN_POSITION_WITH_TOKEN22_NFT: [u8; 8] = [77, 255, 174, 82, 125, 29, 201, 46];
+const CLOSE_POSITION: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+const INCREASE_LIQUIDITY: [u8; 8] = [46, 156, 243, 118, 13, 205, 251, 178];
+const INCREASE_LIQUIDITY_V2: [u8; 8] = [133, 29, 89, 223, 69, 238, 176, 10];
+const DECREASE_LIQUIDITY: [u8; 8] = [160, 38, 208, 111, 104, 91, 44, 1];
+const DECREASE_LIQUIDITY_V2: [u8; 8] = [58, 127, 188, 62, 79, 82, 196, 96];
+const DECREASE_LIQUIDITY_EVENT: [u8; 8] = [58, 222, 86, 58, 68, 50, 85, 56];
+const CREATE_PERSONAL_POSITION_EVENT: [u8; 8] = [100, 30, 87, 249, 196, 223, 154, 206];
+const INCREASE_LIQUIDITY_EVENT: [u8; 8] = [49, 79, 105, 212, 32, 34, 30, 84];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -42,12 +56,24 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_create_pool_instruction(ix).or_else(|| process_position_instruction(ix)) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match raydium::clmm::v3::instructions::unpack(ix.data()) {
         Ok(raydium::clmm::v3::instructions::RaydiumClmmInstruction::Swap(event)) => {
             let accounts = raydium::clmm::v3::accounts::get_swap_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::swap_instruction::Accounts::V1Accounts(pb::SwapAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -65,6 +91,7 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                     other_amount_threshold: event.other_amount_threshold,
                     sqrt_price_limit_x64: event.sqrt_price_limit_x64.to_string(),
                     is_base_input: event.is_base_input,
+                    vault_balances: get_vault_balances(ix, [&accounts.input_vault.to_bytes(), &accounts.output_vault.to_bytes()]),
                 })),
             })
         }
@@ -73,6 +100,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                     accounts: Some(pb::swap_instruction::Accounts::V2Accounts(pb::SwapV2Accounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -93,6 +122,7 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                     other_amount_threshold: event.other_amount_threshold,
                     sqrt_price_limit_x64: event.sqrt_price_limit_x64.to_string(),
                     is_base_input: event.is_base_input,
+                    vault_balances: get_vault_balances(ix, [&accounts.input_vault.to_bytes(), &accounts.output_vault.to_bytes()]),
                 })),
             })
         }
@@ -100,6 +130,134 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
+// create_pool(sqrt_price_x64: u128, open_time: u64)
+fn process_create_pool_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    if data.get(0..8)? != CREATE_POOL {
+        return None;
+    }
+
+    Some(pb::instruction::Instruction::CreatePool(pb::CreatePoolInstruction {
+        accounts: Some(pb::CreatePoolAccounts {
+            pool_creator: account_bytes(ix, 0)?,
+            amm_config: account_bytes(ix, 1)?,
+            pool_state: account_bytes(ix, 2)?,
+            token_mint_0: account_bytes(ix, 3)?,
+            token_mint_1: account_bytes(ix, 4)?,
+            token_vault_0: account_bytes(ix, 5)?,
+            token_vault_1: account_bytes(ix, 6)?,
+        }),
+        sqrt_price_x64: u128::from_le_bytes(data.get(8..24)?.try_into().ok()?).to_string(),
+        open_time: u64::from_le_bytes(data.get(24..32)?.try_into().ok()?),
+    }))
+}
+
+fn process_position_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+
+    match discriminator {
+        // open_position(tick_lower_index: i32, tick_upper_index: i32, tick_array_lower_start_index: i32, tick_array_upper_start_index: i32,
+        //               liquidity: u128, amount_0_max: u64, amount_1_max: u64, ..)
+        OPEN_POSITION | OPEN_POSITION_V2 | OPEN_POSITION_WITH_TOKEN22_NFT => {
+            // the token22 NFT has no metadata account
+            let offset = if discriminator == OPEN_POSITION_WITH_TOKEN22_NFT { 1 } else { 0 };
+            let accounts = pb::OpenPositionAccounts {
+                payer: account_bytes(ix, 0)?,
+                position_nft_owner: account_bytes(ix, 1)?,
+                position_nft_mint: account_bytes(ix, 2)?,
+                position_nft_account: account_bytes(ix, 3)?,
+                pool_state: account_bytes(ix, 5 - offset)?,
+                personal_position: account_bytes(ix, 9 - offset)?,
+                token_vault_0: account_bytes(ix, 12 - offset)?,
+                token_vault_1: account_bytes(ix, 13 - offset)?,
+            };
+            Some(pb::instruction::Instruction::OpenPosition(pb::OpenPositionInstruction {
+                tick_lower_index: i32::from_le_bytes(data.get(8..12)?.try_into().ok()?),
+                tick_upper_index: i32::from_le_bytes(data.get(12..16)?.try_into().ok()?),
+                liquidity: u128::from_le_bytes(data.get(24..40)?.try_into().ok()?).to_string(),
+                amount_0_max: u64::from_le_bytes(data.get(40..48)?.try_into().ok()?),
+                amount_1_max: u64::from_le_bytes(data.get(48..56)?.try_into().ok()?),
+                amount_0: get_transferred_amount(ix, &accounts.token_vault_0, TransferDirection::Received),
+                amount_1: get_transferred_amount(ix, &accounts.token_vault_1, TransferDirection::Received),
+                accounts: Some(accounts),
+            }))
+        }
+        CLOSE_POSITION => Some(pb::instruction::Instruction::ClosePosition(pb::ClosePositionInstruction {
+            accounts: Some(pb::ClosePositionAccounts {
+                nft_owner: account_bytes(ix, 0)?,
+                position_nft_mint: account_bytes(ix, 1)?,
+                position_nft_account: account_bytes(ix, 2)?,
+                personal_position: account_bytes(ix, 3)?,
+            }),
+        })),
+        // increase_liquidity(liquidity: u128, amount_0_max: u64, amount_1_max: u64, ..)
+        INCREASE_LIQUIDITY | INCREASE_LIQUIDITY_V2 => {
+            let accounts = pb::ModifyLiquidityAccounts {
+                nft_owner: account_bytes(ix, 0)?,
+                nft_account: account_bytes(ix, 1)?,
+                pool_state: account_bytes(ix, 2)?,
+                personal_position: account_bytes(ix, 4)?,
+                token_vault_0: account_bytes(ix, 9)?,
+                token_vault_1: account_bytes(ix, 10)?,
+            };
+            Some(pb::instruction::Instruction::IncreaseLiquidity(get_modify_liquidity_instruction(
+                ix,
+                accounts,
+                TransferDirection::Received,
+            )?))
+        }
+        // decrease_liquidity(liquidity: u128, amount_0_min: u64, amount_1_min: u64)
+        DECREASE_LIQUIDITY | DECREASE_LIQUIDITY_V2 => {
+            let accounts = pb::ModifyLiquidityAccounts {
+                nft_owner: account_bytes(ix, 0)?,
+                nft_account: account_bytes(ix, 1)?,
+                personal_position: account_bytes(ix, 2)?,
+                pool_state: account_bytes(ix, 3)?,
+                token_vault_0: account_bytes(ix, 5)?,
+                token_vault_1: account_bytes(ix, 6)?,
+            };
+            Some(pb::instruction::Instruction::DecreaseLiquidity(get_modify_liquidity_instruction(
+                ix,
+                accounts,
+                TransferDirection::Sent,
+            )?))
+        }
+        _ => None,
+    }
+}
+
+fn get_modify_liquidity_instruction(
+    ix: &InstructionView,
+    accounts: pb::ModifyLiquidityAccounts,
+    direction: TransferDirection,
+) -> Option<pb::ModifyLiquidityInstruction> {
+    let data = ix.data();
+    Some(pb::ModifyLiquidityInstruction {
+        liquidity: u128::from_le_bytes(data.get(8..24)?.try_into().ok()?).to_string(),
+        amount_0_limit: u64::from_le_bytes(data.get(24..32)?.try_into().ok()?),
+        amount_1_limit: u64::from_le_bytes(data.get(32..40)?.try_into().ok()?),
+        amount_0: get_transferred_amount(ix, &accounts.token_vault_0, direction),
+        amount_1: get_transferred_amount(ix, &accounts.token_vault_1, direction),
+        accounts: Some(accounts),
+    })
+}
+
+/// Post balances of the pool vaults, resolved from the transaction token balances.
+fn get_vault_balances(ix: &InstructionView, vaults: [&[u8]; 2]) -> Vec<pb::VaultBalance> {
+    let accounts = TokenAccounts::new(ix.transaction());
+    vaults
+        .into_iter()
+        .filter_map(|vault| {
+            Some(pb::VaultBalance {
+                vault: vault.to_vec(),
+                mint: accounts.mint(vault)?.clone(),
+                post_amount: accounts.post_amount(vault)?,
+            })
+        })
+        .collect()
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
@@ -131,6 +289,17 @@ fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec
 fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32) -> Option<pb::Log> {
     let data = parse_program_data(log_message)?;
 
+    if let Some(log) = parse_decrease_liquidity_log(&data)
+        .or_else(|| parse_create_personal_position_log(&data))
+        .or_else(|| parse_increase_liquidity_log(&data))
+    {
+        return Some(pb::Log {
+            program_id: program_id_bytes.to_vec(),
+            invoke_depth,
+            log: Some(log),
+        });
+    }
+
     match raydium::clmm::v3::events::unpack(data.as_slice()) {
         Ok(raydium::clmm::v3::events::RaydiumClmmEvent::SwapEvent(event)) => Some(pb::Log {
             program_id: program_id_bytes.to_vec(),
@@ -153,3 +322,66 @@ fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32)
         _ => None,
     }
 }
+
+// DecreaseLiquidityEvent { position_nft_mint: Pubkey, liquidity: u128, decrease_amount_0: u64, decrease_amount_1: u64,
+//                          fee_amount_0: u64, fee_amount_1: u64, reward_amounts: [u64; 3], transfer_fee_0: u64, transfer_fee_1: u64 }
+fn parse_decrease_liquidity_log(data: &[u8]) -> Option<pb::log::Log> {
+    if data.get(0..8)? != DECREASE_LIQUIDITY_EVENT {
+        return None;
+    }
+    let u64_at = |offset: usize| -> Option<u64> { Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?)) };
+
+    Some(pb::log::Log::DecreaseLiquidity(pb::DecreaseLiquidityLog {
+        position_nft_mint: data.get(8..40)?.to_vec(),
+        liquidity: u128::from_le_bytes(data.get(40..56)?.try_into().ok()?).to_string(),
+        decrease_amount_0: u64_at(56)?,
+        decrease_amount_1: u64_at(64)?,
+        fee_amount_0: u64_at(72)?,
+        fee_amount_1: u64_at(80)?,
+        reward_amounts: vec![u64_at(88)?, u64_at(96)?, u64_at(104)?],
+        transfer_fee_0: u64_at(112)?,
+        transfer_fee_1: u64_at(120)?,
+    }))
+}
+
+// CreatePersonalPositionEvent { pool_state: Pubkey, minter: Pubkey, nft_owner: Pubkey, tick_lower_index: i32, tick_upper_index: i32, liquidity: u128,
+//                               deposit_amount_0: u64, deposit_amount_1: u64, deposit_amount_0_transfer_fee: u64, deposit_amount_1_transfer_fee: u64 }
+// the transfer fees were appended with the Token-2022 support, older events end after `deposit_amount_1`
+fn parse_create_personal_position_log(data: &[u8]) -> Option<pb::log::Log> {
+    if data.get(0..8)? != CREATE_PERSONAL_POSITION_EVENT {
+        return None;
+    }
+    let u64_at = |offset: usize| -> Option<u64> { Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?)) };
+    let i32_at = |offset: usize| -> Option<i32> { Some(i32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?)) };
+
+    Some(pb::log::Log::CreatePersonalPosition(pb::CreatePersonalPositionLog {
+        pool_state: data.get(8..40)?.to_vec(),
+        minter: data.get(40..72)?.to_vec(),
+        nft_owner: data.get(72..104)?.to_vec(),
+        tick_lower_index: i32_at(104)?,
+        tick_upper_index: i32_at(108)?,
+        liquidity: u128::from_le_bytes(data.get(112..128)?.try_into().ok()?).to_string(),
+        deposit_amount_0: u64_at(128)?,
+        deposit_amount_1: u64_at(136)?,
+        deposit_amount_0_transfer_fee: u64_at(144).unwrap_or_default(),
+        deposit_amount_1_transfer_fee: u64_at(152).unwrap_or_default(),
+    }))
+}
+
+// IncreaseLiquidityEvent { position_nft_mint: Pubkey, liquidity: u128, amount_0: u64, amount_1: u64, amount_0_transfer_fee: u64, amount_1_transfer_fee: u64 }
+// the transfer fees were appended with the Token-2022 support, older events end after `amount_1`
+fn parse_increase_liquidity_log(data: &[u8]) -> Option<pb::log::Log> {
+    if data.get(0..8)? != INCREASE_LIQUIDITY_EVENT {
+        return None;
+    }
+    let u64_at = |offset: usize| -> Option<u64> { Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?)) };
+
+    Some(pb::log::Log::IncreaseLiquidity(pb::IncreaseLiquidityLog {
+        position_nft_mint: data.get(8..40)?.to_vec(),
+        liquidity: u128::from_le_bytes(data.get(40..56)?.try_into().ok()?).to_string(),
+        amount_0: u64_at(56)?,
+        amount_1: u64_at(64)?,
+        amount_0_transfer_fee: u64_at(72).unwrap_or_default(),
+        amount_1_transfer_fee: u64_at(80).unwrap_or_default(),
+    }))
+}
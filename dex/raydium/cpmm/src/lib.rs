@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::swaps::{get_transferred_amount, TokenAccounts, TransferDirection};
 use proto::pb::raydium::cpmm::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +8,12 @@ use substreams_solana::{
 };
 use substreams_solana_idls::raydium;
 
+const INITIALIZE: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+const COLLECT_PROTOCOL_FEE: [u8; 8] = [136, 136, 252, 221, 194, 66, 126, 89];
+const COLLECT_FUND_FEE: [u8; 8] = [167, 138, 78, 149, 223, 194, 6, 126];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -42,12 +49,24 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         return None;
     }
 
+    if let Some(instruction) = process_initialize_instruction(ix).or_else(|| process_liquidity_instruction(ix)) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match raydium::cpmm::instructions::unpack(ix.data()) {
         Ok(raydium::cpmm::instructions::RaydiumCpmmInstruction::SwapBaseInput(event)) => {
             let accounts = raydium::cpmm::accounts::get_swap_base_input_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SwapBaseInput(pb::SwapBaseInputInstruction {
                     accounts: Some(pb::SwapAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -64,6 +83,7 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                         output_token_mint: accounts.output_token_mint.to_bytes().to_vec(),
                         observation_state: accounts.observation_state.to_bytes().to_vec(),
                     }),
+                    vault_balances: get_vault_balances(ix, [&accounts.input_vault.to_bytes(), &accounts.output_vault.to_bytes()]),
                     amount_in: event.amount_in,
                     minimum_amount_out: event.minimum_amount_out,
                 })),
@@ -74,6 +94,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SwapBaseOutput(pb::SwapBaseOutputInstruction {
                     accounts: Some(pb::SwapAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -90,6 +112,7 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                         output_token_mint: accounts.output_token_mint.to_bytes().to_vec(),
                         observation_state: accounts.observation_state.to_bytes().to_vec(),
                     }),
+                    vault_balances: get_vault_balances(ix, [&accounts.input_vault.to_bytes(), &accounts.output_vault.to_bytes()]),
                     max_amount_in: event.max_amount_in,
                     amount_out: event.amount_out,
                 })),
@@ -99,6 +122,119 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     }
 }
 
+// initialize(init_amount_0: u64, init_amount_1: u64, open_time: u64)
+fn process_initialize_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    if data.get(0..8)? != INITIALIZE {
+        return None;
+    }
+    let arg = |offset: usize| data.get(offset..offset + 8).and_then(|bytes| bytes.try_into().ok()).map(u64::from_le_bytes);
+
+    Some(pb::instruction::Instruction::Initialize(pb::InitializeInstruction {
+        accounts: Some(pb::InitializeAccounts {
+            creator: account_bytes(ix, 0)?,
+            amm_config: account_bytes(ix, 1)?,
+            pool_state: account_bytes(ix, 3)?,
+            token_0_mint: account_bytes(ix, 4)?,
+            token_1_mint: account_bytes(ix, 5)?,
+            lp_mint: account_bytes(ix, 6)?,
+            token_0_vault: account_bytes(ix, 10)?,
+            token_1_vault: account_bytes(ix, 11)?,
+        }),
+        init_amount_0: arg(8)?,
+        init_amount_1: arg(16)?,
+        open_time: arg(24)?,
+    }))
+}
+
+// deposit / withdraw (lp_token_amount: u64, maximum / minimum_token_0_amount: u64, maximum / minimum_token_1_amount: u64)
+// collect_protocol_fee / collect_fund_fee (amount_0_requested: u64, amount_1_requested: u64)
+fn process_liquidity_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let data = ix.data();
+    let discriminator: [u8; 8] = data.get(0..8)?.try_into().ok()?;
+    let arg = |offset: usize| data.get(offset..offset + 8).and_then(|bytes| bytes.try_into().ok()).map(u64::from_le_bytes);
+
+    match discriminator {
+        DEPOSIT => {
+            let accounts = get_liquidity_accounts(ix)?;
+            Some(pb::instruction::Instruction::Deposit(pb::DepositInstruction {
+                vault_balances: get_vault_balances(ix, [&accounts.token_0_vault, &accounts.token_1_vault]),
+                accounts: Some(accounts),
+                lp_token_amount: arg(8)?,
+                maximum_token_0_amount: arg(16)?,
+                maximum_token_1_amount: arg(24)?,
+            }))
+        }
+        WITHDRAW => {
+            let accounts = get_liquidity_accounts(ix)?;
+            Some(pb::instruction::Instruction::Withdraw(pb::WithdrawInstruction {
+                vault_balances: get_vault_balances(ix, [&accounts.token_0_vault, &accounts.token_1_vault]),
+                accounts: Some(accounts),
+                lp_token_amount: arg(8)?,
+                minimum_token_0_amount: arg(16)?,
+                minimum_token_1_amount: arg(24)?,
+            }))
+        }
+        COLLECT_PROTOCOL_FEE | COLLECT_FUND_FEE => {
+            let accounts = pb::CollectFeeAccounts {
+                owner: account_bytes(ix, 0)?,
+                pool_state: account_bytes(ix, 2)?,
+                amm_config: account_bytes(ix, 3)?,
+                token_0_vault: account_bytes(ix, 4)?,
+                token_1_vault: account_bytes(ix, 5)?,
+                vault_0_mint: account_bytes(ix, 6)?,
+                vault_1_mint: account_bytes(ix, 7)?,
+                recipient_token_0_account: account_bytes(ix, 8)?,
+                recipient_token_1_account: account_bytes(ix, 9)?,
+            };
+            let instruction = pb::CollectFeeInstruction {
+                amount_0: get_transferred_amount(ix, &accounts.recipient_token_0_account, TransferDirection::Received),
+                amount_1: get_transferred_amount(ix, &accounts.recipient_token_1_account, TransferDirection::Received),
+                vault_balances: get_vault_balances(ix, [&accounts.token_0_vault, &accounts.token_1_vault]),
+                accounts: Some(accounts),
+                amount_0_requested: arg(8)?,
+                amount_1_requested: arg(16)?,
+            };
+            match discriminator {
+                COLLECT_PROTOCOL_FEE => Some(pb::instruction::Instruction::CollectProtocolFee(instruction)),
+                _ => Some(pb::instruction::Instruction::CollectFundFee(instruction)),
+            }
+        }
+        _ => None,
+    }
+}
+
+// [owner, authority, pool_state, owner_lp_token, token_0_account, token_1_account, token_0_vault, token_1_vault, token_program, token_program_2022, vault_0_mint, vault_1_mint, lp_mint, (memo_program)]
+fn get_liquidity_accounts(ix: &InstructionView) -> Option<pb::LiquidityAccounts> {
+    Some(pb::LiquidityAccounts {
+        owner: account_bytes(ix, 0)?,
+        pool_state: account_bytes(ix, 2)?,
+        owner_lp_token: account_bytes(ix, 3)?,
+        token_0_account: account_bytes(ix, 4)?,
+        token_1_account: account_bytes(ix, 5)?,
+        token_0_vault: account_bytes(ix, 6)?,
+        token_1_vault: account_bytes(ix, 7)?,
+        vault_0_mint: account_bytes(ix, 10)?,
+        vault_1_mint: account_bytes(ix, 11)?,
+        lp_mint: account_bytes(ix, 12)?,
+    })
+}
+
+/// Post balances of the pool vaults, resolved from the transaction token balances.
+fn get_vault_balances(ix: &InstructionView, vaults: [&[u8]; 2]) -> Vec<pb::VaultBalance> {
+    let accounts = TokenAccounts::new(ix.transaction());
+    vaults
+        .into_iter()
+        .filter_map(|vault| {
+            Some(pb::VaultBalance {
+                vault: vault.to_vec(),
+                mint: accounts.mint(vault)?.clone(),
+                post_amount: accounts.post_amount(vault)?,
+            })
+        })
+        .collect()
+}
+
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
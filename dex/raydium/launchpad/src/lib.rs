@@ -1,4 +1,4 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
 use proto::pb::raydium::launchpad::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -7,6 +7,9 @@ use substreams_solana::{
 };
 use substreams_solana_idls::raydium;
 
+const MIGRATE_TO_AMM: [u8; 8] = [207, 82, 192, 145, 254, 207, 145, 223];
+const MIGRATE_TO_CPSWAP: [u8; 8] = [136, 92, 200, 103, 28, 218, 144, 140];
+
 #[substreams::handlers::map]
 fn map_events(block: Block) -> Result<pb::Events, Error> {
     Ok(pb::Events {
@@ -43,6 +46,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent::TradeEventV1(event) => Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::TradeEvent(pb::TradeEvent {
                     pool_state: event.pool_state.to_bytes().to_vec(),
                     total_base_sell: event.total_base_sell,
@@ -73,6 +78,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent::TradeEventV2(event) => Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::TradeEvent(pb::TradeEvent {
                     pool_state: event.pool_state.to_bytes().to_vec(),
                     total_base_sell: event.total_base_sell,
@@ -103,6 +110,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent::ClaimVestedEvent(event) => Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::ClaimVestedEvent(pb::ClaimVestedEvent {
                     pool_state: event.pool_state.to_bytes().to_vec(),
                     beneficiary: event.beneficiary.to_bytes().to_vec(),
@@ -112,6 +121,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent::CreateVestingEvent(event) => Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::CreateVestingEvent(pb::CreateVestingEvent {
                     pool_state: event.pool_state.to_bytes().to_vec(),
                     beneficiary: event.beneficiary.to_bytes().to_vec(),
@@ -121,6 +132,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent::PoolCreateEvent(event) => Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::PoolCreateEvent(pb::PoolCreateEvent {
                     pool_state: event.pool_state.to_bytes().to_vec(),
                     creator: event.creator.to_bytes().to_vec(),
@@ -164,18 +177,34 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
                         raydium::launchpad::anchor_cpi_event::AmmCreatorFeeOn::QuoteToken => pb::AmmCreatorFeeOn::QuoteToken as i32,
                         raydium::launchpad::anchor_cpi_event::AmmCreatorFeeOn::BothToken => pb::AmmCreatorFeeOn::BothToken as i32,
                     },
+                    base_mint: parent_account_bytes(ix, 6),
+                    quote_mint: parent_account_bytes(ix, 7),
+                    base_vault: parent_account_bytes(ix, 8),
+                    quote_vault: parent_account_bytes(ix, 9),
                 })),
             }),
             raydium::launchpad::anchor_cpi_event::RaydiumLaunchpadAnchorCpiEvent::Unknown => None,
         };
     }
 
+    if let Some(instruction) = process_migrate_instruction(ix) {
+        return Some(pb::Instruction {
+            program_id: program_id.to_vec(),
+            stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
+            instruction: Some(instruction),
+        });
+    }
+
     match raydium::launchpad::instructions::unpack(ix.data()) {
         Ok(raydium::launchpad::instructions::RaydiumLaunchpadInstruction::BuyExactIn(evt)) => {
             let accounts = raydium::launchpad::accounts::get_buy_exact_in_accounts(ix).ok()?;
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::BuyExactIn(pb::BuyExactInInstruction {
                     accounts: Some(pb::TradeAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -205,6 +234,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::BuyExactOut(pb::BuyExactOutInstruction {
                     accounts: Some(pb::TradeAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -234,6 +265,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SellExactIn(pb::SellExactInInstruction {
                     accounts: Some(pb::TradeAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -263,6 +296,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
             Some(pb::Instruction {
                 program_id: program_id.to_vec(),
                 stack_height: ix.stack_height(),
+                parent_program_id: get_parent_program_id(ix),
+                outer_program_id: get_outer_program_id(ix),
                 instruction: Some(pb::instruction::Instruction::SellExactOut(pb::SellExactOutInstruction {
                     accounts: Some(pb::TradeAccounts {
                         payer: accounts.payer.to_bytes().to_vec(),
@@ -290,3 +325,35 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         _ => None,
     }
 }
+
+// Migration instructions are not part of the IDL crate
+fn process_migrate_instruction(ix: &InstructionView) -> Option<pb::instruction::Instruction> {
+    let discriminator: [u8; 8] = ix.data().get(0..8)?.try_into().ok()?;
+    match discriminator {
+        // [payer, base_mint, quote_mint, openbook_program, .., amm_program (12), amm_pool (13), .., pool_state (23), ..]
+        MIGRATE_TO_AMM => Some(pb::instruction::Instruction::MigrateToAmm(get_migrate_instruction(ix, 23, 12, 13)?)),
+        // [payer, base_mint, quote_mint, platform_config, cpswap_program (4), cpswap_pool (5), .., pool_state (17), ..]
+        MIGRATE_TO_CPSWAP => Some(pb::instruction::Instruction::MigrateToCpswap(get_migrate_instruction(ix, 17, 4, 5)?)),
+        _ => None,
+    }
+}
+
+fn get_migrate_instruction(ix: &InstructionView, pool_state: usize, amm_program: usize, amm_pool: usize) -> Option<pb::MigrateInstruction> {
+    Some(pb::MigrateInstruction {
+        accounts: Some(pb::MigrateAccounts {
+            payer: account_bytes(ix, 0)?,
+            base_mint: account_bytes(ix, 1)?,
+            quote_mint: account_bytes(ix, 2)?,
+            pool_state: account_bytes(ix, pool_state)?,
+            amm_program: account_bytes(ix, amm_program)?,
+            amm_pool: account_bytes(ix, amm_pool)?,
+        }),
+    })
+}
+
+/// Account of the instruction emitting the Anchor CPI event (`initialize*` share the same leading accounts).
+fn parent_account_bytes(ix: &InstructionView, idx: usize) -> Vec<u8> {
+    ix.parent_instruction()
+        .and_then(|parent| parent.accounts().get(idx).map(|account| account.0.to_vec()))
+        .unwrap_or_default()
+}
@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::sanctum::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -37,19 +38,52 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(sanctum::instructions::SanctumInstruction::SwapViaStake { amount }) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                 amount_in: amount,
                 minimum_amount_out: 0,
+                accounts: Some(get_swap_via_stake_accounts(ix)),
+                ..SwapAmounts::from_instruction(ix).into()
             })),
         }),
         Ok(sanctum::instructions::SanctumInstruction::PrefundSwapViaStake { amount }) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                 amount_in: amount,
                 minimum_amount_out: 0,
+                accounts: Some(get_prefund_swap_via_stake_accounts(ix)),
+                ..SwapAmounts::from_instruction(ix).into()
             })),
         }),
         _ => None,
     }
 }
+
+// SwapViaStake
+// [user, src_token_from, dest_token_to, bridge_stake, dest_token_fee_token_account, src_token_mint, dest_token_mint, ..]
+fn get_swap_via_stake_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        user: account_bytes(ix, 0).unwrap_or_default(),
+        src_token_from: account_bytes(ix, 1).unwrap_or_default(),
+        dest_token_to: account_bytes(ix, 2).unwrap_or_default(),
+        bridge_stake: account_bytes(ix, 3).unwrap_or_default(),
+        src_token_mint: account_bytes(ix, 5).unwrap_or_default(),
+        dest_token_mint: account_bytes(ix, 6).unwrap_or_default(),
+    }
+}
+
+// PrefundSwapViaStake
+// [user, src_token_from, bridge_stake, dest_token_to, ..]
+fn get_prefund_swap_via_stake_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        user: account_bytes(ix, 0).unwrap_or_default(),
+        src_token_from: account_bytes(ix, 1).unwrap_or_default(),
+        bridge_stake: account_bytes(ix, 2).unwrap_or_default(),
+        dest_token_to: account_bytes(ix, 3).unwrap_or_default(),
+        ..Default::default()
+    }
+}
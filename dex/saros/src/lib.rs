@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::saros::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -45,11 +46,32 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(saros::instructions::SarosInstruction::Swap(event)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                 amount_in: event.amount_in,
                 minimum_amount_out: event.minimum_amount_out,
+                accounts: Some(get_swap_accounts(ix)),
+                ..SwapAmounts::from_instruction(ix).into()
             })),
         }),
         _ => None,
     }
 }
+
+// Swap (SPL token-swap layout)
+// [swap, swap_authority, user_transfer_authority, user_source, pool_source, pool_destination, user_destination, pool_mint, pool_fee_account, token_program, (host_fee_account)]
+fn get_swap_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        swap: account_bytes(ix, 0).unwrap_or_default(),
+        swap_authority: account_bytes(ix, 1).unwrap_or_default(),
+        user_transfer_authority: account_bytes(ix, 2).unwrap_or_default(),
+        user_source: account_bytes(ix, 3).unwrap_or_default(),
+        pool_source: account_bytes(ix, 4).unwrap_or_default(),
+        pool_destination: account_bytes(ix, 5).unwrap_or_default(),
+        user_destination: account_bytes(ix, 6).unwrap_or_default(),
+        pool_mint: account_bytes(ix, 7).unwrap_or_default(),
+        pool_fee_account: account_bytes(ix, 8).unwrap_or_default(),
+        token_program: account_bytes(ix, 9).unwrap_or_default(),
+    }
+}
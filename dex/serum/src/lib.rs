@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{account_bytes, get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::SwapAmounts;
 use proto::pb::serum::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -33,18 +34,73 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
     let program_id = ix.program_id().0;
     if program_id != &serum::PROGRAM_ID { return None; }
 
-    match serum::instructions::unpack(ix.data()) {
+    let accounts = match serum::instructions::unpack(ix.data()) {
         Ok(serum::instructions::SerumInstruction::NewOrder(_)) |
-        Ok(serum::instructions::SerumInstruction::NewOrderV2(_)) |
-        Ok(serum::instructions::SerumInstruction::NewOrderV3(_)) |
-        Ok(serum::instructions::SerumInstruction::SendTake(_)) => Some(pb::Instruction {
-            program_id: program_id.to_vec(),
-            stack_height: ix.stack_height(),
-            instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
-                amount_in: 0,
-                minimum_amount_out: 0,
-            })),
-        }),
-        _ => None,
+        Ok(serum::instructions::SerumInstruction::NewOrderV2(_)) => get_new_order_accounts(ix),
+        Ok(serum::instructions::SerumInstruction::NewOrderV3(_)) => get_new_order_v3_accounts(ix),
+        Ok(serum::instructions::SerumInstruction::SendTake(_)) => get_send_take_accounts(ix),
+        _ => return None,
+    };
+
+    Some(pb::Instruction {
+        program_id: program_id.to_vec(),
+        stack_height: ix.stack_height(),
+        parent_program_id: get_parent_program_id(ix),
+        outer_program_id: get_outer_program_id(ix),
+        instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
+            amount_in: 0,
+            minimum_amount_out: 0,
+            accounts: Some(accounts),
+            ..SwapAmounts::from_instruction(ix).into()
+        })),
+    })
+}
+
+// NewOrder / NewOrderV2
+// [market, open_orders, request_queue, order_payer, owner, coin_vault, pc_vault, token_program, rent, (fee_discount)]
+fn get_new_order_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        market: account_bytes(ix, 0).unwrap_or_default(),
+        open_orders: account_bytes(ix, 1).unwrap_or_default(),
+        order_payer: account_bytes(ix, 3).unwrap_or_default(),
+        owner: account_bytes(ix, 4).unwrap_or_default(),
+        coin_vault: account_bytes(ix, 5).unwrap_or_default(),
+        pc_vault: account_bytes(ix, 6).unwrap_or_default(),
+        token_program: account_bytes(ix, 7).unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+// NewOrderV3
+// [market, open_orders, request_queue, event_queue, bids, asks, order_payer, owner, coin_vault, pc_vault, token_program, rent, (fee_discount)]
+fn get_new_order_v3_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        market: account_bytes(ix, 0).unwrap_or_default(),
+        open_orders: account_bytes(ix, 1).unwrap_or_default(),
+        bids: account_bytes(ix, 4).unwrap_or_default(),
+        asks: account_bytes(ix, 5).unwrap_or_default(),
+        order_payer: account_bytes(ix, 6).unwrap_or_default(),
+        owner: account_bytes(ix, 7).unwrap_or_default(),
+        coin_vault: account_bytes(ix, 8).unwrap_or_default(),
+        pc_vault: account_bytes(ix, 9).unwrap_or_default(),
+        token_program: account_bytes(ix, 10).unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+// SendTake
+// [market, request_queue, event_queue, bids, asks, coin_wallet, pc_wallet, owner, coin_vault, pc_vault, token_program, vault_signer, (fee_discount)]
+fn get_send_take_accounts(ix: &InstructionView) -> pb::SwapAccounts {
+    pb::SwapAccounts {
+        market: account_bytes(ix, 0).unwrap_or_default(),
+        bids: account_bytes(ix, 3).unwrap_or_default(),
+        asks: account_bytes(ix, 4).unwrap_or_default(),
+        coin_wallet: account_bytes(ix, 5).unwrap_or_default(),
+        pc_wallet: account_bytes(ix, 6).unwrap_or_default(),
+        owner: account_bytes(ix, 7).unwrap_or_default(),
+        coin_vault: account_bytes(ix, 8).unwrap_or_default(),
+        pc_vault: account_bytes(ix, 9).unwrap_or_default(),
+        token_program: account_bytes(ix, 10).unwrap_or_default(),
+        ..Default::default()
     }
 }
@@ -1,4 +1,7 @@
-use common::solana::{get_fee_payer, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data, parse_program_id};
+use common::solana::{
+    get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers, is_failed, is_invoke, is_success, parse_invoke_depth, parse_program_data,
+    parse_program_id, InvokeStack,
+};
 use proto::pb::solfi::v1::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::{
@@ -38,6 +41,8 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
         Ok(solfi::v1::instructions::SolfiInstruction::Swap(_)) => Some(pb::Instruction {
             program_id: program_id.to_vec(),
             stack_height: ix.stack_height(),
+            parent_program_id: get_parent_program_id(ix),
+            outer_program_id: get_outer_program_id(ix),
             instruction: Some(pb::instruction::Instruction::Swap(pb::SwapInstruction {
                 amount_in: 0,
                 minimum_out: 0,
@@ -51,19 +56,21 @@ fn process_instruction(ix: &InstructionView) -> Option<pb::Instruction> {
 fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec<pb::Log> {
     let mut logs = Vec::new();
     let mut is_invoked = false;
+    let mut invoke_stack = InvokeStack::default();
     for log_message in tx_meta.log_messages.iter() {
+        invoke_stack.update(log_message);
         let match_program_id = parse_program_id(log_message).map_or(false, |id| id == program_id_bytes.to_vec());
         if is_invoke(log_message) && match_program_id {
             if let Some(invoke_depth) = parse_invoke_depth(log_message) {
                 is_invoked = true;
-                if let Some(log_data) = parse_log_data(log_message, program_id_bytes, invoke_depth) {
+                if let Some(log_data) = parse_log_data(log_message, program_id_bytes, invoke_depth, &invoke_stack) {
                     logs.push(log_data);
                 }
             }
         } else if match_program_id && (is_success(log_message) || is_failed(log_message)) {
             is_invoked = false;
         } else if is_invoked {
-            if let Some(log_data) = parse_log_data(log_message, program_id_bytes, 0) {
+            if let Some(log_data) = parse_log_data(log_message, program_id_bytes, 0, &invoke_stack) {
                 logs.push(log_data);
             }
         }
@@ -71,12 +78,14 @@ fn process_logs(tx_meta: &TransactionStatusMeta, program_id_bytes: &[u8]) -> Vec
     logs
 }
 
-fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32) -> Option<pb::Log> {
+fn parse_log_data(log_message: &str, program_id_bytes: &[u8], invoke_depth: u32, invoke_stack: &InvokeStack) -> Option<pb::Log> {
     let data = parse_program_data(log_message)?;
     match solfi::v1::events::unpack(data.as_slice()) {
         Ok(solfi::v1::events::SolfiEvent::Swap(event)) => Some(pb::Log {
             program_id: program_id_bytes.to_vec(),
             invoke_depth,
+            parent_program_id: invoke_stack.parent_program_id(),
+            outer_program_id: invoke_stack.outer_program_id(),
             log: Some(pb::log::Log::Swap(pb::SwapEvent {
                 user: event.user.to_bytes().to_vec(),
                 amount_in: event.amount_in,
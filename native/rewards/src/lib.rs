@@ -0,0 +1,41 @@
+use proto::pb::solana::native::rewards::v1 as pb;
+use substreams::errors::Error;
+use substreams_solana::base58;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, Reward, RewardType};
+
+#[substreams::handlers::map]
+fn map_events(block: Block) -> Result<pb::Events, Error> {
+    let rewards: Vec<pb::Reward> = block.rewards.iter().filter_map(process_reward).collect();
+
+    // The fee reward is always credited to the slot leader's identity account.
+    let leader = rewards
+        .iter()
+        .find(|reward| reward.reward_type == pb::RewardType::Fee as i32)
+        .map(|reward| reward.account.clone())
+        .unwrap_or_default();
+
+    Ok(pb::Events { leader, rewards })
+}
+
+fn process_reward(reward: &Reward) -> Option<pb::Reward> {
+    let account = base58::decode(&reward.pubkey).ok()?;
+
+    Some(pb::Reward {
+        account,
+        lamports: reward.lamports,
+        post_balance: reward.post_balance,
+        reward_type: to_reward_type(reward.reward_type()) as i32,
+        // Commission is only reported for staking & voting rewards, as a decimal string (ex: "10")
+        commission: reward.commission.parse::<u32>().ok(),
+    })
+}
+
+fn to_reward_type(reward_type: RewardType) -> pb::RewardType {
+    match reward_type {
+        RewardType::Unspecified => pb::RewardType::Unspecified,
+        RewardType::Fee => pb::RewardType::Fee,
+        RewardType::Rent => pb::RewardType::Rent,
+        RewardType::Staking => pb::RewardType::Staking,
+        RewardType::Voting => pb::RewardType::Voting,
+    }
+}
@@ -1,5 +1,6 @@
 use proto::pb::solana::native::stake::v1 as pb;
 use solana_program::stake::instruction::StakeInstruction;
+use solana_program::stake::state::StakeAuthorize;
 
 use bincode::config;
 use substreams_solana::block_view::InstructionView;
@@ -97,6 +98,165 @@ pub fn unpack_instruction(instruction: &InstructionView) -> Option<pb::instructi
                 stake_authority: accounts.get(2).map_or(Vec::new(), |a| a.0.to_vec()),
             }))
         }
+        StakeInstruction::Authorize(new_authority, stake_authorize) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::Authorize(pb::Authorize {
+                stake_account: accounts[0].0.to_vec(),
+                new_authority: new_authority.to_bytes().to_vec(),
+                stake_authorize: to_stake_authorize(stake_authorize) as i32,
+                authority: accounts.get(2).map_or(Vec::new(), |a| a.0.to_vec()),
+                custodian: accounts.get(3).map(|a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::AuthorizeWithSeed(args) => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 2 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::AuthorizeWithSeed(pb::AuthorizeWithSeed {
+                stake_account: accounts[0].0.to_vec(),
+                new_authority: args.new_authorized_pubkey.to_bytes().to_vec(),
+                stake_authorize: to_stake_authorize(args.stake_authorize) as i32,
+                authority_base: accounts[1].0.to_vec(),
+                authority_seed: args.authority_seed,
+                authority_owner: args.authority_owner.to_bytes().to_vec(),
+                custodian: accounts.get(3).map(|a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::AuthorizeChecked(stake_authorize) => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 4 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::AuthorizeChecked(pb::AuthorizeChecked {
+                stake_account: accounts[0].0.to_vec(),
+                new_authority: accounts[3].0.to_vec(),
+                stake_authorize: to_stake_authorize(stake_authorize) as i32,
+                authority: accounts[2].0.to_vec(),
+                custodian: accounts.get(4).map(|a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::AuthorizeCheckedWithSeed(args) => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 4 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::AuthorizeCheckedWithSeed(pb::AuthorizeCheckedWithSeed {
+                stake_account: accounts[0].0.to_vec(),
+                new_authority: accounts[3].0.to_vec(),
+                stake_authorize: to_stake_authorize(args.stake_authorize) as i32,
+                authority_base: accounts[1].0.to_vec(),
+                authority_seed: args.authority_seed,
+                authority_owner: args.authority_owner.to_bytes().to_vec(),
+                custodian: accounts.get(4).map(|a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::SetLockup(lockup) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::SetLockup(pb::SetLockup {
+                stake_account: accounts[0].0.to_vec(),
+                lockup_unix_timestamp: lockup.unix_timestamp,
+                lockup_epoch: lockup.epoch,
+                lockup_custodian: lockup.custodian.map(|c| c.to_bytes().to_vec()),
+                authority: accounts.get(1).map_or(Vec::new(), |a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::SetLockupChecked(lockup) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::SetLockupChecked(pb::SetLockupChecked {
+                stake_account: accounts[0].0.to_vec(),
+                lockup_unix_timestamp: lockup.unix_timestamp,
+                lockup_epoch: lockup.epoch,
+                lockup_custodian: accounts.get(2).map(|a| a.0.to_vec()),
+                authority: accounts.get(1).map_or(Vec::new(), |a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::InitializeChecked => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 4 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::InitializeChecked(pb::InitializeChecked {
+                stake_account: accounts[0].0.to_vec(),
+                staker: accounts[2].0.to_vec(),
+                withdrawer: accounts[3].0.to_vec(),
+            }))
+        }
+        StakeInstruction::DeactivateDelinquent => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 3 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::DeactivateDelinquent(pb::DeactivateDelinquent {
+                stake_account: accounts[0].0.to_vec(),
+                delinquent_vote_account: accounts[1].0.to_vec(),
+                reference_vote_account: accounts[2].0.to_vec(),
+            }))
+        }
+        #[allow(deprecated)]
+        StakeInstruction::Redelegate => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 3 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::Redelegate(pb::Redelegate {
+                stake_account: accounts[0].0.to_vec(),
+                uninitialized_stake_account: accounts[1].0.to_vec(),
+                vote_account: accounts[2].0.to_vec(),
+                stake_authority: accounts.get(4).map_or(Vec::new(), |a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::MoveStake(lamports) => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 2 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::MoveStake(pb::MoveStake {
+                source_stake_account: accounts[0].0.to_vec(),
+                destination_stake_account: accounts[1].0.to_vec(),
+                lamports,
+                stake_authority: accounts.get(2).map_or(Vec::new(), |a| a.0.to_vec()),
+            }))
+        }
+        StakeInstruction::MoveLamports(lamports) => {
+            let accounts = instruction.accounts();
+            if accounts.len() < 2 {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::MoveLamports(pb::MoveLamports {
+                source_stake_account: accounts[0].0.to_vec(),
+                destination_stake_account: accounts[1].0.to_vec(),
+                lamports,
+                stake_authority: accounts.get(2).map_or(Vec::new(), |a| a.0.to_vec()),
+            }))
+        }
         _ => None,
     }
 }
+
+fn to_stake_authorize(stake_authorize: StakeAuthorize) -> pb::StakeAuthorize {
+    match stake_authorize {
+        StakeAuthorize::Staker => pb::StakeAuthorize::Staker,
+        StakeAuthorize::Withdrawer => pb::StakeAuthorize::Withdrawer,
+    }
+}
@@ -63,6 +63,77 @@ pub fn unpack_instruction(instruction: &InstructionView) -> Option<pb::instructi
                 authorized_withdrawer: accounts.get(2).map_or(Vec::new(), |a| a.0.to_vec()),
             }))
         }
+        VoteInstruction::Vote(vote) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::Vote(pb::Vote {
+                vote_account: accounts[0].0.to_vec(),
+                vote_authority: accounts.get(3).map_or(Vec::new(), |a| a.0.to_vec()),
+                slots: vote.slots,
+                hash: vote.hash.to_bytes().to_vec(),
+            }))
+        }
+        VoteInstruction::VoteSwitch(vote, proof_hash) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::VoteSwitch(pb::VoteSwitch {
+                vote_account: accounts[0].0.to_vec(),
+                vote_authority: accounts.get(3).map_or(Vec::new(), |a| a.0.to_vec()),
+                slots: vote.slots,
+                hash: vote.hash.to_bytes().to_vec(),
+                proof_hash: proof_hash.to_bytes().to_vec(),
+            }))
+        }
+        VoteInstruction::CompactUpdateVoteState(_) | VoteInstruction::CompactUpdateVoteStateSwitch(_, _) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::CompactUpdateVoteState(pb::CompactUpdateVoteState {
+                vote_account: accounts[0].0.to_vec(),
+                vote_authority: accounts.get(1).map_or(Vec::new(), |a| a.0.to_vec()),
+            }))
+        }
+        VoteInstruction::TowerSync(tower_sync) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::TowerSync(pb::TowerSync {
+                vote_account: accounts[0].0.to_vec(),
+                vote_authority: accounts.get(1).map_or(Vec::new(), |a| a.0.to_vec()),
+                slots: tower_sync.lockouts.iter().map(|lockout| lockout.slot()).collect(),
+                root: tower_sync.root,
+                hash: tower_sync.hash.to_bytes().to_vec(),
+                timestamp: tower_sync.timestamp,
+                block_id: tower_sync.block_id.to_bytes().to_vec(),
+            }))
+        }
+        VoteInstruction::TowerSyncSwitch(tower_sync, proof_hash) => {
+            let accounts = instruction.accounts();
+            if accounts.is_empty() {
+                return None;
+            }
+
+            Some(pb::instruction::Instruction::TowerSyncSwitch(pb::TowerSyncSwitch {
+                vote_account: accounts[0].0.to_vec(),
+                vote_authority: accounts.get(1).map_or(Vec::new(), |a| a.0.to_vec()),
+                slots: tower_sync.lockouts.iter().map(|lockout| lockout.slot()).collect(),
+                root: tower_sync.root,
+                hash: tower_sync.hash.to_bytes().to_vec(),
+                timestamp: tower_sync.timestamp,
+                block_id: tower_sync.block_id.to_bytes().to_vec(),
+                proof_hash: proof_hash.to_bytes().to_vec(),
+            }))
+        }
         _ => None,
     }
 }
@@ -34,6 +34,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="3")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="10")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -61,6 +67,17 @@ pub struct SwapInstruction {
     /// Direction of the swap.
     #[prost(bool, tag="4")]
     pub x_to_y: bool,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="5")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub input_amount: u64,
+    #[prost(bytes="vec", tag="8")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub output_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -30,6 +30,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="6")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3, 4")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -66,7 +72,7 @@ pub struct Log {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
-    #[prost(oneof="log::Log", tags="3, 4")]
+    #[prost(oneof="log::Log", tags="3, 4, 5, 6")]
     pub log: ::core::option::Option<log::Log>,
 }
 pub mod log {
@@ -77,6 +83,10 @@ pub mod log {
         Bought(super::TokenBoughtEvent),
         #[prost(message, tag="4")]
         Sold(super::TokenSoldEvent),
+        #[prost(message, tag="5")]
+        Created(super::TokenCreatedEvent),
+        #[prost(message, tag="6")]
+        Graduated(super::TokenGraduatedEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -111,3 +121,22 @@ pub struct TokenSoldEvent {
     #[prost(bytes="vec", tag="6")]
     pub recipient: ::prost::alloc::vec::Vec<u8>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenCreatedEvent {
+    #[prost(bytes="vec", tag="1")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub uri: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenGraduatedEvent {
+    #[prost(bytes="vec", tag="1")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+}
+// @@protoc_insertion_point(module)
@@ -62,7 +62,7 @@ pub struct Log {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
-    #[prost(oneof="log::Log", tags="3")]
+    #[prost(oneof="log::Log", tags="3, 4, 5, 6, 7, 8")]
     pub log: ::core::option::Option<log::Log>,
 }
 pub mod log {
@@ -71,6 +71,16 @@ pub mod log {
     pub enum Log {
         #[prost(message, tag="3")]
         Swap(super::SwapEvent),
+        #[prost(message, tag="4")]
+        OrderAction(super::OrderActionEvent),
+        #[prost(message, tag="5")]
+        FundingRate(super::FundingRateEvent),
+        #[prost(message, tag="6")]
+        Liquidation(super::LiquidationEvent),
+        #[prost(message, tag="7")]
+        Deposit(super::DepositEvent),
+        #[prost(message, tag="8")]
+        SettlePnl(super::SettlePnlEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -83,3 +93,290 @@ pub struct SwapEvent {
     #[prost(uint64, tag="3")]
     pub amount_out: u64,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderActionEvent {
+    #[prost(int64, tag="1")]
+    pub ts: i64,
+    #[prost(uint32, tag="2")]
+    pub action: u32,
+    #[prost(uint32, tag="3")]
+    pub action_explanation: u32,
+    #[prost(uint32, tag="4")]
+    pub market_index: u32,
+    #[prost(uint32, tag="5")]
+    pub market_type: u32,
+    #[prost(bytes="vec", optional, tag="6")]
+    pub filler: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, optional, tag="7")]
+    pub filler_reward: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub fill_record_id: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="9")]
+    pub base_asset_amount_filled: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="10")]
+    pub quote_asset_amount_filled: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="11")]
+    pub taker_fee: ::core::option::Option<u64>,
+    #[prost(int64, optional, tag="12")]
+    pub maker_fee: ::core::option::Option<i64>,
+    #[prost(uint32, optional, tag="13")]
+    pub referrer_reward: ::core::option::Option<u32>,
+    #[prost(int64, optional, tag="14")]
+    pub quote_asset_amount_surplus: ::core::option::Option<i64>,
+    #[prost(uint64, optional, tag="15")]
+    pub spot_fulfillment_method_fee: ::core::option::Option<u64>,
+    #[prost(bytes="vec", optional, tag="16")]
+    pub taker: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, optional, tag="17")]
+    pub taker_order_id: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="18")]
+    pub taker_order_direction: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag="19")]
+    pub taker_order_base_asset_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="20")]
+    pub taker_order_cumulative_base_asset_amount_filled: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="21")]
+    pub taker_order_cumulative_quote_asset_amount_filled: ::core::option::Option<u64>,
+    #[prost(bytes="vec", optional, tag="22")]
+    pub maker: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, optional, tag="23")]
+    pub maker_order_id: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag="24")]
+    pub maker_order_direction: ::core::option::Option<u32>,
+    #[prost(uint64, optional, tag="25")]
+    pub maker_order_base_asset_amount: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="26")]
+    pub maker_order_cumulative_base_asset_amount_filled: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="27")]
+    pub maker_order_cumulative_quote_asset_amount_filled: ::core::option::Option<u64>,
+    #[prost(int64, tag="28")]
+    pub oracle_price: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FundingRateEvent {
+    #[prost(int64, tag="1")]
+    pub ts: i64,
+    #[prost(uint64, tag="2")]
+    pub record_id: u64,
+    #[prost(uint32, tag="3")]
+    pub market_index: u32,
+    #[prost(int64, tag="4")]
+    pub funding_rate: i64,
+    #[prost(string, tag="5")]
+    pub funding_rate_long: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub funding_rate_short: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub cumulative_funding_rate_long: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub cumulative_funding_rate_short: ::prost::alloc::string::String,
+    #[prost(int64, tag="9")]
+    pub oracle_price_twap: i64,
+    #[prost(uint64, tag="10")]
+    pub mark_price_twap: u64,
+    #[prost(int64, tag="11")]
+    pub period_revenue: i64,
+    #[prost(string, tag="12")]
+    pub base_asset_amount_with_amm: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub base_asset_amount_with_unsettled_lp: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidationEvent {
+    #[prost(int64, tag="1")]
+    pub ts: i64,
+    #[prost(uint32, tag="2")]
+    pub liquidation_type: u32,
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub liquidator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="5")]
+    pub margin_requirement: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub total_collateral: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub margin_freed: u64,
+    #[prost(uint32, tag="8")]
+    pub liquidation_id: u32,
+    #[prost(bool, tag="9")]
+    pub bankrupt: bool,
+    #[prost(uint32, repeated, tag="10")]
+    pub canceled_order_ids: ::prost::alloc::vec::Vec<u32>,
+    #[prost(message, optional, tag="11")]
+    pub liquidate_perp: ::core::option::Option<LiquidatePerp>,
+    #[prost(message, optional, tag="12")]
+    pub liquidate_spot: ::core::option::Option<LiquidateSpot>,
+    #[prost(message, optional, tag="13")]
+    pub liquidate_borrow_for_perp_pnl: ::core::option::Option<LiquidateBorrowForPerpPnl>,
+    #[prost(message, optional, tag="14")]
+    pub liquidate_perp_pnl_for_deposit: ::core::option::Option<LiquidatePerpPnlForDeposit>,
+    #[prost(message, optional, tag="15")]
+    pub perp_bankruptcy: ::core::option::Option<PerpBankruptcy>,
+    #[prost(message, optional, tag="16")]
+    pub spot_bankruptcy: ::core::option::Option<SpotBankruptcy>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LiquidatePerp {
+    #[prost(uint32, tag="1")]
+    pub market_index: u32,
+    #[prost(int64, tag="2")]
+    pub oracle_price: i64,
+    #[prost(int64, tag="3")]
+    pub base_asset_amount: i64,
+    #[prost(int64, tag="4")]
+    pub quote_asset_amount: i64,
+    #[prost(uint64, tag="5")]
+    pub lp_shares: u64,
+    #[prost(uint64, tag="6")]
+    pub fill_record_id: u64,
+    #[prost(uint32, tag="7")]
+    pub user_order_id: u32,
+    #[prost(uint32, tag="8")]
+    pub liquidator_order_id: u32,
+    #[prost(uint64, tag="9")]
+    pub liquidator_fee: u64,
+    #[prost(uint64, tag="10")]
+    pub if_fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidateSpot {
+    #[prost(uint32, tag="1")]
+    pub asset_market_index: u32,
+    #[prost(int64, tag="2")]
+    pub asset_price: i64,
+    #[prost(string, tag="3")]
+    pub asset_transfer: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub liability_market_index: u32,
+    #[prost(int64, tag="5")]
+    pub liability_price: i64,
+    #[prost(string, tag="6")]
+    pub liability_transfer: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub if_fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidateBorrowForPerpPnl {
+    #[prost(uint32, tag="1")]
+    pub perp_market_index: u32,
+    #[prost(int64, tag="2")]
+    pub market_oracle_price: i64,
+    #[prost(string, tag="3")]
+    pub pnl_transfer: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub liability_market_index: u32,
+    #[prost(int64, tag="5")]
+    pub liability_price: i64,
+    #[prost(string, tag="6")]
+    pub liability_transfer: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidatePerpPnlForDeposit {
+    #[prost(uint32, tag="1")]
+    pub perp_market_index: u32,
+    #[prost(int64, tag="2")]
+    pub market_oracle_price: i64,
+    #[prost(string, tag="3")]
+    pub pnl_transfer: ::prost::alloc::string::String,
+    #[prost(uint32, tag="4")]
+    pub asset_market_index: u32,
+    #[prost(int64, tag="5")]
+    pub asset_price: i64,
+    #[prost(string, tag="6")]
+    pub asset_transfer: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PerpBankruptcy {
+    #[prost(uint32, tag="1")]
+    pub market_index: u32,
+    #[prost(string, tag="2")]
+    pub pnl: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub if_payment: ::prost::alloc::string::String,
+    #[prost(bytes="vec", optional, tag="4")]
+    pub clawback_user: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, optional, tag="5")]
+    pub clawback_user_payment: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub cumulative_funding_rate_delta: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotBankruptcy {
+    #[prost(uint32, tag="1")]
+    pub market_index: u32,
+    #[prost(string, tag="2")]
+    pub borrow_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub if_payment: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub cumulative_deposit_interest_delta: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositEvent {
+    #[prost(int64, tag="1")]
+    pub ts: i64,
+    #[prost(bytes="vec", tag="2")]
+    pub user_authority: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="4")]
+    pub direction: u32,
+    #[prost(uint64, tag="5")]
+    pub deposit_record_id: u64,
+    #[prost(uint64, tag="6")]
+    pub amount: u64,
+    #[prost(uint32, tag="7")]
+    pub market_index: u32,
+    #[prost(int64, tag="8")]
+    pub oracle_price: i64,
+    #[prost(string, tag="9")]
+    pub market_deposit_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub market_withdraw_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub market_cumulative_deposit_interest: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub market_cumulative_borrow_interest: ::prost::alloc::string::String,
+    #[prost(uint64, tag="13")]
+    pub total_deposits_after: u64,
+    #[prost(uint64, tag="14")]
+    pub total_withdraws_after: u64,
+    #[prost(uint32, tag="15")]
+    pub explanation: u32,
+    #[prost(bytes="vec", optional, tag="16")]
+    pub transfer_user: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettlePnlEvent {
+    #[prost(int64, tag="1")]
+    pub ts: i64,
+    #[prost(bytes="vec", tag="2")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="3")]
+    pub market_index: u32,
+    #[prost(string, tag="4")]
+    pub pnl: ::prost::alloc::string::String,
+    #[prost(int64, tag="5")]
+    pub base_asset_amount: i64,
+    #[prost(int64, tag="6")]
+    pub quote_asset_amount_after: i64,
+    #[prost(int64, tag="7")]
+    pub quote_entry_amount: i64,
+    #[prost(int64, tag="8")]
+    pub settle_price: i64,
+    #[prost(uint32, tag="9")]
+    pub explanation: u32,
+}
+// @@protoc_insertion_point(module)
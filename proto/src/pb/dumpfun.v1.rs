@@ -31,6 +31,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="6")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="7")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3, 4, 5")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
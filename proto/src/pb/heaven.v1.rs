@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 6")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -41,6 +47,8 @@ pub mod instruction {
     pub enum Instruction {
         #[prost(message, tag="3")]
         Sell(super::SellInstruction),
+        #[prost(message, tag="6")]
+        Create(super::CreateInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -53,6 +61,15 @@ pub struct SellInstruction {
     #[prost(string, tag="3")]
     pub encoded_user_defined_event_data: ::prost::alloc::string::String,
 }
+/// Token launch, the mint is initialized by the Heaven instruction through an SPL Token CPI
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateInstruction {
+    #[prost(bytes="vec", tag="1")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="2")]
+    pub decimals: u32,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Log {
@@ -60,6 +77,12 @@ pub struct Log {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
+    /// Program invoking the program emitting this log (empty for top-level programs)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction emitting this log
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="log::Log", tags="3")]
     pub log: ::core::option::Option<log::Log>,
 }
@@ -34,7 +34,16 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="10, 11")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="12")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="13")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Jupiter V4 invocation emitting the event (1-based), 0 for Jupiter V6
+    #[prost(uint32, tag="14")]
+    pub invocation_index: u32,
+    #[prost(oneof="instruction::Instruction", tags="10, 11, 20, 21, 22, 23, 24")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -46,6 +55,16 @@ pub mod instruction {
         SwapEvent(super::SwapEvent),
         #[prost(message, tag="11")]
         FeeEvent(super::FeeEvent),
+        #[prost(message, tag="20")]
+        Route(super::RouteInstruction),
+        #[prost(message, tag="21")]
+        SharedAccountsRoute(super::RouteInstruction),
+        #[prost(message, tag="22")]
+        ExactOutRoute(super::ExactOutRouteInstruction),
+        #[prost(message, tag="23")]
+        SharedAccountsExactOutRoute(super::ExactOutRouteInstruction),
+        #[prost(message, tag="24")]
+        Other(super::OtherInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -80,4 +99,132 @@ pub struct FeeEvent {
     #[prost(uint64, tag="3")]
     pub amount: u64,
 }
+/// `route` & `shared_accounts_route` (exact input)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<RouteAccounts>,
+    /// Shared accounts program authority id (0 for `route`).
+    #[prost(uint32, tag="2")]
+    pub id: u32,
+    /// Route plan (empty if a step uses an unsupported swap variant).
+    #[prost(message, repeated, tag="3")]
+    pub route_plan: ::prost::alloc::vec::Vec<RoutePlanStep>,
+    /// Amount of input tokens.
+    #[prost(uint64, tag="4")]
+    pub in_amount: u64,
+    /// Quoted amount of output tokens.
+    #[prost(uint64, tag="5")]
+    pub quoted_out_amount: u64,
+    /// Slippage tolerance in basis points.
+    #[prost(uint32, tag="6")]
+    pub slippage_bps: u32,
+    /// Platform fee in basis points.
+    #[prost(uint32, tag="7")]
+    pub platform_fee_bps: u32,
+    /// Trade realized by the route.
+    #[prost(message, optional, tag="8")]
+    pub trade: ::core::option::Option<Trade>,
+}
+/// `exact_out_route` & `shared_accounts_exact_out_route` (exact output)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExactOutRouteInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<RouteAccounts>,
+    /// Shared accounts program authority id (0 for `exact_out_route`).
+    #[prost(uint32, tag="2")]
+    pub id: u32,
+    /// Route plan (empty if a step uses an unsupported swap variant).
+    #[prost(message, repeated, tag="3")]
+    pub route_plan: ::prost::alloc::vec::Vec<RoutePlanStep>,
+    /// Amount of output tokens.
+    #[prost(uint64, tag="4")]
+    pub out_amount: u64,
+    /// Quoted amount of input tokens.
+    #[prost(uint64, tag="5")]
+    pub quoted_in_amount: u64,
+    /// Slippage tolerance in basis points.
+    #[prost(uint32, tag="6")]
+    pub slippage_bps: u32,
+    /// Platform fee in basis points.
+    #[prost(uint32, tag="7")]
+    pub platform_fee_bps: u32,
+    /// Trade realized by the route.
+    #[prost(message, optional, tag="8")]
+    pub trade: ::core::option::Option<Trade>,
+}
+/// Jupiter V6 instruction invoked by another program (or top-level) which is not decoded (ex: `route_with_token_ledger`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OtherInstruction {
+    /// Anchor discriminator of the instruction.
+    #[prost(bytes="vec", tag="1")]
+    pub discriminator: ::prost::alloc::vec::Vec<u8>,
+    /// Route instruction name (ex: `route_v2`), empty if the instruction is not a route.
+    #[prost(string, tag="2")]
+    pub route: ::prost::alloc::string::String,
+    /// Trade realized by the route (route instructions only).
+    #[prost(message, optional, tag="3")]
+    pub trade: ::core::option::Option<Trade>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RouteAccounts {
+    /// User wallet signing the route.
+    #[prost(bytes="vec", tag="1")]
+    pub user_transfer_authority: ::prost::alloc::vec::Vec<u8>,
+    /// User source token account.
+    #[prost(bytes="vec", tag="2")]
+    pub source_token_account: ::prost::alloc::vec::Vec<u8>,
+    /// Token account receiving the output tokens.
+    #[prost(bytes="vec", tag="3")]
+    pub destination_token_account: ::prost::alloc::vec::Vec<u8>,
+    /// Input token mint (empty for `route`).
+    #[prost(bytes="vec", tag="4")]
+    pub source_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Output token mint.
+    #[prost(bytes="vec", tag="5")]
+    pub destination_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Platform fee token account (Jupiter program id when unused).
+    #[prost(bytes="vec", tag="6")]
+    pub platform_fee_account: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RoutePlanStep {
+    /// Swap variant index (ex: 7 = Raydium, 17 = Whirlpool).
+    #[prost(uint32, tag="1")]
+    pub swap: u32,
+    /// Percentage of the input amount routed through this step.
+    #[prost(uint32, tag="2")]
+    pub percent: u32,
+    /// Index of the input token in the route.
+    #[prost(uint32, tag="3")]
+    pub input_index: u32,
+    /// Index of the output token in the route.
+    #[prost(uint32, tag="4")]
+    pub output_index: u32,
+}
+/// Aggregate of the `SwapEvent`s emitted under a route instruction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trade {
+    /// Input token mint address (first hop).
+    #[prost(bytes="vec", tag="1")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Amount of input tokens swapped.
+    #[prost(uint64, tag="2")]
+    pub input_amount: u64,
+    /// Output token mint address (last hop).
+    #[prost(bytes="vec", tag="3")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Amount of output tokens received.
+    #[prost(uint64, tag="4")]
+    pub output_amount: u64,
+    /// Number of hops of the route, split steps between the same tokens count once.
+    #[prost(uint32, tag="5")]
+    pub hops: u32,
+}
 // @@protoc_insertion_point(module)
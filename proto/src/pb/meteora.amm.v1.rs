@@ -37,7 +37,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 6, 7, 8, 9, 10")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -47,8 +53,48 @@ pub mod instruction {
     pub enum Instruction {
         #[prost(message, tag="3")]
         Swap(super::SwapInstruction),
+        #[prost(message, tag="6")]
+        AddBalanceLiquidity(super::LiquidityInstruction),
+        #[prost(message, tag="7")]
+        AddImbalanceLiquidity(super::LiquidityInstruction),
+        #[prost(message, tag="8")]
+        RemoveBalanceLiquidity(super::LiquidityInstruction),
+        #[prost(message, tag="9")]
+        RemoveLiquiditySingleSide(super::LiquidityInstruction),
+        #[prost(message, tag="10")]
+        BootstrapLiquidity(super::LiquidityInstruction),
     }
 }
+/// Add/remove liquidity instructions, amounts are emitted by the matching liquidity log
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<LiquidityAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub lp_mint: ::prost::alloc::vec::Vec<u8>,
+    /// User LP token account
+    #[prost(bytes="vec", tag="3")]
+    pub user_pool_lp: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub a_token_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub b_token_vault: ::prost::alloc::vec::Vec<u8>,
+    /// Resolved from the token balances of the vaults
+    #[prost(bytes="vec", tag="6")]
+    pub token_a_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Resolved from the token balances of the vaults
+    #[prost(bytes="vec", tag="7")]
+    pub token_b_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SwapInstruction {
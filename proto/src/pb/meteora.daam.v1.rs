@@ -37,7 +37,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4, 5")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="6")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="7")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 8, 9, 10, 11, 12, 13, 14, 15, 16")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -51,6 +57,24 @@ pub mod instruction {
         RemoveLiquidity(super::RemoveLiquidityInstruction),
         #[prost(message, tag="5")]
         Swap(super::SwapInstruction),
+        #[prost(message, tag="8")]
+        InitializePool(super::InitializePoolInstruction),
+        #[prost(message, tag="9")]
+        InitializeCustomizablePool(super::InitializePoolInstruction),
+        #[prost(message, tag="10")]
+        InitializePoolWithDynamicConfig(super::InitializePoolInstruction),
+        #[prost(message, tag="11")]
+        ClaimPositionFee(super::ClaimPositionFeeInstruction),
+        #[prost(message, tag="12")]
+        ClaimReward(super::ClaimRewardInstruction),
+        #[prost(message, tag="13")]
+        LockPosition(super::LockPositionInstruction),
+        #[prost(message, tag="14")]
+        PermanentLockPosition(super::PermanentLockPositionInstruction),
+        #[prost(message, tag="15")]
+        SplitPosition(super::SplitPositionInstruction),
+        #[prost(message, tag="16")]
+        UpdatePoolFees(super::UpdatePoolFeesInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -77,6 +101,101 @@ pub struct SwapInstruction {
     #[prost(message, optional, tag="2")]
     pub params: ::core::option::Option<SwapParameters>,
 }
+/// Parameters are only decoded for `initialize_pool` (empty for the customizable pools)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializePoolInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<InitializePoolAccounts>,
+    #[prost(string, tag="2")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub sqrt_price: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimPositionFeeInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClaimPositionFeeAccounts>,
+    /// Transferred from token A vault
+    #[prost(uint64, tag="2")]
+    pub fee_a: u64,
+    /// Transferred from token B vault
+    #[prost(uint64, tag="3")]
+    pub fee_b: u64,
+}
+/// claim_reward(reward_index: u8)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimRewardInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClaimRewardAccounts>,
+    #[prost(uint32, tag="2")]
+    pub reward_index: u32,
+    /// Transferred from the reward vault
+    #[prost(uint64, tag="3")]
+    pub amount: u64,
+}
+/// Vesting lock, the liquidity is released from `cliff_point` then every `period_frequency`
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LockPositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<LockPositionAccounts>,
+    /// Slot or timestamp, depending on the pool activation type (current point when empty)
+    #[prost(uint64, optional, tag="2")]
+    pub cliff_point: ::core::option::Option<u64>,
+    #[prost(uint64, tag="3")]
+    pub period_frequency: u64,
+    /// u128
+    #[prost(string, tag="4")]
+    pub cliff_unlock_liquidity: ::prost::alloc::string::String,
+    /// u128
+    #[prost(string, tag="5")]
+    pub liquidity_per_period: ::prost::alloc::string::String,
+    #[prost(uint32, tag="6")]
+    pub number_of_period: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermanentLockPositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<PermanentLockPositionAccounts>,
+    /// u128
+    #[prost(string, tag="2")]
+    pub permanent_lock_liquidity: ::prost::alloc::string::String,
+}
+/// Percentages (0-100) of the first position moved to the second position
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitPositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<SplitPositionAccounts>,
+    #[prost(uint32, tag="2")]
+    pub unlocked_liquidity_percentage: u32,
+    #[prost(uint32, tag="3")]
+    pub permanent_locked_liquidity_percentage: u32,
+    #[prost(uint32, tag="4")]
+    pub fee_a_percentage: u32,
+    #[prost(uint32, tag="5")]
+    pub fee_b_percentage: u32,
+    #[prost(uint32, tag="6")]
+    pub reward_0_percentage: u32,
+    #[prost(uint32, tag="7")]
+    pub reward_1_percentage: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePoolFeesInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<UpdatePoolFeesAccounts>,
+    /// Unchanged when empty
+    #[prost(uint64, optional, tag="2")]
+    pub cliff_fee_numerator: ::core::option::Option<u64>,
+    /// Dynamic fee disabled when empty
+    #[prost(message, optional, tag="3")]
+    pub dynamic_fee: ::core::option::Option<DynamicFeeParameters>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddLiquidityAccounts {
@@ -177,6 +296,145 @@ pub struct SwapAccounts {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializePoolAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub creator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub payer: ::prost::alloc::vec::Vec<u8>,
+    /// empty for `initialize_customizable_pool`
+    #[prost(bytes="vec", tag="3")]
+    pub config: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_a_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_b_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_a_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub token_b_vault: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimPositionFeeAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub token_a_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub token_b_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_a_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_b_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_a_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_b_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimRewardAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub reward_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub reward_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub user_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LockPositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub vesting: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub payer: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermanentLockPositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplitPositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub first_position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub first_position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub second_position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub second_position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub first_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub second_owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdatePoolFeesAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// Admin or operator allowed to update the fees
+    #[prost(bytes="vec", tag="2")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DynamicFeeParameters {
+    #[prost(uint32, tag="1")]
+    pub bin_step: u32,
+    /// u128
+    #[prost(string, tag="2")]
+    pub bin_step_u128: ::prost::alloc::string::String,
+    #[prost(uint32, tag="3")]
+    pub filter_period: u32,
+    #[prost(uint32, tag="4")]
+    pub decay_period: u32,
+    #[prost(uint32, tag="5")]
+    pub reduction_factor: u32,
+    #[prost(uint32, tag="6")]
+    pub max_volatility_accumulator: u32,
+    #[prost(uint32, tag="7")]
+    pub variable_fee_control: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddLiquidityParameters {
     #[prost(string, tag="1")]
     pub liquidity_delta: ::prost::alloc::string::String,
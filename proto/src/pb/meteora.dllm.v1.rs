@@ -29,7 +29,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="10, 11")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="12")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="13")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="10, 11, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -41,6 +47,30 @@ pub mod instruction {
         SwapInstruction(super::SwapInstruction),
         #[prost(message, tag="11")]
         SwapEvent(super::SwapEvent),
+        #[prost(message, tag="14")]
+        InitializeLbPair(super::InitializeLbPairInstruction),
+        #[prost(message, tag="15")]
+        InitializePosition(super::InitializePositionInstruction),
+        #[prost(message, tag="16")]
+        ClosePosition(super::ClosePositionInstruction),
+        #[prost(message, tag="17")]
+        AddLiquidity(super::ModifyLiquidityInstruction),
+        #[prost(message, tag="18")]
+        RemoveLiquidity(super::ModifyLiquidityInstruction),
+        #[prost(message, tag="19")]
+        ClaimFee(super::ClaimFeeInstruction),
+        #[prost(message, tag="20")]
+        ClaimReward(super::ClaimRewardInstruction),
+        #[prost(message, tag="21")]
+        AddLiquidityEvent(super::LiquidityEvent),
+        #[prost(message, tag="22")]
+        RemoveLiquidityEvent(super::LiquidityEvent),
+        #[prost(message, tag="23")]
+        ClaimFeeEvent(super::ClaimFeeEvent),
+        #[prost(message, tag="24")]
+        ClaimRewardEvent(super::ClaimRewardEvent),
+        #[prost(message, tag="25")]
+        PositionCreateEvent(super::PositionCreateEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -114,4 +144,224 @@ pub struct SwapAccounts {
     #[prost(bytes="vec", tag="15")]
     pub program: ::prost::alloc::vec::Vec<u8>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeLbPairInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<InitializeLbPairAccounts>,
+    #[prost(int32, tag="2")]
+    pub active_id: i32,
+    #[prost(uint32, tag="3")]
+    pub bin_step: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeLbPairAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub token_mint_x: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub token_mint_y: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub reserve_x: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub reserve_y: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub preset_parameter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub funder: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializePositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub payer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+}
+/// initialize_position & initialize_position_pda
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializePositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<InitializePositionAccounts>,
+    #[prost(int32, tag="2")]
+    pub lower_bin_id: i32,
+    #[prost(int32, tag="3")]
+    pub width: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub rent_receiver: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClosePositionAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyLiquidityAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub user_token_x: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub user_token_y: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub reserve_x: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub reserve_y: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_x_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_y_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+}
+/// add_liquidity*, remove_liquidity* & remove_all_liquidity, one-sided deposits leave the other token accounts empty
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyLiquidityInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ModifyLiquidityAccounts>,
+    /// Transferred to/from reserve X
+    #[prost(uint64, tag="2")]
+    pub amount_x: u64,
+    /// Transferred to/from reserve Y
+    #[prost(uint64, tag="3")]
+    pub amount_y: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimFeeAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub reserve_x: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub reserve_y: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub user_token_x: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub user_token_y: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_x_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub token_y_mint: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimFeeInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClaimFeeAccounts>,
+    /// Transferred from reserve X
+    #[prost(uint64, tag="2")]
+    pub fee_x: u64,
+    /// Transferred from reserve Y
+    #[prost(uint64, tag="3")]
+    pub fee_y: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimRewardAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub reward_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub reward_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub user_token_account: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimRewardInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClaimRewardAccounts>,
+    #[prost(uint64, tag="2")]
+    pub reward_index: u64,
+    /// Transferred from the reward vault
+    #[prost(uint64, tag="3")]
+    pub amount: u64,
+}
+/// AddLiquidity & RemoveLiquidity events
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityEvent {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub amount_x: u64,
+    #[prost(uint64, tag="5")]
+    pub amount_y: u64,
+    #[prost(int32, tag="6")]
+    pub active_bin_id: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimFeeEvent {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub fee_x: u64,
+    #[prost(uint64, tag="5")]
+    pub fee_y: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClaimRewardEvent {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub reward_index: u64,
+    #[prost(uint64, tag="5")]
+    pub total_reward: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionCreateEvent {
+    #[prost(bytes="vec", tag="1")]
+    pub lb_pair: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+}
 // @@protoc_insertion_point(module)
@@ -234,6 +234,13 @@ pub mod raydium {
     }
 }
 pub mod solana {
+    pub mod blocks {
+        // @@protoc_insertion_point(attribute:solana.blocks.v1)
+        pub mod v1 {
+            include!("solana.blocks.v1.rs");
+            // @@protoc_insertion_point(solana.blocks.v1)
+        }
+    }
     pub mod metaplex {
         // @@protoc_insertion_point(attribute:solana.metaplex.v1)
         pub mod v1 {
@@ -242,6 +249,13 @@ pub mod solana {
         }
     }
     pub mod native {
+        pub mod rewards {
+            // @@protoc_insertion_point(attribute:solana.native.rewards.v1)
+            pub mod v1 {
+                include!("solana.native.rewards.v1.rs");
+                // @@protoc_insertion_point(solana.native.rewards.v1)
+            }
+        }
         pub mod stake {
             // @@protoc_insertion_point(attribute:solana.native.stake.v1)
             pub mod v1 {
@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="7")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="8")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -43,6 +49,10 @@ pub mod instruction {
         Buy(super::BuyInstruction),
         #[prost(message, tag="4")]
         Sell(super::SellInstruction),
+        #[prost(message, tag="5")]
+        TokenMint(super::TokenMintInstruction),
+        #[prost(message, tag="6")]
+        MigrateFunds(super::MigrateFundsInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -54,6 +64,11 @@ pub struct BuyInstruction {
     pub collateral_amount: u64,
     #[prost(uint64, tag="3")]
     pub slippage_bps: u64,
+    #[prost(message, optional, tag="4")]
+    pub accounts: ::core::option::Option<TradeAccounts>,
+    /// Tokens left on the curve token account after the transaction
+    #[prost(uint64, tag="5")]
+    pub curve_token_reserves: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -64,6 +79,72 @@ pub struct SellInstruction {
     pub collateral_amount: u64,
     #[prost(uint64, tag="3")]
     pub slippage_bps: u64,
+    #[prost(message, optional, tag="4")]
+    pub accounts: ::core::option::Option<TradeAccounts>,
+    /// Tokens left on the curve token account after the transaction
+    #[prost(uint64, tag="5")]
+    pub curve_token_reserves: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TradeAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub curve_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub curve_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenMintAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub curve_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenMintInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<TokenMintAccounts>,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub uri: ::prost::alloc::string::String,
+    #[prost(uint32, tag="5")]
+    pub decimals: u32,
+    #[prost(uint32, tag="6")]
+    pub collateral_currency: u32,
+    #[prost(uint64, tag="7")]
+    pub amount: u64,
+    #[prost(uint32, tag="8")]
+    pub curve_type: u32,
+    /// 0: Raydium, 1: Meteora
+    #[prost(uint32, tag="9")]
+    pub migration_target: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateFundsAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub migration_authority: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub curve_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateFundsInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<MigrateFundsAccounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
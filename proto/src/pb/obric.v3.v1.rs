@@ -30,6 +30,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="6")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3, 4")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -50,6 +56,18 @@ pub struct SwapXToYInstruction {
     pub input_amount: u64,
     #[prost(uint64, tag="2")]
     pub min_output_amount: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub output_amount: u64,
+    /// Tokens actually sent by the user (`input_amount` is the requested amount)
+    #[prost(uint64, tag="7")]
+    pub realized_input_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -58,6 +76,18 @@ pub struct SwapYToXInstruction {
     pub input_amount: u64,
     #[prost(uint64, tag="2")]
     pub min_output_amount: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub output_amount: u64,
+    /// Tokens actually sent by the user (`input_amount` is the requested amount)
+    #[prost(uint64, tag="7")]
+    pub realized_input_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -31,12 +31,19 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
+/// Nested message and enum types in `Instruction`.
 pub mod instruction {
     #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Instruction {
         #[prost(message, tag="3")]
         Swap(super::SwapInstruction),
@@ -49,6 +56,17 @@ pub struct SwapInstruction {
     pub amount_in: u64,
     #[prost(uint64, tag="2")]
     pub minimum_amount_out: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub input_amount: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub output_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -58,3 +76,4 @@ pub struct Log {
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
 }
+// @@protoc_insertion_point(module)
@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="12")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="13")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6, 7, 8, 9, 10, 11")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -46,24 +52,244 @@ pub mod instruction {
         #[prost(message, tag="5")]
         PlaceTakeOrder(super::PlaceTakeOrderInstruction),
         #[prost(message, tag="6")]
-        CancelAllAndPlaceOrders(super::CancelAllAndPlaceOrdersInstruction),
+        CancelAllAndPlaceOrders(super::PlaceOrdersInstruction),
+        #[prost(message, tag="7")]
+        EditOrder(super::EditOrderInstruction),
+        #[prost(message, tag="8")]
+        CancelOrder(super::CancelOrderInstruction),
+        #[prost(message, tag="9")]
+        CancelOrderByClientOrderId(super::CancelOrderInstruction),
+        #[prost(message, tag="10")]
+        CancelAllOrders(super::CancelAllOrdersInstruction),
+        #[prost(message, tag="11")]
+        SettleFunds(super::SettleFundsInstruction),
     }
 }
+/// -----------------------------------------------------------------------------
+/// Orders (side: 0 = bid, 1 = ask)
+/// order_type: 0 = limit, 1 = immediate_or_cancel, 2 = post_only, 3 = market, 4 = post_only_slide, 5 = fill_or_kill
+/// -----------------------------------------------------------------------------
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PlaceOrderArgs {
+    #[prost(uint32, tag="1")]
+    pub side: u32,
+    #[prost(int64, tag="2")]
+    pub price_lots: i64,
+    #[prost(int64, tag="3")]
+    pub max_base_lots: i64,
+    #[prost(int64, tag="4")]
+    pub max_quote_lots_including_fees: i64,
+    #[prost(uint64, tag="5")]
+    pub client_order_id: u64,
+    #[prost(uint32, tag="6")]
+    pub order_type: u32,
+    /// 0 for orders which do not expire
+    #[prost(uint64, tag="7")]
+    pub expiry_timestamp: u64,
+    #[prost(uint32, tag="8")]
+    pub self_trade_behavior: u32,
+    /// Maximum number of orders matched or evicted
+    #[prost(uint32, tag="9")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceOrderAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub open_orders_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub user_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub bids: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub asks: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub event_heap: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub market_vault: ::prost::alloc::vec::Vec<u8>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlaceOrderInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<PlaceOrderAccounts>,
+    #[prost(message, optional, tag="2")]
+    pub args: ::core::option::Option<PlaceOrderArgs>,
+}
+/// place_orders & cancel_all_and_place_orders, the orders are sized in quote lots
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PlaceMultipleOrdersArgs {
+    #[prost(int64, tag="1")]
+    pub price_lots: i64,
+    #[prost(int64, tag="2")]
+    pub max_quote_lots_including_fees: i64,
+    #[prost(uint64, tag="3")]
+    pub expiry_timestamp: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceOrdersAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub open_orders_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub user_quote_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub user_base_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub bids: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub asks: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub event_heap: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub market_quote_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub market_base_vault: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlaceOrdersInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<PlaceOrdersAccounts>,
+    #[prost(uint32, tag="2")]
+    pub orders_type: u32,
+    #[prost(message, repeated, tag="3")]
+    pub bids: ::prost::alloc::vec::Vec<PlaceMultipleOrdersArgs>,
+    #[prost(message, repeated, tag="4")]
+    pub asks: ::prost::alloc::vec::Vec<PlaceMultipleOrdersArgs>,
+    #[prost(uint32, tag="5")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceTakeOrderAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub bids: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub asks: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub market_base_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub market_quote_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub event_heap: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub user_base_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub user_quote_account: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PlaceTakeOrderInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<PlaceTakeOrderAccounts>,
+    #[prost(uint32, tag="2")]
+    pub side: u32,
+    #[prost(int64, tag="3")]
+    pub price_lots: i64,
+    #[prost(int64, tag="4")]
+    pub max_base_lots: i64,
+    #[prost(int64, tag="5")]
+    pub max_quote_lots_including_fees: i64,
+    #[prost(uint32, tag="6")]
+    pub order_type: u32,
+    #[prost(uint32, tag="7")]
+    pub limit: u32,
+}
+/// Cancels the order `client_order_id` and places a new order
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditOrderInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<PlaceOrderAccounts>,
+    #[prost(uint64, tag="2")]
+    pub client_order_id: u64,
+    #[prost(int64, tag="3")]
+    pub expected_cancel_size: i64,
+    #[prost(message, optional, tag="4")]
+    pub place_order: ::core::option::Option<PlaceOrderArgs>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelOrderAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub open_orders_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub bids: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub asks: ::prost::alloc::vec::Vec<u8>,
+}
+/// cancel_order & cancel_order_by_client_order_id
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelOrderInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<CancelOrderAccounts>,
+    /// u128, empty when cancelled by client order id
+    #[prost(string, tag="2")]
+    pub order_id: ::prost::alloc::string::String,
+    /// 0 when cancelled by order id
+    #[prost(uint64, tag="3")]
+    pub client_order_id: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelAllOrdersInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<CancelOrderAccounts>,
+    /// Both sides when empty
+    #[prost(uint32, optional, tag="2")]
+    pub side: ::core::option::Option<u32>,
+    #[prost(uint32, tag="3")]
+    pub limit: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SettleFundsAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub open_orders_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub market_base_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub market_quote_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub user_base_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub user_quote_account: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CancelAllAndPlaceOrdersInstruction {
+pub struct SettleFundsInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<SettleFundsAccounts>,
+    /// Transferred to the user base account
+    #[prost(uint64, tag="2")]
+    pub base_amount: u64,
+    /// Transferred to the user quote account
+    #[prost(uint64, tag="3")]
+    pub quote_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="7")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="8")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6, 9, 10, 11, 12, 13, 14")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -47,6 +53,18 @@ pub mod instruction {
         TwoHopSwap(super::TwoHopSwapInstruction),
         #[prost(message, tag="6")]
         TwoHopSwapV2(super::TwoHopSwapV2Instruction),
+        #[prost(message, tag="9")]
+        OpenPosition(super::OpenPositionInstruction),
+        #[prost(message, tag="10")]
+        ClosePosition(super::ClosePositionInstruction),
+        #[prost(message, tag="11")]
+        CollectFees(super::CollectFeesInstruction),
+        #[prost(message, tag="12")]
+        CollectReward(super::CollectRewardInstruction),
+        #[prost(message, tag="13")]
+        IncreaseLiquidity(super::ModifyLiquidityInstruction),
+        #[prost(message, tag="14")]
+        DecreaseLiquidity(super::ModifyLiquidityInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -124,6 +142,9 @@ pub struct SwapInstruction {
     pub amount_specified_is_input: bool,
     #[prost(bool, tag="6")]
     pub a_to_b: bool,
+    /// token vaults A & B
+    #[prost(message, repeated, tag="7")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -140,6 +161,20 @@ pub struct SwapV2Instruction {
     pub amount_specified_is_input: bool,
     #[prost(bool, tag="6")]
     pub a_to_b: bool,
+    /// token vaults A & B
+    #[prost(message, repeated, tag="7")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultBalance {
+    #[prost(bytes="vec", tag="1")]
+    pub vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Vault balance at the end of the transaction
+    #[prost(uint64, tag="3")]
+    pub post_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -179,6 +214,156 @@ pub struct TwoHopSwapV2Instruction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenPositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub funder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub position_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub whirlpool: ::prost::alloc::vec::Vec<u8>,
+}
+/// open_position, open_position_with_metadata & open_position_with_token_extensions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenPositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<OpenPositionAccounts>,
+    #[prost(int32, tag="2")]
+    pub tick_lower_index: i32,
+    #[prost(int32, tag="3")]
+    pub tick_upper_index: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub position_authority: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub receiver: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub position_token_account: ::prost::alloc::vec::Vec<u8>,
+}
+/// close_position & close_position_with_token_extensions
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClosePositionAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectFeesAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub whirlpool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position_authority: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_owner_account_a: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_vault_a: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_owner_account_b: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_vault_b: ::prost::alloc::vec::Vec<u8>,
+}
+/// collect_fees & collect_fees_v2
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectFeesInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<CollectFeesAccounts>,
+    /// Transferred from token vault A
+    #[prost(uint64, tag="2")]
+    pub fee_a_amount: u64,
+    /// Transferred from token vault B
+    #[prost(uint64, tag="3")]
+    pub fee_b_amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectRewardAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub whirlpool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position_authority: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub reward_owner_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub reward_vault: ::prost::alloc::vec::Vec<u8>,
+}
+/// collect_reward & collect_reward_v2
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectRewardInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<CollectRewardAccounts>,
+    #[prost(uint32, tag="2")]
+    pub reward_index: u32,
+    #[prost(bytes="vec", tag="3")]
+    pub reward_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Transferred from the reward vault
+    #[prost(uint64, tag="4")]
+    pub amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyLiquidityAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub whirlpool: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position_authority: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_token_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_mint_a: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_mint_b: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_owner_account_a: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_owner_account_b: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub token_vault_a: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub token_vault_b: ::prost::alloc::vec::Vec<u8>,
+}
+/// increase_liquidity, increase_liquidity_v2, decrease_liquidity & decrease_liquidity_v2
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyLiquidityInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ModifyLiquidityAccounts>,
+    /// u128
+    #[prost(string, tag="2")]
+    pub liquidity_amount: ::prost::alloc::string::String,
+    /// Maximum amount (increase) or minimum amount (decrease)
+    #[prost(uint64, tag="3")]
+    pub token_a_limit: u64,
+    #[prost(uint64, tag="4")]
+    pub token_b_limit: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Log {
     #[prost(bytes="vec", tag="1")]
     pub program_id: ::prost::alloc::vec::Vec<u8>,
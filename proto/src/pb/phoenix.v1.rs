@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="9")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="10")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6, 7, 8")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -43,6 +49,17 @@ pub mod instruction {
         Swap(super::SwapInstruction),
         #[prost(message, tag="4")]
         SwapWithFreeFunds(super::SwapWithFreeFundsInstruction),
+        /// place_limit_order & place_limit_order_with_free_funds
+        #[prost(message, tag="5")]
+        PlaceLimitOrder(super::PlaceLimitOrderInstruction),
+        /// reduce_order & reduce_order_with_free_funds
+        #[prost(message, tag="6")]
+        ReduceOrder(super::ReduceOrderInstruction),
+        /// cancel_all_orders, cancel_up_to & cancel_multiple_orders_by_id (with or without free funds)
+        #[prost(message, tag="7")]
+        CancelOrders(super::CancelOrdersInstruction),
+        #[prost(message, tag="8")]
+        WithdrawFunds(super::WithdrawFundsInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -83,6 +100,100 @@ pub struct SwapWithFreeFundsInstruction {
     #[prost(bytes="vec", tag="2")]
     pub order_packet: ::prost::alloc::vec::Vec<u8>,
 }
+/// -----------------------------------------------------------------------------
+/// Orders (side: 0 = bid, 1 = ask; order_type: 0 = post_only, 1 = limit, 2 = immediate_or_cancel)
+/// -----------------------------------------------------------------------------
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub trader: ::prost::alloc::vec::Vec<u8>,
+}
+/// Only the fields shared by the order packets are decoded
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderPacket {
+    #[prost(uint32, tag="1")]
+    pub order_type: u32,
+    #[prost(uint32, tag="2")]
+    pub side: u32,
+    /// 0 for immediate-or-cancel orders without price limit
+    #[prost(uint64, tag="3")]
+    pub price_in_ticks: u64,
+    #[prost(uint64, tag="4")]
+    pub num_base_lots: u64,
+    /// u128
+    #[prost(string, tag="5")]
+    pub client_order_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceLimitOrderInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<OrderAccounts>,
+    #[prost(message, optional, tag="2")]
+    pub order_packet: ::core::option::Option<OrderPacket>,
+    #[prost(bool, tag="3")]
+    pub use_free_funds: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReduceOrderInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<OrderAccounts>,
+    #[prost(uint32, tag="2")]
+    pub side: u32,
+    #[prost(uint64, tag="3")]
+    pub price_in_ticks: u64,
+    #[prost(uint64, tag="4")]
+    pub order_sequence_number: u64,
+    /// Base lots removed from the order
+    #[prost(uint64, tag="5")]
+    pub size: u64,
+    #[prost(bool, tag="6")]
+    pub use_free_funds: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelOrdersInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<OrderAccounts>,
+    #[prost(bool, tag="2")]
+    pub use_free_funds: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawFundsAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub trader: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub base_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub quote_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub base_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub quote_vault: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawFundsInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<WithdrawFundsAccounts>,
+    /// Transferred to the trader base account
+    #[prost(uint64, tag="2")]
+    pub base_amount: u64,
+    /// Transferred to the trader quote account
+    #[prost(uint64, tag="3")]
+    pub quote_amount: u64,
+}
+/// -----------------------------------------------------------------------------
+/// Market events, emitted through the `log` self-CPI
+/// -----------------------------------------------------------------------------
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Log {
@@ -90,7 +201,18 @@ pub struct Log {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
-    #[prost(oneof="log::Log", tags="3")]
+    /// From the header of the events batch
+    #[prost(bytes="vec", tag="9")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    /// Phoenix instruction emitting the event
+    #[prost(uint32, tag="11")]
+    pub instruction: u32,
+    /// Order packet type of the instruction, for the place instructions
+    #[prost(uint32, optional, tag="12")]
+    pub order_type: ::core::option::Option<u32>,
+    #[prost(oneof="log::Log", tags="3, 4, 5, 6, 7, 8")]
     pub log: ::core::option::Option<log::Log>,
 }
 /// Nested message and enum types in `Log`.
@@ -100,10 +222,86 @@ pub mod log {
     pub enum Log {
         #[prost(message, tag="3")]
         Fill(super::FillEvent),
+        #[prost(message, tag="4")]
+        Place(super::PlaceEvent),
+        #[prost(message, tag="5")]
+        Reduce(super::ReduceEvent),
+        #[prost(message, tag="6")]
+        Evict(super::EvictEvent),
+        #[prost(message, tag="7")]
+        FillSummary(super::FillSummaryEvent),
+        #[prost(message, tag="8")]
+        Fee(super::FeeEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlaceEvent {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    #[prost(uint64, tag="2")]
+    pub order_sequence_number: u64,
+    /// u128
+    #[prost(string, tag="3")]
+    pub client_order_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub price_in_ticks: u64,
+    #[prost(uint64, tag="5")]
+    pub base_lots_placed: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReduceEvent {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    #[prost(uint64, tag="2")]
+    pub order_sequence_number: u64,
+    #[prost(uint64, tag="3")]
+    pub price_in_ticks: u64,
+    #[prost(uint64, tag="4")]
+    pub base_lots_removed: u64,
+    #[prost(uint64, tag="5")]
+    pub base_lots_remaining: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EvictEvent {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    #[prost(bytes="vec", tag="2")]
+    pub maker_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="3")]
+    pub order_sequence_number: u64,
+    #[prost(uint64, tag="4")]
+    pub price_in_ticks: u64,
+    #[prost(uint64, tag="5")]
+    pub base_lots_evicted: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FillSummaryEvent {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    /// u128
+    #[prost(string, tag="2")]
+    pub client_order_id: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub total_base_lots_filled: u64,
+    #[prost(uint64, tag="4")]
+    pub total_quote_lots_filled: u64,
+    #[prost(uint64, tag="5")]
+    pub total_fee_in_quote_lots: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FeeEvent {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    #[prost(uint64, tag="2")]
+    pub fees_collected_in_quote_lots: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FillEvent {
     #[prost(uint32, tag="1")]
     pub index: u32,
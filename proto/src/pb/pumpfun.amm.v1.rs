@@ -34,7 +34,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 20, 21, 22")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="23")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="24")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 20, 21, 22, 25, 26")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -56,6 +62,10 @@ pub mod instruction {
         SellEvent(super::SellEvent),
         #[prost(message, tag="22")]
         CreatePoolEvent(super::CreatePoolEvent),
+        #[prost(message, tag="25")]
+        CollectCoinCreatorFeeEvent(super::CollectCoinCreatorFeeEvent),
+        #[prost(message, tag="26")]
+        SetCoinCreatorEvent(super::SetCoinCreatorEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -294,6 +304,54 @@ pub struct CreatePoolEvent {
     /// Coin creator account address.
     #[prost(bytes="vec", optional, tag="20")]
     pub coin_creator: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Accounts of the `create_pool` instruction emitting the event
+    ///
+    /// Pool's base token account address.
+    #[prost(bytes="vec", tag="21")]
+    pub pool_base_token_account: ::prost::alloc::vec::Vec<u8>,
+    /// Pool's quote token account address.
+    #[prost(bytes="vec", tag="22")]
+    pub pool_quote_token_account: ::prost::alloc::vec::Vec<u8>,
+}
+/// Emitted by `collect_coin_creator_fee`, the coin creator vault is claimed for all the pools of the creator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectCoinCreatorFeeEvent {
+    /// Unix-epoch seconds.
+    #[prost(int64, tag="1")]
+    pub timestamp: i64,
+    /// Coin creator account address.
+    #[prost(bytes="vec", tag="2")]
+    pub coin_creator: ::prost::alloc::vec::Vec<u8>,
+    /// Quote tokens claimed from the coin creator vault.
+    #[prost(uint64, tag="3")]
+    pub coin_creator_fee: u64,
+    /// Coin creator vault associated token account address.
+    #[prost(bytes="vec", tag="4")]
+    pub coin_creator_vault_ata: ::prost::alloc::vec::Vec<u8>,
+    /// Coin creator token account receiving the fees.
+    #[prost(bytes="vec", tag="5")]
+    pub coin_creator_token_account: ::prost::alloc::vec::Vec<u8>,
+    /// Mint of the coin creator vault (from the transaction token balances).
+    #[prost(bytes="vec", tag="6")]
+    pub quote_mint: ::prost::alloc::vec::Vec<u8>,
+}
+/// Emitted by `set_coin_creator`, the coin creator is copied from the bonding curve or the Metaplex metadata.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetCoinCreatorEvent {
+    /// Unix-epoch seconds.
+    #[prost(int64, tag="1")]
+    pub timestamp: i64,
+    /// Base mint address.
+    #[prost(bytes="vec", tag="2")]
+    pub base_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Pool account address.
+    #[prost(bytes="vec", tag="3")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// New coin creator account address.
+    #[prost(bytes="vec", tag="4")]
+    pub coin_creator: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
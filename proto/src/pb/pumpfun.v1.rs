@@ -34,7 +34,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="10, 11, 13, 14, 15, 16, 17")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="18")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="19")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="10, 11, 13, 14, 15, 16, 17, 20, 21, 22, 23")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -56,6 +62,14 @@ pub mod instruction {
         Withdraw(super::WithdrawInstruction),
         #[prost(message, tag="17")]
         Trade(super::TradeEvent),
+        #[prost(message, tag="20")]
+        Complete(super::CompleteEvent),
+        #[prost(message, tag="21")]
+        Migrate(super::MigrateInstruction),
+        #[prost(message, tag="22")]
+        CollectCreatorFee(super::CollectCreatorFeeEvent),
+        #[prost(message, tag="23")]
+        SetCreator(super::SetCreatorEvent),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -132,6 +146,107 @@ pub struct CreateInstruction {
     /// Pool creator (receives creator fees).
     #[prost(bytes="vec", tag="4")]
     pub creator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="5")]
+    pub accounts: ::core::option::Option<CreateAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateAccounts {
+    /// SPL-Token mint address.
+    #[prost(bytes="vec", tag="1")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Bonding-curve configuration account.
+    #[prost(bytes="vec", tag="2")]
+    pub bonding_curve: ::prost::alloc::vec::Vec<u8>,
+    /// Vault holding the curve’s token reserve.
+    #[prost(bytes="vec", tag="3")]
+    pub associated_bonding_curve: ::prost::alloc::vec::Vec<u8>,
+    /// Token creator wallet (fee payer).
+    #[prost(bytes="vec", tag="4")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+}
+/// Emitted when the bonding curve is fully sold, the token is then ready to be migrated.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompleteEvent {
+    /// Wallet of the trade completing the curve.
+    #[prost(bytes="vec", tag="1")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// SPL-Token mint address.
+    #[prost(bytes="vec", tag="2")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Bonding-curve configuration account.
+    #[prost(bytes="vec", tag="3")]
+    pub bonding_curve: ::prost::alloc::vec::Vec<u8>,
+    /// Unix-epoch seconds.
+    #[prost(int64, tag="4")]
+    pub timestamp: i64,
+}
+/// Migrates the liquidity of a completed bonding curve to a PumpSwap pool.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<MigrateAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateAccounts {
+    /// Global state account.
+    #[prost(bytes="vec", tag="1")]
+    pub global: ::prost::alloc::vec::Vec<u8>,
+    /// Migration authority.
+    #[prost(bytes="vec", tag="2")]
+    pub withdraw_authority: ::prost::alloc::vec::Vec<u8>,
+    /// SPL-Token mint address.
+    #[prost(bytes="vec", tag="3")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Bonding-curve configuration account.
+    #[prost(bytes="vec", tag="4")]
+    pub bonding_curve: ::prost::alloc::vec::Vec<u8>,
+    /// Vault holding the curve’s token reserve.
+    #[prost(bytes="vec", tag="5")]
+    pub associated_bonding_curve: ::prost::alloc::vec::Vec<u8>,
+    /// Wallet executing the migration.
+    #[prost(bytes="vec", tag="6")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// PumpSwap program ID.
+    #[prost(bytes="vec", tag="7")]
+    pub pump_amm: ::prost::alloc::vec::Vec<u8>,
+    /// PumpSwap pool receiving the liquidity.
+    #[prost(bytes="vec", tag="8")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+}
+/// Emitted by `collect_creator_fee`, the creator vault is claimed for all the coins of the creator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectCreatorFeeEvent {
+    /// Unix-epoch seconds.
+    #[prost(int64, tag="1")]
+    pub timestamp: i64,
+    /// Creator wallet receiving the fees.
+    #[prost(bytes="vec", tag="2")]
+    pub creator: ::prost::alloc::vec::Vec<u8>,
+    /// Lamports claimed from the creator vault.
+    #[prost(uint64, tag="3")]
+    pub creator_fee: u64,
+}
+/// Emitted by `set_creator`, assigns the creator receiving the creator fees of a coin.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetCreatorEvent {
+    /// Unix-epoch seconds.
+    #[prost(int64, tag="1")]
+    pub timestamp: i64,
+    /// SPL-Token mint address.
+    #[prost(bytes="vec", tag="2")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Bonding-curve configuration account.
+    #[prost(bytes="vec", tag="3")]
+    pub bonding_curve: ::prost::alloc::vec::Vec<u8>,
+    /// New creator wallet.
+    #[prost(bytes="vec", tag="4")]
+    pub creator: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
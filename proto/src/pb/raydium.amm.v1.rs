@@ -43,7 +43,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="6")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 7, 8, 9, 10, 11")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -55,6 +61,16 @@ pub mod instruction {
         SwapBaseIn(super::SwapBaseInInstruction),
         #[prost(message, tag="4")]
         SwapBaseOut(super::SwapBaseOutInstruction),
+        #[prost(message, tag="7")]
+        Deposit(super::DepositInstruction),
+        #[prost(message, tag="8")]
+        Withdraw(super::WithdrawInstruction),
+        #[prost(message, tag="9")]
+        Initialize2(super::Initialize2Instruction),
+        #[prost(message, tag="10")]
+        WithdrawPnl(super::WithdrawPnlInstruction),
+        #[prost(message, tag="11")]
+        SetParams(super::SetParamsInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -65,13 +81,13 @@ pub struct Log {
     /// Call stack depth of the log
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
-    #[prost(oneof="log::Log", tags="3, 4")]
+    #[prost(oneof="log::Log", tags="3, 4, 5, 6, 7")]
     pub log: ::core::option::Option<log::Log>,
 }
 /// Nested message and enum types in `Log`.
 pub mod log {
     #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Log {
         /// AMM swap log
         #[prost(message, tag="3")]
@@ -79,6 +95,15 @@ pub mod log {
         /// AMM swap log
         #[prost(message, tag="4")]
         SwapBaseOut(super::SwapBaseOutLog),
+        /// AMM initialization log
+        #[prost(message, tag="5")]
+        Init(super::InitLog),
+        /// AMM deposit log
+        #[prost(message, tag="6")]
+        Deposit(super::DepositLog),
+        /// AMM withdraw log
+        #[prost(message, tag="7")]
+        Withdraw(super::WithdrawLog),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -148,6 +173,9 @@ pub struct SwapBaseInInstruction {
     pub amount_in: u64,
     #[prost(uint64, tag="3")]
     pub minimum_amount_out: u64,
+    /// AMM coin & pc vaults
+    #[prost(message, repeated, tag="4")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -158,6 +186,212 @@ pub struct SwapBaseOutInstruction {
     pub max_amount_in: u64,
     #[prost(uint64, tag="3")]
     pub amount_out: u64,
+    /// AMM coin & pc vaults
+    #[prost(message, repeated, tag="4")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultBalance {
+    #[prost(bytes="vec", tag="1")]
+    pub vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Vault balance at the end of the transaction
+    #[prost(uint64, tag="3")]
+    pub post_amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityAccounts {
+    /// AMM pool account
+    #[prost(bytes="vec", tag="1")]
+    pub amm: ::prost::alloc::vec::Vec<u8>,
+    /// AMM LP mint
+    #[prost(bytes="vec", tag="2")]
+    pub lp_mint: ::prost::alloc::vec::Vec<u8>,
+    /// AMM coin vault (base-token vault)
+    #[prost(bytes="vec", tag="3")]
+    pub amm_coin_vault: ::prost::alloc::vec::Vec<u8>,
+    /// AMM pc vault (quote-token vault)
+    #[prost(bytes="vec", tag="4")]
+    pub amm_pc_vault: ::prost::alloc::vec::Vec<u8>,
+    /// Coin mint (resolved from the vault token balances)
+    #[prost(bytes="vec", tag="5")]
+    pub coin_mint: ::prost::alloc::vec::Vec<u8>,
+    /// PC mint (resolved from the vault token balances)
+    #[prost(bytes="vec", tag="6")]
+    pub pc_mint: ::prost::alloc::vec::Vec<u8>,
+    /// User coin token account
+    #[prost(bytes="vec", tag="7")]
+    pub user_coin_token: ::prost::alloc::vec::Vec<u8>,
+    /// User pc token account
+    #[prost(bytes="vec", tag="8")]
+    pub user_pc_token: ::prost::alloc::vec::Vec<u8>,
+    /// User LP token account
+    #[prost(bytes="vec", tag="9")]
+    pub user_lp_token: ::prost::alloc::vec::Vec<u8>,
+    /// User wallet
+    #[prost(bytes="vec", tag="10")]
+    pub user_owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<LiquidityAccounts>,
+    #[prost(uint64, tag="2")]
+    pub max_coin_amount: u64,
+    #[prost(uint64, tag="3")]
+    pub max_pc_amount: u64,
+    /// 0 = coin, 1 = pc
+    #[prost(uint64, tag="4")]
+    pub base_side: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<LiquidityAccounts>,
+    /// LP tokens to burn
+    #[prost(uint64, tag="2")]
+    pub amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Initialize2Accounts {
+    /// AMM pool account
+    #[prost(bytes="vec", tag="1")]
+    pub amm: ::prost::alloc::vec::Vec<u8>,
+    /// AMM authority PDA
+    #[prost(bytes="vec", tag="2")]
+    pub amm_authority: ::prost::alloc::vec::Vec<u8>,
+    /// AMM open-orders
+    #[prost(bytes="vec", tag="3")]
+    pub amm_open_orders: ::prost::alloc::vec::Vec<u8>,
+    /// AMM LP mint
+    #[prost(bytes="vec", tag="4")]
+    pub lp_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Coin mint (base token)
+    #[prost(bytes="vec", tag="5")]
+    pub coin_mint: ::prost::alloc::vec::Vec<u8>,
+    /// PC mint (quote token)
+    #[prost(bytes="vec", tag="6")]
+    pub pc_mint: ::prost::alloc::vec::Vec<u8>,
+    /// AMM coin vault (base-token vault)
+    #[prost(bytes="vec", tag="7")]
+    pub amm_coin_vault: ::prost::alloc::vec::Vec<u8>,
+    /// AMM pc vault (quote-token vault)
+    #[prost(bytes="vec", tag="8")]
+    pub amm_pc_vault: ::prost::alloc::vec::Vec<u8>,
+    /// AMM target-orders
+    #[prost(bytes="vec", tag="9")]
+    pub amm_target_orders: ::prost::alloc::vec::Vec<u8>,
+    /// OpenBook (or Serum) DEX program
+    #[prost(bytes="vec", tag="10")]
+    pub market_program: ::prost::alloc::vec::Vec<u8>,
+    /// OpenBook (or Serum) DEX market account
+    #[prost(bytes="vec", tag="11")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    /// Pool creator wallet
+    #[prost(bytes="vec", tag="12")]
+    pub user_wallet: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Initialize2Instruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<Initialize2Accounts>,
+    #[prost(uint32, tag="2")]
+    pub nonce: u32,
+    #[prost(uint64, tag="3")]
+    pub open_time: u64,
+    #[prost(uint64, tag="4")]
+    pub init_pc_amount: u64,
+    #[prost(uint64, tag="5")]
+    pub init_coin_amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawPnlAccounts {
+    /// AMM pool account
+    #[prost(bytes="vec", tag="1")]
+    pub amm: ::prost::alloc::vec::Vec<u8>,
+    /// AMM config account
+    #[prost(bytes="vec", tag="2")]
+    pub amm_config: ::prost::alloc::vec::Vec<u8>,
+    /// AMM coin vault (base-token vault)
+    #[prost(bytes="vec", tag="3")]
+    pub amm_coin_vault: ::prost::alloc::vec::Vec<u8>,
+    /// AMM pc vault (quote-token vault)
+    #[prost(bytes="vec", tag="4")]
+    pub amm_pc_vault: ::prost::alloc::vec::Vec<u8>,
+    /// PnL owner coin token account
+    #[prost(bytes="vec", tag="5")]
+    pub coin_pnl_token_account: ::prost::alloc::vec::Vec<u8>,
+    /// PnL owner pc token account
+    #[prost(bytes="vec", tag="6")]
+    pub pc_pnl_token_account: ::prost::alloc::vec::Vec<u8>,
+    /// PnL owner (signer)
+    #[prost(bytes="vec", tag="7")]
+    pub pnl_owner: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawPnlInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<WithdrawPnlAccounts>,
+    /// PnL withdrawn from the vaults, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="2")]
+    pub coin_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="3")]
+    pub coin_amount: u64,
+    #[prost(bytes="vec", tag="4")]
+    pub pc_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub pc_amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetParamsInstruction {
+    /// AMM pool account
+    #[prost(bytes="vec", tag="1")]
+    pub amm: ::prost::alloc::vec::Vec<u8>,
+    /// AMM admin (signer)
+    #[prost(bytes="vec", tag="2")]
+    pub amm_admin: ::prost::alloc::vec::Vec<u8>,
+    /// AmmParams (0 = Status, 1 = State, ... 9 = Fees, 10 = AmmOwner, 11 = SetOpenTime)
+    #[prost(uint32, tag="3")]
+    pub param: u32,
+    /// New value of the numeric params
+    #[prost(uint64, optional, tag="4")]
+    pub value: ::core::option::Option<u64>,
+    /// New AMM owner (AmmOwner)
+    #[prost(bytes="vec", optional, tag="5")]
+    pub new_pubkey: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// New fees (Fees)
+    #[prost(message, optional, tag="6")]
+    pub fees: ::core::option::Option<Fees>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Fees {
+    #[prost(uint64, tag="1")]
+    pub min_separate_numerator: u64,
+    #[prost(uint64, tag="2")]
+    pub min_separate_denominator: u64,
+    #[prost(uint64, tag="3")]
+    pub trade_fee_numerator: u64,
+    #[prost(uint64, tag="4")]
+    pub trade_fee_denominator: u64,
+    #[prost(uint64, tag="5")]
+    pub pnl_numerator: u64,
+    #[prost(uint64, tag="6")]
+    pub pnl_denominator: u64,
+    #[prost(uint64, tag="7")]
+    pub swap_fee_numerator: u64,
+    #[prost(uint64, tag="8")]
+    pub swap_fee_denominator: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
@@ -195,4 +429,81 @@ pub struct SwapBaseOutLog {
     #[prost(uint64, tag="7")]
     pub deduct_in: u64,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitLog {
+    #[prost(uint32, tag="1")]
+    pub pc_decimals: u32,
+    #[prost(uint32, tag="2")]
+    pub coin_decimals: u32,
+    #[prost(uint64, tag="3")]
+    pub pc_lot_size: u64,
+    #[prost(uint64, tag="4")]
+    pub coin_lot_size: u64,
+    #[prost(uint64, tag="5")]
+    pub pc_amount: u64,
+    #[prost(uint64, tag="6")]
+    pub coin_amount: u64,
+    /// 32-byte Pubkey
+    #[prost(bytes="vec", tag="7")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositLog {
+    #[prost(uint64, tag="1")]
+    pub max_coin: u64,
+    #[prost(uint64, tag="2")]
+    pub max_pc: u64,
+    #[prost(uint64, tag="3")]
+    pub base: u64,
+    #[prost(uint64, tag="4")]
+    pub pool_coin: u64,
+    #[prost(uint64, tag="5")]
+    pub pool_pc: u64,
+    #[prost(uint64, tag="6")]
+    pub pool_lp: u64,
+    /// UInt128 as decimal string
+    #[prost(string, tag="7")]
+    pub calc_pnl_x: ::prost::alloc::string::String,
+    /// UInt128 as decimal string
+    #[prost(string, tag="8")]
+    pub calc_pnl_y: ::prost::alloc::string::String,
+    /// Coin tokens deposited
+    #[prost(uint64, tag="9")]
+    pub deduct_coin: u64,
+    /// PC tokens deposited
+    #[prost(uint64, tag="10")]
+    pub deduct_pc: u64,
+    /// LP tokens minted
+    #[prost(uint64, tag="11")]
+    pub mint_lp: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawLog {
+    /// LP tokens burned
+    #[prost(uint64, tag="1")]
+    pub withdraw_lp: u64,
+    #[prost(uint64, tag="2")]
+    pub user_lp: u64,
+    #[prost(uint64, tag="3")]
+    pub pool_coin: u64,
+    #[prost(uint64, tag="4")]
+    pub pool_pc: u64,
+    #[prost(uint64, tag="5")]
+    pub pool_lp: u64,
+    /// UInt128 as decimal string
+    #[prost(string, tag="6")]
+    pub calc_pnl_x: ::prost::alloc::string::String,
+    /// UInt128 as decimal string
+    #[prost(string, tag="7")]
+    pub calc_pnl_y: ::prost::alloc::string::String,
+    /// Coin tokens withdrawn
+    #[prost(uint64, tag="8")]
+    pub out_coin: u64,
+    /// PC tokens withdrawn
+    #[prost(uint64, tag="9")]
+    pub out_pc: u64,
+}
 // @@protoc_insertion_point(module)
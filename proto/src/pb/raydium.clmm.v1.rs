@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 6, 7, 8, 9, 10")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -41,6 +47,16 @@ pub mod instruction {
     pub enum Instruction {
         #[prost(message, tag="3")]
         Swap(super::SwapInstruction),
+        #[prost(message, tag="6")]
+        CreatePool(super::CreatePoolInstruction),
+        #[prost(message, tag="7")]
+        OpenPosition(super::OpenPositionInstruction),
+        #[prost(message, tag="8")]
+        ClosePosition(super::ClosePositionInstruction),
+        #[prost(message, tag="9")]
+        IncreaseLiquidity(super::ModifyLiquidityInstruction),
+        #[prost(message, tag="10")]
+        DecreaseLiquidity(super::ModifyLiquidityInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -108,6 +124,9 @@ pub struct SwapInstruction {
     pub sqrt_price_limit_x64: ::prost::alloc::string::String,
     #[prost(bool, tag="5")]
     pub is_base_input: bool,
+    /// input & output vaults
+    #[prost(message, repeated, tag="7")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
     #[prost(oneof="swap_instruction::Accounts", tags="1, 6")]
     pub accounts: ::core::option::Option<swap_instruction::Accounts>,
 }
@@ -124,12 +143,150 @@ pub mod swap_instruction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultBalance {
+    #[prost(bytes="vec", tag="1")]
+    pub vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Vault balance at the end of the transaction
+    #[prost(uint64, tag="3")]
+    pub post_amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatePoolAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub pool_creator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub amm_config: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub token_mint_0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_mint_1: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_vault_0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_vault_1: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatePoolInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<CreatePoolAccounts>,
+    #[prost(string, tag="2")]
+    pub sqrt_price_x64: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub open_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenPositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub payer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position_nft_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position_nft_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub personal_position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_vault_0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_vault_1: ::prost::alloc::vec::Vec<u8>,
+}
+/// open_position, open_position_v2 & open_position_with_token22_nft
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenPositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<OpenPositionAccounts>,
+    #[prost(int32, tag="2")]
+    pub tick_lower_index: i32,
+    #[prost(int32, tag="3")]
+    pub tick_upper_index: i32,
+    /// u128
+    #[prost(string, tag="4")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub amount_0_max: u64,
+    #[prost(uint64, tag="6")]
+    pub amount_1_max: u64,
+    /// Deposited in token vault 0
+    #[prost(uint64, tag="7")]
+    pub amount_0: u64,
+    /// Deposited in token vault 1
+    #[prost(uint64, tag="8")]
+    pub amount_1: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub nft_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub position_nft_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub position_nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub personal_position: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClosePositionInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ClosePositionAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyLiquidityAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub nft_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub nft_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub personal_position: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_vault_0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_vault_1: ::prost::alloc::vec::Vec<u8>,
+}
+/// increase_liquidity(_v2) & decrease_liquidity(_v2)
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyLiquidityInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<ModifyLiquidityAccounts>,
+    /// u128
+    #[prost(string, tag="2")]
+    pub liquidity: ::prost::alloc::string::String,
+    /// Maximum amount (increase) or minimum amount (decrease)
+    #[prost(uint64, tag="3")]
+    pub amount_0_limit: u64,
+    #[prost(uint64, tag="4")]
+    pub amount_1_limit: u64,
+    /// Transferred to/from token vault 0, decreases include the collected fees
+    #[prost(uint64, tag="5")]
+    pub amount_0: u64,
+    /// Transferred to/from token vault 1, decreases include the collected fees
+    #[prost(uint64, tag="6")]
+    pub amount_1: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Log {
     #[prost(bytes="vec", tag="1")]
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub invoke_depth: u32,
-    #[prost(oneof="log::Log", tags="3")]
+    #[prost(oneof="log::Log", tags="3, 4, 5, 6")]
     pub log: ::core::option::Option<log::Log>,
 }
 /// Nested message and enum types in `Log`.
@@ -139,6 +296,12 @@ pub mod log {
     pub enum Log {
         #[prost(message, tag="3")]
         Swap(super::SwapLog),
+        #[prost(message, tag="4")]
+        DecreaseLiquidity(super::DecreaseLiquidityLog),
+        #[prost(message, tag="5")]
+        CreatePersonalPosition(super::CreatePersonalPositionLog),
+        #[prost(message, tag="6")]
+        IncreaseLiquidity(super::IncreaseLiquidityLog),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -169,4 +332,69 @@ pub struct SwapLog {
     #[prost(sint32, tag="12")]
     pub tick: i32,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecreaseLiquidityLog {
+    #[prost(bytes="vec", tag="1")]
+    pub position_nft_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub decrease_amount_0: u64,
+    #[prost(uint64, tag="4")]
+    pub decrease_amount_1: u64,
+    #[prost(uint64, tag="5")]
+    pub fee_amount_0: u64,
+    #[prost(uint64, tag="6")]
+    pub fee_amount_1: u64,
+    /// Indexed by reward
+    #[prost(uint64, repeated, tag="7")]
+    pub reward_amounts: ::prost::alloc::vec::Vec<u64>,
+    #[prost(uint64, tag="8")]
+    pub transfer_fee_0: u64,
+    #[prost(uint64, tag="9")]
+    pub transfer_fee_1: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatePersonalPositionLog {
+    #[prost(bytes="vec", tag="1")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub minter: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub nft_owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int32, tag="4")]
+    pub tick_lower_index: i32,
+    #[prost(int32, tag="5")]
+    pub tick_upper_index: i32,
+    /// u128
+    #[prost(string, tag="6")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub deposit_amount_0: u64,
+    #[prost(uint64, tag="8")]
+    pub deposit_amount_1: u64,
+    #[prost(uint64, tag="9")]
+    pub deposit_amount_0_transfer_fee: u64,
+    #[prost(uint64, tag="10")]
+    pub deposit_amount_1_transfer_fee: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IncreaseLiquidityLog {
+    #[prost(bytes="vec", tag="1")]
+    pub position_nft_mint: ::prost::alloc::vec::Vec<u8>,
+    /// u128
+    #[prost(string, tag="2")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub amount_0: u64,
+    #[prost(uint64, tag="4")]
+    pub amount_1: u64,
+    #[prost(uint64, tag="5")]
+    pub amount_0_transfer_fee: u64,
+    #[prost(uint64, tag="6")]
+    pub amount_1_transfer_fee: u64,
+}
 // @@protoc_insertion_point(module)
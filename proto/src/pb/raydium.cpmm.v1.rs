@@ -31,7 +31,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="6")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 7, 8, 9, 10, 11")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -43,6 +49,16 @@ pub mod instruction {
         SwapBaseInput(super::SwapBaseInputInstruction),
         #[prost(message, tag="4")]
         SwapBaseOutput(super::SwapBaseOutputInstruction),
+        #[prost(message, tag="7")]
+        Initialize(super::InitializeInstruction),
+        #[prost(message, tag="8")]
+        Deposit(super::DepositInstruction),
+        #[prost(message, tag="9")]
+        Withdraw(super::WithdrawInstruction),
+        #[prost(message, tag="10")]
+        CollectProtocolFee(super::CollectFeeInstruction),
+        #[prost(message, tag="11")]
+        CollectFundFee(super::CollectFeeInstruction),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -84,6 +100,9 @@ pub struct SwapBaseInputInstruction {
     pub amount_in: u64,
     #[prost(uint64, tag="3")]
     pub minimum_amount_out: u64,
+    /// input & output vaults
+    #[prost(message, repeated, tag="4")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -94,6 +113,147 @@ pub struct SwapBaseOutputInstruction {
     pub max_amount_in: u64,
     #[prost(uint64, tag="3")]
     pub amount_out: u64,
+    /// input & output vaults
+    #[prost(message, repeated, tag="4")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultBalance {
+    #[prost(bytes="vec", tag="1")]
+    pub vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub mint: ::prost::alloc::vec::Vec<u8>,
+    /// Vault balance at the end of the transaction
+    #[prost(uint64, tag="3")]
+    pub post_amount: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub creator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub amm_config: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub token_0_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_1_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub lp_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_0_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub token_1_vault: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<InitializeAccounts>,
+    #[prost(uint64, tag="2")]
+    pub init_amount_0: u64,
+    #[prost(uint64, tag="3")]
+    pub init_amount_1: u64,
+    #[prost(uint64, tag="4")]
+    pub open_time: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub owner_lp_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub token_0_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_1_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_0_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub token_1_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub vault_0_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub vault_1_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub lp_mint: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepositInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<LiquidityAccounts>,
+    #[prost(uint64, tag="2")]
+    pub lp_token_amount: u64,
+    #[prost(uint64, tag="3")]
+    pub maximum_token_0_amount: u64,
+    #[prost(uint64, tag="4")]
+    pub maximum_token_1_amount: u64,
+    /// token 0 & token 1 vaults
+    #[prost(message, repeated, tag="5")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithdrawInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<LiquidityAccounts>,
+    #[prost(uint64, tag="2")]
+    pub lp_token_amount: u64,
+    #[prost(uint64, tag="3")]
+    pub minimum_token_0_amount: u64,
+    #[prost(uint64, tag="4")]
+    pub minimum_token_1_amount: u64,
+    /// token 0 & token 1 vaults
+    #[prost(message, repeated, tag="5")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectFeeAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub amm_config: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub token_0_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub token_1_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub vault_0_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub vault_1_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub recipient_token_0_account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub recipient_token_1_account: ::prost::alloc::vec::Vec<u8>,
+}
+/// collect_protocol_fee & collect_fund_fee
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectFeeInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<CollectFeeAccounts>,
+    #[prost(uint64, tag="2")]
+    pub amount_0_requested: u64,
+    #[prost(uint64, tag="3")]
+    pub amount_1_requested: u64,
+    /// fees collected, inferred from inner SPL Token transfers
+    #[prost(uint64, tag="4")]
+    pub amount_0: u64,
+    #[prost(uint64, tag="5")]
+    pub amount_1: u64,
+    /// token 0 & token 1 vaults
+    #[prost(message, repeated, tag="6")]
+    pub vault_balances: ::prost::alloc::vec::Vec<VaultBalance>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -165,6 +325,7 @@ pub struct LpChangeEvent {
     pub token_0_transfer_fee: u64,
     #[prost(uint64, tag="8")]
     pub token_1_transfer_fee: u64,
+    /// 0 = deposit, 1 = withdraw
     #[prost(uint32, tag="9")]
     pub change_type: u32,
 }
@@ -35,7 +35,13 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
-    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6, 7, 8, 9, 10")]
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="11")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="12")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(oneof="instruction::Instruction", tags="3, 4, 5, 6, 7, 8, 9, 10, 13, 14")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -59,6 +65,10 @@ pub mod instruction {
         CreateVestingEvent(super::CreateVestingEvent),
         #[prost(message, tag="10")]
         PoolCreateEvent(super::PoolCreateEvent),
+        #[prost(message, tag="13")]
+        MigrateToAmm(super::MigrateInstruction),
+        #[prost(message, tag="14")]
+        MigrateToCpswap(super::MigrateInstruction),
     }
 }
 /// Accounts shared by trade instructions
@@ -96,6 +106,32 @@ pub struct TradeAccounts {
     #[prost(bytes="vec", tag="15")]
     pub program: ::prost::alloc::vec::Vec<u8>,
 }
+/// Migrates the liquidity of a completed launch to a Raydium AMM v4 (`migrate_to_amm`) or CPMM (`migrate_to_cpswap`) pool.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateInstruction {
+    #[prost(message, optional, tag="1")]
+    pub accounts: ::core::option::Option<MigrateAccounts>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MigrateAccounts {
+    #[prost(bytes="vec", tag="1")]
+    pub payer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub base_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub quote_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Launchpad pool being migrated
+    #[prost(bytes="vec", tag="4")]
+    pub pool_state: ::prost::alloc::vec::Vec<u8>,
+    /// Raydium AMM v4 or CPMM program
+    #[prost(bytes="vec", tag="5")]
+    pub amm_program: ::prost::alloc::vec::Vec<u8>,
+    /// Pool receiving the liquidity
+    #[prost(bytes="vec", tag="6")]
+    pub amm_pool: ::prost::alloc::vec::Vec<u8>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BuyExactInInstruction {
@@ -184,6 +220,15 @@ pub struct PoolCreateEvent {
     pub vesting_param: ::core::option::Option<VestingParams>,
     #[prost(enumeration="AmmCreatorFeeOn", tag="7")]
     pub amm_fee_on: i32,
+    /// Accounts of the `initialize` instruction emitting the event
+    #[prost(bytes="vec", tag="8")]
+    pub base_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub quote_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub base_vault: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub quote_vault: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -31,6 +31,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -42,6 +48,29 @@ pub mod instruction {
         Swap(super::SwapInstruction),
     }
 }
+/// Sanctum router accounts, fields not referenced by the instruction are left empty.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapAccounts {
+    /// User (signer)
+    #[prost(bytes="vec", tag="1")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// User source token account
+    #[prost(bytes="vec", tag="2")]
+    pub src_token_from: ::prost::alloc::vec::Vec<u8>,
+    /// User destination token account
+    #[prost(bytes="vec", tag="3")]
+    pub dest_token_to: ::prost::alloc::vec::Vec<u8>,
+    /// Intermediate stake account
+    #[prost(bytes="vec", tag="4")]
+    pub bridge_stake: ::prost::alloc::vec::Vec<u8>,
+    /// Source token mint (SwapViaStake)
+    #[prost(bytes="vec", tag="5")]
+    pub src_token_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Destination token mint (SwapViaStake)
+    #[prost(bytes="vec", tag="6")]
+    pub dest_token_mint: ::prost::alloc::vec::Vec<u8>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SwapInstruction {
@@ -49,6 +78,19 @@ pub struct SwapInstruction {
     pub amount_in: u64,
     #[prost(uint64, tag="2")]
     pub minimum_amount_out: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub input_amount: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub output_amount: u64,
+    #[prost(message, optional, tag="8")]
+    pub accounts: ::core::option::Option<SwapAccounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -31,6 +31,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -45,11 +51,58 @@ pub mod instruction {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapAccounts {
+    /// Pool (token-swap state) account
+    #[prost(bytes="vec", tag="1")]
+    pub swap: ::prost::alloc::vec::Vec<u8>,
+    /// Pool authority PDA
+    #[prost(bytes="vec", tag="2")]
+    pub swap_authority: ::prost::alloc::vec::Vec<u8>,
+    /// User transfer authority (signer)
+    #[prost(bytes="vec", tag="3")]
+    pub user_transfer_authority: ::prost::alloc::vec::Vec<u8>,
+    /// User source token account
+    #[prost(bytes="vec", tag="4")]
+    pub user_source: ::prost::alloc::vec::Vec<u8>,
+    /// Pool vault receiving the input token
+    #[prost(bytes="vec", tag="5")]
+    pub pool_source: ::prost::alloc::vec::Vec<u8>,
+    /// Pool vault sending the output token
+    #[prost(bytes="vec", tag="6")]
+    pub pool_destination: ::prost::alloc::vec::Vec<u8>,
+    /// User destination token account
+    #[prost(bytes="vec", tag="7")]
+    pub user_destination: ::prost::alloc::vec::Vec<u8>,
+    /// Pool LP mint
+    #[prost(bytes="vec", tag="8")]
+    pub pool_mint: ::prost::alloc::vec::Vec<u8>,
+    /// Pool LP fee account
+    #[prost(bytes="vec", tag="9")]
+    pub pool_fee_account: ::prost::alloc::vec::Vec<u8>,
+    /// Token program
+    #[prost(bytes="vec", tag="10")]
+    pub token_program: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SwapInstruction {
     #[prost(uint64, tag="1")]
     pub amount_in: u64,
     #[prost(uint64, tag="2")]
     pub minimum_amount_out: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub input_amount: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub output_amount: u64,
+    #[prost(message, optional, tag="8")]
+    pub accounts: ::core::option::Option<SwapAccounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
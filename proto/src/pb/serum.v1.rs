@@ -31,6 +31,12 @@ pub struct Instruction {
     pub program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag="2")]
     pub stack_height: u32,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="3")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -42,6 +48,44 @@ pub mod instruction {
         Swap(super::SwapInstruction),
     }
 }
+/// Serum-style orderbook accounts, fields not referenced by the instruction are left empty.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapAccounts {
+    /// Market account
+    #[prost(bytes="vec", tag="1")]
+    pub market: ::prost::alloc::vec::Vec<u8>,
+    /// Open orders account (NewOrder*)
+    #[prost(bytes="vec", tag="2")]
+    pub open_orders: ::prost::alloc::vec::Vec<u8>,
+    /// Bids slab (NewOrderV3, SendTake)
+    #[prost(bytes="vec", tag="3")]
+    pub bids: ::prost::alloc::vec::Vec<u8>,
+    /// Asks slab (NewOrderV3, SendTake)
+    #[prost(bytes="vec", tag="4")]
+    pub asks: ::prost::alloc::vec::Vec<u8>,
+    /// Open orders owner / taker (signer)
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// User token account paying for the order (NewOrder*)
+    #[prost(bytes="vec", tag="6")]
+    pub order_payer: ::prost::alloc::vec::Vec<u8>,
+    /// User base token account (SendTake)
+    #[prost(bytes="vec", tag="7")]
+    pub coin_wallet: ::prost::alloc::vec::Vec<u8>,
+    /// User quote token account (SendTake)
+    #[prost(bytes="vec", tag="8")]
+    pub pc_wallet: ::prost::alloc::vec::Vec<u8>,
+    /// Market base token vault
+    #[prost(bytes="vec", tag="9")]
+    pub coin_vault: ::prost::alloc::vec::Vec<u8>,
+    /// Market quote token vault
+    #[prost(bytes="vec", tag="10")]
+    pub pc_vault: ::prost::alloc::vec::Vec<u8>,
+    /// Token program
+    #[prost(bytes="vec", tag="11")]
+    pub token_program: ::prost::alloc::vec::Vec<u8>,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SwapInstruction {
@@ -49,6 +93,19 @@ pub struct SwapInstruction {
     pub amount_in: u64,
     #[prost(uint64, tag="2")]
     pub minimum_amount_out: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub input_amount: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub output_amount: u64,
+    #[prost(message, optional, tag="8")]
+    pub accounts: ::core::option::Option<SwapAccounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -0,0 +1,55 @@
+// @generated
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockStats {
+    /// transactions
+    ///
+    /// Total transactions in the block (including votes)
+    #[prost(uint64, tag="1")]
+    pub transactions: u64,
+    /// Transactions executed without error
+    #[prost(uint64, tag="2")]
+    pub successful_transactions: u64,
+    /// Transactions that failed (fees are still charged)
+    #[prost(uint64, tag="3")]
+    pub failed_transactions: u64,
+    /// Transactions invoking the Vote program
+    #[prost(uint64, tag="4")]
+    pub vote_transactions: u64,
+    /// All other transactions
+    #[prost(uint64, tag="5")]
+    pub non_vote_transactions: u64,
+    /// fees (lamports)
+    ///
+    /// Sum of all transaction fees
+    #[prost(uint64, tag="6")]
+    pub total_fees: u64,
+    /// Signature fees (5,000 lamports per signature)
+    #[prost(uint64, tag="7")]
+    pub base_fees: u64,
+    /// Fees paid above the base signature fees
+    #[prost(uint64, tag="8")]
+    pub priority_fees: u64,
+    /// execution
+    ///
+    /// Sum of compute units consumed by all transactions
+    #[prost(uint64, tag="9")]
+    pub compute_units_consumed: u64,
+    /// Number of distinct fee payers
+    #[prost(uint64, tag="10")]
+    pub unique_fee_payers: u64,
+    /// Most invoked programs, sorted by invocations (descending)
+    #[prost(message, repeated, tag="11")]
+    pub top_programs: ::prost::alloc::vec::Vec<ProgramStats>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProgramStats {
+    #[prost(bytes="vec", tag="1")]
+    pub program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Number of instructions (including inner instructions) invoking the program
+    #[prost(uint64, tag="2")]
+    pub invocations: u64,
+}
+// @@protoc_insertion_point(module)
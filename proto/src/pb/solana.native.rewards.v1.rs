@@ -0,0 +1,71 @@
+// @generated
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    /// Slot leader (receives the fee reward), empty if the block has no fee reward.
+    #[prost(bytes="vec", tag="1")]
+    pub leader: ::prost::alloc::vec::Vec<u8>,
+    /// Rewards credited in this block.
+    #[prost(message, repeated, tag="2")]
+    pub rewards: ::prost::alloc::vec::Vec<Reward>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Reward {
+    /// Account credited (or debited) with the reward.
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    /// Reward amount in lamports (negative for debits).
+    #[prost(int64, tag="2")]
+    pub lamports: i64,
+    /// Account balance after the reward.
+    #[prost(uint64, tag="3")]
+    pub post_balance: u64,
+    /// Reward type.
+    #[prost(enumeration="RewardType", tag="4")]
+    pub reward_type: i32,
+    /// Vote account commission when the reward was credited (staking & voting rewards only).
+    #[prost(uint32, optional, tag="5")]
+    pub commission: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RewardType {
+    Unspecified = 0,
+    /// Transaction fees credited to the slot leader (every block)
+    Fee = 1,
+    /// Rent collected and credited to the slot leader (deprecated)
+    Rent = 2,
+    /// Inflation rewards credited to stake accounts (first block of an epoch)
+    Staking = 3,
+    /// Inflation commission credited to vote accounts (first block of an epoch)
+    Voting = 4,
+}
+impl RewardType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RewardType::Unspecified => "REWARD_TYPE_UNSPECIFIED",
+            RewardType::Fee => "REWARD_TYPE_FEE",
+            RewardType::Rent => "REWARD_TYPE_RENT",
+            RewardType::Staking => "REWARD_TYPE_STAKING",
+            RewardType::Voting => "REWARD_TYPE_VOTING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "REWARD_TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+            "REWARD_TYPE_FEE" => Some(Self::Fee),
+            "REWARD_TYPE_RENT" => Some(Self::Rent),
+            "REWARD_TYPE_STAKING" => Some(Self::Staking),
+            "REWARD_TYPE_VOTING" => Some(Self::Voting),
+            _ => None,
+        }
+    }
+}
+// @@protoc_insertion_point(module)
@@ -37,7 +37,7 @@ pub struct Instruction {
     /// Indicates if this instruction is a root instruction.
     #[prost(bool, tag="3")]
     pub is_root: bool,
-    #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 13, 14, 15")]
+    #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -57,6 +57,28 @@ pub mod instruction {
         Merge(super::Merge),
         #[prost(message, tag="15")]
         Split(super::Split),
+        #[prost(message, tag="16")]
+        Authorize(super::Authorize),
+        #[prost(message, tag="17")]
+        AuthorizeWithSeed(super::AuthorizeWithSeed),
+        #[prost(message, tag="18")]
+        AuthorizeChecked(super::AuthorizeChecked),
+        #[prost(message, tag="19")]
+        AuthorizeCheckedWithSeed(super::AuthorizeCheckedWithSeed),
+        #[prost(message, tag="20")]
+        SetLockup(super::SetLockup),
+        #[prost(message, tag="21")]
+        SetLockupChecked(super::SetLockupChecked),
+        #[prost(message, tag="22")]
+        InitializeChecked(super::InitializeChecked),
+        #[prost(message, tag="23")]
+        DeactivateDelinquent(super::DeactivateDelinquent),
+        #[prost(message, tag="24")]
+        Redelegate(super::Redelegate),
+        #[prost(message, tag="25")]
+        MoveStake(super::MoveStake),
+        #[prost(message, tag="26")]
+        MoveLamports(super::MoveLamports),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -152,4 +174,232 @@ pub struct Split {
     #[prost(bytes="vec", tag="4")]
     pub stake_authority: ::prost::alloc::vec::Vec<u8>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Authorize {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// New authority.
+    #[prost(bytes="vec", tag="2")]
+    pub new_authority: ::prost::alloc::vec::Vec<u8>,
+    /// Type of authority being changed.
+    #[prost(enumeration="StakeAuthorize", tag="3")]
+    pub stake_authorize: i32,
+    /// Current stake or withdraw authority.
+    #[prost(bytes="vec", tag="4")]
+    pub authority: ::prost::alloc::vec::Vec<u8>,
+    /// Lockup custodian (optional).
+    #[prost(bytes="vec", optional, tag="5")]
+    pub custodian: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeWithSeed {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// New authority.
+    #[prost(bytes="vec", tag="2")]
+    pub new_authority: ::prost::alloc::vec::Vec<u8>,
+    /// Type of authority being changed.
+    #[prost(enumeration="StakeAuthorize", tag="3")]
+    pub stake_authorize: i32,
+    /// Base key of the current authority (derived with seed).
+    #[prost(bytes="vec", tag="4")]
+    pub authority_base: ::prost::alloc::vec::Vec<u8>,
+    /// Seed used to derive the current authority.
+    #[prost(string, tag="5")]
+    pub authority_seed: ::prost::alloc::string::String,
+    /// Owner program used to derive the current authority.
+    #[prost(bytes="vec", tag="6")]
+    pub authority_owner: ::prost::alloc::vec::Vec<u8>,
+    /// Lockup custodian (optional).
+    #[prost(bytes="vec", optional, tag="7")]
+    pub custodian: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeChecked {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// New authority (signer).
+    #[prost(bytes="vec", tag="2")]
+    pub new_authority: ::prost::alloc::vec::Vec<u8>,
+    /// Type of authority being changed.
+    #[prost(enumeration="StakeAuthorize", tag="3")]
+    pub stake_authorize: i32,
+    /// Current stake or withdraw authority.
+    #[prost(bytes="vec", tag="4")]
+    pub authority: ::prost::alloc::vec::Vec<u8>,
+    /// Lockup custodian (optional).
+    #[prost(bytes="vec", optional, tag="5")]
+    pub custodian: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeCheckedWithSeed {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// New authority (signer).
+    #[prost(bytes="vec", tag="2")]
+    pub new_authority: ::prost::alloc::vec::Vec<u8>,
+    /// Type of authority being changed.
+    #[prost(enumeration="StakeAuthorize", tag="3")]
+    pub stake_authorize: i32,
+    /// Base key of the current authority (derived with seed).
+    #[prost(bytes="vec", tag="4")]
+    pub authority_base: ::prost::alloc::vec::Vec<u8>,
+    /// Seed used to derive the current authority.
+    #[prost(string, tag="5")]
+    pub authority_seed: ::prost::alloc::string::String,
+    /// Owner program used to derive the current authority.
+    #[prost(bytes="vec", tag="6")]
+    pub authority_owner: ::prost::alloc::vec::Vec<u8>,
+    /// Lockup custodian (optional).
+    #[prost(bytes="vec", optional, tag="7")]
+    pub custodian: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLockup {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// New lockup timestamp.
+    #[prost(int64, optional, tag="2")]
+    pub lockup_unix_timestamp: ::core::option::Option<i64>,
+    /// New lockup epoch.
+    #[prost(uint64, optional, tag="3")]
+    pub lockup_epoch: ::core::option::Option<u64>,
+    /// New lockup custodian.
+    #[prost(bytes="vec", optional, tag="4")]
+    pub lockup_custodian: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Lockup custodian or withdraw authority.
+    #[prost(bytes="vec", tag="5")]
+    pub authority: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLockupChecked {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// New lockup timestamp.
+    #[prost(int64, optional, tag="2")]
+    pub lockup_unix_timestamp: ::core::option::Option<i64>,
+    /// New lockup epoch.
+    #[prost(uint64, optional, tag="3")]
+    pub lockup_epoch: ::core::option::Option<u64>,
+    /// New lockup custodian (signer).
+    #[prost(bytes="vec", optional, tag="4")]
+    pub lockup_custodian: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
+    /// Lockup custodian or withdraw authority.
+    #[prost(bytes="vec", tag="5")]
+    pub authority: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializeChecked {
+    /// Stake account to initialize.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Staker authority.
+    #[prost(bytes="vec", tag="2")]
+    pub staker: ::prost::alloc::vec::Vec<u8>,
+    /// Withdrawer authority (signer).
+    #[prost(bytes="vec", tag="3")]
+    pub withdrawer: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeactivateDelinquent {
+    /// Stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Delinquent vote account.
+    #[prost(bytes="vec", tag="2")]
+    pub delinquent_vote_account: ::prost::alloc::vec::Vec<u8>,
+    /// Reference vote account proving delinquency.
+    #[prost(bytes="vec", tag="3")]
+    pub reference_vote_account: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Redelegate {
+    /// Delegated stake account to redelegate.
+    #[prost(bytes="vec", tag="1")]
+    pub stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Uninitialized stake account receiving the redelegated stake.
+    #[prost(bytes="vec", tag="2")]
+    pub uninitialized_stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Vote account to redelegate to.
+    #[prost(bytes="vec", tag="3")]
+    pub vote_account: ::prost::alloc::vec::Vec<u8>,
+    /// Stake authority.
+    #[prost(bytes="vec", tag="4")]
+    pub stake_authority: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveStake {
+    /// Active source stake account.
+    #[prost(bytes="vec", tag="1")]
+    pub source_stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Active or inactive destination stake account.
+    #[prost(bytes="vec", tag="2")]
+    pub destination_stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Amount of stake to move.
+    #[prost(uint64, tag="3")]
+    pub lamports: u64,
+    /// Stake authority.
+    #[prost(bytes="vec", tag="4")]
+    pub stake_authority: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveLamports {
+    /// Source stake account (excess lamports only).
+    #[prost(bytes="vec", tag="1")]
+    pub source_stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Destination stake account.
+    #[prost(bytes="vec", tag="2")]
+    pub destination_stake_account: ::prost::alloc::vec::Vec<u8>,
+    /// Amount of lamports to move.
+    #[prost(uint64, tag="3")]
+    pub lamports: u64,
+    /// Stake authority.
+    #[prost(bytes="vec", tag="4")]
+    pub stake_authority: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StakeAuthorize {
+    /// Authority allowed to delegate and deactivate stake
+    Staker = 0,
+    /// Authority allowed to withdraw lamports and change authorities
+    Withdrawer = 1,
+}
+impl StakeAuthorize {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StakeAuthorize::Staker => "STAKE_AUTHORIZE_STAKER",
+            StakeAuthorize::Withdrawer => "STAKE_AUTHORIZE_WITHDRAWER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STAKE_AUTHORIZE_STAKER" => Some(Self::Staker),
+            "STAKE_AUTHORIZE_WITHDRAWER" => Some(Self::Withdrawer),
+            _ => None,
+        }
+    }
+}
 // @@protoc_insertion_point(module)
@@ -37,7 +37,7 @@ pub struct Instruction {
     /// Indicates if this instruction is a root instruction.
     #[prost(bool, tag="3")]
     pub is_root: bool,
-    #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 13, 14, 15, 16")]
+    #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 13, 14, 15, 16, 17, 18")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
 /// Nested message and enum types in `Instruction`.
@@ -59,6 +59,10 @@ pub mod instruction {
         VoteSwitch(super::VoteSwitch),
         #[prost(message, tag="16")]
         CompactUpdateVoteState(super::CompactUpdateVoteState),
+        #[prost(message, tag="17")]
+        TowerSync(super::TowerSync),
+        #[prost(message, tag="18")]
+        TowerSyncSwitch(super::TowerSyncSwitch),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -167,4 +171,57 @@ pub struct CompactUpdateVoteState {
     #[prost(bytes="vec", tag="2")]
     pub vote_authority: ::prost::alloc::vec::Vec<u8>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TowerSync {
+    /// Vote account.
+    #[prost(bytes="vec", tag="1")]
+    pub vote_account: ::prost::alloc::vec::Vec<u8>,
+    /// Vote authority.
+    #[prost(bytes="vec", tag="2")]
+    pub vote_authority: ::prost::alloc::vec::Vec<u8>,
+    /// Slots of the tower lockouts.
+    #[prost(uint64, repeated, tag="3")]
+    pub slots: ::prost::alloc::vec::Vec<u64>,
+    /// Root slot.
+    #[prost(uint64, optional, tag="4")]
+    pub root: ::core::option::Option<u64>,
+    /// Bank hash of the last voted slot.
+    #[prost(bytes="vec", tag="5")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Vote timestamp (unix seconds).
+    #[prost(int64, optional, tag="6")]
+    pub timestamp: ::core::option::Option<i64>,
+    /// Block id of the last voted slot.
+    #[prost(bytes="vec", tag="7")]
+    pub block_id: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TowerSyncSwitch {
+    /// Vote account.
+    #[prost(bytes="vec", tag="1")]
+    pub vote_account: ::prost::alloc::vec::Vec<u8>,
+    /// Vote authority.
+    #[prost(bytes="vec", tag="2")]
+    pub vote_authority: ::prost::alloc::vec::Vec<u8>,
+    /// Slots of the tower lockouts.
+    #[prost(uint64, repeated, tag="3")]
+    pub slots: ::prost::alloc::vec::Vec<u64>,
+    /// Root slot.
+    #[prost(uint64, optional, tag="4")]
+    pub root: ::core::option::Option<u64>,
+    /// Bank hash of the last voted slot.
+    #[prost(bytes="vec", tag="5")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// Vote timestamp (unix seconds).
+    #[prost(int64, optional, tag="6")]
+    pub timestamp: ::core::option::Option<i64>,
+    /// Block id of the last voted slot.
+    #[prost(bytes="vec", tag="7")]
+    pub block_id: ::prost::alloc::vec::Vec<u8>,
+    /// Proof hash for the switch.
+    #[prost(bytes="vec", tag="8")]
+    pub proof_hash: ::prost::alloc::vec::Vec<u8>,
+}
 // @@protoc_insertion_point(module)
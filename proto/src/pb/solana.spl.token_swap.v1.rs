@@ -37,6 +37,12 @@ pub struct Instruction {
     /// Indicates if this instruction is a root instruction.
     #[prost(bool, tag="3")]
     pub is_root: bool,
+    /// Program invoking this instruction (empty for top-level instructions)
+    #[prost(bytes="vec", tag="4")]
+    pub parent_program_id: ::prost::alloc::vec::Vec<u8>,
+    /// Program of the top-level instruction (itself for top-level instructions)
+    #[prost(bytes="vec", tag="5")]
+    pub outer_program_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(oneof="instruction::Instruction", tags="10, 11, 12, 13, 14, 15")]
     pub instruction: ::core::option::Option<instruction::Instruction>,
 }
@@ -120,6 +126,30 @@ pub struct Swap {
     /// Minimum amount of tokens to receive.
     #[prost(uint64, tag="11")]
     pub minimum_amount_out: u64,
+    /// realized swap, inferred from inner SPL Token transfers
+    #[prost(bytes="vec", tag="12")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub input_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="14")]
+    pub input_amount: u64,
+    #[prost(bytes="vec", tag="15")]
+    pub output_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="16")]
+    pub output_amount: u64,
+}
+/// Pool tokens moved by a deposit or withdraw, inferred from the transfers to/from the pool token accounts
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityAmounts {
+    #[prost(bytes="vec", tag="1")]
+    pub token_a_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub token_a_amount: u64,
+    #[prost(bytes="vec", tag="3")]
+    pub token_b_mint: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub token_b_amount: u64,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -148,6 +178,8 @@ pub struct DepositAllTokenTypes {
     pub maximum_token_a_amount: u64,
     #[prost(uint64, tag="12")]
     pub maximum_token_b_amount: u64,
+    #[prost(message, optional, tag="13")]
+    pub amounts: ::core::option::Option<LiquidityAmounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -178,6 +210,8 @@ pub struct WithdrawAllTokenTypes {
     pub minimum_token_a_amount: u64,
     #[prost(uint64, tag="13")]
     pub minimum_token_b_amount: u64,
+    #[prost(message, optional, tag="14")]
+    pub amounts: ::core::option::Option<LiquidityAmounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -202,6 +236,8 @@ pub struct DepositSingleTokenTypeExactAmountIn {
     pub source_token_amount: u64,
     #[prost(uint64, tag="10")]
     pub minimum_pool_token_amount: u64,
+    #[prost(message, optional, tag="11")]
+    pub amounts: ::core::option::Option<LiquidityAmounts>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -228,5 +264,7 @@ pub struct WithdrawSingleTokenTypeExactAmountOut {
     pub destination_token_amount: u64,
     #[prost(uint64, tag="11")]
     pub maximum_pool_token_amount: u64,
+    #[prost(message, optional, tag="12")]
+    pub amounts: ::core::option::Option<LiquidityAmounts>,
 }
 // @@protoc_insertion_point(module)
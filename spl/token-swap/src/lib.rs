@@ -1,4 +1,5 @@
-use common::solana::{get_fee_payer, get_signers};
+use common::solana::{get_fee_payer, get_outer_program_id, get_parent_program_id, get_signers};
+use common::swaps::{get_transferred_amount, SwapAmounts, TokenAccounts, TransferDirection};
 use proto::pb::solana::spl::token_swap::v1 as pb;
 use substreams::errors::Error;
 use substreams_solana::block_view::InstructionView;
@@ -75,6 +76,7 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
             if accounts.len() < 9 {
                 return None;
             }
+            let swap = SwapAmounts::from_instruction(instruction);
             Some(pb::instruction::Instruction::Swap(pb::Swap {
                 swap_account: accounts[0].0.to_vec(),
                 authority: accounts[1].0.to_vec(),
@@ -85,10 +87,105 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
                 destination: accounts[6].0.to_vec(),
                 pool_mint: accounts[7].0.to_vec(),
                 fee_account: accounts[8].0.to_vec(),
-                amount_in: 0,
-                minimum_amount_out: 0,
+                amount_in: read_u64(data, 1)?,
+                minimum_amount_out: read_u64(data, 9)?,
+                user: swap.user,
+                input_mint: swap.input_mint,
+                input_amount: swap.input_amount,
+                output_mint: swap.output_mint,
+                output_amount: swap.output_amount,
             }))
         }
+        2 => {
+            // DepositAllTokenTypes
+            let accounts = instruction.accounts();
+            if accounts.len() < 9 {
+                return None;
+            }
+            Some(pb::instruction::Instruction::DepositAllTokenTypes(pb::DepositAllTokenTypes {
+                swap_account: accounts[0].0.to_vec(),
+                authority: accounts[1].0.to_vec(),
+                user_transfer_authority: accounts[2].0.to_vec(),
+                source_a: accounts[3].0.to_vec(),
+                source_b: accounts[4].0.to_vec(),
+                token_a: accounts[5].0.to_vec(),
+                token_b: accounts[6].0.to_vec(),
+                pool_mint: accounts[7].0.to_vec(),
+                destination: accounts[8].0.to_vec(),
+                pool_token_amount: read_u64(data, 1)?,
+                maximum_token_a_amount: read_u64(data, 9)?,
+                maximum_token_b_amount: read_u64(data, 17)?,
+                amounts: Some(get_liquidity_amounts(instruction, &accounts[5].0, &accounts[6].0, TransferDirection::Received)),
+            }))
+        }
+        3 => {
+            // WithdrawAllTokenTypes
+            let accounts = instruction.accounts();
+            if accounts.len() < 10 {
+                return None;
+            }
+            Some(pb::instruction::Instruction::WithdrawAllTokenTypes(pb::WithdrawAllTokenTypes {
+                swap_account: accounts[0].0.to_vec(),
+                authority: accounts[1].0.to_vec(),
+                user_transfer_authority: accounts[2].0.to_vec(),
+                pool_mint: accounts[3].0.to_vec(),
+                source: accounts[4].0.to_vec(),
+                token_a: accounts[5].0.to_vec(),
+                token_b: accounts[6].0.to_vec(),
+                destination_a: accounts[7].0.to_vec(),
+                destination_b: accounts[8].0.to_vec(),
+                fee_account: accounts[9].0.to_vec(),
+                pool_token_amount: read_u64(data, 1)?,
+                minimum_token_a_amount: read_u64(data, 9)?,
+                minimum_token_b_amount: read_u64(data, 17)?,
+                amounts: Some(get_liquidity_amounts(instruction, &accounts[5].0, &accounts[6].0, TransferDirection::Sent)),
+            }))
+        }
+        4 => {
+            // DepositSingleTokenTypeExactAmountIn
+            let accounts = instruction.accounts();
+            if accounts.len() < 8 {
+                return None;
+            }
+            Some(pb::instruction::Instruction::DepositSingleTokenTypeExactAmountIn(
+                pb::DepositSingleTokenTypeExactAmountIn {
+                    swap_account: accounts[0].0.to_vec(),
+                    authority: accounts[1].0.to_vec(),
+                    user_transfer_authority: accounts[2].0.to_vec(),
+                    source: accounts[3].0.to_vec(),
+                    token_a: accounts[4].0.to_vec(),
+                    token_b: accounts[5].0.to_vec(),
+                    pool_mint: accounts[6].0.to_vec(),
+                    destination: accounts[7].0.to_vec(),
+                    source_token_amount: read_u64(data, 1)?,
+                    minimum_pool_token_amount: read_u64(data, 9)?,
+                    amounts: Some(get_liquidity_amounts(instruction, &accounts[4].0, &accounts[5].0, TransferDirection::Received)),
+                },
+            ))
+        }
+        5 => {
+            // WithdrawSingleTokenTypeExactAmountOut
+            let accounts = instruction.accounts();
+            if accounts.len() < 9 {
+                return None;
+            }
+            Some(pb::instruction::Instruction::WithdrawSingleTokenTypeExactAmountOut(
+                pb::WithdrawSingleTokenTypeExactAmountOut {
+                    swap_account: accounts[0].0.to_vec(),
+                    authority: accounts[1].0.to_vec(),
+                    user_transfer_authority: accounts[2].0.to_vec(),
+                    pool_mint: accounts[3].0.to_vec(),
+                    source: accounts[4].0.to_vec(),
+                    token_a: accounts[5].0.to_vec(),
+                    token_b: accounts[6].0.to_vec(),
+                    destination: accounts[7].0.to_vec(),
+                    fee_account: accounts[8].0.to_vec(),
+                    destination_token_amount: read_u64(data, 1)?,
+                    maximum_pool_token_amount: read_u64(data, 9)?,
+                    amounts: Some(get_liquidity_amounts(instruction, &accounts[5].0, &accounts[6].0, TransferDirection::Sent)),
+                },
+            ))
+        }
         _ => None,
     };
 
@@ -96,6 +193,23 @@ fn process_instruction(instruction: &InstructionView) -> Option<pb::Instruction>
         program_id: program_id.to_vec(),
         stack_height: instruction.stack_height(),
         is_root: instruction.is_root(),
+        parent_program_id: get_parent_program_id(instruction),
+        outer_program_id: get_outer_program_id(instruction),
         instruction: Some(parsed),
     })
 }
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+// Tokens deposited to or withdrawn from the pool token accounts (A & B)
+fn get_liquidity_amounts(instruction: &InstructionView, token_a: &[u8], token_b: &[u8], direction: TransferDirection) -> pb::LiquidityAmounts {
+    let accounts = TokenAccounts::new(instruction.transaction());
+    pb::LiquidityAmounts {
+        token_a_mint: accounts.mint(token_a).cloned().unwrap_or_default(),
+        token_a_amount: get_transferred_amount(instruction, token_a, direction),
+        token_b_mint: accounts.mint(token_b).cloned().unwrap_or_default(),
+        token_b_amount: get_transferred_amount(instruction, token_b, direction),
+    }
+}
@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS block_stats (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- transactions --
+    transactions                UInt32 COMMENT 'Total transactions (including votes)',
+    successful_transactions     UInt32 COMMENT 'Transactions executed without error',
+    failed_transactions         UInt32 COMMENT 'Failed transactions (fees are still charged)',
+    vote_transactions           UInt32 COMMENT 'Transactions invoking the Vote program',
+    non_vote_transactions       UInt32 COMMENT 'All other transactions',
+
+    -- fees --
+    total_fees                  UInt64 COMMENT 'Sum of all transaction fees in lamports',
+    base_fees                   UInt64 COMMENT 'Signature fees in lamports (5,000 lamports per signature)',
+    priority_fees               UInt64 COMMENT 'Fees paid above the base signature fees in lamports',
+
+    -- execution --
+    compute_units_consumed      UInt64 COMMENT 'Sum of compute units consumed by all transactions',
+    unique_fee_payers           UInt32 COMMENT 'Number of distinct fee payers',
+
+    -- top programs --
+    top_programs_raw            String COMMENT 'Most invoked programs (comma separated, sorted by invocations)',
+    top_program_invocations_raw String COMMENT 'Invocation counts matching top_programs_raw (comma separated)',
+    top_programs                Array(FixedString(44)) MATERIALIZED arrayMap(x -> toFixedString(x, 44), arrayFilter(x -> x != '', splitByChar(',', top_programs_raw))),
+    top_program_invocations     Array(UInt64) MATERIALIZED arrayMap(x -> toUInt64OrZero(x), arrayFilter(x -> x != '', splitByChar(',', top_program_invocations_raw))),
+
+    -- indexes --
+    INDEX idx_block_hash        (block_hash)        TYPE bloom_filter(0.01)     GRANULARITY 1,
+    INDEX idx_timestamp         (timestamp)         TYPE minmax                 GRANULARITY 1,
+    INDEX idx_top_programs      (top_programs)      TYPE bloom_filter(0.005)    GRANULARITY 1
+) ENGINE = ReplacingMergeTree(timestamp) -- in case of reorgs, keep the latest block by timestamp
+ORDER BY block_num
+COMMENT 'Per-block chain statistics (transactions, fees, compute units, fee payers, top programs)';
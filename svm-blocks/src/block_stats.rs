@@ -0,0 +1,76 @@
+use std::collections::{HashMap, HashSet};
+
+use common::solana::get_fee_payer;
+use proto::pb::solana::blocks::v1 as pb;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+
+// Vote Program (Vote111111111111111111111111111111111111111)
+const VOTE_PROGRAM: [u8; 32] = [
+    7, 97, 72, 29, 53, 116, 116, 187, 124, 77, 118, 36, 235, 211, 189, 179, 216, 53, 94, 115, 209, 16, 67, 252, 13, 163, 83, 128, 0, 0, 0, 0,
+];
+
+// Base fee charged per transaction signature, anything above is a priority fee
+const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+// Number of programs reported in `top_programs`
+const TOP_PROGRAMS: usize = 10;
+
+pub fn compute(block: &Block) -> pb::BlockStats {
+    let mut stats = pb::BlockStats::default();
+    let mut fee_payers: HashSet<Vec<u8>> = HashSet::new();
+    let mut invocations: HashMap<Vec<u8>, u64> = HashMap::new();
+
+    // `Block::transactions()` skips failed transactions, iterate over all of them
+    for tx in block.transactions.iter() {
+        let Some(meta) = tx.meta.as_ref() else {
+            continue;
+        };
+        stats.transactions += 1;
+
+        if meta.err.is_some() {
+            stats.failed_transactions += 1;
+        } else {
+            stats.successful_transactions += 1;
+        }
+
+        let mut is_vote = false;
+        for iview in tx.walk_instructions() {
+            let program_id = iview.program_id().0;
+            if iview.is_root() && program_id == &VOTE_PROGRAM {
+                is_vote = true;
+            }
+            *invocations.entry(program_id.to_vec()).or_default() += 1;
+        }
+        if is_vote {
+            stats.vote_transactions += 1;
+        } else {
+            stats.non_vote_transactions += 1;
+        }
+
+        let base_fee = num_signatures(tx) * LAMPORTS_PER_SIGNATURE;
+        stats.total_fees += meta.fee;
+        stats.base_fees += base_fee.min(meta.fee);
+        stats.priority_fees += meta.fee.saturating_sub(base_fee);
+        stats.compute_units_consumed += meta.compute_units_consumed();
+
+        if let Some(fee_payer) = get_fee_payer(tx) {
+            fee_payers.insert(fee_payer);
+        }
+    }
+    stats.unique_fee_payers = fee_payers.len() as u64;
+
+    // sort by invocations (descending), ties broken by program id to keep the output deterministic
+    let mut programs: Vec<_> = invocations.into_iter().collect();
+    programs.sort_by(|(a_id, a_count), (b_id, b_count)| b_count.cmp(a_count).then_with(|| a_id.cmp(b_id)));
+    stats.top_programs = programs
+        .into_iter()
+        .take(TOP_PROGRAMS)
+        .map(|(program_id, invocations)| pb::ProgramStats { program_id, invocations })
+        .collect();
+
+    stats
+}
+
+fn num_signatures(tx: &ConfirmedTransaction) -> u64 {
+    tx.transaction.as_ref().map_or(0, |t| t.signatures.len() as u64)
+}
@@ -0,0 +1,38 @@
+mod block_stats;
+
+use common::db::set_clock;
+use proto::pb::solana::blocks::v1 as pb;
+use substreams::{errors::Error, pb::substreams::Clock};
+use substreams_database_change::pb::sf::substreams::sink::database::v1::DatabaseChanges;
+use substreams_solana::{base58, pb::sf::solana::r#type::v1::Block};
+
+#[substreams::handlers::map]
+fn map_block_stats(block: Block) -> Result<pb::BlockStats, Error> {
+    Ok(block_stats::compute(&block))
+}
+
+#[substreams::handlers::map]
+pub fn db_out(clock: Clock, stats: pb::BlockStats) -> Result<DatabaseChanges, Error> {
+    let mut tables = substreams_database_change::tables::Tables::new();
+
+    let top_programs = stats.top_programs.iter().map(|p| base58::encode(&p.program_id)).collect::<Vec<_>>();
+    let top_invocations = stats.top_programs.iter().map(|p| p.invocations.to_string()).collect::<Vec<_>>();
+
+    let row = tables
+        .create_row("block_stats", [("block_num", clock.number.to_string())])
+        .set("transactions", stats.transactions)
+        .set("successful_transactions", stats.successful_transactions)
+        .set("failed_transactions", stats.failed_transactions)
+        .set("vote_transactions", stats.vote_transactions)
+        .set("non_vote_transactions", stats.non_vote_transactions)
+        .set("total_fees", stats.total_fees)
+        .set("base_fees", stats.base_fees)
+        .set("priority_fees", stats.priority_fees)
+        .set("compute_units_consumed", stats.compute_units_consumed)
+        .set("unique_fee_payers", stats.unique_fee_payers)
+        .set("top_programs_raw", top_programs.join(","))
+        .set("top_program_invocations_raw", top_invocations.join(","));
+    set_clock(&clock, row);
+
+    Ok(tables.to_database_changes())
+}
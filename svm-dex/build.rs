@@ -0,0 +1,129 @@
+//! Checks that `substreams.yaml` and `src/lib.rs` agree on the `db_out` inputs.
+//!
+//! Substreams passes the module inputs positionally, an input missing from the handler silently shifts
+//! every following input (or is dropped), so the mismatch is reported at compile time instead.
+//!
+//! Each input is matched to the parameter at the same position:
+//! - `source: sf.substreams.v1.Clock` by type (`Clock`)
+//! - `store: store_<name>` by name (`<name>`)
+//! - `map: <package>:map_events` by name & type (`<package>_events: ...Events`)
+
+use std::fs;
+
+const MANIFEST: &str = "substreams.yaml";
+const HANDLERS: &str = "src/lib.rs";
+const MODULE: &str = "db_out";
+
+// imports which do not provide module inputs
+const SINK_IMPORTS: [&str; 2] = ["database_changes", "sql"];
+
+fn main() {
+    println!("cargo:rerun-if-changed={MANIFEST}");
+    println!("cargo:rerun-if-changed={HANDLERS}");
+
+    let manifest = fs::read_to_string(MANIFEST).expect("read substreams.yaml");
+    let handlers = fs::read_to_string(HANDLERS).expect("read src/lib.rs");
+
+    let inputs = module_inputs(&manifest, MODULE);
+    let params = handler_params(&handlers, MODULE);
+    if inputs.len() != params.len() {
+        panic!(
+            "`{MODULE}` has {} inputs in {MANIFEST} but {} parameters in {HANDLERS}\n  inputs: {inputs:?}\n  parameters: {params:?}",
+            inputs.len(),
+            params.len()
+        );
+    }
+    for (input, param) in inputs.iter().zip(params.iter()) {
+        if !input_matches_param(input, param) {
+            panic!("`{MODULE}` input `{input}` in {MANIFEST} does not match the parameter `{param}` in {HANDLERS}");
+        }
+    }
+
+    // every imported package must be consumed by a module
+    let consumed = manifest_inputs(&manifest);
+    for import in imports(&manifest) {
+        if !SINK_IMPORTS.contains(&import.as_str()) && !consumed.iter().any(|input| input.starts_with(&format!("{import}:"))) {
+            panic!("`{import}` is imported in {MANIFEST} but not used as a module input");
+        }
+    }
+}
+
+/// Inputs of `module` (ex: `map: pumpfun:map_events`), in order.
+fn module_inputs(manifest: &str, module: &str) -> Vec<String> {
+    let mut lines = manifest.lines().skip_while(|line| line.trim() != format!("- name: {module}"));
+    lines.next().unwrap_or_else(|| panic!("module `{module}` not found in {MANIFEST}"));
+    lines
+        .take_while(|line| !line.trim_start().starts_with("- name:"))
+        .skip_while(|line| line.trim() != "inputs:")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with("output:"))
+        .filter_map(input)
+        .collect()
+}
+
+/// Every module input of the manifest.
+fn manifest_inputs(manifest: &str) -> Vec<String> {
+    manifest
+        .lines()
+        .filter_map(input)
+        .map(|input| input.split_once(": ").map_or(input.clone(), |(_, name)| name.to_string()))
+        .collect()
+}
+
+fn input_matches_param(input: &str, param: &str) -> bool {
+    let Some((name, ty)) = param.split_once(':') else {
+        return false;
+    };
+    let name = name.trim().trim_start_matches("mut ").trim();
+    let ty = ty.trim();
+    let Some((kind, module)) = input.split_once(": ") else {
+        return false;
+    };
+    match kind {
+        "source" => module.rsplit('.').next() == Some(ty),
+        "store" => module.strip_prefix("store_") == Some(name),
+        "map" => {
+            let package = module.split(':').next().unwrap_or(module);
+            name == format!("{package}_events") && ty.ends_with("Events")
+        }
+        _ => true,
+    }
+}
+
+fn input(line: &str) -> Option<String> {
+    let entry = line.trim().strip_prefix("- ")?;
+    ["source:", "map:", "store:", "params:"]
+        .iter()
+        .any(|kind| entry.starts_with(kind))
+        .then(|| entry.to_string())
+}
+
+/// Packages listed under `imports:`.
+fn imports(manifest: &str) -> Vec<String> {
+    manifest
+        .lines()
+        .skip_while(|line| *line != "imports:")
+        .skip(1)
+        .take_while(|line| line.is_empty() || line.starts_with(' '))
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.starts_with('#') {
+                return None;
+            }
+            line.split_once(':').map(|(name, _)| name.to_string())
+        })
+        .collect()
+}
+
+/// Parameters of the `handler` function (ex: `pumpfun_events: pb::pumpfun::v1::Events`).
+fn handler_params(source: &str, handler: &str) -> Vec<String> {
+    let signature = format!("pub fn {handler}(");
+    let start = source.find(&signature).unwrap_or_else(|| panic!("handler `{handler}` not found in {HANDLERS}")) + signature.len();
+    let end = start + source[start..].find(") ->").expect("handler return type");
+    source[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(str::to_string)
+        .collect()
+}
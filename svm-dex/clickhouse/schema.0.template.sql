@@ -21,9 +21,13 @@ CREATE TABLE IF NOT EXISTS base_events (
     -- instruction --
     program_id                  LowCardinality(FixedString(44)),
     stack_height                UInt32,
+    parent_program_id           LowCardinality(FixedString(44)) DEFAULT '' COMMENT 'Program invoking this instruction (empty for top-level instructions)',
+    outer_program_id            LowCardinality(FixedString(44)) DEFAULT '' COMMENT 'Program of the top-level instruction',
+    aggregator                  LowCardinality(FixedString(44)) DEFAULT '' COMMENT 'Aggregator routing this instruction (Jupiter, OKX, DFlow), empty for direct swaps',
 
     -- indexes -
     INDEX idx_program_id        (program_id)        TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_aggregator        (aggregator)        TYPE set(8)                 GRANULARITY 1,
     INDEX idx_fee_payer         (fee_payer)         TYPE bloom_filter(0.005)    GRANULARITY 1,
     INDEX idx_signature         (signature)         TYPE bloom_filter(0.005)    GRANULARITY 1,
     INDEX idx_signer            (signer)            TYPE bloom_filter(0.005)    GRANULARITY 1
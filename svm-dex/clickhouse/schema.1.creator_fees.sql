@@ -0,0 +1,39 @@
+-- SVM Creator Fees --
+CREATE TABLE IF NOT EXISTS creator_fees (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- ordering --
+    transaction_index           UInt32,
+    instruction_index           UInt32 COMMENT 'Instruction index of the trade or claim within the transaction',
+
+    -- transaction --
+    signature                   FixedString(88),
+
+    -- fee --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'Pump.fun bonding curve or Pump.fun AMM (program ID)',
+    action                      Enum8('accrue' = 1, 'claim' = 2, 'set_creator' = 3),
+    mint                        FixedString(44) COMMENT 'Token mint, empty for claims (made for all the coins of a creator)',
+    pool                        FixedString(44) COMMENT 'AMM pool, empty for the bonding curve',
+    creator                     FixedString(44) COMMENT 'Coin creator receiving the fees',
+    quote_mint                  FixedString(44) COMMENT 'Token the fee is paid in, empty for SOL (bonding curve)',
+    fee_basis_points            UInt64 COMMENT 'Creator fee rate of the trade, 0 for claims',
+    amount                      UInt64 COMMENT 'Fee accrued by the trade or claimed from the creator vault',
+
+    -- indexes --
+    INDEX idx_program_id     (program_id)   TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_action         (action)       TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_mint           (mint)         TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_pool           (pool)         TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_creator        (creator)      TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_signature      (signature)    TYPE bloom_filter(0.005)    GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree
+ORDER BY (
+    timestamp, block_num,
+    block_hash, transaction_index, instruction_index,
+    program_id, action
+)
+COMMENT 'Pump.fun creator fees accrued by trades, claimed by creators and creator changes';
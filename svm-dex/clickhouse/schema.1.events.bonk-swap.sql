@@ -0,0 +1,31 @@
+-- BonkSwap Swap --
+CREATE TABLE IF NOT EXISTS bonk_swap_swap AS base_events
+COMMENT 'BonkSwap Swap';
+ALTER TABLE bonk_swap_swap
+    ADD COLUMN IF NOT EXISTS delta_in           UInt64 COMMENT 'Amount of tokens provided',
+    ADD COLUMN IF NOT EXISTS price_limit        String COMMENT 'Price limit (decimal string)',
+    ADD COLUMN IF NOT EXISTS x_to_y             Bool COMMENT 'Swap direction, token X to token Y',
+    ADD COLUMN IF NOT EXISTS user               FixedString(44) COMMENT 'User wallet address (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_mint         FixedString(44) COMMENT 'Input token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_amount       UInt64 COMMENT 'Actual input amount (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_mint        FixedString(44) COMMENT 'Output token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_amount      UInt64 COMMENT 'Actual output amount (inferred from inner token transfers)',
+
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS pool                   FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS token_x                FixedString(44) COMMENT 'Token X mint',
+    ADD COLUMN IF NOT EXISTS token_y                FixedString(44) COMMENT 'Token Y mint',
+    ADD COLUMN IF NOT EXISTS pool_x_account         FixedString(44) COMMENT 'Pool token X account',
+    ADD COLUMN IF NOT EXISTS pool_y_account         FixedString(44) COMMENT 'Pool token Y account',
+    ADD COLUMN IF NOT EXISTS swapper_x_account      FixedString(44) COMMENT 'Swapper token X account',
+    ADD COLUMN IF NOT EXISTS swapper_y_account      FixedString(44) COMMENT 'Swapper token Y account',
+    ADD COLUMN IF NOT EXISTS swapper                FixedString(44) COMMENT 'Swapper account',
+    ADD COLUMN IF NOT EXISTS referrer_x_account     FixedString(44) COMMENT 'Referrer token X account, empty without referrer',
+    ADD COLUMN IF NOT EXISTS referrer_y_account     FixedString(44) COMMENT 'Referrer token Y account, empty without referrer',
+    ADD COLUMN IF NOT EXISTS referrer               FixedString(44) COMMENT 'Referrer account, empty without referrer',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint      (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint     (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_pool            (pool)              TYPE bloom_filter(0.005)    GRANULARITY 1;
@@ -0,0 +1,29 @@
+-- ByReal Swap --
+CREATE TABLE IF NOT EXISTS byreal_swap AS base_events
+COMMENT 'ByReal Swap';
+ALTER TABLE byreal_swap
+    ADD COLUMN IF NOT EXISTS amount_in          UInt64 COMMENT 'Amount in',
+    ADD COLUMN IF NOT EXISTS minimum_amount_out UInt64 COMMENT 'Minimum amount out',
+    ADD COLUMN IF NOT EXISTS user               FixedString(44) COMMENT 'User wallet address (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_mint         FixedString(44) COMMENT 'Input token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_amount       UInt64 COMMENT 'Actual input amount (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_mint        FixedString(44) COMMENT 'Output token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_amount      UInt64 COMMENT 'Actual output amount (inferred from inner token transfers)',
+
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS payer                    FixedString(44) COMMENT 'User (signer)',
+    ADD COLUMN IF NOT EXISTS amm_config               FixedString(44) COMMENT 'AMM config',
+    ADD COLUMN IF NOT EXISTS pool_state               FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS input_token_account      FixedString(44) COMMENT 'User input token account',
+    ADD COLUMN IF NOT EXISTS output_token_account     FixedString(44) COMMENT 'User output token account',
+    ADD COLUMN IF NOT EXISTS input_vault              FixedString(44) COMMENT 'Pool vault receiving the input token',
+    ADD COLUMN IF NOT EXISTS output_vault             FixedString(44) COMMENT 'Pool vault sending the output token',
+    ADD COLUMN IF NOT EXISTS observation_state        FixedString(44) COMMENT 'Oracle observation account',
+    ADD COLUMN IF NOT EXISTS token_program            FixedString(44) COMMENT 'Token program',
+    ADD COLUMN IF NOT EXISTS tick_array               FixedString(44) COMMENT 'First tick array',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint      (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint     (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_pool_state      (pool_state)        TYPE bloom_filter(0.005)    GRANULARITY 1;
@@ -0,0 +1,16 @@
+-- DFlow Swap --
+CREATE TABLE IF NOT EXISTS dflow_swap AS base_events
+COMMENT 'DFlow Swap';
+ALTER TABLE dflow_swap
+    ADD COLUMN IF NOT EXISTS amount_in          UInt64 COMMENT 'Amount in',
+    ADD COLUMN IF NOT EXISTS minimum_amount_out UInt64 COMMENT 'Minimum amount out',
+    ADD COLUMN IF NOT EXISTS user               FixedString(44) COMMENT 'User wallet address (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_mint         FixedString(44) COMMENT 'Input token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_amount       UInt64 COMMENT 'Actual input amount (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_mint        FixedString(44) COMMENT 'Output token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_amount      UInt64 COMMENT 'Actual output amount (inferred from inner token transfers)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint      (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint     (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1;
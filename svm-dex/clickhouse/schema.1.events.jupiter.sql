@@ -7,4 +7,54 @@ ALTER TABLE jupiter_swap
     ADD COLUMN IF NOT EXISTS input_mint                  FixedString(44) COMMENT 'Input token mint address',
     ADD COLUMN IF NOT EXISTS input_amount                UInt64 COMMENT 'Amount of input tokens swapped',
     ADD COLUMN IF NOT EXISTS output_mint                 FixedString(44) COMMENT 'Output token mint address',
-    ADD COLUMN IF NOT EXISTS output_amount               UInt64 COMMENT 'Amount of output tokens received';
+    ADD COLUMN IF NOT EXISTS output_amount               UInt64 COMMENT 'Amount of output tokens received',
+    ADD COLUMN IF NOT EXISTS route_instruction_index     UInt32 COMMENT 'instruction_index of the route emitting this swap (first event of the transaction for V4)';
+
+-- Jupiter V4 & V6 Fees --
+CREATE TABLE IF NOT EXISTS jupiter_fee AS base_events
+COMMENT 'Jupiter V4 & V6 platform/referral fees';
+ALTER TABLE jupiter_fee
+    -- log --
+    ADD COLUMN IF NOT EXISTS account                     FixedString(44) COMMENT 'Fee token account of the integrator (referral account)',
+    ADD COLUMN IF NOT EXISTS mint                        FixedString(44) COMMENT 'Token mint address of the fee',
+    ADD COLUMN IF NOT EXISTS amount                      UInt64 COMMENT 'Amount of fee collected',
+    ADD COLUMN IF NOT EXISTS route_instruction_index     UInt32 COMMENT 'instruction_index of the route emitting this fee, joins jupiter_swap & jupiter_trades of the same signature',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_account           (account)           TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_mint              (mint)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_amount            (amount)            TYPE minmax                 GRANULARITY 1;
+
+-- Jupiter V6 Trades (route instructions) --
+CREATE TABLE IF NOT EXISTS jupiter_trades AS base_events
+COMMENT 'Jupiter V6 Trades, one row per route instruction grouping all of its swaps';
+ALTER TABLE jupiter_trades
+    -- instruction --
+    ADD COLUMN IF NOT EXISTS route                       LowCardinality(String) COMMENT 'Route instruction (route, shared_accounts_route, exact_out_route, shared_accounts_exact_out_route, or an undecoded variant such as route_v2 with only its trade)',
+    ADD COLUMN IF NOT EXISTS in_amount                   UInt64 COMMENT 'Amount of input tokens (exact in routes)',
+    ADD COLUMN IF NOT EXISTS quoted_out_amount           UInt64 COMMENT 'Quoted amount of output tokens (exact in routes)',
+    ADD COLUMN IF NOT EXISTS out_amount                  UInt64 COMMENT 'Amount of output tokens (exact out routes)',
+    ADD COLUMN IF NOT EXISTS quoted_in_amount            UInt64 COMMENT 'Quoted amount of input tokens (exact out routes)',
+    ADD COLUMN IF NOT EXISTS slippage_bps                UInt16 COMMENT 'Slippage tolerance in basis points',
+    ADD COLUMN IF NOT EXISTS platform_fee_bps            UInt8 COMMENT 'Platform fee in basis points',
+    ADD COLUMN IF NOT EXISTS route_plan_steps            UInt8 COMMENT 'Number of steps in the route plan (0 if not decoded)',
+
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS user                        FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS source_token_account        FixedString(44) COMMENT 'User source token account',
+    ADD COLUMN IF NOT EXISTS destination_token_account   FixedString(44) COMMENT 'Token account receiving the output tokens',
+    ADD COLUMN IF NOT EXISTS platform_fee_account        FixedString(44) COMMENT 'Platform fee token account',
+
+    -- trade --
+    ADD COLUMN IF NOT EXISTS input_mint                  FixedString(44) COMMENT 'Input token mint address (first hop)',
+    ADD COLUMN IF NOT EXISTS input_amount                UInt64 COMMENT 'Amount of input tokens swapped',
+    ADD COLUMN IF NOT EXISTS output_mint                 FixedString(44) COMMENT 'Output token mint address (last hop)',
+    ADD COLUMN IF NOT EXISTS output_amount               UInt64 COMMENT 'Amount of output tokens received',
+    ADD COLUMN IF NOT EXISTS hops                        UInt8 COMMENT 'Number of hops of the route, split steps between the same tokens count once',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_route             (route)             TYPE set(10)                GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_user              (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint        (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint       (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_hops              (hops)              TYPE minmax                 GRANULARITY 1;
@@ -8,3 +8,35 @@ ALTER TABLE meteora_amm_swap
     ADD COLUMN IF NOT EXISTS output_mint FixedString(44) COMMENT 'Output token account',
     ADD COLUMN IF NOT EXISTS amount_in   UInt64 COMMENT 'Amount of tokens in',
     ADD COLUMN IF NOT EXISTS amount_out  UInt64 COMMENT 'Amount of tokens out';
+
+-- Meteora AMM Add Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_amm_add_liquidity AS base_events
+COMMENT 'Meteora AMM Add Liquidity (balanced & imbalanced)';
+ALTER TABLE meteora_amm_add_liquidity
+    ADD COLUMN IF NOT EXISTS user           FixedString(44) COMMENT 'User account',
+    ADD COLUMN IF NOT EXISTS pool           FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS lp_mint        FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS user_pool_lp   FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS token_a_mint   FixedString(44) COMMENT 'Token A mint',
+    ADD COLUMN IF NOT EXISTS token_b_mint   FixedString(44) COMMENT 'Token B mint',
+    ADD COLUMN IF NOT EXISTS lp_mint_amount UInt64 COMMENT 'LP tokens minted',
+    ADD COLUMN IF NOT EXISTS token_a_amount UInt64 COMMENT 'Amount of token A deposited',
+    ADD COLUMN IF NOT EXISTS token_b_amount UInt64 COMMENT 'Amount of token B deposited';
+
+-- Meteora AMM Bootstrap Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_amm_bootstrap_liquidity AS meteora_amm_add_liquidity
+COMMENT 'Meteora AMM Bootstrap Liquidity (first deposit)';
+
+-- Meteora AMM Remove Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_amm_remove_liquidity AS base_events
+COMMENT 'Meteora AMM Remove Liquidity (balanced & single side)';
+ALTER TABLE meteora_amm_remove_liquidity
+    ADD COLUMN IF NOT EXISTS user               FixedString(44) COMMENT 'User account',
+    ADD COLUMN IF NOT EXISTS pool               FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS lp_mint            FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS user_pool_lp       FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS token_a_mint       FixedString(44) COMMENT 'Token A mint',
+    ADD COLUMN IF NOT EXISTS token_b_mint       FixedString(44) COMMENT 'Token B mint',
+    ADD COLUMN IF NOT EXISTS lp_unmint_amount   UInt64 COMMENT 'LP tokens burned',
+    ADD COLUMN IF NOT EXISTS token_a_out_amount UInt64 COMMENT 'Amount of token A withdrawn',
+    ADD COLUMN IF NOT EXISTS token_b_out_amount UInt64 COMMENT 'Amount of token B withdrawn';
@@ -8,3 +8,111 @@ ALTER TABLE meteora_daam_swap
     ADD COLUMN IF NOT EXISTS output_mint FixedString(44) COMMENT 'Output token mint',
     ADD COLUMN IF NOT EXISTS amount_in   UInt64 COMMENT 'Amount of tokens in',
     ADD COLUMN IF NOT EXISTS amount_out  UInt64 COMMENT 'Amount of tokens out';
+
+-- Meteora DAAM Add Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_daam_add_liquidity AS base_events
+COMMENT 'Meteora DAAM Add Liquidity';
+ALTER TABLE meteora_daam_add_liquidity
+    ADD COLUMN IF NOT EXISTS pool                     FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS position                 FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS owner                    FixedString(44) COMMENT 'Position owner',
+    ADD COLUMN IF NOT EXISTS token_a_mint             FixedString(44) COMMENT 'Token A mint',
+    ADD COLUMN IF NOT EXISTS token_b_mint             FixedString(44) COMMENT 'Token B mint',
+    ADD COLUMN IF NOT EXISTS liquidity_delta          UInt128 COMMENT 'Liquidity added to the position',
+    ADD COLUMN IF NOT EXISTS token_a_amount_threshold UInt64 COMMENT 'Maximum amount of token A',
+    ADD COLUMN IF NOT EXISTS token_b_amount_threshold UInt64 COMMENT 'Maximum amount of token B',
+    ADD COLUMN IF NOT EXISTS token_a_amount           UInt64 COMMENT 'Amount of token A deposited',
+    ADD COLUMN IF NOT EXISTS token_b_amount           UInt64 COMMENT 'Amount of token B deposited',
+    ADD COLUMN IF NOT EXISTS total_amount_a           UInt64 COMMENT 'Amount of token A including transfer fees',
+    ADD COLUMN IF NOT EXISTS total_amount_b           UInt64 COMMENT 'Amount of token B including transfer fees';
+
+-- Meteora DAAM Remove Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_daam_remove_liquidity AS base_events
+COMMENT 'Meteora DAAM Remove Liquidity';
+ALTER TABLE meteora_daam_remove_liquidity
+    ADD COLUMN IF NOT EXISTS pool                     FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS position                 FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS owner                    FixedString(44) COMMENT 'Position owner',
+    ADD COLUMN IF NOT EXISTS token_a_mint             FixedString(44) COMMENT 'Token A mint',
+    ADD COLUMN IF NOT EXISTS token_b_mint             FixedString(44) COMMENT 'Token B mint',
+    ADD COLUMN IF NOT EXISTS liquidity_delta          UInt128 COMMENT 'Liquidity removed from the position',
+    ADD COLUMN IF NOT EXISTS token_a_amount_threshold UInt64 COMMENT 'Minimum amount of token A',
+    ADD COLUMN IF NOT EXISTS token_b_amount_threshold UInt64 COMMENT 'Minimum amount of token B',
+    ADD COLUMN IF NOT EXISTS token_a_amount           UInt64 COMMENT 'Amount of token A withdrawn',
+    ADD COLUMN IF NOT EXISTS token_b_amount           UInt64 COMMENT 'Amount of token B withdrawn';
+
+-- Meteora DAAM Claim Position Fee --
+CREATE TABLE IF NOT EXISTS meteora_daam_claim_position_fee AS base_events
+COMMENT 'Meteora DAAM ClaimPositionFee';
+ALTER TABLE meteora_daam_claim_position_fee
+    ADD COLUMN IF NOT EXISTS pool          FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS position      FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS owner         FixedString(44) COMMENT 'Position owner',
+    ADD COLUMN IF NOT EXISTS token_a_mint  FixedString(44) COMMENT 'Token A mint',
+    ADD COLUMN IF NOT EXISTS token_b_mint  FixedString(44) COMMENT 'Token B mint',
+    ADD COLUMN IF NOT EXISTS fee_a         UInt64 COMMENT 'Token A fees claimed',
+    ADD COLUMN IF NOT EXISTS fee_b         UInt64 COMMENT 'Token B fees claimed';
+
+-- Meteora DAAM Claim Reward --
+CREATE TABLE IF NOT EXISTS meteora_daam_claim_reward AS base_events
+COMMENT 'Meteora DAAM ClaimReward';
+ALTER TABLE meteora_daam_claim_reward
+    ADD COLUMN IF NOT EXISTS pool          FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS position      FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS owner         FixedString(44) COMMENT 'Position owner',
+    ADD COLUMN IF NOT EXISTS reward_mint   FixedString(44) COMMENT 'Reward token mint',
+    ADD COLUMN IF NOT EXISTS reward_index  UInt8 COMMENT 'Reward slot of the pool',
+    ADD COLUMN IF NOT EXISTS amount        UInt64 COMMENT 'Reward amount claimed';
+
+-- Meteora DAAM Position Locks --
+CREATE TABLE IF NOT EXISTS meteora_daam_position_locks AS base_events
+COMMENT 'Meteora DAAM LockPosition (vesting) & PermanentLockPosition';
+ALTER TABLE meteora_daam_position_locks
+    ADD COLUMN IF NOT EXISTS lock_type               Enum8('vesting' = 1, 'permanent' = 2),
+    ADD COLUMN IF NOT EXISTS pool                    FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS position                FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS owner                   FixedString(44) COMMENT 'Position owner',
+    ADD COLUMN IF NOT EXISTS vesting                 FixedString(44) COMMENT 'Vesting account, empty for permanent locks',
+    ADD COLUMN IF NOT EXISTS liquidity               UInt128 COMMENT 'Liquidity locked',
+
+    -- vesting --
+    ADD COLUMN IF NOT EXISTS cliff_point             UInt64 COMMENT 'Slot or timestamp of the cliff (0 for the current point)',
+    ADD COLUMN IF NOT EXISTS period_frequency        UInt64 COMMENT 'Slots or seconds between the releases',
+    ADD COLUMN IF NOT EXISTS cliff_unlock_liquidity  UInt128 COMMENT 'Liquidity released at the cliff',
+    ADD COLUMN IF NOT EXISTS liquidity_per_period    UInt128 COMMENT 'Liquidity released every period',
+    ADD COLUMN IF NOT EXISTS number_of_period        UInt16 COMMENT 'Number of releases after the cliff',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_lock_type       (lock_type)         TYPE set(2)                 GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_pool            (pool)              TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Meteora DAAM Split Position --
+CREATE TABLE IF NOT EXISTS meteora_daam_split_position AS base_events
+COMMENT 'Meteora DAAM SplitPosition';
+ALTER TABLE meteora_daam_split_position
+    ADD COLUMN IF NOT EXISTS pool                                   FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS first_position                         FixedString(44) COMMENT 'Position being split',
+    ADD COLUMN IF NOT EXISTS second_position                        FixedString(44) COMMENT 'Position receiving the split',
+    ADD COLUMN IF NOT EXISTS first_owner                            FixedString(44) COMMENT 'Owner of the first position',
+    ADD COLUMN IF NOT EXISTS second_owner                           FixedString(44) COMMENT 'Owner of the second position',
+    ADD COLUMN IF NOT EXISTS unlocked_liquidity_percentage          UInt8 COMMENT 'Share of the unlocked liquidity moved (%)',
+    ADD COLUMN IF NOT EXISTS permanent_locked_liquidity_percentage  UInt8 COMMENT 'Share of the permanently locked liquidity moved (%)',
+    ADD COLUMN IF NOT EXISTS fee_a_percentage                       UInt8 COMMENT 'Share of the token A fees moved (%)',
+    ADD COLUMN IF NOT EXISTS fee_b_percentage                       UInt8 COMMENT 'Share of the token B fees moved (%)',
+    ADD COLUMN IF NOT EXISTS reward_0_percentage                    UInt8 COMMENT 'Share of the reward 0 moved (%)',
+    ADD COLUMN IF NOT EXISTS reward_1_percentage                    UInt8 COMMENT 'Share of the reward 1 moved (%)';
+
+-- Meteora DAAM Update Pool Fees --
+CREATE TABLE IF NOT EXISTS meteora_daam_update_pool_fees AS base_events
+COMMENT 'Meteora DAAM UpdatePoolFees (base & dynamic fee config)';
+ALTER TABLE meteora_daam_update_pool_fees
+    ADD COLUMN IF NOT EXISTS pool                        FixedString(44) COMMENT 'Pool account',
+    ADD COLUMN IF NOT EXISTS operator                    FixedString(44) COMMENT 'Admin or operator updating the fees',
+    ADD COLUMN IF NOT EXISTS cliff_fee_numerator         UInt64 COMMENT 'New base fee numerator, 0 when unchanged',
+    ADD COLUMN IF NOT EXISTS dynamic_fee                 Bool COMMENT 'Dynamic fee enabled, the parameters are 0 otherwise',
+    ADD COLUMN IF NOT EXISTS bin_step                    UInt16,
+    ADD COLUMN IF NOT EXISTS filter_period               UInt16,
+    ADD COLUMN IF NOT EXISTS decay_period                UInt16,
+    ADD COLUMN IF NOT EXISTS reduction_factor            UInt16,
+    ADD COLUMN IF NOT EXISTS max_volatility_accumulator  UInt32,
+    ADD COLUMN IF NOT EXISTS variable_fee_control        UInt32;
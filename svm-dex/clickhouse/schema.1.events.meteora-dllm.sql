@@ -2,9 +2,98 @@
 CREATE TABLE IF NOT EXISTS meteora_dllm_swap AS base_events
 COMMENT 'Meteora DLLM Swap';
 ALTER TABLE meteora_dllm_swap
-    ADD COLUMN IF NOT EXISTS user        FixedString(44) COMMENT 'User account',
-    ADD COLUMN IF NOT EXISTS lb_pair     FixedString(44) COMMENT 'Liquidity pair',
-    ADD COLUMN IF NOT EXISTS input_mint  FixedString(44) COMMENT 'Input token mint',
-    ADD COLUMN IF NOT EXISTS output_mint FixedString(44) COMMENT 'Output token mint',
-    ADD COLUMN IF NOT EXISTS amount_in   UInt64 COMMENT 'Amount of tokens in',
-    ADD COLUMN IF NOT EXISTS amount_out  UInt64 COMMENT 'Amount of tokens out';
+    ADD COLUMN IF NOT EXISTS user          FixedString(44) COMMENT 'User account',
+    ADD COLUMN IF NOT EXISTS lb_pair       FixedString(44) COMMENT 'Liquidity pair',
+    ADD COLUMN IF NOT EXISTS input_mint    FixedString(44) COMMENT 'Input token mint',
+    ADD COLUMN IF NOT EXISTS output_mint   FixedString(44) COMMENT 'Output token mint',
+    ADD COLUMN IF NOT EXISTS amount_in     UInt64 COMMENT 'Amount of tokens in',
+    ADD COLUMN IF NOT EXISTS amount_out    UInt64 COMMENT 'Amount of tokens out',
+
+    -- bins --
+    ADD COLUMN IF NOT EXISTS start_bin_id  Int32 COMMENT 'Active bin before the swap',
+    ADD COLUMN IF NOT EXISTS end_bin_id    Int32 COMMENT 'Active bin after the swap',
+    ADD COLUMN IF NOT EXISTS bins_crossed  UInt32 COMMENT 'Number of bins crossed by the swap',
+    ADD COLUMN IF NOT EXISTS bin_step_raw  String COMMENT 'Bin step of the pair (empty when the pair was created before the start block)',
+    ADD COLUMN IF NOT EXISTS bin_step      Nullable(UInt16) MATERIALIZED if(empty(bin_step_raw), NULL, toUInt16(bin_step_raw)) COMMENT 'Bin step of the pair in basis points',
+    ADD COLUMN IF NOT EXISTS price_raw     String COMMENT 'Price after the swap (empty when the bin step is unknown)',
+    ADD COLUMN IF NOT EXISTS price         Nullable(Float64) MATERIALIZED if(empty(price_raw), NULL, toFloat64(price_raw)) COMMENT 'Price of token X in token Y after the swap (raw units)',
+
+    -- fees --
+    ADD COLUMN IF NOT EXISTS swap_fee      UInt64 COMMENT 'Total swap fee, in the input token',
+    ADD COLUMN IF NOT EXISTS protocol_fee  UInt64 COMMENT 'Protocol share of the fee',
+    ADD COLUMN IF NOT EXISTS host_fee      UInt64 COMMENT 'Host (referrer) share of the fee',
+    ADD COLUMN IF NOT EXISTS fee_bps       UInt128 COMMENT 'Fee rate of the swap';
+
+-- Meteora DLLM Initialize LB Pair --
+CREATE TABLE IF NOT EXISTS meteora_dllm_initialize_lb_pair AS base_events
+COMMENT 'Meteora DLLM InitializeLbPair (pool creation)';
+ALTER TABLE meteora_dllm_initialize_lb_pair
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS lb_pair           FixedString(44) COMMENT 'Liquidity pair',
+    ADD COLUMN IF NOT EXISTS token_mint_x      FixedString(44) COMMENT 'Token X mint',
+    ADD COLUMN IF NOT EXISTS token_mint_y      FixedString(44) COMMENT 'Token Y mint',
+    ADD COLUMN IF NOT EXISTS reserve_x         FixedString(44) COMMENT 'Token X reserve',
+    ADD COLUMN IF NOT EXISTS reserve_y         FixedString(44) COMMENT 'Token Y reserve',
+    ADD COLUMN IF NOT EXISTS preset_parameter  FixedString(44) COMMENT 'Preset parameter account',
+    ADD COLUMN IF NOT EXISTS funder            FixedString(44) COMMENT 'Pair creator',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS active_id         Int32 COMMENT 'Initial active bin',
+    ADD COLUMN IF NOT EXISTS bin_step          UInt16 COMMENT 'Bin step in basis points',
+    ADD COLUMN IF NOT EXISTS price             Float64 COMMENT 'Initial price of token X in token Y (raw units)';
+
+-- Meteora DLLM Initialize Position --
+CREATE TABLE IF NOT EXISTS meteora_dllm_initialize_position AS base_events
+COMMENT 'Meteora DLLM InitializePosition & InitializePositionPda';
+ALTER TABLE meteora_dllm_initialize_position
+    ADD COLUMN IF NOT EXISTS payer         FixedString(44) COMMENT 'Rent payer',
+    ADD COLUMN IF NOT EXISTS position      FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS lb_pair       FixedString(44) COMMENT 'Liquidity pair',
+    ADD COLUMN IF NOT EXISTS owner         FixedString(44) COMMENT 'Position owner',
+    ADD COLUMN IF NOT EXISTS lower_bin_id  Int32 COMMENT 'Lowest bin of the position',
+    ADD COLUMN IF NOT EXISTS upper_bin_id  Int32 COMMENT 'Highest bin of the position',
+    ADD COLUMN IF NOT EXISTS width         Int32 COMMENT 'Number of bins of the position';
+
+-- Meteora DLLM Add Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_dllm_add_liquidity AS base_events
+COMMENT 'Meteora DLLM AddLiquidity (two-sided, by weight, by strategy & one-sided, v1 & v2)';
+ALTER TABLE meteora_dllm_add_liquidity
+    ADD COLUMN IF NOT EXISTS sender         FixedString(44) COMMENT 'Liquidity provider',
+    ADD COLUMN IF NOT EXISTS position       FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS lb_pair        FixedString(44) COMMENT 'Liquidity pair',
+    ADD COLUMN IF NOT EXISTS token_x_mint   FixedString(44) COMMENT 'Token X mint, empty for one-sided Y deposits',
+    ADD COLUMN IF NOT EXISTS token_y_mint   FixedString(44) COMMENT 'Token Y mint, empty for one-sided X deposits',
+    ADD COLUMN IF NOT EXISTS amount_x       UInt64 COMMENT 'Token X amount',
+    ADD COLUMN IF NOT EXISTS amount_y       UInt64 COMMENT 'Token Y amount',
+    ADD COLUMN IF NOT EXISTS active_bin_id  Int32 COMMENT 'Active bin of the pair (0 when the event is not emitted)',
+    ADD COLUMN IF NOT EXISTS bin_step_raw   String COMMENT 'Bin step of the pair (empty when unknown)',
+    ADD COLUMN IF NOT EXISTS bin_step       Nullable(UInt16) MATERIALIZED if(empty(bin_step_raw), NULL, toUInt16(bin_step_raw)) COMMENT 'Bin step of the pair in basis points',
+    ADD COLUMN IF NOT EXISTS price_raw      String COMMENT 'Price at the active bin (empty when the bin step is unknown)',
+    ADD COLUMN IF NOT EXISTS price          Nullable(Float64) MATERIALIZED if(empty(price_raw), NULL, toFloat64(price_raw)) COMMENT 'Price of token X in token Y at the active bin (raw units)';
+
+-- Meteora DLLM Remove Liquidity --
+CREATE TABLE IF NOT EXISTS meteora_dllm_remove_liquidity AS meteora_dllm_add_liquidity
+COMMENT 'Meteora DLLM RemoveLiquidity & RemoveLiquidityByRange';
+
+-- Meteora DLLM Claim Fee --
+CREATE TABLE IF NOT EXISTS meteora_dllm_claim_fee AS base_events
+COMMENT 'Meteora DLLM ClaimFee';
+ALTER TABLE meteora_dllm_claim_fee
+    ADD COLUMN IF NOT EXISTS sender        FixedString(44) COMMENT 'Position owner (signer)',
+    ADD COLUMN IF NOT EXISTS position      FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS lb_pair       FixedString(44) COMMENT 'Liquidity pair',
+    ADD COLUMN IF NOT EXISTS token_x_mint  FixedString(44) COMMENT 'Token X mint',
+    ADD COLUMN IF NOT EXISTS token_y_mint  FixedString(44) COMMENT 'Token Y mint',
+    ADD COLUMN IF NOT EXISTS fee_x         UInt64 COMMENT 'Token X fees claimed',
+    ADD COLUMN IF NOT EXISTS fee_y         UInt64 COMMENT 'Token Y fees claimed';
+
+-- Meteora DLLM Claim Reward --
+CREATE TABLE IF NOT EXISTS meteora_dllm_claim_reward AS base_events
+COMMENT 'Meteora DLLM ClaimReward';
+ALTER TABLE meteora_dllm_claim_reward
+    ADD COLUMN IF NOT EXISTS sender        FixedString(44) COMMENT 'Position owner (signer)',
+    ADD COLUMN IF NOT EXISTS position      FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS lb_pair       FixedString(44) COMMENT 'Liquidity pair',
+    ADD COLUMN IF NOT EXISTS reward_mint   FixedString(44) COMMENT 'Reward token mint',
+    ADD COLUMN IF NOT EXISTS reward_index  UInt8 COMMENT 'Reward slot of the pair',
+    ADD COLUMN IF NOT EXISTS amount        UInt64 COMMENT 'Reward amount claimed';
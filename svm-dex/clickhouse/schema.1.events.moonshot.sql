@@ -9,7 +9,10 @@ ALTER TABLE moonshot_buy
     ADD COLUMN IF NOT EXISTS sender             FixedString(44) COMMENT 'Sender account',
     ADD COLUMN IF NOT EXISTS trade_type         UInt32 COMMENT 'Trade type',
     ADD COLUMN IF NOT EXISTS cost_token         FixedString(44) COMMENT 'Cost token mint',
-    ADD COLUMN IF NOT EXISTS curve              FixedString(44) COMMENT 'Curve account';
+    ADD COLUMN IF NOT EXISTS curve              FixedString(44) COMMENT 'Curve account',
+    ADD COLUMN IF NOT EXISTS curve_progress     Float64 COMMENT 'Percent of the curve supply sold after the transaction',
+    ADD COLUMN IF NOT EXISTS price              Float64 COMMENT 'Trade price in SOL per token',
+    ADD COLUMN IF NOT EXISTS market_cap         Float64 COMMENT 'Fully-diluted market cap in SOL at the trade price';
 
 -- Moonshot Sell --
 CREATE TABLE IF NOT EXISTS moonshot_sell AS base_events
@@ -22,4 +25,7 @@ ALTER TABLE moonshot_sell
     ADD COLUMN IF NOT EXISTS sender             FixedString(44) COMMENT 'Sender account',
     ADD COLUMN IF NOT EXISTS trade_type         UInt32 COMMENT 'Trade type',
     ADD COLUMN IF NOT EXISTS cost_token         FixedString(44) COMMENT 'Cost token mint',
-    ADD COLUMN IF NOT EXISTS curve              FixedString(44) COMMENT 'Curve account';
+    ADD COLUMN IF NOT EXISTS curve              FixedString(44) COMMENT 'Curve account',
+    ADD COLUMN IF NOT EXISTS curve_progress     Float64 COMMENT 'Percent of the curve supply sold after the transaction',
+    ADD COLUMN IF NOT EXISTS price              Float64 COMMENT 'Trade price in SOL per token',
+    ADD COLUMN IF NOT EXISTS market_cap         Float64 COMMENT 'Fully-diluted market cap in SOL at the trade price';
@@ -7,4 +7,28 @@ ALTER TABLE orca_swap
     ADD COLUMN IF NOT EXISTS input_mint   FixedString(44) COMMENT 'Input token mint',
     ADD COLUMN IF NOT EXISTS output_mint  FixedString(44) COMMENT 'Output token mint',
     ADD COLUMN IF NOT EXISTS amount_in    UInt64 COMMENT 'Amount of tokens in',
-    ADD COLUMN IF NOT EXISTS amount_out   UInt64 COMMENT 'Amount of tokens out';
+    ADD COLUMN IF NOT EXISTS amount_out   UInt64 COMMENT 'Amount of tokens out',
+    ADD COLUMN IF NOT EXISTS pre_sqrt_price  UInt128 COMMENT 'Whirlpool sqrt price before the swap (Q64.64)',
+    ADD COLUMN IF NOT EXISTS post_sqrt_price UInt128 COMMENT 'Whirlpool sqrt price after the swap (Q64.64)',
+    ADD COLUMN IF NOT EXISTS price        Float64 COMMENT 'Price of token A in token B after the swap (raw units)';
+
+-- Orca Liquidity Increased --
+CREATE TABLE IF NOT EXISTS orca_liquidity_increased AS base_events
+COMMENT 'Orca Whirlpool Liquidity Increased';
+ALTER TABLE orca_liquidity_increased
+    ADD COLUMN IF NOT EXISTS whirlpool             FixedString(44) COMMENT 'Whirlpool account',
+    ADD COLUMN IF NOT EXISTS position              FixedString(44) COMMENT 'Position account',
+    ADD COLUMN IF NOT EXISTS position_authority    FixedString(44) COMMENT 'Position authority, from the liquidity instruction',
+    ADD COLUMN IF NOT EXISTS token_mint_a          FixedString(44) COMMENT 'Token A mint, from the liquidity instruction',
+    ADD COLUMN IF NOT EXISTS token_mint_b          FixedString(44) COMMENT 'Token B mint, from the liquidity instruction',
+    ADD COLUMN IF NOT EXISTS tick_lower_index      Int32 COMMENT 'Lower tick of the position',
+    ADD COLUMN IF NOT EXISTS tick_upper_index      Int32 COMMENT 'Upper tick of the position',
+    ADD COLUMN IF NOT EXISTS liquidity             UInt128 COMMENT 'Liquidity delta',
+    ADD COLUMN IF NOT EXISTS token_a_amount        UInt64 COMMENT 'Amount of token A',
+    ADD COLUMN IF NOT EXISTS token_b_amount        UInt64 COMMENT 'Amount of token B',
+    ADD COLUMN IF NOT EXISTS token_a_transfer_fee  UInt64 COMMENT 'Token-2022 transfer fee of token A',
+    ADD COLUMN IF NOT EXISTS token_b_transfer_fee  UInt64 COMMENT 'Token-2022 transfer fee of token B';
+
+-- Orca Liquidity Decreased --
+CREATE TABLE IF NOT EXISTS orca_liquidity_decreased AS orca_liquidity_increased
+COMMENT 'Orca Whirlpool Liquidity Decreased';
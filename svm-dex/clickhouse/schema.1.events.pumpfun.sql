@@ -29,7 +29,12 @@ ALTER TABLE pumpfun_buy
     ADD COLUMN IF NOT EXISTS protocol_fee UInt64 DEFAULT 0 COMMENT 'Protocol fee in lamports',
     ADD COLUMN IF NOT EXISTS creator FixedString(44) DEFAULT '' COMMENT 'Creator account',
     ADD COLUMN IF NOT EXISTS creator_fee_basis_points UInt64 DEFAULT 0 COMMENT 'Creator fee basis points (1 bp = 0.01 %)',
-    ADD COLUMN IF NOT EXISTS creator_fee UInt64 DEFAULT 0 COMMENT 'Creator fee in lamports';
+    ADD COLUMN IF NOT EXISTS creator_fee UInt64 DEFAULT 0 COMMENT 'Creator fee in lamports',
+
+    -- curve --
+    ADD COLUMN IF NOT EXISTS curve_progress Float64 COMMENT 'Percent of the curve supply sold after the trade',
+    ADD COLUMN IF NOT EXISTS price Float64 COMMENT 'Token price in SOL after the trade (virtual reserves)',
+    ADD COLUMN IF NOT EXISTS market_cap Float64 COMMENT 'Fully-diluted market cap in SOL after the trade';
 
 -- Pump.fun Bonding Curve Sell --
 CREATE TABLE IF NOT EXISTS pumpfun_sell AS pumpfun_buy;
@@ -37,3 +37,128 @@ ALTER TABLE raydium_amm_v4_swap_base_in
 CREATE TABLE IF NOT EXISTS raydium_amm_v4_swap_base_out AS raydium_amm_v4_swap_base_in;
 ALTER TABLE raydium_amm_v4_swap_base_out
     RENAME COLUMN IF EXISTS minimum_amount_out TO max_amount_in;
+
+-- Raydium AMM V4 Deposit --
+CREATE TABLE IF NOT EXISTS raydium_amm_v4_deposit AS base_events
+COMMENT 'Raydium AMM V4 Deposit (add liquidity)';
+ALTER TABLE raydium_amm_v4_deposit
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS amm                         FixedString(44) COMMENT 'AMM pool account (Raydium V4 liquidity-state)',
+    ADD COLUMN IF NOT EXISTS lp_mint                     FixedString(44) COMMENT 'AMM LP mint',
+    ADD COLUMN IF NOT EXISTS amm_coin_vault              FixedString(44) COMMENT 'AMM coin vault (base-token vault)',
+    ADD COLUMN IF NOT EXISTS amm_pc_vault                FixedString(44) COMMENT 'AMM pc vault (quote-token vault)',
+    ADD COLUMN IF NOT EXISTS coin_mint                   FixedString(44) COMMENT 'Coin mint (base token)',
+    ADD COLUMN IF NOT EXISTS pc_mint                     FixedString(44) COMMENT 'PC mint (quote token)',
+    ADD COLUMN IF NOT EXISTS user_coin_token             FixedString(44) COMMENT 'User coin token account',
+    ADD COLUMN IF NOT EXISTS user_pc_token               FixedString(44) COMMENT 'User pc token account',
+    ADD COLUMN IF NOT EXISTS user_lp_token               FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS user_owner                  FixedString(44) COMMENT 'User wallet',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS max_coin_amount             UInt64,
+    ADD COLUMN IF NOT EXISTS max_pc_amount               UInt64,
+    ADD COLUMN IF NOT EXISTS base_side                   UInt64 COMMENT '0 = coin, 1 = pc',
+
+    -- log --
+    ADD COLUMN IF NOT EXISTS coin_amount                 UInt64 COMMENT 'Coin tokens deposited',
+    ADD COLUMN IF NOT EXISTS pc_amount                   UInt64 COMMENT 'PC tokens deposited',
+    ADD COLUMN IF NOT EXISTS lp_amount                   UInt64 COMMENT 'LP tokens minted',
+    ADD COLUMN IF NOT EXISTS pool_coin                   UInt64,
+    ADD COLUMN IF NOT EXISTS pool_pc                     UInt64,
+    ADD COLUMN IF NOT EXISTS pool_lp                     UInt64,
+    ADD COLUMN IF NOT EXISTS calc_pnl_x                  UInt128 COMMENT 'Accumulated coin PnL checkpoint of the pool',
+    ADD COLUMN IF NOT EXISTS calc_pnl_y                  UInt128 COMMENT 'Accumulated pc PnL checkpoint of the pool';
+
+-- Raydium AMM V4 Withdraw --
+CREATE TABLE IF NOT EXISTS raydium_amm_v4_withdraw AS raydium_amm_v4_deposit
+COMMENT 'Raydium AMM V4 Withdraw (remove liquidity)';
+ALTER TABLE raydium_amm_v4_withdraw
+    DROP COLUMN IF EXISTS max_coin_amount,
+    DROP COLUMN IF EXISTS max_pc_amount,
+    DROP COLUMN IF EXISTS base_side,
+    ADD COLUMN IF NOT EXISTS amount                      UInt64 COMMENT 'LP tokens to burn',
+    COMMENT COLUMN coin_amount 'Coin tokens withdrawn',
+    COMMENT COLUMN pc_amount 'PC tokens withdrawn',
+    COMMENT COLUMN lp_amount 'LP tokens burned';
+
+-- Raydium AMM V4 Initialize --
+CREATE TABLE IF NOT EXISTS raydium_amm_v4_initialize AS base_events
+COMMENT 'Raydium AMM V4 Initialize2 (pool creation)';
+ALTER TABLE raydium_amm_v4_initialize
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS amm                         FixedString(44) COMMENT 'AMM pool account (Raydium V4 liquidity-state)',
+    ADD COLUMN IF NOT EXISTS amm_authority               FixedString(44) COMMENT 'AMM authority PDA',
+    ADD COLUMN IF NOT EXISTS amm_open_orders             FixedString(44) COMMENT 'AMM open-orders',
+    ADD COLUMN IF NOT EXISTS lp_mint                     FixedString(44) COMMENT 'AMM LP mint',
+    ADD COLUMN IF NOT EXISTS coin_mint                   FixedString(44) COMMENT 'Coin mint (base token)',
+    ADD COLUMN IF NOT EXISTS pc_mint                     FixedString(44) COMMENT 'PC mint (quote token)',
+    ADD COLUMN IF NOT EXISTS amm_coin_vault              FixedString(44) COMMENT 'AMM coin vault (base-token vault)',
+    ADD COLUMN IF NOT EXISTS amm_pc_vault                FixedString(44) COMMENT 'AMM pc vault (quote-token vault)',
+    ADD COLUMN IF NOT EXISTS amm_target_orders           FixedString(44) COMMENT 'AMM target-orders',
+    ADD COLUMN IF NOT EXISTS market_program              FixedString(44) COMMENT 'OpenBook (or Serum) DEX program',
+    ADD COLUMN IF NOT EXISTS market                      FixedString(44) COMMENT 'OpenBook (or Serum) DEX market account',
+    ADD COLUMN IF NOT EXISTS user_wallet                 FixedString(44) COMMENT 'Pool creator wallet',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS nonce                       UInt8,
+    ADD COLUMN IF NOT EXISTS open_time                   UInt64 COMMENT 'Unix timestamp from which swaps are allowed',
+    ADD COLUMN IF NOT EXISTS init_coin_amount            UInt64 COMMENT 'Initial coin tokens',
+    ADD COLUMN IF NOT EXISTS init_pc_amount              UInt64 COMMENT 'Initial pc tokens',
+
+    -- log --
+    ADD COLUMN IF NOT EXISTS coin_decimals               UInt8,
+    ADD COLUMN IF NOT EXISTS pc_decimals                 UInt8,
+    ADD COLUMN IF NOT EXISTS coin_lot_size               UInt64 COMMENT 'Market coin lot size',
+    ADD COLUMN IF NOT EXISTS pc_lot_size                 UInt64 COMMENT 'Market pc lot size',
+    ADD COLUMN IF NOT EXISTS coin_amount                 UInt64 COMMENT 'Coin tokens deposited in the pool',
+    ADD COLUMN IF NOT EXISTS pc_amount                   UInt64 COMMENT 'PC tokens deposited in the pool',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_amm             (amm)               TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_coin_mint       (coin_mint)         TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_pc_mint         (pc_mint)           TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Raydium AMM V4 Withdraw PnL --
+CREATE TABLE IF NOT EXISTS raydium_amm_v4_withdraw_pnl AS base_events
+COMMENT 'Raydium AMM V4 WithdrawPnl (protocol PnL withdrawn from the pool vaults)';
+ALTER TABLE raydium_amm_v4_withdraw_pnl
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS amm                         FixedString(44) COMMENT 'AMM pool account (Raydium V4 liquidity-state)',
+    ADD COLUMN IF NOT EXISTS amm_config                  FixedString(44) COMMENT 'AMM config account',
+    ADD COLUMN IF NOT EXISTS amm_coin_vault              FixedString(44) COMMENT 'AMM coin vault (base-token vault)',
+    ADD COLUMN IF NOT EXISTS amm_pc_vault                FixedString(44) COMMENT 'AMM pc vault (quote-token vault)',
+    ADD COLUMN IF NOT EXISTS coin_pnl_token_account      FixedString(44) COMMENT 'PnL owner coin token account',
+    ADD COLUMN IF NOT EXISTS pc_pnl_token_account        FixedString(44) COMMENT 'PnL owner pc token account',
+    ADD COLUMN IF NOT EXISTS pnl_owner                   FixedString(44) COMMENT 'PnL owner',
+
+    -- inner transfers --
+    ADD COLUMN IF NOT EXISTS coin_mint                   FixedString(44) COMMENT 'Coin mint (base token)',
+    ADD COLUMN IF NOT EXISTS coin_amount                 UInt64 COMMENT 'Coin tokens withdrawn',
+    ADD COLUMN IF NOT EXISTS pc_mint                     FixedString(44) COMMENT 'PC mint (quote token)',
+    ADD COLUMN IF NOT EXISTS pc_amount                   UInt64 COMMENT 'PC tokens withdrawn',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_amm             (amm)               TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Raydium AMM V4 Set Params --
+CREATE TABLE IF NOT EXISTS raydium_amm_v4_set_params AS base_events
+COMMENT 'Raydium AMM V4 SetParams (admin pool parameters)';
+ALTER TABLE raydium_amm_v4_set_params
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS amm                         FixedString(44) COMMENT 'AMM pool account (Raydium V4 liquidity-state)',
+    ADD COLUMN IF NOT EXISTS amm_admin                   FixedString(44) COMMENT 'AMM admin',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS param                       LowCardinality(String) COMMENT 'AmmParams (Status, State, Fees, AmmOwner, SetOpenTime...)',
+    ADD COLUMN IF NOT EXISTS value                       UInt64 COMMENT 'New value of the numeric params, 0 otherwise',
+    ADD COLUMN IF NOT EXISTS new_pubkey                  FixedString(44) COMMENT 'New AMM owner (AmmOwner)',
+    ADD COLUMN IF NOT EXISTS trade_fee_numerator         UInt64 COMMENT 'New trade fee numerator (Fees)',
+    ADD COLUMN IF NOT EXISTS trade_fee_denominator       UInt64 COMMENT 'New trade fee denominator (Fees)',
+    ADD COLUMN IF NOT EXISTS pnl_numerator               UInt64 COMMENT 'New PnL numerator (Fees)',
+    ADD COLUMN IF NOT EXISTS pnl_denominator             UInt64 COMMENT 'New PnL denominator (Fees)',
+    ADD COLUMN IF NOT EXISTS swap_fee_numerator          UInt64 COMMENT 'New swap fee numerator (Fees)',
+    ADD COLUMN IF NOT EXISTS swap_fee_denominator        UInt64 COMMENT 'New swap fee denominator (Fees)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_amm             (amm)               TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_param           (param)             TYPE set(32)                GRANULARITY 1;
@@ -7,4 +7,8 @@ ALTER TABLE raydium_clmm_swap
     ADD COLUMN IF NOT EXISTS input_mint   FixedString(44) COMMENT 'Input token mint or vault',
     ADD COLUMN IF NOT EXISTS output_mint  FixedString(44) COMMENT 'Output token mint or vault',
     ADD COLUMN IF NOT EXISTS amount_in    UInt64 COMMENT 'Amount of tokens in',
-    ADD COLUMN IF NOT EXISTS amount_out   UInt64 COMMENT 'Amount of tokens out';
+    ADD COLUMN IF NOT EXISTS amount_out   UInt64 COMMENT 'Amount of tokens out',
+    ADD COLUMN IF NOT EXISTS sqrt_price_x64 UInt128 COMMENT 'Pool sqrt price after the swap (Q64.64)',
+    ADD COLUMN IF NOT EXISTS price        Float64 COMMENT 'Price of token 0 in token 1 after the swap (raw units)',
+    ADD COLUMN IF NOT EXISTS liquidity    UInt128 COMMENT 'Pool active liquidity after the swap',
+    ADD COLUMN IF NOT EXISTS tick         Int32 COMMENT 'Pool current tick after the swap';
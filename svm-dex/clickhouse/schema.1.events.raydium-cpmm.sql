@@ -7,6 +7,116 @@ ALTER TABLE raydium_cpmm_swap_base_in
     ADD COLUMN IF NOT EXISTS input_token_mint    FixedString(44) COMMENT 'Input token mint',
     ADD COLUMN IF NOT EXISTS output_token_mint   FixedString(44) COMMENT 'Output token mint',
     ADD COLUMN IF NOT EXISTS amount_in           UInt64 COMMENT 'Amount of tokens in',
-    ADD COLUMN IF NOT EXISTS amount_out          UInt64 COMMENT 'Amount of tokens out';
+    ADD COLUMN IF NOT EXISTS amount_out          UInt64 COMMENT 'Amount of tokens out',
+    ADD COLUMN IF NOT EXISTS input_vault_before  UInt64 COMMENT 'Input vault balance before the swap',
+    ADD COLUMN IF NOT EXISTS output_vault_before UInt64 COMMENT 'Output vault balance before the swap',
+    ADD COLUMN IF NOT EXISTS input_transfer_fee  UInt64 COMMENT 'Token-2022 transfer fee on the input token',
+    ADD COLUMN IF NOT EXISTS output_transfer_fee UInt64 COMMENT 'Token-2022 transfer fee on the output token',
+    ADD COLUMN IF NOT EXISTS trade_fee           UInt64 COMMENT 'Trade fee (0 for older program versions)',
+    ADD COLUMN IF NOT EXISTS creator_fee         UInt64 COMMENT 'Pool creator fee (0 for older program versions)';
 
 CREATE TABLE IF NOT EXISTS raydium_cpmm_swap_base_out AS raydium_cpmm_swap_base_in;
+
+-- Raydium CPMM Initialize --
+CREATE TABLE IF NOT EXISTS raydium_cpmm_initialize AS base_events
+COMMENT 'Raydium CPMM Initialize (pool creation)';
+ALTER TABLE raydium_cpmm_initialize
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS creator             FixedString(44) COMMENT 'Pool creator',
+    ADD COLUMN IF NOT EXISTS amm_config          FixedString(44) COMMENT 'AMM config account',
+    ADD COLUMN IF NOT EXISTS pool_state          FixedString(44) COMMENT 'Pool state account',
+    ADD COLUMN IF NOT EXISTS token_0_mint        FixedString(44) COMMENT 'Token 0 mint',
+    ADD COLUMN IF NOT EXISTS token_1_mint        FixedString(44) COMMENT 'Token 1 mint',
+    ADD COLUMN IF NOT EXISTS lp_mint             FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS token_0_vault       FixedString(44) COMMENT 'Pool token 0 vault',
+    ADD COLUMN IF NOT EXISTS token_1_vault       FixedString(44) COMMENT 'Pool token 1 vault',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS init_amount_0       UInt64 COMMENT 'Initial token 0 amount',
+    ADD COLUMN IF NOT EXISTS init_amount_1       UInt64 COMMENT 'Initial token 1 amount',
+    ADD COLUMN IF NOT EXISTS open_time           UInt64 COMMENT 'Unix timestamp from which swaps are allowed';
+
+-- Raydium CPMM Deposit --
+CREATE TABLE IF NOT EXISTS raydium_cpmm_deposit AS base_events
+COMMENT 'Raydium CPMM Deposit';
+ALTER TABLE raydium_cpmm_deposit
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS owner                   FixedString(44) COMMENT 'Liquidity provider',
+    ADD COLUMN IF NOT EXISTS pool_state              FixedString(44) COMMENT 'Pool state account',
+    ADD COLUMN IF NOT EXISTS owner_lp_token          FixedString(44) COMMENT 'Owner LP token account',
+    ADD COLUMN IF NOT EXISTS token_0_account         FixedString(44) COMMENT 'Owner token 0 account',
+    ADD COLUMN IF NOT EXISTS token_1_account         FixedString(44) COMMENT 'Owner token 1 account',
+    ADD COLUMN IF NOT EXISTS token_0_vault           FixedString(44) COMMENT 'Pool token 0 vault',
+    ADD COLUMN IF NOT EXISTS token_1_vault           FixedString(44) COMMENT 'Pool token 1 vault',
+    ADD COLUMN IF NOT EXISTS token_0_mint            FixedString(44) COMMENT 'Token 0 mint',
+    ADD COLUMN IF NOT EXISTS token_1_mint            FixedString(44) COMMENT 'Token 1 mint',
+    ADD COLUMN IF NOT EXISTS lp_mint                 FixedString(44) COMMENT 'Pool LP mint',
+
+    -- log --
+    ADD COLUMN IF NOT EXISTS lp_amount_before        UInt64 COMMENT 'LP supply before the change',
+    ADD COLUMN IF NOT EXISTS token_0_vault_before    UInt64 COMMENT 'Token 0 vault balance before the change',
+    ADD COLUMN IF NOT EXISTS token_1_vault_before    UInt64 COMMENT 'Token 1 vault balance before the change',
+    ADD COLUMN IF NOT EXISTS token_0_amount          UInt64 COMMENT 'Token 0 amount',
+    ADD COLUMN IF NOT EXISTS token_1_amount          UInt64 COMMENT 'Token 1 amount',
+    ADD COLUMN IF NOT EXISTS token_0_transfer_fee    UInt64 COMMENT 'Token-2022 transfer fee on token 0',
+    ADD COLUMN IF NOT EXISTS token_1_transfer_fee    UInt64 COMMENT 'Token-2022 transfer fee on token 1',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS lp_token_amount         UInt64 COMMENT 'LP tokens minted',
+    ADD COLUMN IF NOT EXISTS maximum_token_0_amount  UInt64 COMMENT 'Maximum token 0 amount (slippage limit)',
+    ADD COLUMN IF NOT EXISTS maximum_token_1_amount  UInt64 COMMENT 'Maximum token 1 amount (slippage limit)';
+
+-- Raydium CPMM Withdraw --
+CREATE TABLE IF NOT EXISTS raydium_cpmm_withdraw AS base_events
+COMMENT 'Raydium CPMM Withdraw';
+ALTER TABLE raydium_cpmm_withdraw
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS owner                   FixedString(44) COMMENT 'Liquidity provider',
+    ADD COLUMN IF NOT EXISTS pool_state              FixedString(44) COMMENT 'Pool state account',
+    ADD COLUMN IF NOT EXISTS owner_lp_token          FixedString(44) COMMENT 'Owner LP token account',
+    ADD COLUMN IF NOT EXISTS token_0_account         FixedString(44) COMMENT 'Owner token 0 account',
+    ADD COLUMN IF NOT EXISTS token_1_account         FixedString(44) COMMENT 'Owner token 1 account',
+    ADD COLUMN IF NOT EXISTS token_0_vault           FixedString(44) COMMENT 'Pool token 0 vault',
+    ADD COLUMN IF NOT EXISTS token_1_vault           FixedString(44) COMMENT 'Pool token 1 vault',
+    ADD COLUMN IF NOT EXISTS token_0_mint            FixedString(44) COMMENT 'Token 0 mint',
+    ADD COLUMN IF NOT EXISTS token_1_mint            FixedString(44) COMMENT 'Token 1 mint',
+    ADD COLUMN IF NOT EXISTS lp_mint                 FixedString(44) COMMENT 'Pool LP mint',
+
+    -- log --
+    ADD COLUMN IF NOT EXISTS lp_amount_before        UInt64 COMMENT 'LP supply before the change',
+    ADD COLUMN IF NOT EXISTS token_0_vault_before    UInt64 COMMENT 'Token 0 vault balance before the change',
+    ADD COLUMN IF NOT EXISTS token_1_vault_before    UInt64 COMMENT 'Token 1 vault balance before the change',
+    ADD COLUMN IF NOT EXISTS token_0_amount          UInt64 COMMENT 'Token 0 amount',
+    ADD COLUMN IF NOT EXISTS token_1_amount          UInt64 COMMENT 'Token 1 amount',
+    ADD COLUMN IF NOT EXISTS token_0_transfer_fee    UInt64 COMMENT 'Token-2022 transfer fee on token 0',
+    ADD COLUMN IF NOT EXISTS token_1_transfer_fee    UInt64 COMMENT 'Token-2022 transfer fee on token 1',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS lp_token_amount         UInt64 COMMENT 'LP tokens burned',
+    ADD COLUMN IF NOT EXISTS minimum_token_0_amount  UInt64 COMMENT 'Minimum token 0 amount (slippage limit)',
+    ADD COLUMN IF NOT EXISTS minimum_token_1_amount  UInt64 COMMENT 'Minimum token 1 amount (slippage limit)';
+
+-- Raydium CPMM Collect Protocol Fee --
+CREATE TABLE IF NOT EXISTS raydium_cpmm_collect_protocol_fee AS base_events
+COMMENT 'Raydium CPMM CollectProtocolFee';
+ALTER TABLE raydium_cpmm_collect_protocol_fee
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS owner                       FixedString(44) COMMENT 'Fee owner (signer)',
+    ADD COLUMN IF NOT EXISTS pool_state                  FixedString(44) COMMENT 'Pool state account',
+    ADD COLUMN IF NOT EXISTS amm_config                  FixedString(44) COMMENT 'AMM config account',
+    ADD COLUMN IF NOT EXISTS token_0_vault               FixedString(44) COMMENT 'Pool token 0 vault',
+    ADD COLUMN IF NOT EXISTS token_1_vault               FixedString(44) COMMENT 'Pool token 1 vault',
+    ADD COLUMN IF NOT EXISTS token_0_mint                FixedString(44) COMMENT 'Token 0 mint',
+    ADD COLUMN IF NOT EXISTS token_1_mint                FixedString(44) COMMENT 'Token 1 mint',
+    ADD COLUMN IF NOT EXISTS recipient_token_0_account   FixedString(44) COMMENT 'Recipient token 0 account',
+    ADD COLUMN IF NOT EXISTS recipient_token_1_account   FixedString(44) COMMENT 'Recipient token 1 account',
+
+    -- data --
+    ADD COLUMN IF NOT EXISTS amount_0_requested          UInt64 COMMENT 'Requested token 0 amount (capped by the accrued fees)',
+    ADD COLUMN IF NOT EXISTS amount_1_requested          UInt64 COMMENT 'Requested token 1 amount (capped by the accrued fees)',
+    ADD COLUMN IF NOT EXISTS amount_0                    UInt64 COMMENT 'Token 0 amount collected',
+    ADD COLUMN IF NOT EXISTS amount_1                    UInt64 COMMENT 'Token 1 amount collected';
+
+-- Raydium CPMM Collect Fund Fee --
+CREATE TABLE IF NOT EXISTS raydium_cpmm_collect_fund_fee AS raydium_cpmm_collect_protocol_fee
+COMMENT 'Raydium CPMM CollectFundFee';
@@ -8,6 +8,9 @@ ALTER TABLE raydium_launchpad_buy
     ADD COLUMN IF NOT EXISTS quote_token_mint  FixedString(44) COMMENT 'Quote token mint',
     ADD COLUMN IF NOT EXISTS amount_in         UInt64 COMMENT 'Amount of tokens in',
     ADD COLUMN IF NOT EXISTS amount_out        UInt64 COMMENT 'Amount of tokens out',
-    ADD COLUMN IF NOT EXISTS exact_in          Bool COMMENT 'Whether trade is exact in';
+    ADD COLUMN IF NOT EXISTS exact_in          Bool COMMENT 'Whether trade is exact in',
+    ADD COLUMN IF NOT EXISTS curve_progress    Float64 COMMENT 'Percent of the base tokens sold on the curve after the trade',
+    ADD COLUMN IF NOT EXISTS price             Float64 COMMENT 'Token price in SOL after the trade, 0 when the quote token is not SOL',
+    ADD COLUMN IF NOT EXISTS market_cap        Float64 COMMENT 'Fully-diluted market cap in SOL after the trade, 0 when the quote token is not SOL';
 
 CREATE TABLE IF NOT EXISTS raydium_launchpad_sell AS raydium_launchpad_buy;
@@ -3,4 +3,27 @@ CREATE TABLE IF NOT EXISTS saros_swap AS base_events
 COMMENT 'Saros Swap';
 ALTER TABLE saros_swap
     ADD COLUMN IF NOT EXISTS amount_in          UInt64 COMMENT 'Amount in',
-    ADD COLUMN IF NOT EXISTS minimum_amount_out UInt64 COMMENT 'Minimum amount out';
+    ADD COLUMN IF NOT EXISTS minimum_amount_out UInt64 COMMENT 'Minimum amount out',
+    ADD COLUMN IF NOT EXISTS user               FixedString(44) COMMENT 'User wallet address (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_mint         FixedString(44) COMMENT 'Input token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_amount       UInt64 COMMENT 'Actual input amount (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_mint        FixedString(44) COMMENT 'Output token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_amount      UInt64 COMMENT 'Actual output amount (inferred from inner token transfers)',
+
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS swap                     FixedString(44) COMMENT 'Pool (token-swap state) account',
+    ADD COLUMN IF NOT EXISTS swap_authority           FixedString(44) COMMENT 'Pool authority PDA',
+    ADD COLUMN IF NOT EXISTS user_transfer_authority  FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS user_source              FixedString(44) COMMENT 'User source token account',
+    ADD COLUMN IF NOT EXISTS pool_source              FixedString(44) COMMENT 'Pool vault receiving the input token',
+    ADD COLUMN IF NOT EXISTS pool_destination         FixedString(44) COMMENT 'Pool vault sending the output token',
+    ADD COLUMN IF NOT EXISTS user_destination         FixedString(44) COMMENT 'User destination token account',
+    ADD COLUMN IF NOT EXISTS pool_mint                FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS pool_fee_account         FixedString(44) COMMENT 'Pool LP fee account',
+    ADD COLUMN IF NOT EXISTS token_program            FixedString(44) COMMENT 'Token program',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint      (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint     (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_swap            (swap)              TYPE bloom_filter(0.005)    GRANULARITY 1;
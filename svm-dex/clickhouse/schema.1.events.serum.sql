@@ -0,0 +1,30 @@
+-- Serum Swap --
+CREATE TABLE IF NOT EXISTS serum_swap AS base_events
+COMMENT 'Serum Swap';
+ALTER TABLE serum_swap
+    ADD COLUMN IF NOT EXISTS amount_in          UInt64 COMMENT 'Amount in',
+    ADD COLUMN IF NOT EXISTS minimum_amount_out UInt64 COMMENT 'Minimum amount out',
+    ADD COLUMN IF NOT EXISTS user               FixedString(44) COMMENT 'User wallet address (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_mint         FixedString(44) COMMENT 'Input token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_amount       UInt64 COMMENT 'Actual input amount (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_mint        FixedString(44) COMMENT 'Output token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_amount      UInt64 COMMENT 'Actual output amount (inferred from inner token transfers)',
+
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS market                   FixedString(44) COMMENT 'Market account',
+    ADD COLUMN IF NOT EXISTS open_orders              FixedString(44) COMMENT 'Open orders account (NewOrder*)',
+    ADD COLUMN IF NOT EXISTS bids                     FixedString(44) COMMENT 'Bids slab',
+    ADD COLUMN IF NOT EXISTS asks                     FixedString(44) COMMENT 'Asks slab',
+    ADD COLUMN IF NOT EXISTS owner                    FixedString(44) COMMENT 'Open orders owner / taker',
+    ADD COLUMN IF NOT EXISTS order_payer              FixedString(44) COMMENT 'User token account paying for the order (NewOrder*)',
+    ADD COLUMN IF NOT EXISTS coin_wallet              FixedString(44) COMMENT 'User base token account (SendTake)',
+    ADD COLUMN IF NOT EXISTS pc_wallet                FixedString(44) COMMENT 'User quote token account (SendTake)',
+    ADD COLUMN IF NOT EXISTS coin_vault               FixedString(44) COMMENT 'Market base token vault',
+    ADD COLUMN IF NOT EXISTS pc_vault                 FixedString(44) COMMENT 'Market quote token vault',
+    ADD COLUMN IF NOT EXISTS token_program            FixedString(44) COMMENT 'Token program',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint      (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint     (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_market          (market)            TYPE bloom_filter(0.005)    GRANULARITY 1;
@@ -0,0 +1,122 @@
+-- SPL Token Swap Swap --
+CREATE TABLE IF NOT EXISTS token_swap_swap AS base_events
+COMMENT 'SPL Token Swap Swap';
+ALTER TABLE token_swap_swap
+    ADD COLUMN IF NOT EXISTS amount_in                  UInt64 COMMENT 'Amount in',
+    ADD COLUMN IF NOT EXISTS minimum_amount_out         UInt64 COMMENT 'Minimum amount out',
+    ADD COLUMN IF NOT EXISTS user                       FixedString(44) COMMENT 'User wallet address (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_mint                 FixedString(44) COMMENT 'Input token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS input_amount               UInt64 COMMENT 'Actual input amount (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_mint                FixedString(44) COMMENT 'Output token mint (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS output_amount              UInt64 COMMENT 'Actual output amount (inferred from inner token transfers)',
+
+    -- accounts --
+    ADD COLUMN IF NOT EXISTS swap_account               FixedString(44) COMMENT 'Pool (token-swap state) account',
+    ADD COLUMN IF NOT EXISTS authority                  FixedString(44) COMMENT 'Pool authority PDA',
+    ADD COLUMN IF NOT EXISTS user_transfer_authority    FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS source                     FixedString(44) COMMENT 'User source token account',
+    ADD COLUMN IF NOT EXISTS swap_source                FixedString(44) COMMENT 'Pool vault receiving the input token',
+    ADD COLUMN IF NOT EXISTS swap_destination           FixedString(44) COMMENT 'Pool vault sending the output token',
+    ADD COLUMN IF NOT EXISTS destination                FixedString(44) COMMENT 'User destination token account',
+    ADD COLUMN IF NOT EXISTS pool_mint                  FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS fee_account                FixedString(44) COMMENT 'Pool LP fee account',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_input_mint      (input_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_output_mint     (output_mint)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_swap_account    (swap_account)      TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- SPL Token Swap Deposit (both tokens) --
+CREATE TABLE IF NOT EXISTS token_swap_deposit_all_token_types AS base_events
+COMMENT 'SPL Token Swap DepositAllTokenTypes';
+ALTER TABLE token_swap_deposit_all_token_types
+    ADD COLUMN IF NOT EXISTS swap_account               FixedString(44) COMMENT 'Pool (token-swap state) account',
+    ADD COLUMN IF NOT EXISTS authority                  FixedString(44) COMMENT 'Pool authority PDA',
+    ADD COLUMN IF NOT EXISTS user_transfer_authority    FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS source_a                   FixedString(44) COMMENT 'User token A account',
+    ADD COLUMN IF NOT EXISTS source_b                   FixedString(44) COMMENT 'User token B account',
+    ADD COLUMN IF NOT EXISTS token_a                    FixedString(44) COMMENT 'Pool token A vault',
+    ADD COLUMN IF NOT EXISTS token_b                    FixedString(44) COMMENT 'Pool token B vault',
+    ADD COLUMN IF NOT EXISTS pool_mint                  FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS destination                FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS pool_token_amount          UInt64 COMMENT 'LP tokens minted',
+    ADD COLUMN IF NOT EXISTS maximum_token_a_amount     UInt64 COMMENT 'Maximum token A deposited',
+    ADD COLUMN IF NOT EXISTS maximum_token_b_amount     UInt64 COMMENT 'Maximum token B deposited',
+    ADD COLUMN IF NOT EXISTS token_a_mint               FixedString(44) COMMENT 'Token A mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_a_amount             UInt64 COMMENT 'Token A deposited (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS token_b_mint               FixedString(44) COMMENT 'Token B mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_b_amount             UInt64 COMMENT 'Token B deposited (inferred from inner token transfers)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_swap_account    (swap_account)      TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- SPL Token Swap Withdraw (both tokens) --
+CREATE TABLE IF NOT EXISTS token_swap_withdraw_all_token_types AS base_events
+COMMENT 'SPL Token Swap WithdrawAllTokenTypes';
+ALTER TABLE token_swap_withdraw_all_token_types
+    ADD COLUMN IF NOT EXISTS swap_account               FixedString(44) COMMENT 'Pool (token-swap state) account',
+    ADD COLUMN IF NOT EXISTS authority                  FixedString(44) COMMENT 'Pool authority PDA',
+    ADD COLUMN IF NOT EXISTS user_transfer_authority    FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS pool_mint                  FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS fee_account                FixedString(44) COMMENT 'Pool LP fee account',
+    ADD COLUMN IF NOT EXISTS source                     FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS token_a                    FixedString(44) COMMENT 'Pool token A vault',
+    ADD COLUMN IF NOT EXISTS token_b                    FixedString(44) COMMENT 'Pool token B vault',
+    ADD COLUMN IF NOT EXISTS destination_a              FixedString(44) COMMENT 'User token A account',
+    ADD COLUMN IF NOT EXISTS destination_b              FixedString(44) COMMENT 'User token B account',
+    ADD COLUMN IF NOT EXISTS pool_token_amount          UInt64 COMMENT 'LP tokens burned',
+    ADD COLUMN IF NOT EXISTS minimum_token_a_amount     UInt64 COMMENT 'Minimum token A withdrawn',
+    ADD COLUMN IF NOT EXISTS minimum_token_b_amount     UInt64 COMMENT 'Minimum token B withdrawn',
+    ADD COLUMN IF NOT EXISTS token_a_mint               FixedString(44) COMMENT 'Token A mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_a_amount             UInt64 COMMENT 'Token A withdrawn (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS token_b_mint               FixedString(44) COMMENT 'Token B mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_b_amount             UInt64 COMMENT 'Token B withdrawn (inferred from inner token transfers)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_swap_account    (swap_account)      TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- SPL Token Swap Deposit (single token) --
+CREATE TABLE IF NOT EXISTS token_swap_deposit_single_token_type AS base_events
+COMMENT 'SPL Token Swap DepositSingleTokenTypeExactAmountIn';
+ALTER TABLE token_swap_deposit_single_token_type
+    ADD COLUMN IF NOT EXISTS swap_account               FixedString(44) COMMENT 'Pool (token-swap state) account',
+    ADD COLUMN IF NOT EXISTS authority                  FixedString(44) COMMENT 'Pool authority PDA',
+    ADD COLUMN IF NOT EXISTS user_transfer_authority    FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS source                     FixedString(44) COMMENT 'User source token account',
+    ADD COLUMN IF NOT EXISTS token_a                    FixedString(44) COMMENT 'Pool token A vault',
+    ADD COLUMN IF NOT EXISTS token_b                    FixedString(44) COMMENT 'Pool token B vault',
+    ADD COLUMN IF NOT EXISTS pool_mint                  FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS destination                FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS source_token_amount        UInt64 COMMENT 'Tokens deposited',
+    ADD COLUMN IF NOT EXISTS minimum_pool_token_amount  UInt64 COMMENT 'Minimum LP tokens minted',
+    ADD COLUMN IF NOT EXISTS token_a_mint               FixedString(44) COMMENT 'Token A mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_a_amount             UInt64 COMMENT 'Token A deposited (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS token_b_mint               FixedString(44) COMMENT 'Token B mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_b_amount             UInt64 COMMENT 'Token B deposited (inferred from inner token transfers)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_swap_account    (swap_account)      TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- SPL Token Swap Withdraw (single token) --
+CREATE TABLE IF NOT EXISTS token_swap_withdraw_single_token_type AS base_events
+COMMENT 'SPL Token Swap WithdrawSingleTokenTypeExactAmountOut';
+ALTER TABLE token_swap_withdraw_single_token_type
+    ADD COLUMN IF NOT EXISTS swap_account               FixedString(44) COMMENT 'Pool (token-swap state) account',
+    ADD COLUMN IF NOT EXISTS authority                  FixedString(44) COMMENT 'Pool authority PDA',
+    ADD COLUMN IF NOT EXISTS user_transfer_authority    FixedString(44) COMMENT 'User transfer authority',
+    ADD COLUMN IF NOT EXISTS pool_mint                  FixedString(44) COMMENT 'Pool LP mint',
+    ADD COLUMN IF NOT EXISTS fee_account                FixedString(44) COMMENT 'Pool LP fee account',
+    ADD COLUMN IF NOT EXISTS source                     FixedString(44) COMMENT 'User LP token account',
+    ADD COLUMN IF NOT EXISTS token_a                    FixedString(44) COMMENT 'Pool token A vault',
+    ADD COLUMN IF NOT EXISTS token_b                    FixedString(44) COMMENT 'Pool token B vault',
+    ADD COLUMN IF NOT EXISTS destination                FixedString(44) COMMENT 'User destination token account',
+    ADD COLUMN IF NOT EXISTS destination_token_amount   UInt64 COMMENT 'Tokens withdrawn',
+    ADD COLUMN IF NOT EXISTS maximum_pool_token_amount  UInt64 COMMENT 'Maximum LP tokens burned',
+    ADD COLUMN IF NOT EXISTS token_a_mint               FixedString(44) COMMENT 'Token A mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_a_amount             UInt64 COMMENT 'Token A withdrawn (inferred from inner token transfers)',
+    ADD COLUMN IF NOT EXISTS token_b_mint               FixedString(44) COMMENT 'Token B mint (inferred from token balances)',
+    ADD COLUMN IF NOT EXISTS token_b_amount             UInt64 COMMENT 'Token B withdrawn (inferred from inner token transfers)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_swap_account    (swap_account)      TYPE bloom_filter(0.005)    GRANULARITY 1;
@@ -0,0 +1,31 @@
+-- SVM Token Launch Events --
+CREATE TABLE IF NOT EXISTS launch_events (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- transaction --
+    signature                   FixedString(88),
+
+    -- launch --
+    launchpad                   LowCardinality(FixedString(44)) COMMENT 'Launchpad protocol (program ID)',
+    stage                       Enum8('create' = 1, 'complete' = 2, 'graduate' = 3),
+    mint                        FixedString(44) COMMENT 'Launched token mint',
+    creator                     FixedString(44) COMMENT 'Token creator, empty when not exposed by the stage',
+    name                        String COMMENT 'Token name, only known at creation',
+    symbol                      String COMMENT 'Token symbol, only known at creation',
+    uri                         String COMMENT 'Token metadata URI, only known at creation',
+    bonding_curve               FixedString(44) COMMENT 'Bonding curve (Pump.fun, Moonshot, DumpFun) or pool state (Raydium Launchpad), empty when not exposed',
+    graduation_program_id       FixedString(44) COMMENT 'AMM receiving the liquidity, only known at graduation',
+    graduation_pool             FixedString(44) COMMENT 'AMM pool receiving the liquidity, only known at graduation (empty for Moonshot, the pool is created by the AMM)',
+
+    -- indexes --
+    INDEX idx_launchpad      (launchpad)    TYPE set(16)                GRANULARITY 1,
+    INDEX idx_creator        (creator)      TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_pool           (graduation_pool) TYPE bloom_filter(0.005) GRANULARITY 1,
+    INDEX idx_block_num      (block_num)    TYPE minmax                 GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree(block_num) -- in case of reorgs, keep the latest event
+ORDER BY (mint, stage)
+COMMENT 'Token launch lifecycle (Pump.fun, Moonshot, Raydium Launchpad, Boop, DumpFun, Heaven creations)';
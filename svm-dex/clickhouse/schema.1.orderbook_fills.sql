@@ -0,0 +1,42 @@
+-- SVM Order Book Fills --
+CREATE TABLE IF NOT EXISTS orderbook_fills (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- ordering --
+    transaction_index           UInt32,
+    instruction_index           UInt32 COMMENT 'Log index within the transaction',
+
+    -- transaction --
+    signature                   FixedString(88),
+
+    -- fill --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'Order book protocol (program ID)',
+    market                      FixedString(44) COMMENT 'Order book market',
+    maker                       FixedString(44) COMMENT 'Maker (Phoenix) or maker open orders account (OpenBook)',
+    taker                       FixedString(44) COMMENT 'Signer (Phoenix) or taker open orders account (OpenBook)',
+    taker_side                  Enum8('' = 0, 'bid' = 1, 'ask' = 2),
+    price                       Int64 COMMENT 'Price in ticks (Phoenix) or quote lots per base lot (OpenBook)',
+    quantity                    UInt64 COMMENT 'Base lots filled',
+    maker_order_id              UInt128 COMMENT 'Maker order sequence number (Phoenix), 0 when unknown',
+    maker_client_order_id       UInt128 COMMENT '0 when unknown',
+    taker_client_order_id       UInt128 COMMENT '0 when unknown',
+    maker_fee                   UInt64 COMMENT 'Maker fee in quote lots (OpenBook)',
+    taker_fee                   UInt64 COMMENT 'Taker fee in quote lots (OpenBook)',
+
+    -- indexes --
+    INDEX idx_program_id     (program_id)   TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_market         (market)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_maker          (maker)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_taker          (taker)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_signature      (signature)    TYPE bloom_filter(0.005)    GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree
+ORDER BY (
+    timestamp, block_num,
+    block_hash, transaction_index, instruction_index,
+    program_id
+)
+COMMENT 'Order book fills (Phoenix, OpenBook v2)';
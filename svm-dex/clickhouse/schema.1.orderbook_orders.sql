@@ -0,0 +1,46 @@
+-- SVM Order Book Orders --
+CREATE TABLE IF NOT EXISTS orderbook_orders (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- ordering --
+    transaction_index           UInt32,
+    instruction_index           UInt32 COMMENT 'Instruction or log index within the transaction',
+    order_index                 UInt16 COMMENT 'Order index within instructions placing multiple orders',
+
+    -- transaction --
+    signature                   FixedString(88),
+
+    -- order --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'Order book protocol (program ID)',
+    action                      Enum8('place' = 1, 'cancel' = 2, 'modify' = 3, 'settle' = 4),
+    market                      FixedString(44) COMMENT 'Order book market',
+    trader                      FixedString(44) COMMENT 'Trader (Phoenix) or open orders account (OpenBook, signer for take orders)',
+    side                        Enum8('' = 0, 'bid' = 1, 'ask' = 2) COMMENT 'Empty when the event applies to both sides',
+    order_id                    UInt128 COMMENT 'Order sequence number (Phoenix) or order id (OpenBook), 0 when unknown',
+    client_order_id             UInt128 COMMENT '0 when unknown',
+    order_type                  LowCardinality(String) COMMENT 'limit, post_only, immediate_or_cancel, market, post_only_slide or fill_or_kill',
+    price                       Int64 COMMENT 'Price in ticks (Phoenix) or quote lots per base lot (OpenBook)',
+    size                        UInt64 COMMENT 'Base lots placed, cancelled or reduced (max base lots for OpenBook orders)',
+    quote_size                  UInt64 COMMENT 'Max quote lots including fees (OpenBook)',
+    remaining                   UInt64 COMMENT 'Base lots remaining on the book (Phoenix)',
+    base_amount                 UInt64 COMMENT 'Base tokens settled',
+    quote_amount                UInt64 COMMENT 'Quote tokens settled',
+
+    -- indexes --
+    INDEX idx_program_id     (program_id)   TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_action         (action)       TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_market         (market)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_trader         (trader)       TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_order_id       (order_id)     TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_signature      (signature)    TYPE bloom_filter(0.005)    GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree
+ORDER BY (
+    timestamp, block_num,
+    block_hash, transaction_index, instruction_index,
+    program_id, action, order_index
+)
+COMMENT 'Order book order lifecycle (Phoenix, OpenBook v2)';
@@ -0,0 +1,25 @@
+-- SVM Pool Reserves --
+CREATE TABLE IF NOT EXISTS pool_reserves (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- pool --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'AMM protocol (program ID)',
+    pool                        FixedString(44) COMMENT 'AMM pool',
+    mint0                       FixedString(44) COMMENT 'Token 0 mint (lowest mint, same ordering as ohlc_prices)',
+    mint1                       FixedString(44) COMMENT 'Token 1 mint',
+    reserve0                    UInt64 COMMENT 'Token 0 vault balance after the last swap of the pool',
+    reserve1                    UInt64 COMMENT 'Token 1 vault balance after the last swap of the pool',
+    price                       Float64 COMMENT 'Spot price of token 0 in token 1 (raw amounts)',
+
+    -- indexes --
+    INDEX idx_timestamp      (timestamp)    TYPE minmax                 GRANULARITY 1,
+    INDEX idx_program_id     (program_id)   TYPE set(256)               GRANULARITY 1,
+    INDEX idx_mint0          (mint0)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_mint1          (mint1)        TYPE bloom_filter(0.005)    GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree(block_num)
+ORDER BY pool
+COMMENT 'Solana AMM pool reserves from the vault token balances (latest reserves per-pool)';
@@ -0,0 +1,32 @@
+-- SVM Pools --
+CREATE TABLE IF NOT EXISTS pools (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- transaction --
+    signature                   FixedString(88) COMMENT 'Transaction creating the pool',
+
+    -- pool --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'AMM protocol (program ID)',
+    pool                        FixedString(44) COMMENT 'AMM pool',
+    mint0                       FixedString(44) COMMENT 'Token 0 mint (base, coin or token A)',
+    mint1                       FixedString(44) COMMENT 'Token 1 mint (quote, pc or token B)',
+    vault0                      FixedString(44) COMMENT 'Token 0 vault, empty when not exposed by the pool creation',
+    vault1                      FixedString(44) COMMENT 'Token 1 vault, empty when not exposed by the pool creation',
+    lp_mint                     FixedString(44) COMMENT 'LP token mint, empty for position based pools',
+    config                      FixedString(44) COMMENT 'Fee / AMM config account',
+    fee_tier                    UInt32 COMMENT 'Tick spacing (Orca) or bin step (Meteora DLMM), 0 when the fee is set by the config',
+    creator                     FixedString(44) COMMENT 'Pool creator',
+
+    -- indexes --
+    INDEX idx_program_id     (program_id)   TYPE set(256)               GRANULARITY 1,
+    INDEX idx_mint0          (mint0)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_mint1          (mint1)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_creator        (creator)      TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_block_num      (block_num)    TYPE minmax                 GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree(block_num) -- in case of reorgs, keep the latest creation
+ORDER BY pool
+COMMENT 'Solana AMM pools, registered at creation';
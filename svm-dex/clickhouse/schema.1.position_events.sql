@@ -0,0 +1,44 @@
+-- SVM Concentrated Liquidity Position Events --
+CREATE TABLE IF NOT EXISTS position_events (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- ordering --
+    transaction_index           UInt32,
+    instruction_index           UInt32 COMMENT 'Instruction or log index within the transaction',
+
+    -- transaction --
+    signature                   FixedString(88),
+
+    -- position --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'AMM protocol (program ID)',
+    action                      Enum8('open' = 1, 'close' = 2, 'increase_liquidity' = 3, 'decrease_liquidity' = 4, 'collect_fees' = 5, 'collect_reward' = 6),
+    pool                        FixedString(44) COMMENT 'AMM pool, empty when not exposed by the event',
+    position                    FixedString(44) COMMENT 'Position account (Orca, Meteora DLMM) or personal position (Raydium CLMM)',
+    owner                       FixedString(44) COMMENT 'Position owner or authority, empty when not exposed by the event',
+    lower_index                 Int32 COMMENT 'Lower tick (Orca, Raydium CLMM) or lower bin id (Meteora DLMM), 0 when unknown',
+    upper_index                 Int32 COMMENT 'Upper tick (Orca, Raydium CLMM) or upper bin id (Meteora DLMM), 0 when unknown',
+    liquidity                   UInt128 COMMENT 'Liquidity delta (ticks), 0 for Meteora DLMM',
+    amount0                     UInt64 COMMENT 'Token 0 (A, X) deposited, withdrawn or collected as fees',
+    amount1                     UInt64 COMMENT 'Token 1 (B, Y) deposited, withdrawn or collected as fees',
+    reward_index                UInt8 COMMENT 'Reward slot of the pool',
+    reward_mint                 FixedString(44) COMMENT 'Reward token mint, empty when not exposed by the event',
+    reward_amount               UInt64 COMMENT 'Reward tokens collected',
+
+    -- indexes --
+    INDEX idx_program_id     (program_id)   TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_action         (action)       TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_pool           (pool)         TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_position       (position)     TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_owner          (owner)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_signature      (signature)    TYPE bloom_filter(0.005)    GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree
+ORDER BY (
+    timestamp, block_num,
+    block_hash, transaction_index, instruction_index,
+    program_id, action, reward_index
+)
+COMMENT 'Concentrated liquidity position lifecycle (Orca, Raydium CLMM, Meteora DLMM)';
@@ -0,0 +1,37 @@
+-- SVM Protocol Fees --
+CREATE TABLE IF NOT EXISTS protocol_fees (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- ordering --
+    transaction_index           UInt32,
+    instruction_index           UInt32 COMMENT 'Instruction index of the trade within the transaction',
+
+    -- transaction --
+    signature                   FixedString(88),
+
+    -- fee --
+    program_id                  LowCardinality(FixedString(44)) COMMENT 'Pump.fun bonding curve or Pump.fun AMM (program ID)',
+    mint                        FixedString(44) COMMENT 'Token mint',
+    pool                        FixedString(44) COMMENT 'AMM pool, empty for the bonding curve',
+    quote_mint                  FixedString(44) COMMENT 'Token the fee is paid in, empty for SOL (bonding curve)',
+    fee_recipient               FixedString(44) COMMENT 'Protocol fee recipient',
+    fee_basis_points            UInt64 COMMENT 'Protocol fee rate of the trade',
+    amount                      UInt64 COMMENT 'Protocol fee charged by the trade',
+
+    -- indexes --
+    INDEX idx_program_id     (program_id)       TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_mint           (mint)             TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_pool           (pool)             TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_fee_recipient  (fee_recipient)    TYPE set(64)                GRANULARITY 1,
+    INDEX idx_signature      (signature)        TYPE bloom_filter(0.005)    GRANULARITY 1
+
+) ENGINE = ReplacingMergeTree
+ORDER BY (
+    timestamp, block_num,
+    block_hash, transaction_index, instruction_index,
+    program_id
+)
+COMMENT 'Pump.fun protocol fees charged by trades';
@@ -0,0 +1,846 @@
+-- SVM Liquidity Events --
+CREATE TABLE IF NOT EXISTS liquidity_events AS base_events
+COMMENT 'Solana AMM liquidity provision (add & remove liquidity)';
+ALTER TABLE liquidity_events
+    ADD COLUMN IF NOT EXISTS event_type                  Enum8('add' = 1, 'remove' = 2) COMMENT 'Liquidity added or removed',
+    ADD COLUMN IF NOT EXISTS amm                         FixedString(44) COMMENT 'AMM protocol (program ID)',
+    ADD COLUMN IF NOT EXISTS amm_pool                    FixedString(44) COMMENT 'AMM pool',
+    ADD COLUMN IF NOT EXISTS provider                    FixedString(44) COMMENT 'Liquidity provider wallet',
+    ADD COLUMN IF NOT EXISTS position                    FixedString(44) COMMENT 'Position account (concentrated liquidity), empty for LP token pools',
+    ADD COLUMN IF NOT EXISTS token_a_mint                FixedString(44) COMMENT 'Token A mint address',
+    ADD COLUMN IF NOT EXISTS token_a_amount              UInt64 COMMENT 'Amount of token A added or removed',
+    ADD COLUMN IF NOT EXISTS token_b_mint                FixedString(44) COMMENT 'Token B mint address',
+    ADD COLUMN IF NOT EXISTS token_b_amount              UInt64 COMMENT 'Amount of token B added or removed',
+    ADD COLUMN IF NOT EXISTS lp_mint                     FixedString(44) COMMENT 'LP token mint, empty for position based pools',
+    ADD COLUMN IF NOT EXISTS lp_amount                   UInt64 COMMENT 'LP tokens minted (add) or burned (remove)',
+    ADD COLUMN IF NOT EXISTS liquidity                   UInt128 COMMENT 'Liquidity delta of the position (concentrated liquidity)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_event_type        (event_type)        TYPE set(2)                 GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_amm               (amm)               TYPE set(256)               GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_amm_pool          (amm_pool)          TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_provider          (provider)          TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_position          (position)          TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_token_a_mint      (token_a_mint)      TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_token_b_mint      (token_b_mint)      TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   1.  Raydium AMM V4 → liquidity_events
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_raydium_amm_v4_deposit
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.amm                   AS amm_pool,
+    s.user_owner            AS provider,
+    ''                      AS position,
+    s.coin_mint             AS token_a_mint,
+    s.coin_amount           AS token_a_amount,
+    s.pc_mint               AS token_b_mint,
+    s.pc_amount             AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_amount             AS lp_amount,
+    0                       AS liquidity
+
+FROM raydium_amm_v4_deposit AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_raydium_amm_v4_withdraw
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.amm                   AS amm_pool,
+    s.user_owner            AS provider,
+    ''                      AS position,
+    s.coin_mint             AS token_a_mint,
+    s.coin_amount           AS token_a_amount,
+    s.pc_mint               AS token_b_mint,
+    s.pc_amount             AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_amount             AS lp_amount,
+    0                       AS liquidity
+
+FROM raydium_amm_v4_withdraw AS s;
+
+-- initial liquidity, the LP tokens minted to the creator are not logged
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_raydium_amm_v4_initialize
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.amm                   AS amm_pool,
+    s.user_wallet           AS provider,
+    ''                      AS position,
+    s.coin_mint             AS token_a_mint,
+    s.coin_amount           AS token_a_amount,
+    s.pc_mint               AS token_b_mint,
+    s.pc_amount             AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    0                       AS lp_amount,
+    0                       AS liquidity
+
+FROM raydium_amm_v4_initialize AS s;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   2.  Orca Whirlpool → liquidity_events (position authority & mints from the paired instruction)
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_orca_liquidity_increased
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.whirlpool             AS amm_pool,
+    s.position_authority    AS provider,
+    s.position              AS position,
+    s.token_mint_a          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_mint_b          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    ''                      AS lp_mint,
+    0                       AS lp_amount,
+    s.liquidity             AS liquidity
+
+FROM orca_liquidity_increased AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_orca_liquidity_decreased
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.whirlpool             AS amm_pool,
+    s.position_authority    AS provider,
+    s.position              AS position,
+    s.token_mint_a          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_mint_b          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    ''                      AS lp_mint,
+    0                       AS lp_amount,
+    s.liquidity             AS liquidity
+
+FROM orca_liquidity_decreased AS s;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   3.  Meteora AMM → liquidity_events
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_amm_add_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.pool                  AS amm_pool,
+    s.user                  AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_mint_amount        AS lp_amount,
+    0                       AS liquidity
+
+FROM meteora_amm_add_liquidity AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_amm_bootstrap_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.pool                  AS amm_pool,
+    s.user                  AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_mint_amount        AS lp_amount,
+    0                       AS liquidity
+
+FROM meteora_amm_bootstrap_liquidity AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_amm_remove_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.pool                  AS amm_pool,
+    s.user                  AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_out_amount    AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_out_amount    AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_unmint_amount      AS lp_amount,
+    0                       AS liquidity
+
+FROM meteora_amm_remove_liquidity AS s;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   4.  Meteora DAAM → liquidity_events
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_daam_add_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.pool                  AS amm_pool,
+    s.owner                 AS provider,
+    s.position              AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    ''                      AS lp_mint,
+    0                       AS lp_amount,
+    s.liquidity_delta       AS liquidity
+
+FROM meteora_daam_add_liquidity AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_daam_remove_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.pool                  AS amm_pool,
+    s.owner                 AS provider,
+    s.position              AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    ''                      AS lp_mint,
+    0                       AS lp_amount,
+    s.liquidity_delta       AS liquidity
+
+FROM meteora_daam_remove_liquidity AS s;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   5.  SPL Token Swap → liquidity_events
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_token_swap_deposit_all_token_types
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.swap_account          AS amm_pool,
+    s.user_transfer_authority AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    s.pool_mint             AS lp_mint,
+    s.pool_token_amount     AS lp_amount,
+    0                       AS liquidity
+
+FROM token_swap_deposit_all_token_types AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_token_swap_withdraw_all_token_types
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.swap_account          AS amm_pool,
+    s.user_transfer_authority AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    s.pool_mint             AS lp_mint,
+    s.pool_token_amount     AS lp_amount,
+    0                       AS liquidity
+
+FROM token_swap_withdraw_all_token_types AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_token_swap_deposit_single_token_type
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.swap_account          AS amm_pool,
+    s.user_transfer_authority AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    s.pool_mint             AS lp_mint,
+    0                       AS lp_amount, -- only bounded by the instruction for single token deposits & withdraws
+    0                       AS liquidity
+
+FROM token_swap_deposit_single_token_type AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_token_swap_withdraw_single_token_type
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.swap_account          AS amm_pool,
+    s.user_transfer_authority AS provider,
+    ''                      AS position,
+    s.token_a_mint          AS token_a_mint,
+    s.token_a_amount        AS token_a_amount,
+    s.token_b_mint          AS token_b_mint,
+    s.token_b_amount        AS token_b_amount,
+    s.pool_mint             AS lp_mint,
+    0                       AS lp_amount, -- only bounded by the instruction for single token deposits & withdraws
+    0                       AS liquidity
+
+FROM token_swap_withdraw_single_token_type AS s;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   6.  Raydium CPMM → liquidity_events
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_raydium_cpmm_deposit
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.pool_state            AS amm_pool,
+    s.owner                 AS provider,
+    ''                      AS position,
+    s.token_0_mint          AS token_a_mint,
+    s.token_0_amount        AS token_a_amount,
+    s.token_1_mint          AS token_b_mint,
+    s.token_1_amount        AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_token_amount       AS lp_amount,
+    0                       AS liquidity
+
+FROM raydium_cpmm_deposit AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_raydium_cpmm_withdraw
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.pool_state            AS amm_pool,
+    s.owner                 AS provider,
+    ''                      AS position,
+    s.token_0_mint          AS token_a_mint,
+    s.token_0_amount        AS token_a_amount,
+    s.token_1_mint          AS token_b_mint,
+    s.token_1_amount        AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    s.lp_token_amount       AS lp_amount,
+    0                       AS liquidity
+
+FROM raydium_cpmm_withdraw AS s;
+
+-- initial liquidity, the LP tokens minted to the creator are not logged
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_raydium_cpmm_initialize
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.pool_state            AS amm_pool,
+    s.creator               AS provider,
+    ''                      AS position,
+    s.token_0_mint          AS token_a_mint,
+    s.init_amount_0         AS token_a_amount,
+    s.token_1_mint          AS token_b_mint,
+    s.init_amount_1         AS token_b_amount,
+    s.lp_mint               AS lp_mint,
+    0                       AS lp_amount,
+    0                       AS liquidity
+
+FROM raydium_cpmm_initialize AS s;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   7.  Meteora DLLM → liquidity_events (liquidity is spread over bins, no LP mint)
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_dllm_add_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'add'                   AS event_type,
+    s.program_id            AS amm,
+    s.lb_pair               AS amm_pool,
+    s.sender                AS provider,
+    s.position              AS position,
+    s.token_x_mint          AS token_a_mint,
+    s.amount_x              AS token_a_amount,
+    s.token_y_mint          AS token_b_mint,
+    s.amount_y              AS token_b_amount,
+    ''                      AS lp_mint,
+    0                       AS lp_amount,
+    0                       AS liquidity
+
+FROM meteora_dllm_add_liquidity AS s;
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_dllm_remove_liquidity
+TO liquidity_events AS
+SELECT
+    -- block --
+    block_num,
+    block_hash,
+    timestamp,
+
+    -- ordering --
+    transaction_index,
+    instruction_index,
+
+    -- transaction --
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    -- instruction --
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    -- liquidity --
+    'remove'                AS event_type,
+    s.program_id            AS amm,
+    s.lb_pair               AS amm_pool,
+    s.sender                AS provider,
+    s.position              AS position,
+    s.token_x_mint          AS token_a_mint,
+    s.amount_x              AS token_a_amount,
+    s.token_y_mint          AS token_b_mint,
+    s.amount_y              AS token_b_amount,
+    ''                      AS lp_mint,
+    0                       AS lp_amount,
+    0                       AS liquidity
+
+FROM meteora_dllm_remove_liquidity AS s;
@@ -49,6 +49,9 @@ SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     user_source_owner       AS user,
@@ -91,6 +94,9 @@ SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     user_source_owner       AS user,
@@ -134,6 +140,9 @@ SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     s.fee_payer             AS user, -- Jupiter does not use user wallets, so we use fee_payer as a placeholder
@@ -176,6 +185,9 @@ SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     user,
@@ -212,6 +224,9 @@ AS SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     user,
@@ -251,6 +266,9 @@ SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     user,
@@ -287,6 +305,9 @@ SELECT
     -- instruction --
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     -- common fields --
     user,
@@ -322,6 +343,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     payer AS user,
     program_id AS amm,
@@ -351,6 +375,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     payer AS user,
     program_id AS amm,
@@ -383,6 +410,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     payer AS user,
     program_id AS amm,
@@ -415,6 +445,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     payer AS user,
     program_id AS amm,
@@ -444,6 +477,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     payer AS user,
     program_id AS amm,
@@ -476,6 +512,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     user,
     program_id AS amm,
@@ -508,6 +547,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     payer AS user,
     program_id AS amm,
@@ -540,6 +582,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     user,
     program_id AS amm,
@@ -572,6 +617,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     user,
     program_id AS amm,
@@ -604,6 +652,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     trader AS user,
     program_id AS amm,
@@ -636,6 +687,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     user,
     program_id AS amm,
@@ -665,6 +719,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     user,
     program_id AS amm,
@@ -697,6 +754,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     buyer AS user,
     program_id AS amm,
@@ -726,6 +786,9 @@ SELECT
 
     program_id,
     stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
 
     seller AS user,
     program_id AS amm,
@@ -736,3 +799,143 @@ SELECT
     amount_out AS output_amount
 FROM boop_sell AS s
 WHERE input_amount > 1 AND output_amount > 1;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   1.  Saros → swaps
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_saros_swap
+TO swaps AS
+SELECT
+    block_num,
+    block_hash,
+    timestamp,
+
+    transaction_index,
+    instruction_index,
+
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    user,
+    program_id AS amm,
+    swap AS amm_pool,
+    input_mint,
+    input_amount,
+    output_mint,
+    output_amount
+FROM saros_swap AS s
+WHERE input_amount > 1 AND output_amount > 1;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   1.  ByReal CLMM → swaps
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_byreal_swap
+TO swaps AS
+SELECT
+    block_num,
+    block_hash,
+    timestamp,
+
+    transaction_index,
+    instruction_index,
+
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    user,
+    program_id AS amm,
+    pool_state AS amm_pool,
+    input_mint,
+    input_amount,
+    output_mint,
+    output_amount
+FROM byreal_swap AS s
+WHERE input_amount > 1 AND output_amount > 1;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   1.  BonkSwap → swaps
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_bonk_swap_swap
+TO swaps AS
+SELECT
+    block_num,
+    block_hash,
+    timestamp,
+
+    transaction_index,
+    instruction_index,
+
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    user,
+    program_id AS amm,
+    pool AS amm_pool,
+    input_mint,
+    input_amount,
+    output_mint,
+    output_amount
+FROM bonk_swap_swap AS s
+WHERE input_amount > 1 AND output_amount > 1;
+
+/* ──────────────────────────────────────────────────────────────────────────
+   1.  SPL Token Swap → swaps
+   ────────────────────────────────────────────────────────────────────────── */
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_token_swap_swap
+TO swaps AS
+SELECT
+    block_num,
+    block_hash,
+    timestamp,
+
+    transaction_index,
+    instruction_index,
+
+    signature,
+    fee_payer,
+    signers_raw,
+    fee,
+    compute_units_consumed,
+
+    program_id,
+    stack_height,
+    parent_program_id,
+    outer_program_id,
+    aggregator,
+
+    user,
+    program_id AS amm,
+    swap_account AS amm_pool,
+    input_mint,
+    input_amount,
+    output_mint,
+    output_amount
+FROM token_swap_swap AS s
+WHERE input_amount > 1 AND output_amount > 1;
@@ -0,0 +1,46 @@
+-- Creator Fee Balances --
+CREATE TABLE IF NOT EXISTS creator_fee_balances (
+    -- Order By --
+    creator                     FixedString(44),
+    program_id                  LowCardinality(FixedString(44)),
+    quote_mint                  FixedString(44) COMMENT 'Empty for SOL (bonding curve)',
+
+    -- fees --
+    accrued                     SimpleAggregateFunction(sum, UInt128) COMMENT 'Creator fees accrued by trades',
+    claimed                     SimpleAggregateFunction(sum, UInt128) COMMENT 'Creator fees claimed from the creator vault',
+    trades                      SimpleAggregateFunction(sum, UInt64),
+    claims                      SimpleAggregateFunction(sum, UInt64),
+
+    -- lifecycle --
+    min_block_num               SimpleAggregateFunction(min, UInt32),
+    max_block_num               SimpleAggregateFunction(max, UInt32),
+    last_claimed_at             SimpleAggregateFunction(max, DateTime(0, 'UTC')),
+
+    -- indexes --
+    INDEX idx_program_id        (program_id)        TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_quote_mint        (quote_mint)        TYPE bloom_filter(0.005)    GRANULARITY 1
+)
+ENGINE = AggregatingMergeTree
+ORDER BY (creator, program_id, quote_mint)
+COMMENT 'Creator fees accrued vs claimed, per creator and quote token';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_creator_fee_balances
+TO creator_fee_balances
+AS
+SELECT
+    creator,
+    program_id,
+    quote_mint,
+
+    /* fees */
+    if(action = 'accrue', amount, 0)                    AS accrued,
+    if(action = 'claim', amount, 0)                     AS claimed,
+    action = 'accrue'                                   AS trades,
+    action = 'claim'                                    AS claims,
+
+    /* lifecycle */
+    block_num                                           AS min_block_num,
+    block_num                                           AS max_block_num,
+    if(action = 'claim', timestamp, toDateTime(0, 'UTC')) AS last_claimed_at
+FROM creator_fees
+WHERE action IN ('accrue', 'claim');
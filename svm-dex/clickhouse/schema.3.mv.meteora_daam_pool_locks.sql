@@ -0,0 +1,41 @@
+-- Meteora DAAM Pool Locks --
+CREATE TABLE IF NOT EXISTS meteora_daam_pool_locks (
+    -- Order By --
+    pool                        FixedString(44),
+    program_id                  LowCardinality(FixedString(44)),
+
+    -- locks --
+    permanent_locked_liquidity  SimpleAggregateFunction(sum, UInt128) COMMENT 'Liquidity permanently locked, never withdrawable',
+    vesting_locked_liquidity    SimpleAggregateFunction(sum, UInt128) COMMENT 'Liquidity locked by vestings (released over time)',
+    permanent_locks             SimpleAggregateFunction(sum, UInt64),
+    vesting_locks               SimpleAggregateFunction(sum, UInt64),
+
+    -- lifecycle --
+    min_block_num               SimpleAggregateFunction(min, UInt32),
+    max_block_num               SimpleAggregateFunction(max, UInt32),
+    first_locked_at             SimpleAggregateFunction(min, DateTime(0, 'UTC')),
+    last_locked_at              SimpleAggregateFunction(max, DateTime(0, 'UTC'))
+)
+ENGINE = AggregatingMergeTree
+ORDER BY (pool, program_id)
+COMMENT 'Meteora DAAM liquidity locked per pool (a pool with permanent_locked_liquidity > 0 has permanently locked liquidity)';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_meteora_daam_pool_locks
+TO meteora_daam_pool_locks
+AS
+SELECT
+    pool,
+    program_id,
+
+    /* locks */
+    if(lock_type = 'permanent', liquidity, 0)           AS permanent_locked_liquidity,
+    if(lock_type = 'vesting', liquidity, 0)             AS vesting_locked_liquidity,
+    lock_type = 'permanent'                             AS permanent_locks,
+    lock_type = 'vesting'                               AS vesting_locks,
+
+    /* lifecycle */
+    block_num                                           AS min_block_num,
+    block_num                                           AS max_block_num,
+    timestamp                                           AS first_locked_at,
+    timestamp                                           AS last_locked_at
+FROM meteora_daam_position_locks;
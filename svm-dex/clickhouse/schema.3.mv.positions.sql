@@ -0,0 +1,75 @@
+-- Concentrated Liquidity Positions --
+CREATE TABLE IF NOT EXISTS positions (
+    -- Order By --
+    position                    FixedString(44),
+
+    -- position --
+    program_id                  SimpleAggregateFunction(any, LowCardinality(FixedString(44))),
+    pool                        SimpleAggregateFunction(max, FixedString(44)),
+    owner                       SimpleAggregateFunction(max, FixedString(44)) COMMENT 'Owner when the position was opened',
+    lower_index                 SimpleAggregateFunction(any, Nullable(Int32)) COMMENT 'Lower tick or bin id',
+    upper_index                 SimpleAggregateFunction(any, Nullable(Int32)) COMMENT 'Upper tick or bin id',
+
+    -- liquidity --
+    liquidity                   SimpleAggregateFunction(sum, Int256) COMMENT 'Current liquidity (ticks), 0 for Meteora DLMM',
+    deposited0                  SimpleAggregateFunction(sum, UInt128) COMMENT 'Token 0 deposited in the position',
+    deposited1                  SimpleAggregateFunction(sum, UInt128) COMMENT 'Token 1 deposited in the position',
+    withdrawn0                  SimpleAggregateFunction(sum, UInt128) COMMENT 'Token 0 withdrawn from the position',
+    withdrawn1                  SimpleAggregateFunction(sum, UInt128) COMMENT 'Token 1 withdrawn from the position',
+
+    -- fees --
+    fees0                       SimpleAggregateFunction(sum, UInt128) COMMENT 'Token 0 fees collected',
+    fees1                       SimpleAggregateFunction(sum, UInt128) COMMENT 'Token 1 fees collected',
+    reward_claims               SimpleAggregateFunction(sum, UInt64) COMMENT 'Number of reward collections',
+
+    -- lifecycle --
+    opened_at                   SimpleAggregateFunction(any, Nullable(DateTime(0, 'UTC'))),
+    closed_at                   SimpleAggregateFunction(any, Nullable(DateTime(0, 'UTC'))),
+    min_block_num               SimpleAggregateFunction(min, UInt32),
+    max_block_num               SimpleAggregateFunction(max, UInt32),
+    events                      SimpleAggregateFunction(sum, UInt64),
+
+    -- indexes --
+    INDEX idx_program_id        (program_id)        TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_pool              (pool)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_owner             (owner)             TYPE bloom_filter(0.005)    GRANULARITY 1
+)
+ENGINE = AggregatingMergeTree
+ORDER BY position
+COMMENT 'Concentrated liquidity positions, with liquidity and fees collected';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_positions
+TO positions
+AS
+WITH
+    action IN ('open', 'increase_liquidity') AS is_deposit,
+    action = 'decrease_liquidity' AS is_withdraw,
+    -- the range is only known by some events, an empty range is never valid
+    (lower_index = 0 AND upper_index = 0) AS no_range
+SELECT
+    position,
+    program_id,
+    pool,
+    if(action = 'open', owner, '')                      AS owner,
+    if(no_range, NULL, lower_index)                     AS lower_index,
+    if(no_range, NULL, upper_index)                     AS upper_index,
+
+    /* liquidity */
+    multiIf(is_deposit, toInt256(liquidity), is_withdraw, -toInt256(liquidity), 0) AS liquidity,
+    if(is_deposit, amount0, 0)                          AS deposited0,
+    if(is_deposit, amount1, 0)                          AS deposited1,
+    if(is_withdraw, amount0, 0)                         AS withdrawn0,
+    if(is_withdraw, amount1, 0)                         AS withdrawn1,
+
+    /* fees */
+    if(action = 'collect_fees', amount0, 0)             AS fees0,
+    if(action = 'collect_fees', amount1, 0)             AS fees1,
+    action = 'collect_reward'                           AS reward_claims,
+
+    /* lifecycle */
+    if(action = 'open', timestamp, NULL)                AS opened_at,
+    if(action = 'close', timestamp, NULL)               AS closed_at,
+    block_num                                           AS min_block_num,
+    block_num                                           AS max_block_num,
+    1                                                   AS events
+FROM position_events;
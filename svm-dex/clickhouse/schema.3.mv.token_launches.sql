@@ -0,0 +1,54 @@
+-- Token Launches --
+CREATE TABLE IF NOT EXISTS token_launches (
+    -- Order By --
+    mint                        FixedString(44),
+
+    -- token --
+    launchpad                   SimpleAggregateFunction(any, LowCardinality(FixedString(44))),
+    creator                     SimpleAggregateFunction(max, FixedString(44)),
+    name                        SimpleAggregateFunction(max, String),
+    symbol                      SimpleAggregateFunction(max, String),
+    uri                         SimpleAggregateFunction(max, String),
+    bonding_curve               SimpleAggregateFunction(max, FixedString(44)),
+    graduation_program_id       SimpleAggregateFunction(max, FixedString(44)),
+    graduation_pool             SimpleAggregateFunction(max, FixedString(44)),
+
+    -- lifecycle --
+    created_block_num           SimpleAggregateFunction(any, Nullable(UInt32)),
+    created_at                  SimpleAggregateFunction(any, Nullable(DateTime(0, 'UTC'))),
+    completed_block_num         SimpleAggregateFunction(any, Nullable(UInt32)) COMMENT 'Bonding curve fully sold',
+    completed_at                SimpleAggregateFunction(any, Nullable(DateTime(0, 'UTC'))),
+    graduated_block_num         SimpleAggregateFunction(any, Nullable(UInt32)) COMMENT 'Liquidity migrated to an AMM pool',
+    graduated_at                SimpleAggregateFunction(any, Nullable(DateTime(0, 'UTC'))),
+
+    -- indexes --
+    INDEX idx_launchpad         (launchpad)         TYPE set(16)                GRANULARITY 1,
+    INDEX idx_creator           (creator)           TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_graduation_pool   (graduation_pool)   TYPE bloom_filter(0.005)    GRANULARITY 1
+)
+ENGINE = AggregatingMergeTree
+ORDER BY mint
+COMMENT 'Launched tokens, from creation on a bonding curve to graduation to an AMM pool (Pump.fun, Moonshot, Raydium Launchpad, Boop, DumpFun, Heaven)';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_token_launches
+TO token_launches
+AS
+SELECT
+    mint,
+    launchpad,
+    creator,
+    name,
+    symbol,
+    uri,
+    bonding_curve,
+    graduation_program_id,
+    graduation_pool,
+
+    /* lifecycle */
+    if(stage = 'create', block_num, NULL)               AS created_block_num,
+    if(stage = 'create', timestamp, NULL)               AS created_at,
+    if(stage = 'complete', block_num, NULL)             AS completed_block_num,
+    if(stage = 'complete', timestamp, NULL)             AS completed_at,
+    if(stage = 'graduate', block_num, NULL)             AS graduated_block_num,
+    if(stage = 'graduate', timestamp, NULL)             AS graduated_at
+FROM launch_events;
@@ -0,0 +1,27 @@
+use substreams_database_change::tables::Row;
+use substreams_solana::base58;
+
+// Aggregators routing swaps through AMM pools via CPI
+const AGGREGATORS: [&str; 4] = [
+    "JUP4Fb2cqiRUcaTHdrPC8h2gNsA2ETXiPDD33WcGuJB",  // Jupiter Aggregator v4
+    "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4",  // Jupiter Aggregator v6
+    "6m2CDdhRgxpH4WjvdzxAYbGxwdGUz5MziiL5jek2kBma", // OKX DEX Aggregation Router v2
+    "DF1ow4tspfHX9JwWJsAb9epbkA8hmpSEAtxXy1V27QBH", // DFlow Aggregator v4
+];
+
+/// Sets the instruction ancestry and the aggregator which routed the swap (empty for direct swaps).
+///
+/// The direct parent takes precedence over the top-level instruction (ex: Jupiter route nested in a wallet program).
+pub fn set_aggregator(parent_program_id: &[u8], outer_program_id: &[u8], row: &mut Row) {
+    let parent_program_id = base58::encode(parent_program_id);
+    let outer_program_id = base58::encode(outer_program_id);
+    let aggregator = [&parent_program_id, &outer_program_id]
+        .into_iter()
+        .find(|program_id| AGGREGATORS.contains(&program_id.as_str()))
+        .cloned()
+        .unwrap_or_default();
+
+    row.set("parent_program_id", parent_program_id)
+        .set("outer_program_id", outer_program_id)
+        .set("aggregator", aggregator);
+}
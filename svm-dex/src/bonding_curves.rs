@@ -0,0 +1,428 @@
+use proto::pb::moonshot::v1 as moonshot;
+use proto::pb::pumpfun::v1 as pumpfun;
+use proto::pb::raydium::launchpad::v1 as raydium_launchpad;
+use substreams::store::{StoreGet, StoreGetString, StoreSet, StoreSetString};
+use substreams_database_change::tables::Row;
+use substreams_solana::base58;
+
+// Pump.fun global parameters, replaced by each `set_params`
+const INITIAL_VIRTUAL_TOKEN_RESERVES: &str = "initial_virtual_token_reserves";
+const INITIAL_REAL_TOKEN_RESERVES: &str = "initial_real_token_reserves";
+const TOKEN_TOTAL_SUPPLY: &str = "token_total_supply";
+
+// Pump.fun global parameters since launch, used when no `set_params` was seen since the start block
+const DEFAULT_INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 1_073_000_000_000_000;
+const DEFAULT_INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+const DEFAULT_TOKEN_TOTAL_SUPPLY: u64 = 1_000_000_000_000_000;
+
+// Each curve field is stored under its own key: `<mint>:<field>`
+const TOTAL_SUPPLY: &str = "total_supply";
+const CURVE_SUPPLY: &str = "curve_supply";
+const VIRTUAL_TOKEN_OFFSET: &str = "virtual_token_offset";
+const DECIMALS: &str = "decimals";
+const CURVE_TYPE: &str = "curve_type";
+const MIGRATION_TARGET: &str = "migration_target";
+
+// Pump.fun tokens are always minted with 6 decimals
+const PUMPFUN_DECIMALS: u32 = 6;
+// Moonshot sells 80% of the supply on the curve, the rest is migrated with the collateral
+const MOONSHOT_CURVE_SUPPLY_BPS: u64 = 8_000;
+const SOL_DECIMALS: i32 = 9;
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+// Raydium Launchpad curve types, as set by `PoolCreateEvent.curve_param`
+const CURVE_CONSTANT: &str = "constant";
+const CURVE_FIXED: &str = "fixed";
+const CURVE_LINEAR: &str = "linear";
+
+pub fn store_pumpfun_params(events: &pumpfun::Events, store: &StoreSetString) {
+    let mut ordinal = 0;
+    for tx in events.transactions.iter() {
+        for ix in tx.instructions.iter() {
+            if let Some(pumpfun::instruction::Instruction::SetParams(params)) = &ix.instruction {
+                for (field, value) in [
+                    (INITIAL_VIRTUAL_TOKEN_RESERVES, params.initial_virtual_token_reserves),
+                    (INITIAL_REAL_TOKEN_RESERVES, params.initial_real_token_reserves),
+                    (TOKEN_TOTAL_SUPPLY, params.token_total_supply),
+                ] {
+                    store.set(ordinal, field, &value.to_string());
+                    ordinal += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Stores the curve parameters of the tokens created on Pump.fun, Moonshot & Raydium Launchpad.
+pub fn store_bonding_curves(
+    pumpfun_events: &pumpfun::Events,
+    moonshot_events: &moonshot::Events,
+    raydium_launchpad_events: &raydium_launchpad::Events,
+    pumpfun_params: &StoreGetString,
+    store: &StoreSetString,
+) {
+    let mut writer = CurveWriter { store, ordinal: 0 };
+
+    for tx in pumpfun_events.transactions.iter() {
+        for ix in tx.instructions.iter() {
+            let Some(pumpfun::instruction::Instruction::Create(data)) = &ix.instruction else {
+                continue;
+            };
+            let Some(accounts) = &data.accounts else {
+                continue;
+            };
+            // curves are initialized with the global parameters at creation
+            let param = |field: &str, default: u64| pumpfun_params.get_last(field).and_then(|value| value.parse::<u64>().ok()).unwrap_or(default);
+            let curve_supply = param(INITIAL_REAL_TOKEN_RESERVES, DEFAULT_INITIAL_REAL_TOKEN_RESERVES);
+            let virtual_token_offset = param(INITIAL_VIRTUAL_TOKEN_RESERVES, DEFAULT_INITIAL_VIRTUAL_TOKEN_RESERVES).saturating_sub(curve_supply);
+            writer.set(&accounts.mint, TOTAL_SUPPLY, param(TOKEN_TOTAL_SUPPLY, DEFAULT_TOKEN_TOTAL_SUPPLY));
+            writer.set(&accounts.mint, CURVE_SUPPLY, curve_supply);
+            writer.set(&accounts.mint, VIRTUAL_TOKEN_OFFSET, virtual_token_offset);
+            writer.set(&accounts.mint, DECIMALS, PUMPFUN_DECIMALS);
+        }
+    }
+
+    for tx in moonshot_events.transactions.iter() {
+        for ix in tx.instructions.iter() {
+            let Some(moonshot::instruction::Instruction::TokenMint(data)) = &ix.instruction else {
+                continue;
+            };
+            let Some(accounts) = &data.accounts else {
+                continue;
+            };
+            let curve_supply = (data.amount as u128 * MOONSHOT_CURVE_SUPPLY_BPS as u128 / 10_000) as u64;
+            writer.set(&accounts.mint, TOTAL_SUPPLY, data.amount);
+            writer.set(&accounts.mint, CURVE_SUPPLY, curve_supply);
+            writer.set(&accounts.mint, DECIMALS, data.decimals);
+            writer.set(&accounts.mint, MIGRATION_TARGET, data.migration_target);
+        }
+    }
+
+    for tx in raydium_launchpad_events.transactions.iter() {
+        for ix in tx.instructions.iter() {
+            let Some(raydium_launchpad::instruction::Instruction::PoolCreateEvent(event)) = &ix.instruction else {
+                continue;
+            };
+            let (curve_type, supply) = match event.curve_param.as_ref().and_then(|param| param.curve.as_ref()) {
+                Some(raydium_launchpad::curve_params::Curve::Constant(curve)) => (CURVE_CONSTANT, curve.supply),
+                Some(raydium_launchpad::curve_params::Curve::Fixed(curve)) => (CURVE_FIXED, curve.supply),
+                Some(raydium_launchpad::curve_params::Curve::Linear(curve)) => (CURVE_LINEAR, curve.supply),
+                None => continue,
+            };
+            writer.set(&event.base_mint, TOTAL_SUPPLY, supply);
+            writer.set(&event.base_mint, DECIMALS, event.base_mint_param.as_ref().map_or(0, |param| param.decimals));
+            writer.set(&event.base_mint, CURVE_TYPE, curve_type);
+        }
+    }
+}
+
+struct CurveWriter<'a> {
+    store: &'a StoreSetString,
+    ordinal: u64,
+}
+
+impl CurveWriter<'_> {
+    fn set(&mut self, mint: &[u8], field: &str, value: impl ToString) {
+        self.store.set(self.ordinal, key(mint, field), &value.to_string());
+        self.ordinal += 1;
+    }
+}
+
+/// Parameters of a bonding curve, as stored at the token creation.
+pub struct CurveParams {
+    pub total_supply: u64,
+    pub curve_supply: u64, // tokens sold on the curve until completion (Pump.fun & Moonshot)
+    pub virtual_token_offset: u64,
+    pub decimals: u32,
+    pub curve_type: String,
+    pub migration_target: u32, // Moonshot: 0 = Raydium, 1 = Meteora
+}
+
+impl CurveParams {
+    /// Returns `None` when the token was created before the start block of the store.
+    pub fn get(store: &StoreGetString, mint: &[u8]) -> Option<Self> {
+        let get = |field: &str| store.get_last(key(mint, field));
+        let number = |field: &str| get(field).and_then(|value| value.parse::<u64>().ok()).unwrap_or(0);
+        Some(Self {
+            total_supply: get(TOTAL_SUPPLY)?.parse().ok()?,
+            curve_supply: number(CURVE_SUPPLY),
+            virtual_token_offset: number(VIRTUAL_TOKEN_OFFSET),
+            decimals: number(DECIMALS) as u32,
+            curve_type: get(CURVE_TYPE).unwrap_or_default(),
+            migration_target: number(MIGRATION_TARGET) as u32,
+        })
+    }
+
+    /// SOL price of a whole token, from a raw `lamports / token` price.
+    fn price(&self, raw_price: f64) -> f64 {
+        raw_price * 10f64.powi(self.decimals as i32 - SOL_DECIMALS)
+    }
+
+    /// Fully-diluted market cap in SOL, from a raw `lamports / token` price.
+    fn market_cap(&self, raw_price: f64) -> f64 {
+        raw_price * self.total_supply as f64 / 10f64.powi(SOL_DECIMALS)
+    }
+}
+
+/// Curve progress, price & market cap after a launchpad trade.
+#[derive(Default)]
+pub struct CurveMetrics {
+    pub progress: f64,   // percent of the curve supply sold
+    pub price: f64,      // SOL per token
+    pub market_cap: f64, // fully-diluted, in SOL
+}
+
+impl CurveMetrics {
+    /// Pump.fun: spot price from the virtual reserves, progress from the real token reserves.
+    pub fn pumpfun(params: &CurveParams, event: &pumpfun::TradeEvent) -> Self {
+        let raw_price = ratio(event.virtual_sol_reserves, event.virtual_token_reserves);
+        // older events do not include the real reserves, they are derived from the virtual reserves
+        let real_token_reserves = event
+            .real_token_reserves
+            .unwrap_or(event.virtual_token_reserves.saturating_sub(params.virtual_token_offset));
+        Self {
+            progress: percent(params.curve_supply.saturating_sub(real_token_reserves), params.curve_supply),
+            price: params.price(raw_price),
+            market_cap: params.market_cap(raw_price),
+        }
+    }
+
+    /// Moonshot: trade execution price, progress from the tokens left on the curve.
+    pub fn moonshot(params: &CurveParams, event: &moonshot::TradeEvent, curve_token_reserves: u64) -> Self {
+        let raw_price = ratio(event.collateral_amount, event.amount);
+        let sold = params.total_supply.saturating_sub(curve_token_reserves);
+        Self {
+            progress: percent(sold, params.curve_supply),
+            price: params.price(raw_price),
+            market_cap: params.market_cap(raw_price),
+        }
+    }
+
+    /// Raydium Launchpad: spot price of the curve, progress from the base tokens sold.
+    ///
+    /// The price & market cap are only computed for SOL quoted pools.
+    pub fn raydium_launchpad(params: &CurveParams, event: &raydium_launchpad::TradeEvent, quote_mint: &[u8]) -> Self {
+        let progress = percent(event.real_base_after, event.total_base_sell);
+        if base58::encode(quote_mint) != WSOL_MINT {
+            return Self {
+                progress,
+                ..Default::default()
+            };
+        }
+        let raw_price = match params.curve_type.as_str() {
+            CURVE_CONSTANT => ratio(
+                event.virtual_quote.saturating_add(event.real_quote_after),
+                event.virtual_base.saturating_sub(event.real_base_after),
+            ),
+            CURVE_FIXED => ratio(event.virtual_quote, event.virtual_base),
+            // linear curves have no closed form spot price in the event, the execution price is used instead
+            _ if event.trade_direction == raydium_launchpad::TradeDirection::Buy as i32 => ratio(event.amount_in, event.amount_out),
+            _ => ratio(event.amount_out, event.amount_in),
+        };
+        Self {
+            progress,
+            price: params.price(raw_price),
+            market_cap: params.market_cap(raw_price),
+        }
+    }
+
+    pub fn set(&self, row: &mut Row) {
+        row.set("curve_progress", self.progress.to_string())
+            .set("price", self.price.to_string())
+            .set("market_cap", self.market_cap.to_string());
+    }
+}
+
+fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+    numerator as f64 / denominator as f64
+}
+
+fn percent(value: u64, total: u64) -> f64 {
+    (ratio(value, total) * 100.0).min(100.0)
+}
+
+fn key(mint: &[u8], field: &str) -> String {
+    format!("{}:{}", base58::encode(mint), field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() <= expected.abs() * 1e-9, "{actual} != {expected}");
+    }
+
+    // Pump.fun curve created with the default global parameters
+    fn pumpfun_params() -> CurveParams {
+        CurveParams {
+            total_supply: DEFAULT_TOKEN_TOTAL_SUPPLY,
+            curve_supply: DEFAULT_INITIAL_REAL_TOKEN_RESERVES,
+            virtual_token_offset: DEFAULT_INITIAL_VIRTUAL_TOKEN_RESERVES - DEFAULT_INITIAL_REAL_TOKEN_RESERVES,
+            decimals: PUMPFUN_DECIMALS,
+            curve_type: String::new(),
+        }
+    }
+
+    fn pumpfun_trade(virtual_sol_reserves: u64, virtual_token_reserves: u64, real_token_reserves: Option<u64>) -> pumpfun::TradeEvent {
+        pumpfun::TradeEvent {
+            virtual_sol_reserves,
+            virtual_token_reserves,
+            real_token_reserves,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn pumpfun_start() {
+        let metrics = CurveMetrics::pumpfun(
+            &pumpfun_params(),
+            &pumpfun_trade(30_000_000_000, 1_073_000_000_000_000, Some(793_100_000_000_000)),
+        );
+        assert_eq!(metrics.progress, 0.0);
+        assert_close(metrics.price, 2.7958993476234855e-8);
+        assert_close(metrics.market_cap, 27.958993476234856);
+    }
+
+    #[test]
+    fn pumpfun_mid_curve() {
+        let metrics = CurveMetrics::pumpfun(
+            &pumpfun_params(),
+            &pumpfun_trade(47_586_665_681, 676_450_000_000_000, Some(396_550_000_000_000)),
+        );
+        assert_close(metrics.progress, 50.0);
+        assert_close(metrics.price, 7.034764680464188e-8);
+        assert_close(metrics.market_cap, 70.34764680464188);
+    }
+
+    #[test]
+    fn pumpfun_complete() {
+        let metrics = CurveMetrics::pumpfun(&pumpfun_params(), &pumpfun_trade(115_005_359_056, 279_900_000_000_000, Some(0)));
+        assert_eq!(metrics.progress, 100.0);
+        assert_close(metrics.price, 4.108801681171847e-7);
+        assert_close(metrics.market_cap, 410.88016811718467);
+    }
+
+    #[test]
+    fn pumpfun_without_real_reserves() {
+        // older events: real token reserves derived from the virtual reserves
+        let metrics = CurveMetrics::pumpfun(&pumpfun_params(), &pumpfun_trade(47_586_665_681, 676_450_000_000_000, None));
+        assert_close(metrics.progress, 50.0);
+    }
+
+    // Moonshot token minted with 1B tokens & 9 decimals, 80% sold on the curve
+    fn moonshot_params() -> CurveParams {
+        CurveParams {
+            total_supply: 1_000_000_000_000_000_000,
+            curve_supply: 800_000_000_000_000_000,
+            virtual_token_offset: 0,
+            decimals: 9,
+            curve_type: String::new(),
+        }
+    }
+
+    fn moonshot_trade(amount: u64, collateral_amount: u64) -> moonshot::TradeEvent {
+        moonshot::TradeEvent {
+            amount,
+            collateral_amount,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn moonshot_start() {
+        let metrics = CurveMetrics::moonshot(&moonshot_params(), &moonshot_trade(1_000_000_000, 30), 1_000_000_000_000_000_000);
+        assert_eq!(metrics.progress, 0.0);
+        assert_close(metrics.price, 3e-8);
+        assert_close(metrics.market_cap, 30.0);
+    }
+
+    #[test]
+    fn moonshot_mid_curve() {
+        let metrics = CurveMetrics::moonshot(&moonshot_params(), &moonshot_trade(1_000_000_000, 120), 600_000_000_000_000_000);
+        assert_close(metrics.progress, 50.0);
+        assert_close(metrics.price, 1.2e-7);
+        assert_close(metrics.market_cap, 120.0);
+    }
+
+    #[test]
+    fn moonshot_complete() {
+        // tokens left above the curve supply are migrated, progress is capped
+        let metrics = CurveMetrics::moonshot(&moonshot_params(), &moonshot_trade(1_000_000_000, 400), 150_000_000_000_000_000);
+        assert_eq!(metrics.progress, 100.0);
+        assert_close(metrics.market_cap, 400.0);
+    }
+
+    // Raydium Launchpad constant product curve, 1B tokens with 6 decimals
+    fn raydium_launchpad_params() -> CurveParams {
+        CurveParams {
+            total_supply: 1_000_000_000_000_000,
+            curve_supply: 0,
+            virtual_token_offset: 0,
+            decimals: 6,
+            curve_type: CURVE_CONSTANT.to_string(),
+        }
+    }
+
+    fn raydium_launchpad_trade(real_base_after: u64, real_quote_after: u64) -> raydium_launchpad::TradeEvent {
+        raydium_launchpad::TradeEvent {
+            total_base_sell: 793_100_000_000_000,
+            virtual_base: 1_073_025_605_596_382,
+            virtual_quote: 30_000_852_951,
+            real_base_after,
+            real_quote_after,
+            ..Default::default()
+        }
+    }
+
+    fn wsol() -> Vec<u8> {
+        base58::decode(WSOL_MINT).unwrap()
+    }
+
+    #[test]
+    fn raydium_launchpad_start() {
+        let metrics = CurveMetrics::raydium_launchpad(&raydium_launchpad_params(), &raydium_launchpad_trade(0, 0), &wsol());
+        assert_eq!(metrics.progress, 0.0);
+        assert_close(metrics.price, 2.7959121193874663e-8);
+        assert_close(metrics.market_cap, 27.959121193874665);
+    }
+
+    #[test]
+    fn raydium_launchpad_mid_curve() {
+        let metrics = CurveMetrics::raydium_launchpad(
+            &raydium_launchpad_params(),
+            &raydium_launchpad_trade(396_550_000_000_000, 17_586_499_999),
+            &wsol(),
+        );
+        assert_close(metrics.progress, 50.0);
+        assert_close(metrics.price, 7.034599999810328e-8);
+        assert_close(metrics.market_cap, 70.34599999810327);
+    }
+
+    #[test]
+    fn raydium_launchpad_complete() {
+        let metrics = CurveMetrics::raydium_launchpad(
+            &raydium_launchpad_params(),
+            &raydium_launchpad_trade(793_100_000_000_000, 84_999_999_999),
+            &wsol(),
+        );
+        assert_eq!(metrics.progress, 100.0);
+        assert_close(metrics.price, 4.108264862194028e-7);
+        assert_close(metrics.market_cap, 410.8264862194028);
+    }
+
+    #[test]
+    fn raydium_launchpad_non_sol_quote() {
+        let metrics = CurveMetrics::raydium_launchpad(
+            &raydium_launchpad_params(),
+            &raydium_launchpad_trade(396_550_000_000_000, 17_586_499_999),
+            &[1; 32],
+        );
+        assert_close(metrics.progress, 50.0);
+        assert_eq!(metrics.price, 0.0);
+        assert_eq!(metrics.market_cap, 0.0);
+    }
+}
@@ -0,0 +1,73 @@
+use common::db::{common_key_v2, set_clock};
+use proto::pb::bonk::swap::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::{Row, Tables};
+use substreams_solana::base58;
+
+use crate::aggregators::set_aggregator;
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
+            if let Some(pb::instruction::Instruction::SwapInstruction(data)) = &ix.instruction {
+                handle_swap(tables, clock, tx, ix, data, transaction_index, instruction_index);
+            }
+        }
+    }
+}
+
+fn handle_swap(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::SwapInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("bonk_swap_swap", key)
+        .set("delta_in", data.delta_in)
+        .set("price_limit", &data.price_limit)
+        .set("x_to_y", data.x_to_y)
+        .set("user", base58::encode(&data.user))
+        .set("input_mint", base58::encode(&data.input_mint))
+        .set("input_amount", data.input_amount)
+        .set("output_mint", base58::encode(&data.output_mint))
+        .set("output_amount", data.output_amount);
+    if let Some(accounts) = &data.accounts {
+        set_swap_accounts(accounts, row);
+    }
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+}
+
+fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
+    row.set("signature", base58::encode(&tx.signature))
+        .set("fee_payer", base58::encode(&tx.fee_payer))
+        .set("signers_raw", tx.signers.iter().map(base58::encode).collect::<Vec<_>>().join(","))
+        .set("fee", tx.fee)
+        .set("compute_units_consumed", tx.compute_units_consumed);
+}
+
+fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
+    row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+}
+
+fn set_swap_accounts(accounts: &pb::SwapAccounts, row: &mut Row) {
+    let optional = |account: &Option<Vec<u8>>| account.as_ref().map(base58::encode).unwrap_or_default();
+    row.set("pool", base58::encode(&accounts.pool))
+        .set("token_x", base58::encode(&accounts.token_x))
+        .set("token_y", base58::encode(&accounts.token_y))
+        .set("pool_x_account", base58::encode(&accounts.pool_x_account))
+        .set("pool_y_account", base58::encode(&accounts.pool_y_account))
+        .set("swapper_x_account", base58::encode(&accounts.swapper_x_account))
+        .set("swapper_y_account", base58::encode(&accounts.swapper_y_account))
+        .set("swapper", base58::encode(&accounts.swapper))
+        .set("referrer_x_account", optional(&accounts.referrer_x_account))
+        .set("referrer_y_account", optional(&accounts.referrer_y_account))
+        .set("referrer", optional(&accounts.referrer));
+}
@@ -4,8 +4,13 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::Tables;
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::launches::{insert_token_launch, LaunchStage, TokenLaunch};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (tx_idx, tx) in events.transactions.iter().enumerate() {
+        // trade logs are paired with their buy & sell instruction by position
+        let mut trades = trade_instructions(tx);
         for (log_idx, log) in tx.logs.iter().enumerate() {
             let key = common_key_v2(clock, tx_idx, log_idx);
             match &log.log {
@@ -20,6 +25,9 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         .set("fee_payer", base58::encode(&tx.fee_payer))
                         .set("fee", tx.fee)
                         .set("compute_units_consumed", tx.compute_units_consumed);
+                    if let Some(ix) = trades.next() {
+                        set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+                    }
                     set_clock(clock, row);
                 }
                 Some(pb::log::Log::Sold(event)) => {
@@ -33,10 +41,57 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         .set("fee_payer", base58::encode(&tx.fee_payer))
                         .set("fee", tx.fee)
                         .set("compute_units_consumed", tx.compute_units_consumed);
+                    if let Some(ix) = trades.next() {
+                        set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+                    }
                     set_clock(clock, row);
                 }
+                Some(pb::log::Log::Created(event)) => {
+                    let launch = TokenLaunch {
+                        launchpad: &log.program_id,
+                        mint: &event.mint,
+                        creator: &tx.fee_payer,
+                        name: &event.name,
+                        symbol: &event.symbol,
+                        uri: &event.uri,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Create, launch);
+                }
+                Some(pb::log::Log::Graduated(event)) => {
+                    let launch = TokenLaunch {
+                        launchpad: &log.program_id,
+                        mint: &event.mint,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Complete, launch);
+                }
                 _ => {}
             }
         }
     }
 }
+
+/// Buy & sell instructions of the transaction, empty when they can't be paired with the trade logs.
+fn trade_instructions(tx: &pb::Transaction) -> std::vec::IntoIter<&pb::Instruction> {
+    let instructions: Vec<_> = tx
+        .instructions
+        .iter()
+        .filter(|ix| {
+            matches!(
+                ix.instruction,
+                Some(pb::instruction::Instruction::Buy(_) | pb::instruction::Instruction::Sell(_))
+            )
+        })
+        .collect();
+    let logs = tx
+        .logs
+        .iter()
+        .filter(|log| matches!(log.log, Some(pb::log::Log::Bought(_) | pb::log::Log::Sold(_))))
+        .count();
+    if instructions.len() == logs {
+        instructions.into_iter()
+    } else {
+        Vec::new().into_iter()
+    }
+}
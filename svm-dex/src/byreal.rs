@@ -1,9 +1,11 @@
 use common::db::{common_key_v2, set_clock};
 use proto::pb::byreal::v1 as pb;
 use substreams::pb::substreams::Clock;
-use substreams_database_change::tables::Tables;
+use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (tx_idx, tx) in events.transactions.iter().enumerate() {
         for (ix_idx, ix) in tx.instructions.iter().enumerate() {
@@ -11,13 +13,35 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                 let key = common_key_v2(clock, tx_idx, ix_idx);
                 let row = tables.create_row("byreal_swap", key)
                     .set("amount_in", data.amount_in)
-                    .set("minimum_amount_out", data.minimum_amount_out);
+                    .set("minimum_amount_out", data.minimum_amount_out)
+                    .set("user", base58::encode(&data.user))
+                    .set("input_mint", base58::encode(&data.input_mint))
+                    .set("input_amount", data.input_amount)
+                    .set("output_mint", base58::encode(&data.output_mint))
+                    .set("output_amount", data.output_amount);
                 row.set("signature", base58::encode(&tx.signature))
                     .set("fee_payer", base58::encode(&tx.fee_payer))
                     .set("fee", tx.fee)
                     .set("compute_units_consumed", tx.compute_units_consumed);
+                if let Some(accounts) = &data.accounts {
+                    set_swap_accounts(accounts, row);
+                }
+                set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
                 set_clock(clock, row);
             }
         }
     }
 }
+
+fn set_swap_accounts(accounts: &pb::SwapAccounts, row: &mut Row) {
+    row.set("payer", base58::encode(&accounts.payer))
+        .set("amm_config", base58::encode(&accounts.amm_config))
+        .set("pool_state", base58::encode(&accounts.pool_state))
+        .set("input_token_account", base58::encode(&accounts.input_token_account))
+        .set("output_token_account", base58::encode(&accounts.output_token_account))
+        .set("input_vault", base58::encode(&accounts.input_vault))
+        .set("output_vault", base58::encode(&accounts.output_vault))
+        .set("observation_state", base58::encode(&accounts.observation_state))
+        .set("token_program", base58::encode(&accounts.token_program))
+        .set("tick_array", base58::encode(&accounts.tick_array));
+}
@@ -4,15 +4,39 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::launches::{insert_token_launch, LaunchStage, TokenLaunch};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        // trade logs are paired with their buy & sell instruction by position
+        let mut trades = trade_instructions(tx);
         for (log_index, log) in tx.logs.iter().enumerate() {
             match &log.log {
                 Some(pb::log::Log::Buy(event)) => {
-                    handle_buy(tables, clock, tx, event, transaction_index, log_index);
+                    handle_buy(tables, clock, tx, trades.next(), event, transaction_index, log_index);
                 }
                 Some(pb::log::Log::Sell(event)) => {
-                    handle_sell(tables, clock, tx, event, transaction_index, log_index);
+                    handle_sell(tables, clock, tx, trades.next(), event, transaction_index, log_index);
+                }
+                Some(pb::log::Log::TokenCreated(event)) => {
+                    let launch = TokenLaunch {
+                        launchpad: &log.program_id,
+                        mint: &event.mint,
+                        creator: &event.creator,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Create, launch);
+                }
+                // the pool is drained once the curve is sold
+                Some(pb::log::Log::DrainPool(event)) => {
+                    let launch = TokenLaunch {
+                        launchpad: &log.program_id,
+                        mint: &event.mint,
+                        bonding_curve: &event.pool,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Complete, launch);
                 }
                 _ => {}
             }
@@ -20,7 +44,15 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     }
 }
 
-fn handle_buy(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, event: &pb::BuyTokenEvent, transaction_index: usize, instruction_index: usize) {
+fn handle_buy(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: Option<&pb::Instruction>,
+    event: &pb::BuyTokenEvent,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
     let key = common_key_v2(clock, transaction_index, instruction_index);
     let row = tables
         .create_row("dumpfun_buy", key)
@@ -29,11 +61,22 @@ fn handle_buy(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, event: &
         .set("sol_in", event.sol_in)
         .set("token_out", event.token_out)
         .set("buy_time", event.buy_time);
+    if let Some(ix) = ix {
+        set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+    }
     set_transaction(tx, row);
     set_clock(clock, row);
 }
 
-fn handle_sell(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, event: &pb::SellTokenEvent, transaction_index: usize, instruction_index: usize) {
+fn handle_sell(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: Option<&pb::Instruction>,
+    event: &pb::SellTokenEvent,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
     let key = common_key_v2(clock, transaction_index, instruction_index);
     let row = tables
         .create_row("dumpfun_sell", key)
@@ -42,6 +85,9 @@ fn handle_sell(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, event:
         .set("token_in", event.token_in)
         .set("sol_out", event.sol_out)
         .set("sell_time", event.sell_time);
+    if let Some(ix) = ix {
+        set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+    }
     set_transaction(tx, row);
     set_clock(clock, row);
 }
@@ -53,3 +99,18 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
         .set("fee", tx.fee)
         .set("compute_units_consumed", tx.compute_units_consumed);
 }
+
+/// Buy & sell instructions of the transaction, empty when they can't be paired with the trade logs.
+fn trade_instructions(tx: &pb::Transaction) -> std::vec::IntoIter<&pb::Instruction> {
+    let instructions: Vec<_> = tx.instructions.iter().filter(|ix| ix.instruction.is_some()).collect();
+    let logs = tx
+        .logs
+        .iter()
+        .filter(|log| matches!(log.log, Some(pb::log::Log::Buy(_) | pb::log::Log::Sell(_))))
+        .count();
+    if instructions.len() == logs {
+        instructions.into_iter()
+    } else {
+        Vec::new().into_iter()
+    }
+}
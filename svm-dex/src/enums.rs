@@ -22,3 +22,52 @@ impl From<Direction> for String {
         dir.as_str().to_owned() // creates an owned `String`
     }
 }
+
+/// Raydium AMM v4 `SetParams` param.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromPrimitive)]
+#[repr(u32)]
+pub enum AmmParam {
+    Status = 0,
+    State = 1,
+    OrderNum = 2,
+    Depth = 3,
+    AmountWave = 4,
+    MinPriceMultiplier = 5,
+    MaxPriceMultiplier = 6,
+    MinSize = 7,
+    VolMaxCutRatio = 8,
+    Fees = 9,
+    AmmOwner = 10,
+    SetOpenTime = 11,
+    LastOrderDistance = 12,
+    InitOrderDepth = 13,
+    SetSwitchTime = 14,
+    ClearOpenTime = 15,
+    Seperate = 16,
+    UpdateOpenOrder = 17,
+}
+
+impl AmmParam {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            AmmParam::Status => "Status",
+            AmmParam::State => "State",
+            AmmParam::OrderNum => "OrderNum",
+            AmmParam::Depth => "Depth",
+            AmmParam::AmountWave => "AmountWave",
+            AmmParam::MinPriceMultiplier => "MinPriceMultiplier",
+            AmmParam::MaxPriceMultiplier => "MaxPriceMultiplier",
+            AmmParam::MinSize => "MinSize",
+            AmmParam::VolMaxCutRatio => "VolMaxCutRatio",
+            AmmParam::Fees => "Fees",
+            AmmParam::AmmOwner => "AmmOwner",
+            AmmParam::SetOpenTime => "SetOpenTime",
+            AmmParam::LastOrderDistance => "LastOrderDistance",
+            AmmParam::InitOrderDepth => "InitOrderDepth",
+            AmmParam::SetSwitchTime => "SetSwitchTime",
+            AmmParam::ClearOpenTime => "ClearOpenTime",
+            AmmParam::Seperate => "Seperate",
+            AmmParam::UpdateOpenOrder => "UpdateOpenOrder",
+        }
+    }
+}
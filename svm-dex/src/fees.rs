@@ -0,0 +1,86 @@
+use common::db::{common_key_v2, set_clock};
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+/// Creator fee movement, accrued by the trades and claimed from the creator vault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreatorFeeAction {
+    Accrue,
+    Claim,
+    SetCreator,
+}
+
+impl CreatorFeeAction {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            CreatorFeeAction::Accrue => "accrue",
+            CreatorFeeAction::Claim => "claim",
+            CreatorFeeAction::SetCreator => "set_creator",
+        }
+    }
+}
+
+/// Creator fee event, normalized across Pump.fun bonding curve & AMM.
+///
+/// Claims are made for all the coins of a creator, the mint & pool are left empty.
+#[derive(Default)]
+pub struct CreatorFee<'a> {
+    pub program_id: &'a [u8],
+    pub mint: &'a [u8],
+    pub pool: &'a [u8],
+    pub creator: &'a [u8],
+    pub quote_mint: &'a [u8],
+    pub fee_basis_points: u64,
+    pub amount: u64,
+}
+
+/// Protocol fee charged by a trade.
+pub struct ProtocolFee<'a> {
+    pub program_id: &'a [u8],
+    pub mint: &'a [u8],
+    pub pool: &'a [u8],
+    pub quote_mint: &'a [u8],
+    pub fee_recipient: &'a [u8],
+    pub fee_basis_points: u64,
+    pub amount: u64,
+}
+
+/// Inserts a `creator_fees` row, `instruction_index` is the index of the trade or claim within the transaction.
+pub fn insert_creator_fee(
+    tables: &mut Tables,
+    clock: &Clock,
+    signature: &[u8],
+    transaction_index: usize,
+    instruction_index: usize,
+    action: CreatorFeeAction,
+    fee: CreatorFee,
+) {
+    let row = tables
+        .create_row("creator_fees", common_key_v2(clock, transaction_index, instruction_index))
+        .set("signature", base58::encode(signature))
+        .set("program_id", base58::encode(fee.program_id))
+        .set("action", action.as_str())
+        .set("mint", base58::encode(fee.mint))
+        .set("pool", base58::encode(fee.pool))
+        .set("creator", base58::encode(fee.creator))
+        .set("quote_mint", base58::encode(fee.quote_mint))
+        .set("fee_basis_points", fee.fee_basis_points)
+        .set("amount", fee.amount);
+    set_clock(clock, row);
+}
+
+/// Inserts a `protocol_fees` row, `instruction_index` is the index of the trade within the transaction.
+pub fn insert_protocol_fee(tables: &mut Tables, clock: &Clock, signature: &[u8], transaction_index: usize, instruction_index: usize, fee: ProtocolFee) {
+    let row = tables
+        .create_row("protocol_fees", common_key_v2(clock, transaction_index, instruction_index))
+        .set("signature", base58::encode(signature))
+        .set("program_id", base58::encode(fee.program_id))
+        .set("mint", base58::encode(fee.mint))
+        .set("pool", base58::encode(fee.pool))
+        .set("quote_mint", base58::encode(fee.quote_mint))
+        .set("fee_recipient", base58::encode(fee.fee_recipient))
+        .set("fee_basis_points", fee.fee_basis_points)
+        .set("amount", fee.amount);
+    set_clock(clock, row);
+}
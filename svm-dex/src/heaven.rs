@@ -4,11 +4,26 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::launches::{insert_token_launch, LaunchStage, TokenLaunch};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (log_index, log) in tx.logs.iter().enumerate() {
             if let Some(pb::log::Log::Sell(event)) = &log.log {
-                handle_sell(tables, clock, tx, event, transaction_index, log_index);
+                handle_sell(tables, clock, tx, log, event, transaction_index, log_index);
+            }
+        }
+        for ix in tx.instructions.iter() {
+            if let Some(pb::instruction::Instruction::Create(data)) = &ix.instruction {
+                // the creator is not exposed by the instruction, the fee payer signs the launch
+                let launch = TokenLaunch {
+                    launchpad: &ix.program_id,
+                    mint: &data.mint,
+                    creator: &tx.fee_payer,
+                    ..Default::default()
+                };
+                insert_token_launch(tables, clock, &tx.signature, LaunchStage::Create, launch);
             }
         }
     }
@@ -18,6 +33,7 @@ fn handle_sell(
     tables: &mut Tables,
     clock: &Clock,
     tx: &pb::Transaction,
+    log: &pb::Log,
     event: &pb::SellLog,
     transaction_index: usize,
     instruction_index: usize,
@@ -28,6 +44,7 @@ fn handle_sell(
         .set("user", base58::encode(&event.user))
         .set("mint", base58::encode(&event.mint))
         .set("amount", event.amount);
+    set_log(log, row);
     set_transaction(tx, row);
     set_clock(clock, row);
 }
@@ -39,3 +56,8 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
         .set("fee", tx.fee)
         .set("compute_units_consumed", tx.compute_units_consumed);
 }
+
+fn set_log(log: &pb::Log, row: &mut Row) {
+    row.set("program_id", base58::encode(&log.program_id));
+    set_aggregator(&log.parent_program_id, &log.outer_program_id, row);
+}
@@ -3,12 +3,67 @@ use proto::pb::jupiter::v1 as pb;
 use substreams::pb::substreams::Clock;
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_events(tables: &mut substreams_database_change::tables::Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, transaction) in events.transactions.iter().enumerate() {
+        // events are linked to the route instruction emitting them,
+        // Jupiter V4 routes are not decoded: the first event of each invocation identifies the route
+        let mut route_index: Option<usize> = None;
+        let mut invocation_index = 0;
         for (instruction_index, instruction) in transaction.instructions.iter().enumerate() {
+            if is_outer_instruction(instruction) {
+                route_index = Some(instruction_index);
+            } else if instruction.invocation_index != 0 && instruction.invocation_index != invocation_index {
+                invocation_index = instruction.invocation_index;
+                route_index = Some(instruction_index);
+            }
             match &instruction.instruction {
                 Some(pb::instruction::Instruction::SwapEvent(event)) => {
-                    handle_swap(tables, clock, transaction, instruction, event, transaction_index, instruction_index);
+                    let route_index = route_index.unwrap_or(instruction_index);
+                    handle_swap(
+                        tables,
+                        clock,
+                        transaction,
+                        instruction,
+                        event,
+                        route_index,
+                        transaction_index,
+                        instruction_index,
+                    );
+                }
+                Some(pb::instruction::Instruction::FeeEvent(event)) => {
+                    let route_index = route_index.unwrap_or(instruction_index);
+                    handle_fee(
+                        tables,
+                        clock,
+                        transaction,
+                        instruction,
+                        event,
+                        route_index,
+                        transaction_index,
+                        instruction_index,
+                    );
+                }
+                Some(pb::instruction::Instruction::Route(data)) => {
+                    let trade = Trade::exact_in("route", data);
+                    handle_trade(tables, clock, transaction, instruction, trade, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::SharedAccountsRoute(data)) => {
+                    let trade = Trade::exact_in("shared_accounts_route", data);
+                    handle_trade(tables, clock, transaction, instruction, trade, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::ExactOutRoute(data)) => {
+                    let trade = Trade::exact_out("exact_out_route", data);
+                    handle_trade(tables, clock, transaction, instruction, trade, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::SharedAccountsExactOutRoute(data)) => {
+                    let trade = Trade::exact_out("shared_accounts_exact_out_route", data);
+                    handle_trade(tables, clock, transaction, instruction, trade, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::Other(data)) if !data.route.is_empty() => {
+                    let trade = Trade::undecoded(data);
+                    handle_trade(tables, clock, transaction, instruction, trade, transaction_index, instruction_index);
                 }
                 _ => {}
             }
@@ -22,12 +77,14 @@ fn handle_swap(
     transaction: &pb::Transaction,
     instruction: &pb::Instruction,
     data: &pb::SwapEvent,
+    route_index: usize,
     transaction_index: usize,
     instruction_index: usize,
 ) {
     let key = common_key_v2(&clock, transaction_index, instruction_index);
     let row = tables
         .create_row("jupiter_swap", key)
+        .set("route_instruction_index", route_index)
         .set("amm", base58::encode(&data.amm))
         .set("input_mint", base58::encode(&data.input_mint))
         .set("input_amount", data.input_amount)
@@ -35,6 +92,143 @@ fn handle_swap(
         .set("output_amount", data.output_amount);
 
     set_jupiter_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_jupiter_transaction_v2(transaction, row);
+    set_clock(clock, row);
+}
+
+fn handle_fee(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::FeeEvent,
+    route_index: usize,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let key = common_key_v2(&clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("jupiter_fee", key)
+        .set("route_instruction_index", route_index)
+        .set("account", base58::encode(&data.account))
+        .set("mint", base58::encode(&data.mint))
+        .set("amount", data.amount);
+
+    set_jupiter_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_jupiter_transaction_v2(transaction, row);
+    set_clock(clock, row);
+}
+
+// Jupiter V6 instructions which are not self CPI events, decoded or not, each start a new route
+fn is_outer_instruction(instruction: &pb::Instruction) -> bool {
+    matches!(
+        instruction.instruction,
+        Some(pb::instruction::Instruction::Route(_))
+            | Some(pb::instruction::Instruction::SharedAccountsRoute(_))
+            | Some(pb::instruction::Instruction::ExactOutRoute(_))
+            | Some(pb::instruction::Instruction::SharedAccountsExactOutRoute(_))
+            | Some(pb::instruction::Instruction::Other(_))
+    )
+}
+
+/// Route instruction (exact in or exact out) with the trade realized by its swaps.
+struct Trade<'a> {
+    route: &'a str,
+    accounts: Option<&'a pb::RouteAccounts>,
+    trade: Option<&'a pb::Trade>,
+    route_plan_steps: usize,
+    in_amount: u64,
+    quoted_out_amount: u64,
+    out_amount: u64,
+    quoted_in_amount: u64,
+    slippage_bps: u32,
+    platform_fee_bps: u32,
+}
+
+impl<'a> Trade<'a> {
+    fn exact_in(route: &'a str, data: &'a pb::RouteInstruction) -> Self {
+        Self {
+            route,
+            accounts: data.accounts.as_ref(),
+            trade: data.trade.as_ref(),
+            route_plan_steps: data.route_plan.len(),
+            in_amount: data.in_amount,
+            quoted_out_amount: data.quoted_out_amount,
+            out_amount: 0,
+            quoted_in_amount: 0,
+            slippage_bps: data.slippage_bps,
+            platform_fee_bps: data.platform_fee_bps,
+        }
+    }
+
+    // Route variants whose arguments are not decoded, only the trade is known
+    fn undecoded(data: &'a pb::OtherInstruction) -> Self {
+        Self {
+            route: &data.route,
+            accounts: None,
+            trade: data.trade.as_ref(),
+            route_plan_steps: 0,
+            in_amount: 0,
+            quoted_out_amount: 0,
+            out_amount: 0,
+            quoted_in_amount: 0,
+            slippage_bps: 0,
+            platform_fee_bps: 0,
+        }
+    }
+
+    fn exact_out(route: &'a str, data: &'a pb::ExactOutRouteInstruction) -> Self {
+        Self {
+            route,
+            accounts: data.accounts.as_ref(),
+            trade: data.trade.as_ref(),
+            route_plan_steps: data.route_plan.len(),
+            in_amount: 0,
+            quoted_out_amount: 0,
+            out_amount: data.out_amount,
+            quoted_in_amount: data.quoted_in_amount,
+            slippage_bps: data.slippage_bps,
+            platform_fee_bps: data.platform_fee_bps,
+        }
+    }
+}
+
+fn handle_trade(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: Trade,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let key = common_key_v2(&clock, transaction_index, instruction_index);
+    let accounts = data.accounts.cloned().unwrap_or_default();
+    let trade = data.trade.cloned().unwrap_or_default();
+    let row = tables
+        .create_row("jupiter_trades", key)
+        .set("route", data.route)
+        .set("user", base58::encode(&accounts.user_transfer_authority))
+        .set("source_token_account", base58::encode(&accounts.source_token_account))
+        .set("destination_token_account", base58::encode(&accounts.destination_token_account))
+        .set("platform_fee_account", base58::encode(&accounts.platform_fee_account))
+        .set("input_mint", base58::encode(&trade.input_mint))
+        .set("input_amount", trade.input_amount)
+        .set("output_mint", base58::encode(&trade.output_mint))
+        .set("output_amount", trade.output_amount)
+        .set("hops", trade.hops)
+        .set("route_plan_steps", data.route_plan_steps as u32)
+        .set("in_amount", data.in_amount)
+        .set("quoted_out_amount", data.quoted_out_amount)
+        .set("out_amount", data.out_amount)
+        .set("quoted_in_amount", data.quoted_in_amount)
+        .set("slippage_bps", data.slippage_bps)
+        .set("platform_fee_bps", data.platform_fee_bps);
+
+    set_jupiter_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_jupiter_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
@@ -0,0 +1,54 @@
+use common::db::set_clock;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+/// Lifecycle stage of a token launched on a bonding curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchStage {
+    Create,   // token & bonding curve created
+    Complete, // bonding curve fully sold
+    Graduate, // liquidity migrated to an AMM pool
+}
+
+impl LaunchStage {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            LaunchStage::Create => "create",
+            LaunchStage::Complete => "complete",
+            LaunchStage::Graduate => "graduate",
+        }
+    }
+}
+
+/// Token launch event, normalized across the launchpads.
+///
+/// Fields which are not exposed by the stage are left empty (ex: the metadata is only known at creation).
+#[derive(Default)]
+pub struct TokenLaunch<'a> {
+    pub launchpad: &'a [u8], // program id of the launchpad
+    pub mint: &'a [u8],
+    pub creator: &'a [u8],
+    pub name: &'a str,
+    pub symbol: &'a str,
+    pub uri: &'a str,
+    pub bonding_curve: &'a [u8],
+    pub graduation_program_id: &'a [u8],
+    pub graduation_pool: &'a [u8],
+}
+
+/// Inserts a `launch_events` row, keyed by mint & stage.
+pub fn insert_token_launch(tables: &mut Tables, clock: &Clock, signature: &[u8], stage: LaunchStage, launch: TokenLaunch) {
+    let row = tables
+        .create_row("launch_events", [("mint", base58::encode(launch.mint)), ("stage", stage.as_str().to_string())])
+        .set("launchpad", base58::encode(launch.launchpad))
+        .set("creator", base58::encode(launch.creator))
+        .set("name", launch.name)
+        .set("symbol", launch.symbol)
+        .set("uri", launch.uri)
+        .set("bonding_curve", base58::encode(launch.bonding_curve))
+        .set("graduation_program_id", base58::encode(launch.graduation_program_id))
+        .set("graduation_pool", base58::encode(launch.graduation_pool))
+        .set("signature", base58::encode(signature));
+    set_clock(clock, row);
+}
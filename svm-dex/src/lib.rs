@@ -1,4 +1,7 @@
+mod aggregators;
 mod aldrin;
+mod bonding_curves;
+mod bonk_swap;
 mod boop;
 mod byreal;
 mod darklake;
@@ -6,9 +9,11 @@ mod dflow;
 mod drift;
 mod dumpfun;
 mod enums;
+mod fees;
 mod goonfi;
 mod heaven;
 mod jupiter;
+mod launches;
 mod lifinity;
 mod meteora_amm;
 mod meteora_daam;
@@ -18,9 +23,13 @@ mod obric;
 mod okx_dex;
 mod openbook;
 mod orca;
+mod orderbooks;
 mod pancakeswap;
 mod phoenix;
 mod plasma;
+mod pools;
+mod positions;
+mod prices;
 mod pumpfun;
 mod pumpfun_amm;
 mod pumpswap;
@@ -28,20 +37,46 @@ mod raydium_amm_v4;
 mod raydium_clmm;
 mod raydium_cpmm;
 mod raydium_launchpad;
+mod reserves;
 mod sanctum;
 mod saros;
 mod serum;
 mod solfi;
 mod stabble;
+mod token_swap;
 
 use common::db::set_clock;
 use proto::pb;
+use substreams::store::{StoreGet, StoreGetInt64, StoreGetString, StoreNew, StoreSetInt64, StoreSetString};
 use substreams::{errors::Error, pb::substreams::Clock};
 use substreams_database_change::pb::sf::substreams::sink::database::v1::DatabaseChanges;
 
+#[substreams::handlers::store]
+pub fn store_pumpfun_params(pumpfun_events: pb::pumpfun::v1::Events, store: StoreSetString) {
+    bonding_curves::store_pumpfun_params(&pumpfun_events, &store);
+}
+
+#[substreams::handlers::store]
+pub fn store_bonding_curves(
+    pumpfun_events: pb::pumpfun::v1::Events,
+    moonshot_events: pb::moonshot::v1::Events,
+    raydium_launchpad_events: pb::raydium::launchpad::v1::Events,
+    pumpfun_params: StoreGetString,
+    store: StoreSetString,
+) {
+    bonding_curves::store_bonding_curves(&pumpfun_events, &moonshot_events, &raydium_launchpad_events, &pumpfun_params, &store);
+}
+
+#[substreams::handlers::store]
+pub fn store_dllm_bin_steps(meteora_dllm_events: pb::meteora::dllm::v1::Events, store: StoreSetInt64) {
+    meteora_dllm::store_bin_steps(&meteora_dllm_events, &store);
+}
+
 #[substreams::handlers::map]
 pub fn db_out(
     mut clock: Clock,
+    bonding_curves: StoreGetString,
+    dllm_bin_steps: StoreGetInt64,
     pumpfun_events: pb::pumpfun::v1::Events,
     pumpfun_amm_events: pb::pumpfun::amm::v1::Events,
     raydium_amm_v4_events: pb::raydium::amm::v1::Events,
@@ -74,22 +109,24 @@ pub fn db_out(
     drift_events: pb::drift::v1::Events,
     obric_v2_events: pb::obric::v2::v1::Events,
     obric_v3_events: pb::obric::v3::v1::Events,
-    okx_events: pb::okx::dex::v1::Events,
+    okx_dex_events: pb::okx::dex::v1::Events,
     sanctum_events: pb::sanctum::v1::Events,
     serum_events: pb::serum::v1::Events,
     solfi_v1_events: pb::solfi::v1::v1::Events,
     solfi_v2_events: pb::solfi::v2::v1::Events,
+    bonk_swap_events: pb::bonk::swap::v1::Events,
+    spl_token_swap_events: pb::solana::spl::token_swap::v1::Events,
 ) -> Result<DatabaseChanges, Error> {
     let mut tables = substreams_database_change::tables::Tables::new();
 
     // Process Events
-    pumpfun::process_events(&mut tables, &clock, &pumpfun_events);
+    pumpfun::process_events(&mut tables, &clock, &pumpfun_events, &bonding_curves);
     pumpfun_amm::process_events(&mut tables, &clock, &pumpfun_amm_events);
     raydium_amm_v4::process_events(&mut tables, &clock, &raydium_amm_v4_events);
     raydium_cpmm::process_events(&mut tables, &clock, &raydium_cpmm_events);
     raydium_clmm::process_events(&mut tables, &clock, &raydium_clmm_events);
-    raydium_launchpad::process_events(&mut tables, &clock, &raydium_launchpad_events);
-    meteora_dllm::process_events(&mut tables, &clock, &meteora_dllm_events);
+    raydium_launchpad::process_events(&mut tables, &clock, &raydium_launchpad_events, &bonding_curves);
+    meteora_dllm::process_events(&mut tables, &clock, &meteora_dllm_events, &dllm_bin_steps);
     meteora_daam::process_events(&mut tables, &clock, &meteora_daam_events);
     meteora_amm::process_events(&mut tables, &clock, &meteora_amm_events);
     jupiter::process_events(&mut tables, &clock, &jupiter_v4_events);
@@ -100,7 +137,7 @@ pub fn db_out(
     pumpswap::process_events(&mut tables, &clock, &pumpswap_events);
     darklake::process_events(&mut tables, &clock, &darklake_events);
     lifinity::process_events(&mut tables, &clock, &lifinity_events);
-    moonshot::process_events(&mut tables, &clock, &moonshot_events);
+    moonshot::process_events(&mut tables, &clock, &moonshot_events, &bonding_curves);
     pancakeswap::process_events(&mut tables, &clock, &pancakeswap_events);
     stabble::process_events(&mut tables, &clock, &stabble_events);
     dumpfun::process_events(&mut tables, &clock, &dumpfun_events);
@@ -115,11 +152,13 @@ pub fn db_out(
     drift::process_events(&mut tables, &clock, &drift_events);
     obric::process_v2_events(&mut tables, &clock, &obric_v2_events);
     obric::process_v3_events(&mut tables, &clock, &obric_v3_events);
-    okx_dex::process_events(&mut tables, &clock, &okx_events);
+    okx_dex::process_events(&mut tables, &clock, &okx_dex_events);
     sanctum::process_events(&mut tables, &clock, &sanctum_events);
     serum::process_events(&mut tables, &clock, &serum_events);
     solfi::process_v1_events(&mut tables, &clock, &solfi_v1_events);
     solfi::process_v2_events(&mut tables, &clock, &solfi_v2_events);
+    bonk_swap::process_events(&mut tables, &clock, &bonk_swap_events);
+    token_swap::process_events(&mut tables, &clock, &spl_token_swap_events);
 
     // ONLY include blocks if events are present
     if tables.all_row_count() > 0 {
@@ -4,8 +4,29 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::pools::{insert_pool, Pool};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        process_liquidity(tables, clock, tx, transaction_index);
+
+        for log in tx.logs.iter() {
+            if let Some(pb::log::Log::PoolCreated(event)) = &log.log {
+                // the pool creator is not part of the log
+                let pool = Pool {
+                    program_id: &log.program_id,
+                    pool: &event.pool,
+                    mint0: &event.token_a_mint,
+                    mint1: &event.token_b_mint,
+                    lp_mint: &event.lp_mint,
+                    creator: &tx.fee_payer,
+                    ..Default::default()
+                };
+                insert_pool(tables, clock, &tx.signature, pool);
+            }
+        }
+
         if tx.logs.len() != tx.instructions.len() {
             continue;
         }
@@ -49,6 +70,69 @@ fn handle_swap(
     set_clock(clock, row);
 }
 
+/// Liquidity logs do not reference the pool, they are matched in order with the liquidity instructions.
+fn process_liquidity(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, transaction_index: usize) {
+    let instructions: Vec<_> = tx
+        .instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(instruction_index, ix)| get_liquidity_accounts(ix).map(|accounts| (instruction_index, ix, accounts)))
+        .collect();
+    let logs: Vec<_> = tx
+        .logs
+        .iter()
+        .filter_map(|log| match &log.log {
+            Some(l @ (pb::log::Log::AddLiquidity(_) | pb::log::Log::RemoveLiquidity(_) | pb::log::Log::BootstrapLiquidity(_))) => Some(l),
+            _ => None,
+        })
+        .collect();
+    if instructions.len() != logs.len() {
+        return;
+    }
+
+    for ((instruction_index, ix, accounts), log) in instructions.into_iter().zip(logs) {
+        let key = common_key_v2(clock, transaction_index, instruction_index);
+        let row = match log {
+            pb::log::Log::AddLiquidity(l) => tables
+                .create_row("meteora_amm_add_liquidity", key)
+                .set("lp_mint_amount", l.lp_mint_amount)
+                .set("token_a_amount", l.token_a_amount)
+                .set("token_b_amount", l.token_b_amount),
+            pb::log::Log::RemoveLiquidity(l) => tables
+                .create_row("meteora_amm_remove_liquidity", key)
+                .set("lp_unmint_amount", l.lp_unmint_amount)
+                .set("token_a_out_amount", l.token_a_out_amount)
+                .set("token_b_out_amount", l.token_b_out_amount),
+            pb::log::Log::BootstrapLiquidity(l) => tables
+                .create_row("meteora_amm_bootstrap_liquidity", key)
+                .set("lp_mint_amount", l.lp_mint_amount)
+                .set("token_a_amount", l.token_a_amount)
+                .set("token_b_amount", l.token_b_amount),
+            _ => continue,
+        };
+        row.set("user", base58::encode(&accounts.user))
+            .set("pool", base58::encode(&accounts.pool))
+            .set("lp_mint", base58::encode(&accounts.lp_mint))
+            .set("user_pool_lp", base58::encode(&accounts.user_pool_lp))
+            .set("token_a_mint", base58::encode(&accounts.token_a_mint))
+            .set("token_b_mint", base58::encode(&accounts.token_b_mint));
+        set_instruction(ix, row);
+        set_transaction(tx, row);
+        set_clock(clock, row);
+    }
+}
+
+fn get_liquidity_accounts(ix: &pb::Instruction) -> Option<&pb::LiquidityAccounts> {
+    match &ix.instruction {
+        Some(pb::instruction::Instruction::AddBalanceLiquidity(data))
+        | Some(pb::instruction::Instruction::AddImbalanceLiquidity(data))
+        | Some(pb::instruction::Instruction::RemoveBalanceLiquidity(data))
+        | Some(pb::instruction::Instruction::RemoveLiquiditySingleSide(data))
+        | Some(pb::instruction::Instruction::BootstrapLiquidity(data)) => data.accounts.as_ref(),
+        _ => None,
+    }
+}
+
 fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
     row.set("signature", base58::encode(&tx.signature))
         .set("fee_payer", base58::encode(&tx.fee_payer))
@@ -59,4 +143,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
@@ -4,23 +4,204 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::pools::{insert_pool, Pool};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
-        if tx.logs.len() != tx.instructions.len() {
+        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
+            if let Some(data) = get_initialize_pool(ix) {
+                handle_initialize_pool(tables, clock, tx, ix, data);
+                continue;
+            }
+            let key = common_key_v2(clock, transaction_index, instruction_index);
+            let row = match &ix.instruction {
+                Some(pb::instruction::Instruction::ClaimPositionFee(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    tables
+                        .create_row("meteora_daam_claim_position_fee", key)
+                        .set("pool", base58::encode(&accounts.pool))
+                        .set("position", base58::encode(&accounts.position))
+                        .set("owner", base58::encode(&accounts.owner))
+                        .set("token_a_mint", base58::encode(&accounts.token_a_mint))
+                        .set("token_b_mint", base58::encode(&accounts.token_b_mint))
+                        .set("fee_a", data.fee_a)
+                        .set("fee_b", data.fee_b)
+                }
+                Some(pb::instruction::Instruction::ClaimReward(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    tables
+                        .create_row("meteora_daam_claim_reward", key)
+                        .set("pool", base58::encode(&accounts.pool))
+                        .set("position", base58::encode(&accounts.position))
+                        .set("owner", base58::encode(&accounts.owner))
+                        .set("reward_mint", base58::encode(&accounts.reward_mint))
+                        .set("reward_index", data.reward_index)
+                        .set("amount", data.amount)
+                }
+                Some(pb::instruction::Instruction::LockPosition(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    tables
+                        .create_row("meteora_daam_position_locks", key)
+                        .set("lock_type", "vesting")
+                        .set("pool", base58::encode(&accounts.pool))
+                        .set("position", base58::encode(&accounts.position))
+                        .set("owner", base58::encode(&accounts.owner))
+                        .set("vesting", base58::encode(&accounts.vesting))
+                        .set("liquidity", vesting_liquidity(data).to_string())
+                        .set("cliff_point", data.cliff_point.unwrap_or_default())
+                        .set("period_frequency", data.period_frequency)
+                        .set("cliff_unlock_liquidity", &data.cliff_unlock_liquidity)
+                        .set("liquidity_per_period", &data.liquidity_per_period)
+                        .set("number_of_period", data.number_of_period)
+                }
+                Some(pb::instruction::Instruction::PermanentLockPosition(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    tables
+                        .create_row("meteora_daam_position_locks", key)
+                        .set("lock_type", "permanent")
+                        .set("pool", base58::encode(&accounts.pool))
+                        .set("position", base58::encode(&accounts.position))
+                        .set("owner", base58::encode(&accounts.owner))
+                        .set("liquidity", &data.permanent_lock_liquidity)
+                }
+                Some(pb::instruction::Instruction::SplitPosition(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    tables
+                        .create_row("meteora_daam_split_position", key)
+                        .set("pool", base58::encode(&accounts.pool))
+                        .set("first_position", base58::encode(&accounts.first_position))
+                        .set("second_position", base58::encode(&accounts.second_position))
+                        .set("first_owner", base58::encode(&accounts.first_owner))
+                        .set("second_owner", base58::encode(&accounts.second_owner))
+                        .set("unlocked_liquidity_percentage", data.unlocked_liquidity_percentage)
+                        .set("permanent_locked_liquidity_percentage", data.permanent_locked_liquidity_percentage)
+                        .set("fee_a_percentage", data.fee_a_percentage)
+                        .set("fee_b_percentage", data.fee_b_percentage)
+                        .set("reward_0_percentage", data.reward_0_percentage)
+                        .set("reward_1_percentage", data.reward_1_percentage)
+                }
+                Some(pb::instruction::Instruction::UpdatePoolFees(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let dynamic_fee = data.dynamic_fee.clone().unwrap_or_default();
+                    tables
+                        .create_row("meteora_daam_update_pool_fees", key)
+                        .set("pool", base58::encode(&accounts.pool))
+                        .set("operator", base58::encode(&accounts.operator))
+                        .set("cliff_fee_numerator", data.cliff_fee_numerator.unwrap_or_default())
+                        .set("dynamic_fee", data.dynamic_fee.is_some())
+                        .set("bin_step", dynamic_fee.bin_step)
+                        .set("filter_period", dynamic_fee.filter_period)
+                        .set("decay_period", dynamic_fee.decay_period)
+                        .set("reduction_factor", dynamic_fee.reduction_factor)
+                        .set("max_volatility_accumulator", dynamic_fee.max_volatility_accumulator)
+                        .set("variable_fee_control", dynamic_fee.variable_fee_control)
+                }
+                _ => continue,
+            };
+            set_instruction(ix, row);
+            set_transaction(tx, row);
+            set_clock(clock, row);
+        }
+        // only swaps & liquidity changes are paired with their event by position, the other events are not decoded
+        let instructions: Vec<_> = tx.instructions.iter().enumerate().filter(|(_, ix)| emits_log(ix)).collect();
+        if tx.logs.len() != instructions.len() {
             continue;
         }
-        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
-            if let Some(pb::instruction::Instruction::Swap(data)) = &ix.instruction {
-                let log = match &tx.logs[instruction_index].log {
-                    Some(pb::log::Log::Swap(l)) => l,
-                    _ => continue,
-                };
-                handle_swap(tables, clock, tx, ix, data.accounts.as_ref(), log, transaction_index, instruction_index);
+        for ((instruction_index, ix), log) in instructions.into_iter().zip(tx.logs.iter()) {
+            match (&ix.instruction, &log.log) {
+                (Some(pb::instruction::Instruction::Swap(data)), Some(pb::log::Log::Swap(log))) => {
+                    handle_swap(tables, clock, tx, ix, data.accounts.as_ref(), log, transaction_index, instruction_index);
+                }
+                (Some(pb::instruction::Instruction::AddLiquidity(data)), Some(pb::log::Log::AddLiquidity(log))) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let params = log.params.clone().unwrap_or_default();
+                    let row = tables
+                        .create_row("meteora_daam_add_liquidity", key)
+                        .set("pool", base58::encode(&log.pool))
+                        .set("position", base58::encode(&log.position))
+                        .set("owner", base58::encode(&log.owner))
+                        .set("token_a_mint", base58::encode(&accounts.token_a_mint))
+                        .set("token_b_mint", base58::encode(&accounts.token_b_mint))
+                        .set("liquidity_delta", params.liquidity_delta)
+                        .set("token_a_amount_threshold", params.token_a_amount_threshold)
+                        .set("token_b_amount_threshold", params.token_b_amount_threshold)
+                        .set("token_a_amount", log.token_a_amount)
+                        .set("token_b_amount", log.token_b_amount)
+                        .set("total_amount_a", log.total_amount_a)
+                        .set("total_amount_b", log.total_amount_b);
+                    set_instruction(ix, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                (Some(pb::instruction::Instruction::RemoveLiquidity(data)), Some(pb::log::Log::RemoveLiquidity(log))) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let params = log.params.clone().unwrap_or_default();
+                    let row = tables
+                        .create_row("meteora_daam_remove_liquidity", key)
+                        .set("pool", base58::encode(&log.pool))
+                        .set("position", base58::encode(&log.position))
+                        .set("owner", base58::encode(&log.owner))
+                        .set("token_a_mint", base58::encode(&accounts.token_a_mint))
+                        .set("token_b_mint", base58::encode(&accounts.token_b_mint))
+                        .set("liquidity_delta", params.liquidity_delta)
+                        .set("token_a_amount_threshold", params.token_a_amount_threshold)
+                        .set("token_b_amount_threshold", params.token_b_amount_threshold)
+                        .set("token_a_amount", log.token_a_amount)
+                        .set("token_b_amount", log.token_b_amount);
+                    set_instruction(ix, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                _ => {}
             }
         }
     }
 }
 
+fn emits_log(ix: &pb::Instruction) -> bool {
+    matches!(
+        ix.instruction,
+        Some(pb::instruction::Instruction::Swap(_))
+            | Some(pb::instruction::Instruction::AddLiquidity(_))
+            | Some(pb::instruction::Instruction::RemoveLiquidity(_))
+    )
+}
+
+/// Total liquidity released by the vesting, `cliff_unlock_liquidity + liquidity_per_period * number_of_period`.
+fn vesting_liquidity(data: &pb::LockPositionInstruction) -> u128 {
+    let cliff = data.cliff_unlock_liquidity.parse::<u128>().unwrap_or_default();
+    let per_period = data.liquidity_per_period.parse::<u128>().unwrap_or_default();
+    cliff.saturating_add(per_period.saturating_mul(data.number_of_period as u128))
+}
+
+fn get_initialize_pool(ix: &pb::Instruction) -> Option<&pb::InitializePoolInstruction> {
+    match &ix.instruction {
+        Some(pb::instruction::Instruction::InitializePool(data))
+        | Some(pb::instruction::Instruction::InitializeCustomizablePool(data))
+        | Some(pb::instruction::Instruction::InitializePoolWithDynamicConfig(data)) => Some(data),
+        _ => None,
+    }
+}
+
+fn handle_initialize_pool(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, ix: &pb::Instruction, data: &pb::InitializePoolInstruction) {
+    let Some(accounts) = &data.accounts else { return };
+    let pool = Pool {
+        program_id: &ix.program_id,
+        pool: &accounts.pool,
+        mint0: &accounts.token_a_mint,
+        mint1: &accounts.token_b_mint,
+        vault0: &accounts.token_a_vault,
+        vault1: &accounts.token_b_vault,
+        config: &accounts.config,
+        creator: &accounts.creator,
+        ..Default::default()
+    };
+    insert_pool(tables, clock, &tx.signature, pool);
+}
+
 fn handle_swap(
     tables: &mut Tables,
     clock: &Clock,
@@ -65,4 +246,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
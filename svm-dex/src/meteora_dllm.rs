@@ -1,16 +1,167 @@
 use common::db::{common_key_v2, set_clock};
 use proto::pb::meteora::dllm::v1 as pb;
 use substreams::pb::substreams::Clock;
+use substreams::store::{StoreGet, StoreGetInt64, StoreSet, StoreSetInt64};
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
-pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+use crate::aggregators::set_aggregator;
+use crate::pools::{insert_pool, Pool};
+use crate::positions::{insert_position_event, PositionAction, PositionEvent};
+use crate::prices::bin_id_to_price;
+
+/// Stores the bin step of the pairs created by `initialize_lb_pair`, keyed by LB pair.
+pub fn store_bin_steps(events: &pb::Events, store: &StoreSetInt64) {
+    let mut ordinal = 0;
+    for tx in events.transactions.iter() {
+        for ix in tx.instructions.iter() {
+            let Some(pb::instruction::Instruction::InitializeLbPair(data)) = &ix.instruction else {
+                continue;
+            };
+            let Some(accounts) = &data.accounts else { continue };
+            store.set(ordinal, base58::encode(&accounts.lb_pair), &(data.bin_step as i64));
+            ordinal += 1;
+        }
+    }
+}
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events, bin_steps: &StoreGetInt64) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
-            if let Some(pb::instruction::Instruction::SwapInstruction(data)) = &ix.instruction {
-                if let Some(event) = get_swap_event(tx, instruction_index) {
-                    handle_swap(tables, clock, tx, ix, data.accounts.as_ref(), event, transaction_index, instruction_index);
+            match &ix.instruction {
+                Some(pb::instruction::Instruction::SwapInstruction(data)) => {
+                    if let Some(event) = get_swap_event(tx, instruction_index) {
+                        handle_swap(
+                            tables,
+                            clock,
+                            tx,
+                            ix,
+                            data.accounts.as_ref(),
+                            event,
+                            bin_steps,
+                            transaction_index,
+                            instruction_index,
+                        );
+                    }
+                }
+                Some(pb::instruction::Instruction::InitializeLbPair(data)) => {
+                    handle_initialize_lb_pair(tables, clock, tx, ix, data, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::InitializePosition(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    handle_initialize_position(tables, clock, tx, ix, accounts, data, transaction_index, instruction_index);
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.lb_pair,
+                        position: &accounts.position,
+                        owner: &accounts.owner,
+                        lower_index: data.lower_bin_id,
+                        upper_index: data.lower_bin_id + data.width - 1,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::Open,
+                        position,
+                    );
                 }
+                Some(pb::instruction::Instruction::ClosePosition(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.lb_pair,
+                        position: &accounts.position,
+                        owner: &accounts.sender,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::Close,
+                        position,
+                    );
+                }
+                Some(pb::instruction::Instruction::AddLiquidity(data)) => {
+                    handle_modify_liquidity(
+                        tables,
+                        clock,
+                        tx,
+                        ix,
+                        data,
+                        PositionAction::IncreaseLiquidity,
+                        "meteora_dllm_add_liquidity",
+                        bin_steps,
+                        transaction_index,
+                        instruction_index,
+                    );
+                }
+                Some(pb::instruction::Instruction::RemoveLiquidity(data)) => {
+                    handle_modify_liquidity(
+                        tables,
+                        clock,
+                        tx,
+                        ix,
+                        data,
+                        PositionAction::DecreaseLiquidity,
+                        "meteora_dllm_remove_liquidity",
+                        bin_steps,
+                        transaction_index,
+                        instruction_index,
+                    );
+                }
+                Some(pb::instruction::Instruction::ClaimFee(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    handle_claim_fee(tables, clock, tx, ix, accounts, data, transaction_index, instruction_index);
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.lb_pair,
+                        position: &accounts.position,
+                        owner: &accounts.sender,
+                        amount0: data.fee_x,
+                        amount1: data.fee_y,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::CollectFees,
+                        position,
+                    );
+                }
+                Some(pb::instruction::Instruction::ClaimReward(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    handle_claim_reward(tables, clock, tx, ix, accounts, data, transaction_index, instruction_index);
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.lb_pair,
+                        position: &accounts.position,
+                        owner: &accounts.sender,
+                        reward_index: data.reward_index as u32,
+                        reward_mint: &accounts.reward_mint,
+                        reward_amount: data.amount,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::CollectReward,
+                        position,
+                    );
+                }
+                _ => {}
             }
         }
     }
@@ -25,6 +176,111 @@ fn get_swap_event(tx: &pb::Transaction, instruction_index: usize) -> Option<&pb:
     None
 }
 
+/// `AddLiquidity` or `RemoveLiquidity` event emitted by the instruction, the event CPI directly follows it.
+fn get_liquidity_event(tx: &pb::Transaction, instruction_index: usize) -> Option<&pb::LiquidityEvent> {
+    match &tx.instructions.get(instruction_index + 1)?.instruction {
+        Some(pb::instruction::Instruction::AddLiquidityEvent(ev)) | Some(pb::instruction::Instruction::RemoveLiquidityEvent(ev)) => Some(ev),
+        _ => None,
+    }
+}
+
+/// `ClaimReward` event emitted by the instruction, the event CPI directly follows it.
+fn get_claim_reward_event(tx: &pb::Transaction, instruction_index: usize) -> Option<&pb::ClaimRewardEvent> {
+    match &tx.instructions.get(instruction_index + 1)?.instruction {
+        Some(pb::instruction::Instruction::ClaimRewardEvent(ev)) => Some(ev),
+        _ => None,
+    }
+}
+
+fn handle_initialize_lb_pair(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::InitializeLbPairInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("meteora_dllm_initialize_lb_pair", key)
+        .set("lb_pair", base58::encode(&accounts.lb_pair))
+        .set("token_mint_x", base58::encode(&accounts.token_mint_x))
+        .set("token_mint_y", base58::encode(&accounts.token_mint_y))
+        .set("reserve_x", base58::encode(&accounts.reserve_x))
+        .set("reserve_y", base58::encode(&accounts.reserve_y))
+        .set("preset_parameter", base58::encode(&accounts.preset_parameter))
+        .set("funder", base58::encode(&accounts.funder))
+        .set("active_id", data.active_id)
+        .set("bin_step", data.bin_step)
+        .set("price", bin_id_to_price(data.active_id, data.bin_step as i64).to_string());
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+
+    let pool = Pool {
+        program_id: &ix.program_id,
+        pool: &accounts.lb_pair,
+        mint0: &accounts.token_mint_x,
+        mint1: &accounts.token_mint_y,
+        vault0: &accounts.reserve_x,
+        vault1: &accounts.reserve_y,
+        config: &accounts.preset_parameter,
+        fee_tier: data.bin_step,
+        creator: &accounts.funder,
+        ..Default::default()
+    };
+    insert_pool(tables, clock, &tx.signature, pool);
+}
+
+fn handle_modify_liquidity(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::ModifyLiquidityInstruction,
+    action: PositionAction,
+    table: &str,
+    bin_steps: &StoreGetInt64,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let lb_pair = base58::encode(&accounts.lb_pair);
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row(table, key)
+        .set("sender", base58::encode(&accounts.sender))
+        .set("position", base58::encode(&accounts.position))
+        .set("lb_pair", &lb_pair)
+        .set("token_x_mint", base58::encode(&accounts.token_x_mint))
+        .set("token_y_mint", base58::encode(&accounts.token_y_mint))
+        .set("amount_x", data.amount_x)
+        .set("amount_y", data.amount_y);
+    // the active bin is only logged by the programs emitting the liquidity events
+    if let Some(event) = get_liquidity_event(tx, instruction_index) {
+        let bin_step = bin_steps.get_last(&lb_pair);
+        row.set("active_bin_id", event.active_bin_id)
+            .set("bin_step_raw", bin_step.map(|step| step.to_string()).unwrap_or_default())
+            .set("price_raw", bin_price(event.active_bin_id, bin_step));
+    }
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+
+    let position = PositionEvent {
+        program_id: &ix.program_id,
+        pool: &accounts.lb_pair,
+        position: &accounts.position,
+        owner: &accounts.sender,
+        amount0: data.amount_x,
+        amount1: data.amount_y,
+        ..Default::default()
+    };
+    insert_position_event(tables, clock, &tx.signature, transaction_index, instruction_index, action, position);
+}
+
 fn handle_swap(
     tables: &mut Tables,
     clock: &Clock,
@@ -32,6 +288,7 @@ fn handle_swap(
     ix: &pb::Instruction,
     accounts_opt: Option<&pb::SwapAccounts>,
     event: &pb::SwapEvent,
+    bin_steps: &StoreGetInt64,
     transaction_index: usize,
     instruction_index: usize,
 ) {
@@ -44,15 +301,109 @@ fn handle_swap(
     } else {
         (&accounts.token_y_mint, &accounts.token_x_mint)
     };
+    let lb_pair = base58::encode(&accounts.lb_pair);
+    let bin_step = bin_steps.get_last(&lb_pair);
     let key = common_key_v2(clock, transaction_index, instruction_index);
     let row = tables
         .create_row("meteora_dllm_swap", key)
         .set("user", base58::encode(&accounts.user))
-        .set("lb_pair", base58::encode(&accounts.lb_pair))
+        .set("lb_pair", &lb_pair)
         .set("input_mint", base58::encode(input_mint))
         .set("output_mint", base58::encode(output_mint))
         .set("amount_in", event.amount_in)
-        .set("amount_out", event.amount_out);
+        .set("amount_out", event.amount_out)
+        // -- bins --
+        .set("start_bin_id", event.start_bin_id)
+        .set("end_bin_id", event.end_bin_id)
+        .set("bins_crossed", event.start_bin_id.abs_diff(event.end_bin_id))
+        .set("bin_step_raw", bin_step.map(|step| step.to_string()).unwrap_or_default())
+        .set("price_raw", bin_price(event.end_bin_id, bin_step))
+        // -- fees --
+        .set("swap_fee", event.fee)
+        .set("protocol_fee", event.protocol_fee)
+        .set("host_fee", event.host_fee)
+        .set("fee_bps", &event.fee_bps);
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+}
+
+/// Price of the bin, empty when the bin step of the pair is unknown (pair created before the start block).
+fn bin_price(bin_id: i32, bin_step: Option<i64>) -> String {
+    bin_step.map(|step| bin_id_to_price(bin_id, step).to_string()).unwrap_or_default()
+}
+
+fn handle_initialize_position(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    accounts: &pb::InitializePositionAccounts,
+    data: &pb::InitializePositionInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("meteora_dllm_initialize_position", key)
+        .set("payer", base58::encode(&accounts.payer))
+        .set("position", base58::encode(&accounts.position))
+        .set("lb_pair", base58::encode(&accounts.lb_pair))
+        .set("owner", base58::encode(&accounts.owner))
+        .set("lower_bin_id", data.lower_bin_id)
+        .set("upper_bin_id", data.lower_bin_id + data.width - 1)
+        .set("width", data.width);
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+}
+
+fn handle_claim_fee(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    accounts: &pb::ClaimFeeAccounts,
+    data: &pb::ClaimFeeInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("meteora_dllm_claim_fee", key)
+        .set("sender", base58::encode(&accounts.sender))
+        .set("position", base58::encode(&accounts.position))
+        .set("lb_pair", base58::encode(&accounts.lb_pair))
+        .set("token_x_mint", base58::encode(&accounts.token_x_mint))
+        .set("token_y_mint", base58::encode(&accounts.token_y_mint))
+        .set("fee_x", data.fee_x)
+        .set("fee_y", data.fee_y);
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+}
+
+fn handle_claim_reward(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    accounts: &pb::ClaimRewardAccounts,
+    data: &pb::ClaimRewardInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    // the event reports the reward of the position, the transfer is missing when it is 0
+    let amount = get_claim_reward_event(tx, instruction_index).map_or(data.amount, |event| event.total_reward);
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("meteora_dllm_claim_reward", key)
+        .set("sender", base58::encode(&accounts.sender))
+        .set("position", base58::encode(&accounts.position))
+        .set("lb_pair", base58::encode(&accounts.lb_pair))
+        .set("reward_mint", base58::encode(&accounts.reward_mint))
+        .set("reward_index", data.reward_index)
+        .set("amount", amount);
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
@@ -68,4 +419,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
@@ -1,24 +1,116 @@
 use common::db::{common_key_v2, set_clock};
 use proto::pb::moonshot::v1 as pb;
 use substreams::pb::substreams::Clock;
+use substreams::store::StoreGetString;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
-pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+use crate::aggregators::set_aggregator;
+use crate::bonding_curves::{CurveMetrics, CurveParams};
+use crate::launches::{insert_token_launch, LaunchStage, TokenLaunch};
+
+// Programs receiving the curve liquidity, by `TokenMint.migration_target`
+const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+const METEORA_AMM: &str = "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB";
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events, curves: &StoreGetString) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        // trade logs are emitted by the buy & sell instructions, in the same order
+        let trades: Vec<&pb::Instruction> = tx
+            .instructions
+            .iter()
+            .filter(|ix| {
+                matches!(
+                    ix.instruction,
+                    Some(pb::instruction::Instruction::Buy(_)) | Some(pb::instruction::Instruction::Sell(_))
+                )
+            })
+            .collect();
+        // migration logs are emitted by the migrate funds instructions, in the same order
+        let migrations: Vec<&pb::Instruction> = tx
+            .instructions
+            .iter()
+            .filter(|ix| matches!(ix.instruction, Some(pb::instruction::Instruction::MigrateFunds(_))))
+            .collect();
+        let mut trade_index = 0;
+        let mut migration_index = 0;
         for (log_index, log) in tx.logs.iter().enumerate() {
-            if let Some(pb::log::Log::Trade(event)) = &log.log {
-                let ix = if log_index < tx.instructions.len() { &tx.instructions[log_index] } else { continue };
-                let table = if event.trade_type == 0 { "moonshot_buy" } else { "moonshot_sell" };
-                handle_trade(tables, clock, tx, ix, event, table, transaction_index, log_index);
+            match &log.log {
+                Some(pb::log::Log::Trade(event)) => {
+                    let Some(ix) = trades.get(trade_index) else { continue };
+                    trade_index += 1;
+                    let table = if event.trade_type == 0 { "moonshot_buy" } else { "moonshot_sell" };
+                    handle_trade(tables, clock, curves, tx, ix, event, table, transaction_index, log_index);
+                }
+                Some(pb::log::Log::Migration(_)) => {
+                    let Some(ix) = migrations.get(migration_index) else { continue };
+                    migration_index += 1;
+                    handle_graduate(tables, clock, curves, tx, ix);
+                }
+                _ => {}
+            }
+        }
+        for ix in tx.instructions.iter() {
+            match &ix.instruction {
+                Some(pb::instruction::Instruction::TokenMint(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let launch = TokenLaunch {
+                        launchpad: &ix.program_id,
+                        mint: &accounts.mint,
+                        creator: &accounts.sender,
+                        name: &data.name,
+                        symbol: &data.symbol,
+                        uri: &data.uri,
+                        bonding_curve: &accounts.curve_account,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Create, launch);
+                }
+                // emitted once the curve is sold, the pool is then created by the migration target (Raydium or Meteora)
+                Some(pb::instruction::Instruction::MigrateFunds(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let launch = TokenLaunch {
+                        launchpad: &ix.program_id,
+                        mint: &accounts.mint,
+                        bonding_curve: &accounts.curve_account,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Complete, launch);
+                }
+                _ => {}
             }
         }
     }
 }
 
+// The migration target is chosen at the token creation, the pool itself is created by the target program
+fn handle_graduate(tables: &mut Tables, clock: &Clock, curves: &StoreGetString, tx: &pb::Transaction, ix: &pb::Instruction) {
+    let Some(pb::instruction::Instruction::MigrateFunds(data)) = &ix.instruction else {
+        return;
+    };
+    let Some(accounts) = &data.accounts else {
+        return;
+    };
+    // unknown for tokens created before the start block
+    let graduation_program_id = match CurveParams::get(curves, &accounts.mint).map(|params| params.migration_target) {
+        Some(0) => base58::decode(RAYDIUM_AMM_V4).unwrap_or_default(),
+        Some(1) => base58::decode(METEORA_AMM).unwrap_or_default(),
+        _ => vec![],
+    };
+    let launch = TokenLaunch {
+        launchpad: &ix.program_id,
+        mint: &accounts.mint,
+        bonding_curve: &accounts.curve_account,
+        graduation_program_id: &graduation_program_id,
+        ..Default::default()
+    };
+    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Graduate, launch);
+}
+
 fn handle_trade(
     tables: &mut Tables,
     clock: &Clock,
+    curves: &StoreGetString,
     tx: &pb::Transaction,
     ix: &pb::Instruction,
     event: &pb::TradeEvent,
@@ -37,11 +129,25 @@ fn handle_trade(
         .set("trade_type", event.trade_type)
         .set("cost_token", base58::encode(&event.cost_token))
         .set("curve", base58::encode(&event.curve));
+    set_curve_metrics(curves, ix, event, row);
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
 }
 
+fn set_curve_metrics(curves: &StoreGetString, ix: &pb::Instruction, event: &pb::TradeEvent, row: &mut Row) {
+    let (accounts, curve_token_reserves) = match &ix.instruction {
+        Some(pb::instruction::Instruction::Buy(data)) => (data.accounts.as_ref(), data.curve_token_reserves),
+        Some(pb::instruction::Instruction::Sell(data)) => (data.accounts.as_ref(), data.curve_token_reserves),
+        _ => (None, 0),
+    };
+    accounts
+        .and_then(|accounts| CurveParams::get(curves, &accounts.mint))
+        .map(|params| CurveMetrics::moonshot(&params, event, curve_token_reserves))
+        .unwrap_or_default()
+        .set(row);
+}
+
 fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
     row.set("signature", base58::encode(&tx.signature))
         .set("fee_payer", base58::encode(&tx.fee_payer))
@@ -52,4 +158,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
@@ -1,11 +1,22 @@
 use common::db::{common_key_v2, set_clock};
 use proto::pb::obric;
 use substreams::pb::substreams::Clock;
-use substreams_database_change::tables::Tables;
+use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
-fn write_swap_row(
-    tables: &mut Tables,
+use crate::aggregators::set_aggregator;
+
+// Realized swap, inferred from the inner SPL Token transfers
+struct SwapFields<'a> {
+    user: &'a [u8],
+    input_mint: &'a [u8],
+    input_amount: u64,
+    output_mint: &'a [u8],
+    output_amount: u64,
+}
+
+fn write_swap_row<'a>(
+    tables: &'a mut Tables,
     clock: &Clock,
     key: [(&'static str, String); 3],
     signature: &[u8],
@@ -14,23 +25,30 @@ fn write_swap_row(
     compute_units_consumed: u64,
     input_amount: u64,
     min_output_amount: u64,
-) {
+    swap: SwapFields,
+) -> &'a mut Row {
     let row = tables
         .create_row("obric_swap", key)
         .set("input_amount", input_amount)
-        .set("min_output_amount", min_output_amount);
+        .set("min_output_amount", min_output_amount)
+        .set("user", base58::encode(swap.user))
+        .set("input_mint", base58::encode(swap.input_mint))
+        .set("realized_input_amount", swap.input_amount)
+        .set("output_mint", base58::encode(swap.output_mint))
+        .set("output_amount", swap.output_amount);
     row.set("signature", base58::encode(signature))
         .set("fee_payer", base58::encode(fee_payer))
         .set("fee", fee)
         .set("compute_units_consumed", compute_units_consumed);
     set_clock(clock, row);
+    row
 }
 
 pub fn process_v2_events(tables: &mut Tables, clock: &Clock, events: &obric::v2::v1::Events) {
     for (tx_idx, tx) in events.transactions.iter().enumerate() {
         for (ix_idx, ix) in tx.instructions.iter().enumerate() {
             let key = common_key_v2(clock, tx_idx, ix_idx);
-            match &ix.instruction {
+            let row = match &ix.instruction {
                 Some(obric::v2::v1::instruction::Instruction::SwapXToY(data)) => write_swap_row(
                     tables,
                     clock,
@@ -41,6 +59,13 @@ pub fn process_v2_events(tables: &mut Tables, clock: &Clock, events: &obric::v2:
                     tx.compute_units_consumed,
                     data.input_amount,
                     data.min_output_amount,
+                    SwapFields {
+                        user: &data.user,
+                        input_mint: &data.input_mint,
+                        input_amount: data.realized_input_amount,
+                        output_mint: &data.output_mint,
+                        output_amount: data.output_amount,
+                    },
                 ),
                 Some(obric::v2::v1::instruction::Instruction::SwapYToX(data)) => write_swap_row(
                     tables,
@@ -52,9 +77,18 @@ pub fn process_v2_events(tables: &mut Tables, clock: &Clock, events: &obric::v2:
                     tx.compute_units_consumed,
                     data.input_amount,
                     data.min_output_amount,
+                    SwapFields {
+                        user: &data.user,
+                        input_mint: &data.input_mint,
+                        input_amount: data.realized_input_amount,
+                        output_mint: &data.output_mint,
+                        output_amount: data.output_amount,
+                    },
                 ),
-                _ => {}
-            }
+                _ => continue,
+            };
+            row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+            set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
         }
     }
 }
@@ -63,7 +97,7 @@ pub fn process_v3_events(tables: &mut Tables, clock: &Clock, events: &obric::v3:
     for (tx_idx, tx) in events.transactions.iter().enumerate() {
         for (ix_idx, ix) in tx.instructions.iter().enumerate() {
             let key = common_key_v2(clock, tx_idx, ix_idx);
-            match &ix.instruction {
+            let row = match &ix.instruction {
                 Some(obric::v3::v1::instruction::Instruction::SwapXToY(data)) => write_swap_row(
                     tables,
                     clock,
@@ -74,6 +108,13 @@ pub fn process_v3_events(tables: &mut Tables, clock: &Clock, events: &obric::v3:
                     tx.compute_units_consumed,
                     data.input_amount,
                     data.min_output_amount,
+                    SwapFields {
+                        user: &data.user,
+                        input_mint: &data.input_mint,
+                        input_amount: data.realized_input_amount,
+                        output_mint: &data.output_mint,
+                        output_amount: data.output_amount,
+                    },
                 ),
                 Some(obric::v3::v1::instruction::Instruction::SwapYToX(data)) => write_swap_row(
                     tables,
@@ -85,9 +126,18 @@ pub fn process_v3_events(tables: &mut Tables, clock: &Clock, events: &obric::v3:
                     tx.compute_units_consumed,
                     data.input_amount,
                     data.min_output_amount,
+                    SwapFields {
+                        user: &data.user,
+                        input_mint: &data.input_mint,
+                        input_amount: data.realized_input_amount,
+                        output_mint: &data.output_mint,
+                        output_amount: data.output_amount,
+                    },
                 ),
-                _ => {}
-            }
+                _ => continue,
+            };
+            row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+            set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
         }
     }
 }
@@ -4,6 +4,8 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::Tables;
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
@@ -12,8 +14,14 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                 let row = tables
                     .create_row("okx_swap", key)
                     .set("amount_in", data.amount_in)
-                    .set("minimum_amount_out", data.minimum_amount_out);
+                    .set("minimum_amount_out", data.minimum_amount_out)
+                    .set("user", base58::encode(&data.user))
+                    .set("input_mint", base58::encode(&data.input_mint))
+                    .set("input_amount", data.input_amount)
+                    .set("output_mint", base58::encode(&data.output_mint))
+                    .set("output_amount", data.output_amount);
                 row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+                set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
                 row.set("signature", base58::encode(&tx.signature))
                     .set("fee_payer", base58::encode(&tx.fee_payer))
                     .set("fee", tx.fee)
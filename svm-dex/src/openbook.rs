@@ -4,11 +4,32 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::orderbooks::{insert_fill, insert_order, FillEvent, OrderAction, OrderEvent, OrderSide};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
+            handle_order_instruction(tables, clock, tx, ix, transaction_index, instruction_index);
+        }
         for (log_index, log) in tx.logs.iter().enumerate() {
             match &log.log {
                 Some(pb::log::Log::FillLog(event)) => {
+                    let fill = FillEvent {
+                        program_id: &log.program_id,
+                        market: &event.market,
+                        maker: &event.maker,
+                        taker: &event.taker,
+                        taker_side: OrderSide::from_u32(event.taker_side),
+                        price: event.price,
+                        quantity: event.quantity as u64,
+                        maker_client_order_id: &event.maker_client_order_id.to_string(),
+                        taker_client_order_id: &event.taker_client_order_id.to_string(),
+                        maker_fee: event.maker_fee,
+                        taker_fee: event.taker_fee_ceil,
+                        ..Default::default()
+                    };
+                    insert_fill(tables, clock, &tx.signature, transaction_index, log_index, fill);
                     let ix = if log_index < tx.instructions.len() { &tx.instructions[log_index] } else { continue };
                     handle_fill_log(tables, clock, tx, ix, event, transaction_index, log_index);
                 }
@@ -22,6 +43,181 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     }
 }
 
+/// Orders are attributed to the open orders account of the trader (the signer for take orders), as the makers of the fills.
+fn handle_order_instruction(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let mut insert = |action: OrderAction, order: OrderEvent| {
+        let order = OrderEvent {
+            program_id: &ix.program_id,
+            ..order
+        };
+        insert_order(tables, clock, &tx.signature, transaction_index, instruction_index, action, order);
+    };
+
+    match &ix.instruction {
+        Some(pb::instruction::Instruction::PlaceOrder(data)) => {
+            let (Some(accounts), Some(args)) = (&data.accounts, &data.args) else {
+                return;
+            };
+            let client_order_id = args.client_order_id.to_string();
+            insert(
+                OrderAction::Place,
+                place_order_event(&accounts.market, &accounts.open_orders_account, args, &client_order_id),
+            );
+        }
+        Some(pb::instruction::Instruction::PlaceOrders(data)) => {
+            let Some(accounts) = &data.accounts else { return };
+            for (order_index, (side, args)) in place_orders(data).enumerate() {
+                insert(
+                    OrderAction::Place,
+                    place_orders_event(&accounts.market, &accounts.open_orders_account, data.orders_type, side, args, order_index),
+                );
+            }
+        }
+        Some(pb::instruction::Instruction::CancelAllAndPlaceOrders(data)) => {
+            let Some(accounts) = &data.accounts else { return };
+            insert(
+                OrderAction::Cancel,
+                OrderEvent {
+                    market: &accounts.market,
+                    trader: &accounts.open_orders_account,
+                    ..Default::default()
+                },
+            );
+            for (order_index, (side, args)) in place_orders(data).enumerate() {
+                insert(
+                    OrderAction::Place,
+                    place_orders_event(&accounts.market, &accounts.open_orders_account, data.orders_type, side, args, order_index),
+                );
+            }
+        }
+        Some(pb::instruction::Instruction::PlaceTakeOrder(data)) => {
+            let Some(accounts) = &data.accounts else { return };
+            insert(
+                OrderAction::Place,
+                OrderEvent {
+                    market: &accounts.market,
+                    trader: &accounts.signer,
+                    side: OrderSide::from_u32(data.side),
+                    order_type: order_type_name(data.order_type),
+                    price: data.price_lots,
+                    size: data.max_base_lots as u64,
+                    quote_size: data.max_quote_lots_including_fees as u64,
+                    ..Default::default()
+                },
+            );
+        }
+        // cancels the order `client_order_id` and places a new order
+        Some(pb::instruction::Instruction::EditOrder(data)) => {
+            let (Some(accounts), Some(args)) = (&data.accounts, &data.place_order) else {
+                return;
+            };
+            let client_order_id = args.client_order_id.to_string();
+            let order = place_order_event(&accounts.market, &accounts.open_orders_account, args, &client_order_id);
+            insert(OrderAction::Modify, order);
+        }
+        Some(pb::instruction::Instruction::CancelOrder(data)) | Some(pb::instruction::Instruction::CancelOrderByClientOrderId(data)) => {
+            let Some(accounts) = &data.accounts else { return };
+            let client_order_id = data.client_order_id.to_string();
+            insert(
+                OrderAction::Cancel,
+                OrderEvent {
+                    market: &accounts.market,
+                    trader: &accounts.open_orders_account,
+                    order_id: &data.order_id,
+                    client_order_id: &client_order_id,
+                    ..Default::default()
+                },
+            );
+        }
+        Some(pb::instruction::Instruction::CancelAllOrders(data)) => {
+            let Some(accounts) = &data.accounts else { return };
+            insert(
+                OrderAction::Cancel,
+                OrderEvent {
+                    market: &accounts.market,
+                    trader: &accounts.open_orders_account,
+                    side: data.side.map(OrderSide::from_u32).unwrap_or_default(),
+                    ..Default::default()
+                },
+            );
+        }
+        Some(pb::instruction::Instruction::SettleFunds(data)) => {
+            let Some(accounts) = &data.accounts else { return };
+            insert(
+                OrderAction::Settle,
+                OrderEvent {
+                    market: &accounts.market,
+                    trader: &accounts.open_orders_account,
+                    base_amount: data.base_amount,
+                    quote_amount: data.quote_amount,
+                    ..Default::default()
+                },
+            );
+        }
+        None => {}
+    }
+}
+
+fn place_order_event<'a>(market: &'a [u8], trader: &'a [u8], args: &pb::PlaceOrderArgs, client_order_id: &'a str) -> OrderEvent<'a> {
+    OrderEvent {
+        market,
+        trader,
+        side: OrderSide::from_u32(args.side),
+        client_order_id,
+        order_type: order_type_name(args.order_type),
+        price: args.price_lots,
+        size: args.max_base_lots as u64,
+        quote_size: args.max_quote_lots_including_fees as u64,
+        ..Default::default()
+    }
+}
+
+/// Orders of `place_orders` & `cancel_all_and_place_orders`, bids first.
+fn place_orders(data: &pb::PlaceOrdersInstruction) -> impl Iterator<Item = (OrderSide, &pb::PlaceMultipleOrdersArgs)> {
+    let bids = data.bids.iter().map(|args| (OrderSide::Bid, args));
+    let asks = data.asks.iter().map(|args| (OrderSide::Ask, args));
+    bids.chain(asks)
+}
+
+fn place_orders_event<'a>(
+    market: &'a [u8],
+    trader: &'a [u8],
+    orders_type: u32,
+    side: OrderSide,
+    args: &pb::PlaceMultipleOrdersArgs,
+    order_index: usize,
+) -> OrderEvent<'a> {
+    OrderEvent {
+        market,
+        trader,
+        side,
+        order_type: order_type_name(orders_type),
+        price: args.price_lots,
+        quote_size: args.max_quote_lots_including_fees as u64,
+        order_index,
+        ..Default::default()
+    }
+}
+
+fn order_type_name(order_type: u32) -> &'static str {
+    match order_type {
+        0 => "limit",
+        1 => "immediate_or_cancel",
+        2 => "post_only",
+        3 => "market",
+        4 => "post_only_slide",
+        5 => "fill_or_kill",
+        _ => "",
+    }
+}
+
 fn handle_fill_log(
     tables: &mut Tables,
     clock: &Clock,
@@ -78,4 +274,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
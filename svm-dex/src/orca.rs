@@ -4,23 +4,267 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::pools::{insert_pool, Pool};
+use crate::positions::{insert_position_event, PositionAction, PositionEvent};
+use crate::prices::sqrt_price_x64_to_price;
+use crate::reserves::PoolReserves;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    let mut reserves = PoolReserves::default();
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        // position instructions emit no `Traded` log, they are skipped when pairing swaps with their log
+        let mut position_instructions = 0;
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
             match &ix.instruction {
                 Some(pb::instruction::Instruction::Swap(data)) => {
-                    if let Some(event) = get_traded_event(tx, instruction_index) {
+                    if let Some(accounts) = &data.accounts {
+                        reserves.insert(
+                            &ix.program_id,
+                            &accounts.whirlpool,
+                            data.vault_balances.iter().map(|v| (v.mint.as_slice(), v.post_amount)),
+                        );
+                    }
+                    if let Some(event) = get_traded_event(tx, instruction_index - position_instructions) {
                         handle_swap_v1(tables, clock, tx, ix, data, event, transaction_index, instruction_index);
                     }
                 }
                 Some(pb::instruction::Instruction::SwapV2(data)) => {
-                    if let Some(event) = get_traded_event(tx, instruction_index) {
+                    if let Some(accounts) = &data.accounts {
+                        reserves.insert(
+                            &ix.program_id,
+                            &accounts.whirlpool,
+                            data.vault_balances.iter().map(|v| (v.mint.as_slice(), v.post_amount)),
+                        );
+                    }
+                    if let Some(event) = get_traded_event(tx, instruction_index - position_instructions) {
                         handle_swap_v2(tables, clock, tx, ix, data, event, transaction_index, instruction_index);
                     }
                 }
+                Some(pb::instruction::Instruction::OpenPosition(data)) => {
+                    position_instructions += 1;
+                    let Some(accounts) = &data.accounts else { continue };
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.whirlpool,
+                        position: &accounts.position,
+                        owner: &accounts.owner,
+                        lower_index: data.tick_lower_index,
+                        upper_index: data.tick_upper_index,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::Open,
+                        position,
+                    );
+                }
+                Some(pb::instruction::Instruction::ClosePosition(data)) => {
+                    position_instructions += 1;
+                    let Some(accounts) = &data.accounts else { continue };
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        position: &accounts.position,
+                        owner: &accounts.position_authority,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::Close,
+                        position,
+                    );
+                }
+                Some(pb::instruction::Instruction::CollectFees(data)) => {
+                    position_instructions += 1;
+                    let Some(accounts) = &data.accounts else { continue };
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.whirlpool,
+                        position: &accounts.position,
+                        owner: &accounts.position_authority,
+                        amount0: data.fee_a_amount,
+                        amount1: data.fee_b_amount,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::CollectFees,
+                        position,
+                    );
+                }
+                Some(pb::instruction::Instruction::CollectReward(data)) => {
+                    position_instructions += 1;
+                    let Some(accounts) = &data.accounts else { continue };
+                    let position = PositionEvent {
+                        program_id: &ix.program_id,
+                        pool: &accounts.whirlpool,
+                        position: &accounts.position,
+                        owner: &accounts.position_authority,
+                        reward_index: data.reward_index,
+                        reward_mint: &data.reward_mint,
+                        reward_amount: data.amount,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        instruction_index,
+                        PositionAction::CollectReward,
+                        position,
+                    );
+                }
+                Some(pb::instruction::Instruction::IncreaseLiquidity(_)) | Some(pb::instruction::Instruction::DecreaseLiquidity(_)) => {
+                    position_instructions += 1;
+                }
                 _ => {}
             }
         }
+        // liquidity logs are paired with their instruction by position, it holds the position authority & the token mints
+        let mut increases = liquidity_instructions(tx, true);
+        let mut decreases = liquidity_instructions(tx, false);
+        for (log_index, log) in tx.logs.iter().enumerate() {
+            match &log.log {
+                Some(pb::log::Log::LiquidityIncreased(event)) => {
+                    let instruction = increases.next();
+                    let accounts = instruction.and_then(|(_, data)| data.accounts.as_ref());
+                    let key = common_key_v2(clock, transaction_index, log_index);
+                    let row = tables
+                        .create_row("orca_liquidity_increased", key)
+                        .set("whirlpool", base58::encode(&event.whirlpool))
+                        .set("position", base58::encode(&event.position))
+                        .set("tick_lower_index", event.tick_lower_index)
+                        .set("tick_upper_index", event.tick_upper_index)
+                        .set("liquidity", &event.liquidity)
+                        .set("token_a_amount", event.token_a_amount)
+                        .set("token_b_amount", event.token_b_amount)
+                        .set("token_a_transfer_fee", event.token_a_transfer_fee)
+                        .set("token_b_transfer_fee", event.token_b_transfer_fee);
+                    set_log(log, row);
+                    set_liquidity_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                    let position = PositionEvent {
+                        program_id: &log.program_id,
+                        pool: &event.whirlpool,
+                        position: &event.position,
+                        owner: accounts.map_or(&[][..], |accounts| accounts.position_authority.as_slice()),
+                        lower_index: event.tick_lower_index,
+                        upper_index: event.tick_upper_index,
+                        liquidity: &event.liquidity,
+                        amount0: event.token_a_amount,
+                        amount1: event.token_b_amount,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        log_index,
+                        PositionAction::IncreaseLiquidity,
+                        position,
+                    );
+                }
+                Some(pb::log::Log::LiquidityDecreased(event)) => {
+                    let instruction = decreases.next();
+                    let accounts = instruction.and_then(|(_, data)| data.accounts.as_ref());
+                    let key = common_key_v2(clock, transaction_index, log_index);
+                    let row = tables
+                        .create_row("orca_liquidity_decreased", key)
+                        .set("whirlpool", base58::encode(&event.whirlpool))
+                        .set("position", base58::encode(&event.position))
+                        .set("tick_lower_index", event.tick_lower_index)
+                        .set("tick_upper_index", event.tick_upper_index)
+                        .set("liquidity", &event.liquidity)
+                        .set("token_a_amount", event.token_a_amount)
+                        .set("token_b_amount", event.token_b_amount)
+                        .set("token_a_transfer_fee", event.token_a_transfer_fee)
+                        .set("token_b_transfer_fee", event.token_b_transfer_fee);
+                    set_log(log, row);
+                    set_liquidity_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                    let position = PositionEvent {
+                        program_id: &log.program_id,
+                        pool: &event.whirlpool,
+                        position: &event.position,
+                        owner: accounts.map_or(&[][..], |accounts| accounts.position_authority.as_slice()),
+                        lower_index: event.tick_lower_index,
+                        upper_index: event.tick_upper_index,
+                        liquidity: &event.liquidity,
+                        amount0: event.token_a_amount,
+                        amount1: event.token_b_amount,
+                        ..Default::default()
+                    };
+                    insert_position_event(
+                        tables,
+                        clock,
+                        &tx.signature,
+                        transaction_index,
+                        log_index,
+                        PositionAction::DecreaseLiquidity,
+                        position,
+                    );
+                }
+                Some(pb::log::Log::PoolInitialized(event)) => {
+                    // token vaults & funder are not part of the log
+                    let pool = Pool {
+                        program_id: &log.program_id,
+                        pool: &event.whirlpool,
+                        mint0: &event.token_mint_a,
+                        mint1: &event.token_mint_b,
+                        config: &event.whirlpools_config,
+                        fee_tier: event.tick_spacing,
+                        creator: &tx.fee_payer,
+                        ..Default::default()
+                    };
+                    insert_pool(tables, clock, &tx.signature, pool);
+                }
+                _ => {}
+            }
+        }
+    }
+    reserves.insert_rows(tables, clock);
+}
+
+/// Increase or decrease liquidity instructions of the transaction, empty when they can't be paired with their logs.
+fn liquidity_instructions(tx: &pb::Transaction, increase: bool) -> std::vec::IntoIter<(&pb::Instruction, &pb::ModifyLiquidityInstruction)> {
+    let instructions: Vec<_> = tx
+        .instructions
+        .iter()
+        .filter_map(|ix| match &ix.instruction {
+            Some(pb::instruction::Instruction::IncreaseLiquidity(data)) if increase => Some((ix, data)),
+            Some(pb::instruction::Instruction::DecreaseLiquidity(data)) if !increase => Some((ix, data)),
+            _ => None,
+        })
+        .collect();
+    let logs = tx
+        .logs
+        .iter()
+        .filter(|log| match &log.log {
+            Some(pb::log::Log::LiquidityIncreased(_)) => increase,
+            Some(pb::log::Log::LiquidityDecreased(_)) => !increase,
+            _ => false,
+        })
+        .count();
+    if instructions.len() == logs {
+        instructions.into_iter()
+    } else {
+        Vec::new().into_iter()
     }
 }
 
@@ -60,7 +304,10 @@ fn handle_swap_v1(
         .set("input_mint", base58::encode(input_mint))
         .set("output_mint", base58::encode(output_mint))
         .set("amount_in", event.input_amount)
-        .set("amount_out", event.output_amount);
+        .set("amount_out", event.output_amount)
+        .set("pre_sqrt_price", &event.pre_sqrt_price)
+        .set("post_sqrt_price", &event.post_sqrt_price)
+        .set("price", sqrt_price_x64_to_price(&event.post_sqrt_price).to_string());
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
@@ -93,7 +340,10 @@ fn handle_swap_v2(
         .set("input_mint", base58::encode(input_mint))
         .set("output_mint", base58::encode(output_mint))
         .set("amount_in", event.input_amount)
-        .set("amount_out", event.output_amount);
+        .set("amount_out", event.output_amount)
+        .set("pre_sqrt_price", &event.pre_sqrt_price)
+        .set("post_sqrt_price", &event.post_sqrt_price)
+        .set("price", sqrt_price_x64_to_price(&event.post_sqrt_price).to_string());
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
@@ -109,4 +359,19 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+}
+
+fn set_liquidity_instruction(instruction: Option<(&pb::Instruction, &pb::ModifyLiquidityInstruction)>, row: &mut Row) {
+    let Some((ix, data)) = instruction else { return };
+    if let Some(accounts) = &data.accounts {
+        row.set("position_authority", base58::encode(&accounts.position_authority))
+            .set("token_mint_a", base58::encode(&accounts.token_mint_a))
+            .set("token_mint_b", base58::encode(&accounts.token_mint_b));
+    }
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+}
+
+fn set_log(log: &pb::Log, row: &mut Row) {
+    row.set("program_id", base58::encode(&log.program_id)).set("stack_height", log.invoke_depth);
 }
@@ -0,0 +1,160 @@
+use common::db::set_clock;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+/// Lifecycle step of a limit order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderAction {
+    Place,
+    Cancel,
+    Modify,
+    Settle,
+}
+
+impl OrderAction {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            OrderAction::Place => "place",
+            OrderAction::Cancel => "cancel",
+            OrderAction::Modify => "modify",
+            OrderAction::Settle => "settle",
+        }
+    }
+}
+
+/// Side of an order, `None` when the event applies to both sides (ex: cancel all orders, settle funds).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    #[default]
+    None,
+    Bid,
+    Ask,
+}
+
+impl OrderSide {
+    /// Side as encoded by Phoenix & OpenBook v2 (0 = bid, 1 = ask).
+    pub const fn from_u32(side: u32) -> Self {
+        match side {
+            0 => OrderSide::Bid,
+            1 => OrderSide::Ask,
+            _ => OrderSide::None,
+        }
+    }
+
+    pub const fn opposite(&self) -> Self {
+        match self {
+            OrderSide::Bid => OrderSide::Ask,
+            OrderSide::Ask => OrderSide::Bid,
+            OrderSide::None => OrderSide::None,
+        }
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            OrderSide::None => "",
+            OrderSide::Bid => "bid",
+            OrderSide::Ask => "ask",
+        }
+    }
+}
+
+/// Order event, normalized across Phoenix & OpenBook v2.
+///
+/// Prices and sizes are in the market lots (price ticks for Phoenix), fields which are not exposed by the event are left empty.
+#[derive(Default, Clone, Copy)]
+pub struct OrderEvent<'a> {
+    pub program_id: &'a [u8],
+    pub market: &'a [u8],
+    pub trader: &'a [u8],
+    pub side: OrderSide,
+    pub order_id: &'a str,        // u128, empty when unknown
+    pub client_order_id: &'a str, // u128, empty when unknown
+    pub order_type: &'a str,
+    pub price: i64,
+    pub size: u64,          // base lots placed, cancelled or reduced
+    pub quote_size: u64,    // max quote lots (OpenBook)
+    pub remaining: u64,     // base lots remaining on the book
+    pub base_amount: u64,   // base tokens settled
+    pub quote_amount: u64,  // quote tokens settled
+    pub order_index: usize, // position of the order within instructions placing multiple orders
+}
+
+/// Inserts an `orderbook_orders` row, `index` is the instruction (or log) index of the event within the transaction.
+pub fn insert_order(tables: &mut Tables, clock: &Clock, signature: &[u8], transaction_index: usize, index: usize, action: OrderAction, event: OrderEvent) {
+    let key = [
+        ("block_hash", clock.id.to_string()),
+        ("transaction_index", transaction_index.to_string()),
+        ("instruction_index", index.to_string()),
+        ("program_id", base58::encode(event.program_id)),
+        ("action", action.as_str().to_string()),
+        ("order_index", event.order_index.to_string()),
+    ];
+    let row = tables
+        .create_row("orderbook_orders", key)
+        .set("signature", base58::encode(signature))
+        .set("market", base58::encode(event.market))
+        .set("trader", base58::encode(event.trader))
+        .set("side", event.side.as_str())
+        .set("order_id", or_zero(event.order_id))
+        .set("client_order_id", or_zero(event.client_order_id))
+        .set("order_type", event.order_type)
+        .set("price", event.price)
+        .set("size", event.size)
+        .set("quote_size", event.quote_size)
+        .set("remaining", event.remaining)
+        .set("base_amount", event.base_amount)
+        .set("quote_amount", event.quote_amount);
+    set_clock(clock, row);
+}
+
+/// Fill between a resting (maker) order and a taker, normalized across Phoenix & OpenBook v2.
+#[derive(Default, Clone, Copy)]
+pub struct FillEvent<'a> {
+    pub program_id: &'a [u8],
+    pub market: &'a [u8],
+    pub maker: &'a [u8],
+    pub taker: &'a [u8],
+    pub taker_side: OrderSide,
+    pub price: i64,
+    pub quantity: u64, // base lots filled
+    pub maker_order_id: &'a str,
+    pub maker_client_order_id: &'a str,
+    pub taker_client_order_id: &'a str,
+    pub maker_fee: u64,
+    pub taker_fee: u64,
+}
+
+/// Inserts an `orderbook_fills` row, `index` is the log index of the fill within the transaction.
+pub fn insert_fill(tables: &mut Tables, clock: &Clock, signature: &[u8], transaction_index: usize, index: usize, event: FillEvent) {
+    let key = [
+        ("block_hash", clock.id.to_string()),
+        ("transaction_index", transaction_index.to_string()),
+        ("instruction_index", index.to_string()),
+        ("program_id", base58::encode(event.program_id)),
+    ];
+    let row = tables
+        .create_row("orderbook_fills", key)
+        .set("signature", base58::encode(signature))
+        .set("market", base58::encode(event.market))
+        .set("maker", base58::encode(event.maker))
+        .set("taker", base58::encode(event.taker))
+        .set("taker_side", event.taker_side.as_str())
+        .set("price", event.price)
+        .set("quantity", event.quantity)
+        .set("maker_order_id", or_zero(event.maker_order_id))
+        .set("maker_client_order_id", or_zero(event.maker_client_order_id))
+        .set("taker_client_order_id", or_zero(event.taker_client_order_id))
+        .set("maker_fee", event.maker_fee)
+        .set("taker_fee", event.taker_fee);
+    set_clock(clock, row);
+}
+
+/// Order ids are UInt128 columns, `0` when unknown.
+fn or_zero(id: &str) -> &str {
+    if id.is_empty() {
+        "0"
+    } else {
+        id
+    }
+}
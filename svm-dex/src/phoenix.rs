@@ -4,6 +4,9 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::orderbooks::{insert_fill, insert_order, FillEvent, OrderAction, OrderEvent, OrderSide};
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
@@ -18,9 +21,120 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         handle_swap(tables, clock, tx, ix, accounts, transaction_index, instruction_index);
                     }
                 }
+                Some(pb::instruction::Instruction::WithdrawFunds(data)) => {
+                    let Some(accounts) = &data.accounts else { continue };
+                    let order = OrderEvent {
+                        program_id: &ix.program_id,
+                        market: &accounts.market,
+                        trader: &accounts.trader,
+                        base_amount: data.base_amount,
+                        quote_amount: data.quote_amount,
+                        ..Default::default()
+                    };
+                    insert_order(tables, clock, &tx.signature, transaction_index, instruction_index, OrderAction::Settle, order);
+                }
+                // placed, reduced & cancelled orders are read from the market events
                 _ => {}
             }
         }
+        for (log_index, log) in tx.logs.iter().enumerate() {
+            handle_market_event(tables, clock, tx, log, transaction_index, log_index);
+        }
+    }
+}
+
+fn handle_market_event(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, log: &pb::Log, transaction_index: usize, log_index: usize) {
+    let order_type = log.order_type.map(order_type_name).unwrap_or_default();
+    let order_id = match &log.log {
+        Some(pb::log::Log::Place(event)) => event.order_sequence_number,
+        Some(pb::log::Log::Reduce(event)) => event.order_sequence_number,
+        Some(pb::log::Log::Evict(event)) => event.order_sequence_number,
+        Some(pb::log::Log::Fill(event)) => event.order_sequence_number,
+        _ => return,
+    }
+    .to_string();
+    let (action, order) = match &log.log {
+        Some(pb::log::Log::Place(event)) => (
+            OrderAction::Place,
+            OrderEvent {
+                side: order_side(event.order_sequence_number),
+                order_id: &order_id,
+                client_order_id: &event.client_order_id,
+                order_type,
+                price: event.price_in_ticks as i64,
+                size: event.base_lots_placed,
+                remaining: event.base_lots_placed,
+                ..Default::default()
+            },
+        ),
+        Some(pb::log::Log::Reduce(event)) => (
+            if event.base_lots_remaining == 0 {
+                OrderAction::Cancel
+            } else {
+                OrderAction::Modify
+            },
+            OrderEvent {
+                side: order_side(event.order_sequence_number),
+                order_id: &order_id,
+                price: event.price_in_ticks as i64,
+                size: event.base_lots_removed,
+                remaining: event.base_lots_remaining,
+                ..Default::default()
+            },
+        ),
+        // orders evicted from a full book are cancelled on behalf of their maker
+        Some(pb::log::Log::Evict(event)) => (
+            OrderAction::Cancel,
+            OrderEvent {
+                trader: &event.maker_id,
+                side: order_side(event.order_sequence_number),
+                order_id: &order_id,
+                price: event.price_in_ticks as i64,
+                size: event.base_lots_evicted,
+                ..Default::default()
+            },
+        ),
+        Some(pb::log::Log::Fill(event)) => {
+            let fill = FillEvent {
+                program_id: &log.program_id,
+                market: &log.market,
+                maker: &event.maker_id,
+                taker: &log.signer,
+                taker_side: order_side(event.order_sequence_number).opposite(),
+                price: event.price_in_ticks as i64,
+                quantity: event.base_lots_filled,
+                maker_order_id: &order_id,
+                ..Default::default()
+            };
+            insert_fill(tables, clock, &tx.signature, transaction_index, log_index, fill);
+            return;
+        }
+        _ => return,
+    };
+    let order = OrderEvent {
+        program_id: &log.program_id,
+        market: &log.market,
+        trader: if order.trader.is_empty() { log.signer.as_slice() } else { order.trader },
+        ..order
+    };
+    insert_order(tables, clock, &tx.signature, transaction_index, log_index, action, order);
+}
+
+/// Phoenix order sequence numbers of bids have their most significant bit set.
+fn order_side(order_sequence_number: u64) -> OrderSide {
+    if order_sequence_number.leading_zeros() == 0 {
+        OrderSide::Bid
+    } else {
+        OrderSide::Ask
+    }
+}
+
+fn order_type_name(order_type: u32) -> &'static str {
+    match order_type {
+        0 => "post_only",
+        1 => "limit",
+        2 => "immediate_or_cancel",
+        _ => "",
     }
 }
 
@@ -55,4 +169,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
@@ -0,0 +1,38 @@
+use common::db::set_clock;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+/// Pool created by an AMM, normalized across the DEXs.
+///
+/// Accounts which are not exposed by the pool creation (ex: vaults of log based events) are left empty.
+#[derive(Default)]
+pub struct Pool<'a> {
+    pub program_id: &'a [u8],
+    pub pool: &'a [u8],
+    pub mint0: &'a [u8],
+    pub mint1: &'a [u8],
+    pub vault0: &'a [u8],
+    pub vault1: &'a [u8],
+    pub lp_mint: &'a [u8],
+    pub config: &'a [u8],
+    pub fee_tier: u32, // tick spacing (Orca), bin step (DLMM), 0 when the fee is set by the config
+    pub creator: &'a [u8],
+}
+
+/// Inserts the pool in the `pools` registry, keyed by pool address.
+pub fn insert_pool(tables: &mut Tables, clock: &Clock, signature: &[u8], pool: Pool) {
+    let row = tables
+        .create_row("pools", [("pool", base58::encode(pool.pool))])
+        .set("program_id", base58::encode(pool.program_id))
+        .set("mint0", base58::encode(pool.mint0))
+        .set("mint1", base58::encode(pool.mint1))
+        .set("vault0", base58::encode(pool.vault0))
+        .set("vault1", base58::encode(pool.vault1))
+        .set("lp_mint", base58::encode(pool.lp_mint))
+        .set("config", base58::encode(pool.config))
+        .set("fee_tier", pool.fee_tier)
+        .set("creator", base58::encode(pool.creator))
+        .set("signature", base58::encode(signature));
+    set_clock(clock, row);
+}
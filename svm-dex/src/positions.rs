@@ -0,0 +1,81 @@
+use common::db::set_clock;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+/// Lifecycle step of a concentrated liquidity position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionAction {
+    Open,
+    Close,
+    IncreaseLiquidity,
+    DecreaseLiquidity,
+    CollectFees,
+    CollectReward,
+}
+
+impl PositionAction {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PositionAction::Open => "open",
+            PositionAction::Close => "close",
+            PositionAction::IncreaseLiquidity => "increase_liquidity",
+            PositionAction::DecreaseLiquidity => "decrease_liquidity",
+            PositionAction::CollectFees => "collect_fees",
+            PositionAction::CollectReward => "collect_reward",
+        }
+    }
+}
+
+/// Concentrated liquidity position event, normalized across Orca, Raydium CLMM & Meteora DLMM.
+///
+/// Fields which are not exposed by the event are left empty (ex: the range is only known when the position is opened).
+#[derive(Default, Clone, Copy)]
+pub struct PositionEvent<'a> {
+    pub program_id: &'a [u8],
+    pub pool: &'a [u8],
+    pub position: &'a [u8],
+    pub owner: &'a [u8],
+    pub lower_index: i32,   // lower tick (Orca, Raydium CLMM) or lower bin id (DLMM)
+    pub upper_index: i32,   // upper tick (Orca, Raydium CLMM) or upper bin id (DLMM)
+    pub liquidity: &'a str, // liquidity delta, empty for DLMM (liquidity is tracked per bin)
+    pub amount0: u64,       // deposited, withdrawn or collected fees of token 0 (A, X)
+    pub amount1: u64,       // deposited, withdrawn or collected fees of token 1 (B, Y)
+    pub reward_index: u32,
+    pub reward_mint: &'a [u8],
+    pub reward_amount: u64,
+}
+
+/// Inserts a `position_events` row, `index` is the instruction (or log) index of the event within the transaction.
+pub fn insert_position_event(
+    tables: &mut Tables,
+    clock: &Clock,
+    signature: &[u8],
+    transaction_index: usize,
+    index: usize,
+    action: PositionAction,
+    event: PositionEvent,
+) {
+    let key = [
+        ("block_hash", clock.id.to_string()),
+        ("transaction_index", transaction_index.to_string()),
+        ("instruction_index", index.to_string()),
+        ("program_id", base58::encode(event.program_id)),
+        ("action", action.as_str().to_string()),
+        ("reward_index", event.reward_index.to_string()),
+    ];
+    let row = tables
+        .create_row("position_events", key)
+        .set("signature", base58::encode(signature))
+        .set("pool", base58::encode(event.pool))
+        .set("position", base58::encode(event.position))
+        .set("owner", base58::encode(event.owner))
+        .set("lower_index", event.lower_index)
+        .set("upper_index", event.upper_index)
+        .set("liquidity", if event.liquidity.is_empty() { "0" } else { event.liquidity })
+        .set("amount0", event.amount0)
+        .set("amount1", event.amount1)
+        .set("reward_mint", base58::encode(event.reward_mint))
+        .set("reward_amount", event.reward_amount);
+    set_clock(clock, row);
+}
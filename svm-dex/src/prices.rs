@@ -0,0 +1,80 @@
+/// Converts a Q64.64 `sqrt_price_x64` (as decoded from CLMM pools) to the price of token 0 (A) in token 1 (B).
+///
+/// The price is in raw token units, it is not adjusted by the decimals of the mints.
+pub fn sqrt_price_x64_to_price(sqrt_price_x64: &str) -> f64 {
+    match sqrt_price_x64.parse::<u128>() {
+        Ok(sqrt_price) => {
+            let sqrt_price = sqrt_price as f64 / 2f64.powi(64);
+            sqrt_price * sqrt_price
+        }
+        Err(_) => 0.0,
+    }
+}
+
+/// Converts a DLMM bin id to the price of token X in token Y, `(1 + bin_step / 10_000) ^ bin_id`.
+///
+/// The price is in raw token units, it is not adjusted by the decimals of the mints.
+pub fn bin_id_to_price(bin_id: i32, bin_step: i64) -> f64 {
+    (1.0 + bin_step as f64 / 10_000.0).powi(bin_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Scales a raw token unit price to a UI price
+    fn adjust_decimals(price: f64, decimals0: i32, decimals1: i32) -> f64 {
+        price * 10f64.powi(decimals0 - decimals1)
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() <= expected.abs() * 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn sqrt_price_x64_one() {
+        // Whirlpool USDC/USDT pool at parity (both 6 decimals)
+        assert_close(sqrt_price_x64_to_price("18446744073709551616"), 1.0);
+    }
+
+    #[test]
+    fn sqrt_price_x64_square() {
+        // sqrt price of 2.0
+        assert_close(sqrt_price_x64_to_price("36893488147419103232"), 4.0);
+    }
+
+    #[test]
+    fn sqrt_price_x64_with_decimals() {
+        // Raydium CLMM SOL (9 decimals) / USDC (6 decimals) pool at 150 USDC per SOL
+        let price = sqrt_price_x64_to_price("7144393258922745856");
+        assert_close(price, 0.15);
+        assert_close(adjust_decimals(price, 9, 6), 150.0);
+    }
+
+    #[test]
+    fn sqrt_price_x64_invalid() {
+        assert_eq!(sqrt_price_x64_to_price(""), 0.0);
+        assert_eq!(sqrt_price_x64_to_price("-1"), 0.0);
+        assert_eq!(sqrt_price_x64_to_price("not a number"), 0.0);
+    }
+
+    #[test]
+    fn bin_id_zero() {
+        assert_eq!(bin_id_to_price(0, 10), 1.0);
+        assert_eq!(bin_id_to_price(0, 100), 1.0);
+    }
+
+    #[test]
+    fn bin_id_positive_and_negative() {
+        assert_close(bin_id_to_price(100, 10), 1.105115697720756);
+        assert_close(bin_id_to_price(-100, 25), 0.779043791354649);
+    }
+
+    #[test]
+    fn bin_id_with_decimals() {
+        // Meteora DLMM SOL (9 decimals) / USDC (6 decimals) pool with a 10 bps bin step, ~150 USDC per SOL
+        let price = bin_id_to_price(-1898, 10);
+        assert_close(price, 0.15001025325444642);
+        assert_close(adjust_decimals(price, 9, 6), 150.0102532544464);
+    }
+}
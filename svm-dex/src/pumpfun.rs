@@ -1,10 +1,16 @@
 use common::db::{common_key_v2, set_clock, set_pumpfun_instruction_v2, set_pumpfun_transaction_v2};
 use proto::pb::pumpfun::v1 as pb;
 use substreams::pb::substreams::Clock;
+use substreams::store::StoreGetString;
 use substreams_database_change::tables::Row;
 use substreams_solana::base58;
 
-pub fn process_events(tables: &mut substreams_database_change::tables::Tables, clock: &Clock, events: &pb::Events) {
+use crate::aggregators::set_aggregator;
+use crate::bonding_curves::{CurveMetrics, CurveParams};
+use crate::fees::{insert_creator_fee, insert_protocol_fee, CreatorFee, CreatorFeeAction, ProtocolFee};
+use crate::launches::{insert_token_launch, LaunchStage, TokenLaunch};
+
+pub fn process_events(tables: &mut substreams_database_change::tables::Tables, clock: &Clock, events: &pb::Events, curves: &StoreGetString) {
     for (transaction_index, transaction) in events.transactions.iter().enumerate() {
         for (instruction_index, instruction) in transaction.instructions.iter().enumerate() {
             match &instruction.instruction {
@@ -12,21 +18,161 @@ pub fn process_events(tables: &mut substreams_database_change::tables::Tables, c
                     let Some(event) = get_trade_event(transaction, instruction_index) else {
                         continue;
                     };
-                    handle_buy(tables, clock, transaction, instruction, data, event, transaction_index, instruction_index);
+                    handle_buy(
+                        tables,
+                        clock,
+                        curves,
+                        transaction,
+                        instruction,
+                        data,
+                        event,
+                        transaction_index,
+                        instruction_index,
+                    );
+                    insert_trade_fees(tables, clock, transaction, instruction, event, transaction_index, instruction_index);
                 }
                 Some(pb::instruction::Instruction::Sell(data)) => {
                     let Some(event) = get_trade_event(transaction, instruction_index) else {
                         continue;
                     };
-                    handle_sell(tables, clock, transaction, instruction, data, event, transaction_index, instruction_index);
+                    handle_sell(
+                        tables,
+                        clock,
+                        curves,
+                        transaction,
+                        instruction,
+                        data,
+                        event,
+                        transaction_index,
+                        instruction_index,
+                    );
+                    insert_trade_fees(tables, clock, transaction, instruction, event, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::Create(data)) => {
+                    let Some(accounts) = &data.accounts else {
+                        continue;
+                    };
+                    let launch = TokenLaunch {
+                        launchpad: &instruction.program_id,
+                        mint: &accounts.mint,
+                        creator: &data.creator,
+                        name: &data.name,
+                        symbol: &data.symbol,
+                        uri: &data.uri,
+                        bonding_curve: &accounts.bonding_curve,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &transaction.signature, LaunchStage::Create, launch);
+                }
+                Some(pb::instruction::Instruction::Complete(event)) => {
+                    let launch = TokenLaunch {
+                        launchpad: &instruction.program_id,
+                        mint: &event.mint,
+                        bonding_curve: &event.bonding_curve,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &transaction.signature, LaunchStage::Complete, launch);
+                }
+                Some(pb::instruction::Instruction::Migrate(data)) => {
+                    let Some(accounts) = &data.accounts else {
+                        continue;
+                    };
+                    let launch = TokenLaunch {
+                        launchpad: &instruction.program_id,
+                        mint: &accounts.mint,
+                        bonding_curve: &accounts.bonding_curve,
+                        graduation_program_id: &accounts.pump_amm,
+                        graduation_pool: &accounts.pool,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &transaction.signature, LaunchStage::Graduate, launch);
+                }
+                Some(pb::instruction::Instruction::CollectCreatorFee(event)) => {
+                    let fee = CreatorFee {
+                        program_id: &instruction.program_id,
+                        creator: &event.creator,
+                        amount: event.creator_fee,
+                        ..Default::default()
+                    };
+                    insert_creator_fee(
+                        tables,
+                        clock,
+                        &transaction.signature,
+                        transaction_index,
+                        instruction_index,
+                        CreatorFeeAction::Claim,
+                        fee,
+                    );
+                }
+                Some(pb::instruction::Instruction::SetCreator(event)) => {
+                    let fee = CreatorFee {
+                        program_id: &instruction.program_id,
+                        mint: &event.mint,
+                        creator: &event.creator,
+                        ..Default::default()
+                    };
+                    insert_creator_fee(
+                        tables,
+                        clock,
+                        &transaction.signature,
+                        transaction_index,
+                        instruction_index,
+                        CreatorFeeAction::SetCreator,
+                        fee,
+                    );
                 }
-
                 _ => {}
             }
         }
     }
 }
 
+/// Fees are paid in SOL, the quote mint is left empty.
+fn insert_trade_fees(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    event: &pb::TradeEvent,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    // only emitted by TradeEventV2
+    if let (Some(fee_recipient), Some(amount)) = (&event.fee_recipient, event.fee) {
+        let fee = ProtocolFee {
+            program_id: &instruction.program_id,
+            mint: &event.mint,
+            pool: &[],
+            quote_mint: &[],
+            fee_recipient,
+            fee_basis_points: event.fee_basis_points.unwrap_or(0),
+            amount,
+        };
+        insert_protocol_fee(tables, clock, &transaction.signature, transaction_index, instruction_index, fee);
+    }
+    if let (Some(creator), Some(amount)) = (&event.creator, event.creator_fee) {
+        if amount > 0 {
+            let fee = CreatorFee {
+                program_id: &instruction.program_id,
+                mint: &event.mint,
+                creator,
+                fee_basis_points: event.creator_fee_basis_points.unwrap_or(0),
+                amount,
+                ..Default::default()
+            };
+            insert_creator_fee(
+                tables,
+                clock,
+                &transaction.signature,
+                transaction_index,
+                instruction_index,
+                CreatorFeeAction::Accrue,
+                fee,
+            );
+        }
+    }
+}
+
 fn get_trade_event(transaction: &pb::Transaction, instruction_index: usize) -> Option<&pb::TradeEvent> {
     if instruction_index + 1 < transaction.instructions.len() {
         match &transaction.instructions[instruction_index + 1].instruction {
@@ -41,6 +187,7 @@ fn get_trade_event(transaction: &pb::Transaction, instruction_index: usize) -> O
 fn handle_buy(
     tables: &mut substreams_database_change::tables::Tables,
     clock: &Clock,
+    curves: &StoreGetString,
     transaction: &pb::Transaction,
     instruction: &pb::Instruction,
     data: &pb::BuyInstruction,
@@ -59,7 +206,9 @@ fn handle_buy(
         .set("max_sol_cost", data.max_sol_cost);
 
     set_trade_event(event, accounts, row);
+    set_curve_metrics(curves, event, row);
     set_pumpfun_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_pumpfun_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
@@ -67,6 +216,7 @@ fn handle_buy(
 fn handle_sell(
     tables: &mut substreams_database_change::tables::Tables,
     clock: &Clock,
+    curves: &StoreGetString,
     transaction: &pb::Transaction,
     instruction: &pb::Instruction,
     data: &pb::SellInstruction,
@@ -85,11 +235,20 @@ fn handle_sell(
         .set("min_sol_output", data.min_sol_output);
 
     set_trade_event(event, accounts, row);
+    set_curve_metrics(curves, event, row);
     set_pumpfun_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_pumpfun_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
 
+fn set_curve_metrics(curves: &StoreGetString, event: &pb::TradeEvent, row: &mut Row) {
+    CurveParams::get(curves, &event.mint)
+        .map(|params| CurveMetrics::pumpfun(&params, event))
+        .unwrap_or_default()
+        .set(row);
+}
+
 fn set_trade_event(event: &pb::TradeEvent, accounts: &pb::TradeAccounts, row: &mut Row) {
     row.set("global", base58::encode(&accounts.mint))
         .set("fee_recipient", base58::encode(&accounts.fee_recipient))
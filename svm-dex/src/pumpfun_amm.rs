@@ -4,6 +4,10 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::Row;
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::fees::{insert_creator_fee, insert_protocol_fee, CreatorFee, CreatorFeeAction, ProtocolFee};
+use crate::pools::{insert_pool, Pool};
+
 pub fn process_events(tables: &mut substreams_database_change::tables::Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, transaction) in events.transactions.iter().enumerate() {
         for (instruction_index, instruction) in transaction.instructions.iter().enumerate() {
@@ -13,12 +17,68 @@ pub fn process_events(tables: &mut substreams_database_change::tables::Tables, c
                         continue;
                     };
                     handle_buy(tables, clock, transaction, instruction, data, event, transaction_index, instruction_index);
+                    if let (Some(accounts), Some(trade)) = (&data.accounts, &event.trade) {
+                        insert_trade_fees(tables, clock, transaction, instruction, accounts, trade, transaction_index, instruction_index);
+                    }
                 }
                 Some(pb::instruction::Instruction::SellInstruction(data)) => {
                     let Some(event) = get_sell_event(transaction, instruction_index) else {
                         continue;
                     };
                     handle_sell(tables, clock, transaction, instruction, data, event, transaction_index, instruction_index);
+                    if let (Some(accounts), Some(trade)) = (&data.accounts, &event.trade) {
+                        insert_trade_fees(tables, clock, transaction, instruction, accounts, trade, transaction_index, instruction_index);
+                    }
+                }
+                Some(pb::instruction::Instruction::CreatePoolEvent(event)) => {
+                    let pool = Pool {
+                        program_id: &instruction.program_id,
+                        pool: &event.pool,
+                        mint0: &event.base_mint,
+                        mint1: &event.quote_mint,
+                        vault0: &event.pool_base_token_account,
+                        vault1: &event.pool_quote_token_account,
+                        lp_mint: &event.lp_mint,
+                        creator: &event.creator,
+                        ..Default::default()
+                    };
+                    insert_pool(tables, clock, &transaction.signature, pool);
+                }
+                Some(pb::instruction::Instruction::CollectCoinCreatorFeeEvent(event)) => {
+                    let fee = CreatorFee {
+                        program_id: &instruction.program_id,
+                        creator: &event.coin_creator,
+                        quote_mint: &event.quote_mint,
+                        amount: event.coin_creator_fee,
+                        ..Default::default()
+                    };
+                    insert_creator_fee(
+                        tables,
+                        clock,
+                        &transaction.signature,
+                        transaction_index,
+                        instruction_index,
+                        CreatorFeeAction::Claim,
+                        fee,
+                    );
+                }
+                Some(pb::instruction::Instruction::SetCoinCreatorEvent(event)) => {
+                    let fee = CreatorFee {
+                        program_id: &instruction.program_id,
+                        mint: &event.base_mint,
+                        pool: &event.pool,
+                        creator: &event.coin_creator,
+                        ..Default::default()
+                    };
+                    insert_creator_fee(
+                        tables,
+                        clock,
+                        &transaction.signature,
+                        transaction_index,
+                        instruction_index,
+                        CreatorFeeAction::SetCreator,
+                        fee,
+                    );
                 }
 
                 _ => {}
@@ -74,6 +134,7 @@ fn handle_buy(
 
     set_trade_account(accounts, row);
     set_pumpfun_amm_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_pumpfun_amm_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
@@ -105,10 +166,58 @@ fn handle_sell(
 
     set_trade_account(accounts, row);
     set_pumpfun_amm_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_pumpfun_amm_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
 
+/// Fees are paid in the quote token of the pool.
+fn insert_trade_fees(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    accounts: &pb::TradeAccounts,
+    trade: &pb::TradeDetails,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let fee = ProtocolFee {
+        program_id: &instruction.program_id,
+        mint: &accounts.base_mint,
+        pool: &accounts.pool,
+        quote_mint: &accounts.quote_mint,
+        fee_recipient: &trade.protocol_fee_recipient,
+        fee_basis_points: trade.protocol_fee_basis_points,
+        amount: trade.protocol_fee,
+    };
+    insert_protocol_fee(tables, clock, &transaction.signature, transaction_index, instruction_index, fee);
+
+    // only emitted by the V2 events
+    if let (Some(creator), Some(amount)) = (&trade.coin_creator, trade.coin_creator_fee) {
+        if amount > 0 {
+            let fee = CreatorFee {
+                program_id: &instruction.program_id,
+                mint: &accounts.base_mint,
+                pool: &accounts.pool,
+                creator,
+                quote_mint: &accounts.quote_mint,
+                fee_basis_points: trade.coin_creator_fee_basis_points.unwrap_or(0),
+                amount,
+            };
+            insert_creator_fee(
+                tables,
+                clock,
+                &transaction.signature,
+                transaction_index,
+                instruction_index,
+                CreatorFeeAction::Accrue,
+                fee,
+            );
+        }
+    }
+}
+
 fn set_trade_account(accounts: &pb::TradeAccounts, row: &mut Row) {
     row.set("pool", base58::encode(accounts.pool.as_slice()))
         .set("user", base58::encode(accounts.user.as_slice()))
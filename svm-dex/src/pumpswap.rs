@@ -4,6 +4,8 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (log_index, log) in tx.logs.iter().enumerate() {
@@ -15,6 +17,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                 Some(pb::log::Log::Sell(event)) => {
                     handle_sell(tables, clock, tx, ix, event, transaction_index, log_index);
                 }
+                // `CreatePoolEvent` is registered in `pools` by `pumpfun_amm` (same program, including the pool vaults)
                 _ => {}
             }
         }
@@ -83,4 +86,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
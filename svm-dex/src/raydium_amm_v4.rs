@@ -4,48 +4,225 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::Row;
 use substreams_solana::base58;
 
-use crate::enums::Direction;
+use crate::aggregators::set_aggregator;
+use crate::enums::{AmmParam, Direction};
+use crate::pools::{insert_pool, Pool};
+use crate::reserves::PoolReserves;
 
 pub fn process_events(tables: &mut substreams_database_change::tables::Tables, clock: &Clock, events: &pb::Events) {
+    let mut reserves = PoolReserves::default();
     for (transaction_index, transaction) in events.transactions.iter().enumerate() {
+        // pool registry, reserves & admin instructions do not depend on the logs
+        for (instruction_index, instruction) in transaction.instructions.iter().enumerate() {
+            let (accounts, vault_balances) = match &instruction.instruction {
+                Some(pb::instruction::Instruction::Initialize2(data)) => {
+                    handle_initialize2(tables, clock, transaction, instruction, data);
+                    continue;
+                }
+                Some(pb::instruction::Instruction::WithdrawPnl(data)) => {
+                    handle_withdraw_pnl(tables, clock, transaction, instruction, data, transaction_index, instruction_index);
+                    continue;
+                }
+                Some(pb::instruction::Instruction::SetParams(data)) => {
+                    handle_set_params(tables, clock, transaction, instruction, data, transaction_index, instruction_index);
+                    continue;
+                }
+                Some(pb::instruction::Instruction::SwapBaseIn(data)) => (&data.accounts, &data.vault_balances),
+                Some(pb::instruction::Instruction::SwapBaseOut(data)) => (&data.accounts, &data.vault_balances),
+                _ => continue,
+            };
+            if let Some(accounts) = accounts {
+                reserves.insert(
+                    &instruction.program_id,
+                    &accounts.amm,
+                    vault_balances.iter().map(|v| (v.mint.as_slice(), v.post_amount)),
+                );
+            }
+        }
+        // `WithdrawPnl` & `SetParams` do not emit a `ray_log`
+        let logged: Vec<(usize, &pb::Instruction)> = transaction.instructions.iter().enumerate().filter(|(_, ix)| emits_log(ix)).collect();
+
         // skip if log truncates (max size of 10KB and won't emit log events)
         // this is a workaround for the issue where the transaction logs are not emitted
         // if the transaction is too large, which can happen with large swap transactions.
-        if transaction.logs.len() != transaction.instructions.len() {
+        if transaction.logs.len() != logged.len() {
             continue;
         }
-        // assumes that logs & instruction sizes are equal
+        // assumes that logs & logged instruction sizes are equal
         // if not, it will skip the instruction
-        for (instruction_index, instruction) in transaction.instructions.iter().enumerate() {
+        for ((instruction_index, instruction), log) in logged.into_iter().zip(transaction.logs.iter()) {
             match &instruction.instruction {
                 Some(pb::instruction::Instruction::SwapBaseIn(data)) => {
-                    handle_swap_base_in(
-                        tables,
-                        clock,
-                        transaction,
-                        instruction,
-                        data,
-                        &transaction.logs[instruction_index],
-                        transaction_index,
-                        instruction_index,
-                    );
+                    handle_swap_base_in(tables, clock, transaction, instruction, data, log, transaction_index, instruction_index);
                 }
                 Some(pb::instruction::Instruction::SwapBaseOut(data)) => {
-                    handle_swap_base_out(
-                        tables,
-                        clock,
-                        transaction,
-                        instruction,
-                        data,
-                        &transaction.logs[instruction_index],
-                        transaction_index,
-                        instruction_index,
-                    );
+                    handle_swap_base_out(tables, clock, transaction, instruction, data, log, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::Deposit(data)) => {
+                    handle_deposit(tables, clock, transaction, instruction, data, log, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::Withdraw(data)) => {
+                    handle_withdraw(tables, clock, transaction, instruction, data, log, transaction_index, instruction_index);
+                }
+                Some(pb::instruction::Instruction::Initialize2(data)) => {
+                    handle_init_log(tables, clock, transaction, instruction, data, log, transaction_index, instruction_index);
                 }
                 _ => {}
             }
         }
     }
+    reserves.insert_rows(tables, clock);
+}
+
+fn handle_initialize2(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::Initialize2Instruction,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let pool = Pool {
+        program_id: &instruction.program_id,
+        pool: &accounts.amm,
+        mint0: &accounts.coin_mint,
+        mint1: &accounts.pc_mint,
+        vault0: &accounts.amm_coin_vault,
+        vault1: &accounts.amm_pc_vault,
+        lp_mint: &accounts.lp_mint,
+        creator: &accounts.user_wallet,
+        ..Default::default()
+    };
+    insert_pool(tables, clock, &transaction.signature, pool);
+}
+
+fn emits_log(instruction: &pb::Instruction) -> bool {
+    !matches!(
+        instruction.instruction,
+        Some(pb::instruction::Instruction::WithdrawPnl(_)) | Some(pb::instruction::Instruction::SetParams(_)) | None
+    )
+}
+
+fn handle_init_log(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::Initialize2Instruction,
+    log_cursor: &pb::Log,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let log = match &log_cursor.log {
+        Some(pb::log::Log::Init(l)) => l,
+        _ => return,
+    };
+    let accounts = match &data.accounts {
+        Some(accounts) => accounts,
+        None => return,
+    };
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("raydium_amm_v4_initialize", key)
+        // -- accounts --
+        .set("amm", base58::encode(&accounts.amm))
+        .set("amm_authority", base58::encode(&accounts.amm_authority))
+        .set("amm_open_orders", base58::encode(&accounts.amm_open_orders))
+        .set("lp_mint", base58::encode(&accounts.lp_mint))
+        .set("coin_mint", base58::encode(&accounts.coin_mint))
+        .set("pc_mint", base58::encode(&accounts.pc_mint))
+        .set("amm_coin_vault", base58::encode(&accounts.amm_coin_vault))
+        .set("amm_pc_vault", base58::encode(&accounts.amm_pc_vault))
+        .set("amm_target_orders", base58::encode(&accounts.amm_target_orders))
+        .set("market_program", base58::encode(&accounts.market_program))
+        .set("market", base58::encode(&accounts.market))
+        .set("user_wallet", base58::encode(&accounts.user_wallet))
+        // -- data --
+        .set("nonce", data.nonce)
+        .set("open_time", data.open_time)
+        .set("init_coin_amount", data.init_coin_amount)
+        .set("init_pc_amount", data.init_pc_amount)
+        // -- log --
+        .set("coin_decimals", log.coin_decimals)
+        .set("pc_decimals", log.pc_decimals)
+        .set("coin_lot_size", log.coin_lot_size)
+        .set("pc_lot_size", log.pc_lot_size)
+        .set("coin_amount", log.coin_amount)
+        .set("pc_amount", log.pc_amount);
+
+    set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_transaction_v2(transaction, row);
+    set_clock(clock, row);
+}
+
+fn handle_withdraw_pnl(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::WithdrawPnlInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let accounts = match &data.accounts {
+        Some(accounts) => accounts,
+        None => return,
+    };
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("raydium_amm_v4_withdraw_pnl", key)
+        // -- accounts --
+        .set("amm", base58::encode(&accounts.amm))
+        .set("amm_config", base58::encode(&accounts.amm_config))
+        .set("amm_coin_vault", base58::encode(&accounts.amm_coin_vault))
+        .set("amm_pc_vault", base58::encode(&accounts.amm_pc_vault))
+        .set("coin_pnl_token_account", base58::encode(&accounts.coin_pnl_token_account))
+        .set("pc_pnl_token_account", base58::encode(&accounts.pc_pnl_token_account))
+        .set("pnl_owner", base58::encode(&accounts.pnl_owner))
+        // -- inner transfers --
+        .set("coin_mint", base58::encode(&data.coin_mint))
+        .set("coin_amount", data.coin_amount)
+        .set("pc_mint", base58::encode(&data.pc_mint))
+        .set("pc_amount", data.pc_amount);
+
+    set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_transaction_v2(transaction, row);
+    set_clock(clock, row);
+}
+
+fn handle_set_params(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::SetParamsInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let fees = data.fees.clone().unwrap_or_default();
+    let row = tables
+        .create_row("raydium_amm_v4_set_params", key)
+        // -- accounts --
+        .set("amm", base58::encode(&data.amm))
+        .set("amm_admin", base58::encode(&data.amm_admin))
+        // -- data --
+        .set("param", AmmParam::try_from(data.param).map_or("Unknown", |param| param.as_str()))
+        .set("value", data.value.unwrap_or_default())
+        .set("new_pubkey", data.new_pubkey.as_ref().map(base58::encode).unwrap_or_default())
+        .set("trade_fee_numerator", fees.trade_fee_numerator)
+        .set("trade_fee_denominator", fees.trade_fee_denominator)
+        .set("pnl_numerator", fees.pnl_numerator)
+        .set("pnl_denominator", fees.pnl_denominator)
+        .set("swap_fee_numerator", fees.swap_fee_numerator)
+        .set("swap_fee_denominator", fees.swap_fee_denominator);
+
+    set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_transaction_v2(transaction, row);
+    set_clock(clock, row);
 }
 
 fn handle_swap_base_in(
@@ -83,6 +260,7 @@ fn handle_swap_base_in(
 
     set_swap_accounts(accounts, row);
     set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
@@ -122,10 +300,106 @@ fn handle_swap_base_out(
 
     set_swap_accounts(accounts, row);
     set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_transaction_v2(transaction, row);
+    set_clock(clock, row);
+}
+
+fn handle_deposit(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::DepositInstruction,
+    log_cursor: &pb::Log,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let log = match &log_cursor.log {
+        Some(pb::log::Log::Deposit(l)) => l,
+        _ => return,
+    };
+    let accounts = match &data.accounts {
+        Some(accounts) => accounts,
+        None => return,
+    };
+    let key = common_key_v2(&clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("raydium_amm_v4_deposit", key)
+        // -- data --
+        .set("max_coin_amount", data.max_coin_amount)
+        .set("max_pc_amount", data.max_pc_amount)
+        .set("base_side", data.base_side)
+        // -- log --
+        .set("coin_amount", log.deduct_coin)
+        .set("pc_amount", log.deduct_pc)
+        .set("lp_amount", log.mint_lp)
+        .set("pool_coin", log.pool_coin)
+        .set("pool_pc", log.pool_pc)
+        .set("pool_lp", log.pool_lp)
+        .set("calc_pnl_x", &log.calc_pnl_x)
+        .set("calc_pnl_y", &log.calc_pnl_y);
+
+    set_liquidity_accounts(accounts, row);
+    set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
+    set_transaction_v2(transaction, row);
+    set_clock(clock, row);
+}
+
+fn handle_withdraw(
+    tables: &mut substreams_database_change::tables::Tables,
+    clock: &Clock,
+    transaction: &pb::Transaction,
+    instruction: &pb::Instruction,
+    data: &pb::WithdrawInstruction,
+    log_cursor: &pb::Log,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let log = match &log_cursor.log {
+        Some(pb::log::Log::Withdraw(l)) => l,
+        _ => return,
+    };
+    let accounts = match &data.accounts {
+        Some(accounts) => accounts,
+        None => return,
+    };
+    let key = common_key_v2(&clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("raydium_amm_v4_withdraw", key)
+        // -- data --
+        .set("amount", data.amount)
+        // -- log --
+        .set("coin_amount", log.out_coin)
+        .set("pc_amount", log.out_pc)
+        .set("lp_amount", log.withdraw_lp)
+        .set("pool_coin", log.pool_coin)
+        .set("pool_pc", log.pool_pc)
+        .set("pool_lp", log.pool_lp)
+        .set("calc_pnl_x", &log.calc_pnl_x)
+        .set("calc_pnl_y", &log.calc_pnl_y);
+
+    set_liquidity_accounts(accounts, row);
+    set_instruction_v2(instruction, row);
+    set_aggregator(&instruction.parent_program_id, &instruction.outer_program_id, row);
     set_transaction_v2(transaction, row);
     set_clock(clock, row);
 }
 
+fn set_liquidity_accounts(accounts: &pb::LiquidityAccounts, row: &mut Row) {
+    row.set("amm", base58::encode(&accounts.amm))
+        .set("lp_mint", base58::encode(&accounts.lp_mint))
+        .set("amm_coin_vault", base58::encode(&accounts.amm_coin_vault))
+        .set("amm_pc_vault", base58::encode(&accounts.amm_pc_vault))
+        .set("coin_mint", base58::encode(&accounts.coin_mint))
+        .set("pc_mint", base58::encode(&accounts.pc_mint))
+        .set("user_coin_token", base58::encode(&accounts.user_coin_token))
+        .set("user_pc_token", base58::encode(&accounts.user_pc_token))
+        .set("user_lp_token", base58::encode(&accounts.user_lp_token))
+        .set("user_owner", base58::encode(&accounts.user_owner));
+}
+
 fn set_swap_accounts(accounts: &pb::SwapAccounts, row: &mut Row) {
     row.set("token_program", base58::encode(&accounts.token_program))
         .set("amm", base58::encode(&accounts.amm))
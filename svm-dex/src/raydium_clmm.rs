@@ -4,21 +4,148 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::pools::{insert_pool, Pool};
+use crate::positions::{insert_position_event, PositionAction, PositionEvent};
+use crate::prices::sqrt_price_x64_to_price;
+use crate::reserves::PoolReserves;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    let mut reserves = PoolReserves::default();
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
-        if tx.logs.len() != tx.instructions.len() {
-            continue;
-        }
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
-            if let Some(pb::instruction::Instruction::Swap(data)) = &ix.instruction {
-                let log = match &tx.logs[instruction_index].log {
-                    Some(pb::log::Log::Swap(l)) => l,
-                    _ => continue,
-                };
+            match &ix.instruction {
+                Some(pb::instruction::Instruction::CreatePool(data)) => handle_create_pool(tables, clock, tx, ix, data),
+                Some(pb::instruction::Instruction::Swap(data)) => {
+                    let pool_state = match &data.accounts {
+                        Some(pb::swap_instruction::Accounts::V1Accounts(acc)) => &acc.pool_state,
+                        Some(pb::swap_instruction::Accounts::V2Accounts(acc)) => &acc.pool_state,
+                        None => continue,
+                    };
+                    reserves.insert(
+                        &ix.program_id,
+                        pool_state,
+                        data.vault_balances.iter().map(|v| (v.mint.as_slice(), v.post_amount)),
+                    );
+                }
+                Some(pb::instruction::Instruction::ClosePosition(data)) => {
+                    handle_close_position(tables, clock, tx, ix, data, transaction_index, instruction_index);
+                }
+                _ => {}
+            }
+        }
+        // swaps & position changes are paired with their log by position, `PoolCreatedEvent` is not decoded
+        let swaps: Vec<_> = tx
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ix)| match &ix.instruction {
+                Some(pb::instruction::Instruction::Swap(data)) => Some((i, ix, data)),
+                _ => None,
+            })
+            .collect();
+        let swap_logs: Vec<_> = tx
+            .logs
+            .iter()
+            .filter_map(|log| match &log.log {
+                Some(pb::log::Log::Swap(l)) => Some(l),
+                _ => None,
+            })
+            .collect();
+        if swaps.len() == swap_logs.len() {
+            for ((instruction_index, ix, data), log) in swaps.into_iter().zip(swap_logs) {
                 handle_swap(tables, clock, tx, ix, data, log, transaction_index, instruction_index);
             }
         }
+
+        // the liquidity argument is 0 when the position is opened or increased from the token amounts (`base_flag`),
+        // the minted liquidity is only known from the event
+        let opens: Vec<_> = tx
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ix)| match &ix.instruction {
+                Some(pb::instruction::Instruction::OpenPosition(data)) => Some((i, ix, data)),
+                _ => None,
+            })
+            .collect();
+        let open_logs: Vec<_> = tx
+            .logs
+            .iter()
+            .filter_map(|log| match &log.log {
+                Some(pb::log::Log::CreatePersonalPosition(l)) => Some(l),
+                _ => None,
+            })
+            .collect();
+        let paired = opens.len() == open_logs.len();
+        for (n, (instruction_index, ix, data)) in opens.into_iter().enumerate() {
+            let log = if paired { Some(open_logs[n]) } else { None };
+            handle_open_position(tables, clock, tx, ix, data, log, transaction_index, instruction_index);
+        }
+
+        let increases: Vec<_> = tx
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ix)| match &ix.instruction {
+                Some(pb::instruction::Instruction::IncreaseLiquidity(data)) => Some((i, ix, data)),
+                _ => None,
+            })
+            .collect();
+        let increase_logs: Vec<_> = tx
+            .logs
+            .iter()
+            .filter_map(|log| match &log.log {
+                Some(pb::log::Log::IncreaseLiquidity(l)) => Some(l),
+                _ => None,
+            })
+            .collect();
+        let paired = increases.len() == increase_logs.len();
+        for (n, (instruction_index, ix, data)) in increases.into_iter().enumerate() {
+            let log = if paired { Some(increase_logs[n]) } else { None };
+            handle_increase_liquidity(tables, clock, tx, ix, data, log, transaction_index, instruction_index);
+        }
+
+        let decreases: Vec<_> = tx
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ix)| match &ix.instruction {
+                Some(pb::instruction::Instruction::DecreaseLiquidity(data)) => Some((i, ix, data)),
+                _ => None,
+            })
+            .collect();
+        let decrease_logs: Vec<_> = tx
+            .logs
+            .iter()
+            .filter_map(|log| match &log.log {
+                Some(pb::log::Log::DecreaseLiquidity(l)) => Some(l),
+                _ => None,
+            })
+            .collect();
+        let paired = decreases.len() == decrease_logs.len();
+        for (n, (instruction_index, ix, data)) in decreases.into_iter().enumerate() {
+            let log = if paired { Some(decrease_logs[n]) } else { None };
+            handle_decrease_liquidity(tables, clock, tx, ix, data, log, transaction_index, instruction_index);
+        }
     }
+    reserves.insert_rows(tables, clock);
+}
+
+fn handle_create_pool(tables: &mut Tables, clock: &Clock, tx: &pb::Transaction, ix: &pb::Instruction, data: &pb::CreatePoolInstruction) {
+    let Some(accounts) = &data.accounts else { return };
+    let pool = Pool {
+        program_id: &ix.program_id,
+        pool: &accounts.pool_state,
+        mint0: &accounts.token_mint_0,
+        mint1: &accounts.token_mint_1,
+        vault0: &accounts.token_vault_0,
+        vault1: &accounts.token_vault_1,
+        config: &accounts.amm_config,
+        creator: &accounts.pool_creator,
+        ..Default::default()
+    };
+    insert_pool(tables, clock, &tx.signature, pool);
 }
 
 fn handle_swap(
@@ -61,12 +188,209 @@ fn handle_swap(
         .set("input_mint", input_mint)
         .set("output_mint", output_mint)
         .set("amount_in", amount_in)
-        .set("amount_out", amount_out);
+        .set("amount_out", amount_out)
+        .set("sqrt_price_x64", &log.sqrt_price_x64)
+        .set("price", sqrt_price_x64_to_price(&log.sqrt_price_x64).to_string())
+        .set("liquidity", &log.liquidity)
+        .set("tick", log.tick);
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
 }
 
+fn handle_open_position(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::OpenPositionInstruction,
+    log: Option<&pb::CreatePersonalPositionLog>,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let (liquidity, amount0, amount1) = match log {
+        Some(log) => (&log.liquidity, log.deposit_amount_0, log.deposit_amount_1),
+        None => (&data.liquidity, data.amount_0, data.amount_1),
+    };
+    let position = PositionEvent {
+        program_id: &ix.program_id,
+        pool: &accounts.pool_state,
+        position: &accounts.personal_position,
+        owner: &accounts.position_nft_owner,
+        lower_index: data.tick_lower_index,
+        upper_index: data.tick_upper_index,
+        liquidity,
+        amount0,
+        amount1,
+        ..Default::default()
+    };
+    insert_position_event(
+        tables,
+        clock,
+        &tx.signature,
+        transaction_index,
+        instruction_index,
+        PositionAction::Open,
+        position,
+    );
+}
+
+fn handle_close_position(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::ClosePositionInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let position = PositionEvent {
+        program_id: &ix.program_id,
+        position: &accounts.personal_position,
+        owner: &accounts.nft_owner,
+        ..Default::default()
+    };
+    insert_position_event(
+        tables,
+        clock,
+        &tx.signature,
+        transaction_index,
+        instruction_index,
+        PositionAction::Close,
+        position,
+    );
+}
+
+fn handle_increase_liquidity(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::ModifyLiquidityInstruction,
+    log: Option<&pb::IncreaseLiquidityLog>,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let (liquidity, amount0, amount1) = match log {
+        Some(log) => (&log.liquidity, log.amount_0, log.amount_1),
+        None => (&data.liquidity, data.amount_0, data.amount_1),
+    };
+    let position = PositionEvent {
+        program_id: &ix.program_id,
+        pool: &accounts.pool_state,
+        position: &accounts.personal_position,
+        owner: &accounts.nft_owner,
+        liquidity,
+        amount0,
+        amount1,
+        ..Default::default()
+    };
+    insert_position_event(
+        tables,
+        clock,
+        &tx.signature,
+        transaction_index,
+        instruction_index,
+        PositionAction::IncreaseLiquidity,
+        position,
+    );
+}
+
+/// Fees & rewards are collected by decreasing the liquidity, they are split out of the decrease when its log is known.
+fn handle_decrease_liquidity(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::ModifyLiquidityInstruction,
+    log: Option<&pb::DecreaseLiquidityLog>,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let base = PositionEvent {
+        program_id: &ix.program_id,
+        pool: &accounts.pool_state,
+        position: &accounts.personal_position,
+        owner: &accounts.nft_owner,
+        ..Default::default()
+    };
+
+    let Some(log) = log else {
+        // without the log, the transferred amounts include the collected fees
+        let position = PositionEvent {
+            liquidity: &data.liquidity,
+            amount0: data.amount_0,
+            amount1: data.amount_1,
+            ..base
+        };
+        insert_position_event(
+            tables,
+            clock,
+            &tx.signature,
+            transaction_index,
+            instruction_index,
+            PositionAction::DecreaseLiquidity,
+            position,
+        );
+        return;
+    };
+
+    if log.liquidity != "0" {
+        let position = PositionEvent {
+            liquidity: &log.liquidity,
+            amount0: log.decrease_amount_0,
+            amount1: log.decrease_amount_1,
+            ..base
+        };
+        insert_position_event(
+            tables,
+            clock,
+            &tx.signature,
+            transaction_index,
+            instruction_index,
+            PositionAction::DecreaseLiquidity,
+            position,
+        );
+    }
+    if log.fee_amount_0 > 0 || log.fee_amount_1 > 0 {
+        let position = PositionEvent {
+            amount0: log.fee_amount_0,
+            amount1: log.fee_amount_1,
+            ..base
+        };
+        insert_position_event(
+            tables,
+            clock,
+            &tx.signature,
+            transaction_index,
+            instruction_index,
+            PositionAction::CollectFees,
+            position,
+        );
+    }
+    for (reward_index, amount) in log.reward_amounts.iter().enumerate().filter(|(_, amount)| **amount > 0) {
+        // reward mints are stored in the pool state, they are not part of the instruction
+        let position = PositionEvent {
+            reward_index: reward_index as u32,
+            reward_amount: *amount,
+            ..base
+        };
+        insert_position_event(
+            tables,
+            clock,
+            &tx.signature,
+            transaction_index,
+            instruction_index,
+            PositionAction::CollectReward,
+            position,
+        );
+    }
+}
+
 fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
     row.set("signature", base58::encode(&tx.signature))
         .set("fee_payer", base58::encode(&tx.fee_payer))
@@ -77,4 +401,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
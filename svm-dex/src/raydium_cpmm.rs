@@ -5,15 +5,64 @@ use substreams_database_change::tables::Row;
 use substreams_database_change::tables::Tables;
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+use crate::pools::{insert_pool, Pool};
+use crate::reserves::PoolReserves;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    let mut reserves = PoolReserves::default();
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
-        if tx.logs.len() != tx.instructions.len() {
+        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
+            let (pool_state, vault_balances) = match &ix.instruction {
+                Some(pb::instruction::Instruction::Initialize(data)) => {
+                    handle_initialize(tables, clock, tx, ix, data, transaction_index, instruction_index);
+                    continue;
+                }
+                Some(pb::instruction::Instruction::SwapBaseInput(data)) => (data.accounts.as_ref().map(|a| &a.pool_state), &data.vault_balances),
+                Some(pb::instruction::Instruction::SwapBaseOutput(data)) => (data.accounts.as_ref().map(|a| &a.pool_state), &data.vault_balances),
+                Some(pb::instruction::Instruction::Deposit(data)) => (data.accounts.as_ref().map(|a| &a.pool_state), &data.vault_balances),
+                Some(pb::instruction::Instruction::Withdraw(data)) => (data.accounts.as_ref().map(|a| &a.pool_state), &data.vault_balances),
+                Some(pb::instruction::Instruction::CollectProtocolFee(data)) => {
+                    handle_collect_fee(
+                        tables,
+                        clock,
+                        tx,
+                        ix,
+                        data,
+                        "raydium_cpmm_collect_protocol_fee",
+                        transaction_index,
+                        instruction_index,
+                    );
+                    (data.accounts.as_ref().map(|a| &a.pool_state), &data.vault_balances)
+                }
+                Some(pb::instruction::Instruction::CollectFundFee(data)) => {
+                    handle_collect_fee(
+                        tables,
+                        clock,
+                        tx,
+                        ix,
+                        data,
+                        "raydium_cpmm_collect_fund_fee",
+                        transaction_index,
+                        instruction_index,
+                    );
+                    (data.accounts.as_ref().map(|a| &a.pool_state), &data.vault_balances)
+                }
+                _ => continue,
+            };
+            if let Some(pool_state) = pool_state {
+                reserves.insert(&ix.program_id, pool_state, vault_balances.iter().map(|v| (v.mint.as_slice(), v.post_amount)));
+            }
+        }
+        // swaps, deposits & withdraws are paired with their log by position, the other instructions do not emit any log
+        let instructions: Vec<_> = tx.instructions.iter().enumerate().filter(|(_, ix)| emits_log(ix)).collect();
+        if tx.logs.len() != instructions.len() {
             continue;
         }
-        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
+        for ((instruction_index, ix), log) in instructions.into_iter().zip(tx.logs.iter()) {
             match &ix.instruction {
                 Some(pb::instruction::Instruction::SwapBaseInput(data)) => {
-                    let log = match &tx.logs[instruction_index].log {
+                    let log = match &log.log {
                         Some(pb::log::Log::Swap(l)) => l,
                         _ => continue,
                     };
@@ -34,7 +83,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                     );
                 }
                 Some(pb::instruction::Instruction::SwapBaseOutput(data)) => {
-                    let log = match &tx.logs[instruction_index].log {
+                    let log = match &log.log {
                         Some(pb::log::Log::Swap(l)) => l,
                         _ => continue,
                     };
@@ -54,10 +103,102 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         instruction_index,
                     );
                 }
+                Some(pb::instruction::Instruction::Deposit(data)) => {
+                    let (Some(pb::log::Log::LpChange(log)), Some(accounts)) = (&log.log, &data.accounts) else {
+                        continue;
+                    };
+                    let row = handle_lp_change(
+                        tables,
+                        clock,
+                        tx,
+                        ix,
+                        accounts,
+                        log,
+                        "raydium_cpmm_deposit",
+                        transaction_index,
+                        instruction_index,
+                    );
+                    row.set("lp_token_amount", data.lp_token_amount)
+                        .set("maximum_token_0_amount", data.maximum_token_0_amount)
+                        .set("maximum_token_1_amount", data.maximum_token_1_amount);
+                }
+                Some(pb::instruction::Instruction::Withdraw(data)) => {
+                    let (Some(pb::log::Log::LpChange(log)), Some(accounts)) = (&log.log, &data.accounts) else {
+                        continue;
+                    };
+                    let row = handle_lp_change(
+                        tables,
+                        clock,
+                        tx,
+                        ix,
+                        accounts,
+                        log,
+                        "raydium_cpmm_withdraw",
+                        transaction_index,
+                        instruction_index,
+                    );
+                    row.set("lp_token_amount", data.lp_token_amount)
+                        .set("minimum_token_0_amount", data.minimum_token_0_amount)
+                        .set("minimum_token_1_amount", data.minimum_token_1_amount);
+                }
                 _ => {}
             }
         }
     }
+    reserves.insert_rows(tables, clock);
+}
+
+fn emits_log(ix: &pb::Instruction) -> bool {
+    matches!(
+        ix.instruction,
+        Some(pb::instruction::Instruction::SwapBaseInput(_))
+            | Some(pb::instruction::Instruction::SwapBaseOutput(_))
+            | Some(pb::instruction::Instruction::Deposit(_))
+            | Some(pb::instruction::Instruction::Withdraw(_))
+    )
+}
+
+fn handle_initialize(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::InitializeInstruction,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row("raydium_cpmm_initialize", key)
+        .set("creator", base58::encode(&accounts.creator))
+        .set("amm_config", base58::encode(&accounts.amm_config))
+        .set("pool_state", base58::encode(&accounts.pool_state))
+        .set("token_0_mint", base58::encode(&accounts.token_0_mint))
+        .set("token_1_mint", base58::encode(&accounts.token_1_mint))
+        .set("lp_mint", base58::encode(&accounts.lp_mint))
+        .set("token_0_vault", base58::encode(&accounts.token_0_vault))
+        .set("token_1_vault", base58::encode(&accounts.token_1_vault))
+        .set("init_amount_0", data.init_amount_0)
+        .set("init_amount_1", data.init_amount_1)
+        .set("open_time", data.open_time);
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+
+    let pool = Pool {
+        program_id: &ix.program_id,
+        pool: &accounts.pool_state,
+        mint0: &accounts.token_0_mint,
+        mint1: &accounts.token_1_mint,
+        vault0: &accounts.token_0_vault,
+        vault1: &accounts.token_1_vault,
+        lp_mint: &accounts.lp_mint,
+        config: &accounts.amm_config,
+        creator: &accounts.creator,
+        ..Default::default()
+    };
+    insert_pool(tables, clock, &tx.signature, pool);
 }
 
 fn handle_swap(
@@ -79,7 +220,84 @@ fn handle_swap(
         .set("input_token_mint", base58::encode(&accounts.input_token_mint))
         .set("output_token_mint", base58::encode(&accounts.output_token_mint))
         .set("amount_in", log.input_amount)
-        .set("amount_out", log.output_amount);
+        .set("amount_out", log.output_amount)
+        .set("input_vault_before", log.input_vault_before)
+        .set("output_vault_before", log.output_vault_before)
+        .set("input_transfer_fee", log.input_transfer_fee)
+        .set("output_transfer_fee", log.output_transfer_fee)
+        .set("trade_fee", log.trade_fee.unwrap_or_default())
+        .set("creator_fee", log.creator_fee.unwrap_or_default());
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+}
+
+/// Deposit & withdraw, the instruction arguments are set by the caller.
+fn handle_lp_change<'a>(
+    tables: &'a mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    accounts: &pb::LiquidityAccounts,
+    log: &pb::LpChangeEvent,
+    table: &str,
+    transaction_index: usize,
+    instruction_index: usize,
+) -> &'a mut Row {
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row(table, key)
+        .set("owner", base58::encode(&accounts.owner))
+        .set("pool_state", base58::encode(&accounts.pool_state))
+        .set("owner_lp_token", base58::encode(&accounts.owner_lp_token))
+        .set("token_0_account", base58::encode(&accounts.token_0_account))
+        .set("token_1_account", base58::encode(&accounts.token_1_account))
+        .set("token_0_vault", base58::encode(&accounts.token_0_vault))
+        .set("token_1_vault", base58::encode(&accounts.token_1_vault))
+        .set("token_0_mint", base58::encode(&accounts.vault_0_mint))
+        .set("token_1_mint", base58::encode(&accounts.vault_1_mint))
+        .set("lp_mint", base58::encode(&accounts.lp_mint))
+        // -- log --
+        .set("lp_amount_before", log.lp_amount_before)
+        .set("token_0_vault_before", log.token_0_vault_before)
+        .set("token_1_vault_before", log.token_1_vault_before)
+        .set("token_0_amount", log.token_0_amount)
+        .set("token_1_amount", log.token_1_amount)
+        .set("token_0_transfer_fee", log.token_0_transfer_fee)
+        .set("token_1_transfer_fee", log.token_1_transfer_fee);
+    set_instruction(ix, row);
+    set_transaction(tx, row);
+    set_clock(clock, row);
+    row
+}
+
+fn handle_collect_fee(
+    tables: &mut Tables,
+    clock: &Clock,
+    tx: &pb::Transaction,
+    ix: &pb::Instruction,
+    data: &pb::CollectFeeInstruction,
+    table: &str,
+    transaction_index: usize,
+    instruction_index: usize,
+) {
+    let Some(accounts) = &data.accounts else { return };
+    let key = common_key_v2(clock, transaction_index, instruction_index);
+    let row = tables
+        .create_row(table, key)
+        .set("owner", base58::encode(&accounts.owner))
+        .set("pool_state", base58::encode(&accounts.pool_state))
+        .set("amm_config", base58::encode(&accounts.amm_config))
+        .set("token_0_vault", base58::encode(&accounts.token_0_vault))
+        .set("token_1_vault", base58::encode(&accounts.token_1_vault))
+        .set("token_0_mint", base58::encode(&accounts.vault_0_mint))
+        .set("token_1_mint", base58::encode(&accounts.vault_1_mint))
+        .set("recipient_token_0_account", base58::encode(&accounts.recipient_token_0_account))
+        .set("recipient_token_1_account", base58::encode(&accounts.recipient_token_1_account))
+        .set("amount_0_requested", data.amount_0_requested)
+        .set("amount_1_requested", data.amount_1_requested)
+        .set("amount_0", data.amount_0)
+        .set("amount_1", data.amount_1);
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
@@ -95,4 +313,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
@@ -1,10 +1,16 @@
 use common::db::{common_key_v2, set_clock};
 use proto::pb::raydium::launchpad::v1 as pb;
 use substreams::pb::substreams::Clock;
+use substreams::store::StoreGetString;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
-pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+use crate::aggregators::set_aggregator;
+use crate::bonding_curves::{CurveMetrics, CurveParams};
+use crate::launches::{insert_token_launch, LaunchStage, TokenLaunch};
+use crate::pools::{insert_pool, Pool};
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events, curves: &StoreGetString) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
             match &ix.instruction {
@@ -13,6 +19,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         handle_trade(
                             tables,
                             clock,
+                            curves,
                             tx,
                             ix,
                             data.accounts.as_ref(),
@@ -28,6 +35,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         handle_trade(
                             tables,
                             clock,
+                            curves,
                             tx,
                             ix,
                             data.accounts.as_ref(),
@@ -43,6 +51,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         handle_trade(
                             tables,
                             clock,
+                            curves,
                             tx,
                             ix,
                             data.accounts.as_ref(),
@@ -58,6 +67,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         handle_trade(
                             tables,
                             clock,
+                            curves,
                             tx,
                             ix,
                             data.accounts.as_ref(),
@@ -68,6 +78,47 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                         );
                     }
                 }
+                Some(pb::instruction::Instruction::PoolCreateEvent(event)) => {
+                    let pool = Pool {
+                        program_id: &ix.program_id,
+                        pool: &event.pool_state,
+                        mint0: &event.base_mint,
+                        mint1: &event.quote_mint,
+                        vault0: &event.base_vault,
+                        vault1: &event.quote_vault,
+                        config: &event.config,
+                        creator: &event.creator,
+                        ..Default::default()
+                    };
+                    insert_pool(tables, clock, &tx.signature, pool);
+
+                    let mint_params = event.base_mint_param.clone().unwrap_or_default();
+                    let launch = TokenLaunch {
+                        launchpad: &ix.program_id,
+                        mint: &event.base_mint,
+                        creator: &event.creator,
+                        name: &mint_params.name,
+                        symbol: &mint_params.symbol,
+                        uri: &mint_params.uri,
+                        bonding_curve: &event.pool_state,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Create, launch);
+                }
+                Some(pb::instruction::Instruction::MigrateToAmm(data)) | Some(pb::instruction::Instruction::MigrateToCpswap(data)) => {
+                    let Some(accounts) = &data.accounts else {
+                        continue;
+                    };
+                    let launch = TokenLaunch {
+                        launchpad: &ix.program_id,
+                        mint: &accounts.base_mint,
+                        bonding_curve: &accounts.pool_state,
+                        graduation_program_id: &accounts.amm_program,
+                        graduation_pool: &accounts.amm_pool,
+                        ..Default::default()
+                    };
+                    insert_token_launch(tables, clock, &tx.signature, LaunchStage::Graduate, launch);
+                }
                 _ => {}
             }
         }
@@ -86,6 +137,7 @@ fn get_trade_event(tx: &pb::Transaction, instruction_index: usize) -> Option<&pb
 fn handle_trade(
     tables: &mut Tables,
     clock: &Clock,
+    curves: &StoreGetString,
     tx: &pb::Transaction,
     ix: &pb::Instruction,
     accounts_opt: Option<&pb::TradeAccounts>,
@@ -108,9 +160,24 @@ fn handle_trade(
         .set("amount_in", event.amount_in)
         .set("amount_out", event.amount_out)
         .set("exact_in", event.exact_in.unwrap_or(false));
+    CurveParams::get(curves, &accounts.base_token_mint)
+        .map(|params| CurveMetrics::raydium_launchpad(&params, event, &accounts.quote_token_mint))
+        .unwrap_or_default()
+        .set(row);
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
+
+    // the trade selling the remaining supply switches the pool to the migrate status
+    if event.pool_status == pb::PoolStatus::Migrate as i32 {
+        let launch = TokenLaunch {
+            launchpad: &ix.program_id,
+            mint: &accounts.base_token_mint,
+            bonding_curve: &accounts.pool_state,
+            ..Default::default()
+        };
+        insert_token_launch(tables, clock, &tx.signature, LaunchStage::Complete, launch);
+    }
 }
 
 fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
@@ -123,4 +190,5 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
 }
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+
+use common::db::set_clock;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+/// Pool vault balances, tokens are ordered by mint (same as `mint0` / `mint1` in `ohlc_prices`).
+struct Reserves {
+    program_id: Vec<u8>,
+    mint0: String,
+    mint1: String,
+    reserve0: u64,
+    reserve1: u64,
+}
+
+/// Reserves of the pools swapped in the block, only the balances after the last swap of each pool are kept.
+#[derive(Default)]
+pub struct PoolReserves {
+    pools: BTreeMap<Vec<u8>, Reserves>,
+}
+
+impl PoolReserves {
+    /// Records the post balances `(mint, amount)` of the pool vaults, skipped unless both vault balances are known.
+    pub fn insert<'a>(&mut self, program_id: &[u8], pool: &[u8], vaults: impl IntoIterator<Item = (&'a [u8], u64)>) {
+        let vaults: Vec<_> = vaults.into_iter().map(|(mint, amount)| (base58::encode(mint), amount)).collect();
+        let Ok([vault0, vault1]) = <[(String, u64); 2]>::try_from(vaults) else {
+            return;
+        };
+        // base58 ordering, as the mints are compared in ClickHouse
+        let ((mint0, reserve0), (mint1, reserve1)) = if vault0.0 <= vault1.0 { (vault0, vault1) } else { (vault1, vault0) };
+        self.pools.insert(
+            pool.to_vec(),
+            Reserves {
+                program_id: program_id.to_vec(),
+                mint0,
+                mint1,
+                reserve0,
+                reserve1,
+            },
+        );
+    }
+
+    /// Upserts the latest `pool_reserves` row of each pool swapped in the block.
+    pub fn insert_rows(self, tables: &mut Tables, clock: &Clock) {
+        for (pool, reserves) in self.pools {
+            let row = tables
+                .upsert_row("pool_reserves", base58::encode(&pool))
+                .set("pool", base58::encode(&pool))
+                .set("program_id", base58::encode(&reserves.program_id))
+                .set("mint0", reserves.mint0)
+                .set("mint1", reserves.mint1)
+                .set("reserve0", reserves.reserve0)
+                .set("reserve1", reserves.reserve1)
+                .set("price", spot_price(reserves.reserve0, reserves.reserve1));
+            set_clock(clock, row);
+        }
+    }
+}
+
+/// Spot price of token 0 in token 1 (raw amounts), 0 for an empty pool.
+fn spot_price(reserve0: u64, reserve1: u64) -> f64 {
+    if reserve0 == 0 {
+        return 0.0;
+    }
+    reserve1 as f64 / reserve0 as f64
+}
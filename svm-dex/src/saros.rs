@@ -4,6 +4,8 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
@@ -27,7 +29,15 @@ fn handle_swap(
     let row = tables
         .create_row("saros_swap", key)
         .set("amount_in", data.amount_in)
-        .set("minimum_amount_out", data.minimum_amount_out);
+        .set("minimum_amount_out", data.minimum_amount_out)
+        .set("user", base58::encode(&data.user))
+        .set("input_mint", base58::encode(&data.input_mint))
+        .set("input_amount", data.input_amount)
+        .set("output_mint", base58::encode(&data.output_mint))
+        .set("output_amount", data.output_amount);
+    if let Some(accounts) = &data.accounts {
+        set_swap_accounts(accounts, row);
+    }
     set_instruction(ix, row);
     set_transaction(tx, row);
     set_clock(clock, row);
@@ -43,4 +53,18 @@ fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
 
 fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
     row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+}
+
+fn set_swap_accounts(accounts: &pb::SwapAccounts, row: &mut Row) {
+    row.set("swap", base58::encode(&accounts.swap))
+        .set("swap_authority", base58::encode(&accounts.swap_authority))
+        .set("user_transfer_authority", base58::encode(&accounts.user_transfer_authority))
+        .set("user_source", base58::encode(&accounts.user_source))
+        .set("pool_source", base58::encode(&accounts.pool_source))
+        .set("pool_destination", base58::encode(&accounts.pool_destination))
+        .set("user_destination", base58::encode(&accounts.user_destination))
+        .set("pool_mint", base58::encode(&accounts.pool_mint))
+        .set("pool_fee_account", base58::encode(&accounts.pool_fee_account))
+        .set("token_program", base58::encode(&accounts.token_program));
 }
@@ -1,9 +1,11 @@
 use common::db::{common_key_v2, set_clock};
 use proto::pb::serum::v1 as pb;
 use substreams::pb::substreams::Clock;
-use substreams_database_change::tables::Tables;
+use substreams_database_change::tables::{Row, Tables};
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
     for (transaction_index, tx) in events.transactions.iter().enumerate() {
         for (instruction_index, ix) in tx.instructions.iter().enumerate() {
@@ -12,14 +14,37 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                 let row = tables
                     .create_row("serum_swap", key)
                     .set("amount_in", data.amount_in)
-                    .set("minimum_amount_out", data.minimum_amount_out);
+                    .set("minimum_amount_out", data.minimum_amount_out)
+                    .set("user", base58::encode(&data.user))
+                    .set("input_mint", base58::encode(&data.input_mint))
+                    .set("input_amount", data.input_amount)
+                    .set("output_mint", base58::encode(&data.output_mint))
+                    .set("output_amount", data.output_amount);
                 row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+                set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
                 row.set("signature", base58::encode(&tx.signature))
                     .set("fee_payer", base58::encode(&tx.fee_payer))
                     .set("fee", tx.fee)
                     .set("compute_units_consumed", tx.compute_units_consumed);
+                if let Some(accounts) = &data.accounts {
+                    set_swap_accounts(accounts, row);
+                }
                 set_clock(clock, row);
             }
         }
     }
 }
+
+fn set_swap_accounts(accounts: &pb::SwapAccounts, row: &mut Row) {
+    row.set("market", base58::encode(&accounts.market))
+        .set("open_orders", base58::encode(&accounts.open_orders))
+        .set("bids", base58::encode(&accounts.bids))
+        .set("asks", base58::encode(&accounts.asks))
+        .set("owner", base58::encode(&accounts.owner))
+        .set("order_payer", base58::encode(&accounts.order_payer))
+        .set("coin_wallet", base58::encode(&accounts.coin_wallet))
+        .set("pc_wallet", base58::encode(&accounts.pc_wallet))
+        .set("coin_vault", base58::encode(&accounts.coin_vault))
+        .set("pc_vault", base58::encode(&accounts.pc_vault))
+        .set("token_program", base58::encode(&accounts.token_program));
+}
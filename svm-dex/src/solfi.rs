@@ -4,6 +4,8 @@ use substreams::pb::substreams::Clock;
 use substreams_database_change::tables::Tables;
 use substreams_solana::base58;
 
+use crate::aggregators::set_aggregator;
+
 pub fn process_v1_events(tables: &mut Tables, clock: &Clock, events: &solfi::v1::v1::Events) {
     for (tx_idx, tx) in events.transactions.iter().enumerate() {
         for (log_idx, log) in tx.logs.iter().enumerate() {
@@ -17,6 +19,8 @@ pub fn process_v1_events(tables: &mut Tables, clock: &Clock, events: &solfi::v1:
                     .set("fee_payer", base58::encode(&tx.fee_payer))
                     .set("fee", tx.fee)
                     .set("compute_units_consumed", tx.compute_units_consumed);
+                row.set("program_id", base58::encode(&log.program_id));
+                set_aggregator(&log.parent_program_id, &log.outer_program_id, row);
                 set_clock(clock, row);
             }
         }
@@ -36,6 +40,8 @@ pub fn process_v2_events(tables: &mut Tables, clock: &Clock, events: &solfi::v2:
                     .set("fee_payer", base58::encode(&tx.fee_payer))
                     .set("fee", tx.fee)
                     .set("compute_units_consumed", tx.compute_units_consumed);
+                row.set("program_id", base58::encode(&log.program_id));
+                set_aggregator(&log.parent_program_id, &log.outer_program_id, row);
                 set_clock(clock, row);
             }
         }
@@ -0,0 +1,128 @@
+use common::db::{common_key_v2, set_clock};
+use proto::pb::solana::spl::token_swap::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::{Row, Tables};
+use substreams_solana::base58;
+
+use crate::aggregators::set_aggregator;
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        for (instruction_index, ix) in tx.instructions.iter().enumerate() {
+            let key = common_key_v2(clock, transaction_index, instruction_index);
+            let row = match &ix.instruction {
+                Some(pb::instruction::Instruction::Swap(data)) => tables
+                    .create_row("token_swap_swap", key)
+                    .set("swap_account", base58::encode(&data.swap_account))
+                    .set("authority", base58::encode(&data.authority))
+                    .set("user_transfer_authority", base58::encode(&data.user_transfer_authority))
+                    .set("source", base58::encode(&data.source))
+                    .set("swap_source", base58::encode(&data.swap_source))
+                    .set("swap_destination", base58::encode(&data.swap_destination))
+                    .set("destination", base58::encode(&data.destination))
+                    .set("pool_mint", base58::encode(&data.pool_mint))
+                    .set("fee_account", base58::encode(&data.fee_account))
+                    .set("amount_in", data.amount_in)
+                    .set("minimum_amount_out", data.minimum_amount_out)
+                    .set("user", base58::encode(&data.user))
+                    .set("input_mint", base58::encode(&data.input_mint))
+                    .set("input_amount", data.input_amount)
+                    .set("output_mint", base58::encode(&data.output_mint))
+                    .set("output_amount", data.output_amount),
+                Some(pb::instruction::Instruction::DepositAllTokenTypes(data)) => {
+                    let row = tables
+                        .create_row("token_swap_deposit_all_token_types", key)
+                        .set("swap_account", base58::encode(&data.swap_account))
+                        .set("authority", base58::encode(&data.authority))
+                        .set("user_transfer_authority", base58::encode(&data.user_transfer_authority))
+                        .set("source_a", base58::encode(&data.source_a))
+                        .set("source_b", base58::encode(&data.source_b))
+                        .set("token_a", base58::encode(&data.token_a))
+                        .set("token_b", base58::encode(&data.token_b))
+                        .set("pool_mint", base58::encode(&data.pool_mint))
+                        .set("destination", base58::encode(&data.destination))
+                        .set("pool_token_amount", data.pool_token_amount)
+                        .set("maximum_token_a_amount", data.maximum_token_a_amount)
+                        .set("maximum_token_b_amount", data.maximum_token_b_amount);
+                    set_liquidity_amounts(&data.amounts, row)
+                }
+                Some(pb::instruction::Instruction::WithdrawAllTokenTypes(data)) => {
+                    let row = tables
+                        .create_row("token_swap_withdraw_all_token_types", key)
+                        .set("swap_account", base58::encode(&data.swap_account))
+                        .set("authority", base58::encode(&data.authority))
+                        .set("user_transfer_authority", base58::encode(&data.user_transfer_authority))
+                        .set("pool_mint", base58::encode(&data.pool_mint))
+                        .set("fee_account", base58::encode(&data.fee_account))
+                        .set("source", base58::encode(&data.source))
+                        .set("token_a", base58::encode(&data.token_a))
+                        .set("token_b", base58::encode(&data.token_b))
+                        .set("destination_a", base58::encode(&data.destination_a))
+                        .set("destination_b", base58::encode(&data.destination_b))
+                        .set("pool_token_amount", data.pool_token_amount)
+                        .set("minimum_token_a_amount", data.minimum_token_a_amount)
+                        .set("minimum_token_b_amount", data.minimum_token_b_amount);
+                    set_liquidity_amounts(&data.amounts, row)
+                }
+                Some(pb::instruction::Instruction::DepositSingleTokenTypeExactAmountIn(data)) => {
+                    let row = tables
+                        .create_row("token_swap_deposit_single_token_type", key)
+                        .set("swap_account", base58::encode(&data.swap_account))
+                        .set("authority", base58::encode(&data.authority))
+                        .set("user_transfer_authority", base58::encode(&data.user_transfer_authority))
+                        .set("source", base58::encode(&data.source))
+                        .set("token_a", base58::encode(&data.token_a))
+                        .set("token_b", base58::encode(&data.token_b))
+                        .set("pool_mint", base58::encode(&data.pool_mint))
+                        .set("destination", base58::encode(&data.destination))
+                        .set("source_token_amount", data.source_token_amount)
+                        .set("minimum_pool_token_amount", data.minimum_pool_token_amount);
+                    set_liquidity_amounts(&data.amounts, row)
+                }
+                Some(pb::instruction::Instruction::WithdrawSingleTokenTypeExactAmountOut(data)) => {
+                    let row = tables
+                        .create_row("token_swap_withdraw_single_token_type", key)
+                        .set("swap_account", base58::encode(&data.swap_account))
+                        .set("authority", base58::encode(&data.authority))
+                        .set("user_transfer_authority", base58::encode(&data.user_transfer_authority))
+                        .set("pool_mint", base58::encode(&data.pool_mint))
+                        .set("fee_account", base58::encode(&data.fee_account))
+                        .set("source", base58::encode(&data.source))
+                        .set("token_a", base58::encode(&data.token_a))
+                        .set("token_b", base58::encode(&data.token_b))
+                        .set("destination", base58::encode(&data.destination))
+                        .set("destination_token_amount", data.destination_token_amount)
+                        .set("maximum_pool_token_amount", data.maximum_pool_token_amount);
+                    set_liquidity_amounts(&data.amounts, row)
+                }
+                _ => continue,
+            };
+            set_instruction(ix, row);
+            set_transaction(tx, row);
+            set_clock(clock, row);
+        }
+    }
+}
+
+fn set_liquidity_amounts<'a>(amounts: &Option<pb::LiquidityAmounts>, row: &'a mut Row) -> &'a mut Row {
+    let Some(amounts) = amounts else {
+        return row;
+    };
+    row.set("token_a_mint", base58::encode(&amounts.token_a_mint))
+        .set("token_a_amount", amounts.token_a_amount)
+        .set("token_b_mint", base58::encode(&amounts.token_b_mint))
+        .set("token_b_amount", amounts.token_b_amount)
+}
+
+fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
+    row.set("signature", base58::encode(&tx.signature))
+        .set("fee_payer", base58::encode(&tx.fee_payer))
+        .set("signers_raw", tx.signers.iter().map(base58::encode).collect::<Vec<_>>().join(","))
+        .set("fee", tx.fee)
+        .set("compute_units_consumed", tx.compute_units_consumed);
+}
+
+fn set_instruction(ix: &pb::Instruction, row: &mut Row) {
+    row.set("program_id", base58::encode(&ix.program_id)).set("stack_height", ix.stack_height);
+    set_aggregator(&ix.parent_program_id, &ix.outer_program_id, row);
+}
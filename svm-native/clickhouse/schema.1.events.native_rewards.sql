@@ -0,0 +1,30 @@
+-- Block Rewards --
+CREATE TABLE IF NOT EXISTS native_rewards (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- ordering --
+    reward_index                UInt32,
+
+    -- reward --
+    leader                      FixedString(44) COMMENT 'Slot leader identity (fee reward recipient)',
+    account                     FixedString(44) COMMENT 'Account credited with the reward',
+    reward_type                 LowCardinality(String) COMMENT 'Reward type (REWARD_TYPE_FEE, REWARD_TYPE_RENT, REWARD_TYPE_STAKING, REWARD_TYPE_VOTING)',
+    lamports                    Int64 COMMENT 'Reward amount in lamports',
+    post_balance                UInt64 COMMENT 'Account balance after the reward',
+    commission                  Nullable(UInt8) COMMENT 'Vote account commission (staking & voting rewards only)',
+    epoch                       UInt64 MATERIALIZED intDiv(block_num, 432000) COMMENT 'Epoch (432,000 slots per epoch)',
+
+    -- indexes --
+    INDEX idx_leader            (leader)            TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_account           (account)           TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_reward_type       (reward_type)       TYPE set(8)                 GRANULARITY 1
+)
+ENGINE = ReplacingMergeTree
+ORDER BY (
+    timestamp, block_num,
+    block_hash, reward_index
+)
+COMMENT 'Native block rewards (leader fees, rent, staking & voting rewards)';
@@ -0,0 +1,75 @@
+-- Native Vote Initialize Account --
+CREATE TABLE IF NOT EXISTS native_vote_initialize_account AS base_events
+COMMENT 'Native Vote Program: initialize vote account';
+ALTER TABLE native_vote_initialize_account
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS node_pubkey            FixedString(44) COMMENT 'Validator identity',
+    ADD COLUMN IF NOT EXISTS authorized_voter       FixedString(44) COMMENT 'Authorized voter',
+    ADD COLUMN IF NOT EXISTS authorized_withdrawer  FixedString(44) COMMENT 'Authorized withdrawer',
+    ADD COLUMN IF NOT EXISTS commission             UInt8 COMMENT 'Commission percentage (0-100)';
+
+-- Native Vote Withdraw --
+CREATE TABLE IF NOT EXISTS native_vote_withdraw AS base_events
+COMMENT 'Native Vote Program: withdraw from vote account';
+ALTER TABLE native_vote_withdraw
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS destination            FixedString(44) COMMENT 'Destination account',
+    ADD COLUMN IF NOT EXISTS lamports               UInt64 COMMENT 'Amount to withdraw',
+    ADD COLUMN IF NOT EXISTS withdraw_authority     FixedString(44) COMMENT 'Withdraw authority';
+
+-- Native Vote Update Validator Identity --
+CREATE TABLE IF NOT EXISTS native_vote_update_validator_identity AS base_events
+COMMENT 'Native Vote Program: update validator identity';
+ALTER TABLE native_vote_update_validator_identity
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS node_pubkey            FixedString(44) COMMENT 'New validator identity',
+    ADD COLUMN IF NOT EXISTS authorized_withdrawer  FixedString(44) COMMENT 'Authorized withdrawer';
+
+-- Native Vote Update Commission --
+CREATE TABLE IF NOT EXISTS native_vote_update_commission AS base_events
+COMMENT 'Native Vote Program: update commission';
+ALTER TABLE native_vote_update_commission
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS commission             UInt8 COMMENT 'New commission percentage (0-100)',
+    ADD COLUMN IF NOT EXISTS authorized_withdrawer  FixedString(44) COMMENT 'Authorized withdrawer';
+
+-- Native Vote Vote (only populated with `include_votes=true`) --
+CREATE TABLE IF NOT EXISTS native_vote_vote AS base_events
+COMMENT 'Native Vote Program: vote & vote switch';
+ALTER TABLE native_vote_vote
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS vote_authority         FixedString(44) COMMENT 'Vote authority',
+    ADD COLUMN IF NOT EXISTS slots_raw              String COMMENT 'Slots voted on (comma separated)',
+    ADD COLUMN IF NOT EXISTS slots                  Array(UInt64) MATERIALIZED arrayMap(x -> toUInt64OrZero(x), arrayFilter(x -> x != '', splitByChar(',', slots_raw))) COMMENT 'Slots voted on',
+    ADD COLUMN IF NOT EXISTS hash                   FixedString(44) COMMENT 'Recent block hash',
+    ADD COLUMN IF NOT EXISTS proof_hash             String DEFAULT '' COMMENT 'Proof hash (vote switch only)';
+
+-- Native Vote Compact Update Vote State (only populated with `include_votes=true`) --
+CREATE TABLE IF NOT EXISTS native_vote_compact_update_vote_state AS base_events
+COMMENT 'Native Vote Program: compact update vote state';
+ALTER TABLE native_vote_compact_update_vote_state
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS vote_authority         FixedString(44) COMMENT 'Vote authority';
+
+-- Native Vote Tower Sync (only populated with `include_votes=true`) --
+CREATE TABLE IF NOT EXISTS native_vote_tower_sync AS base_events
+COMMENT 'Native Vote Program: tower sync & tower sync switch';
+ALTER TABLE native_vote_tower_sync
+    ADD COLUMN IF NOT EXISTS is_root                Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS vote_account           FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS vote_authority         FixedString(44) COMMENT 'Vote authority',
+    ADD COLUMN IF NOT EXISTS slots_raw              String COMMENT 'Slots of the tower lockouts (comma separated)',
+    ADD COLUMN IF NOT EXISTS slots                  Array(UInt64) MATERIALIZED arrayMap(x -> toUInt64OrZero(x), arrayFilter(x -> x != '', splitByChar(',', slots_raw))) COMMENT 'Slots of the tower lockouts',
+    ADD COLUMN IF NOT EXISTS root_raw               String COMMENT 'Root slot (empty when not set)',
+    ADD COLUMN IF NOT EXISTS root                   Nullable(UInt64) MATERIALIZED if(empty(root_raw), NULL, toUInt64(root_raw)) COMMENT 'Root slot',
+    ADD COLUMN IF NOT EXISTS hash                   FixedString(44) COMMENT 'Bank hash of the last voted slot',
+    ADD COLUMN IF NOT EXISTS vote_timestamp_raw     String COMMENT 'Vote timestamp in unix seconds (empty when not set)',
+    ADD COLUMN IF NOT EXISTS vote_timestamp         Nullable(DateTime(0, 'UTC')) MATERIALIZED if(empty(vote_timestamp_raw), NULL, toDateTime(toInt64(vote_timestamp_raw), 'UTC')) COMMENT 'Vote timestamp',
+    ADD COLUMN IF NOT EXISTS block_id               FixedString(44) COMMENT 'Block id of the last voted slot',
+    ADD COLUMN IF NOT EXISTS proof_hash             String DEFAULT '' COMMENT 'Proof hash (tower sync switch only)';
@@ -0,0 +1,58 @@
+-- Leader Fee Rewards (per block) --
+CREATE TABLE IF NOT EXISTS block_fee_rewards (
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    leader                      FixedString(44) COMMENT 'Slot leader identity',
+    lamports                    Int64 COMMENT 'Fee reward credited to the leader in lamports',
+
+    -- indexes --
+    INDEX idx_leader            (leader)            TYPE bloom_filter(0.005)    GRANULARITY 1
+)
+ENGINE = ReplacingMergeTree
+ORDER BY (block_num, block_hash)
+COMMENT 'Leader fee rewards, one row per block';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_block_fee_rewards
+TO block_fee_rewards AS
+SELECT
+    block_num, block_hash, timestamp,
+    account     AS leader,
+    lamports
+FROM native_rewards
+WHERE reward_type = 'REWARD_TYPE_FEE';
+
+-- Epoch Staking & Voting Rewards --
+-- Inflation rewards are paid over the first blocks of each epoch (partitioned epoch rewards)
+CREATE TABLE IF NOT EXISTS epoch_rewards (
+    epoch                       UInt64 COMMENT 'Epoch the rewards were paid in',
+    reward_type                 LowCardinality(String) COMMENT 'Reward type (REWARD_TYPE_STAKING, REWARD_TYPE_VOTING)',
+    account                     FixedString(44) COMMENT 'Stake or vote account credited',
+
+    lamports                    Int64 COMMENT 'Total rewards in lamports',
+    rewards                     UInt64 COMMENT 'Number of reward payments',
+    min_block_num               SimpleAggregateFunction(min, UInt32),
+    max_block_num               SimpleAggregateFunction(max, UInt32),
+    commission                  SimpleAggregateFunction(anyLast, Nullable(UInt8)),
+
+    -- indexes --
+    INDEX idx_account           (account)           TYPE bloom_filter(0.005)    GRANULARITY 1
+)
+ENGINE = SummingMergeTree((lamports, rewards))
+ORDER BY (epoch, reward_type, account)
+COMMENT 'Staking & voting rewards aggregated per epoch and account';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_epoch_rewards
+TO epoch_rewards AS
+SELECT
+    intDiv(block_num, 432000)   AS epoch,
+    reward_type,
+    account,
+    lamports,
+    1                           AS rewards,
+    block_num                   AS min_block_num,
+    block_num                   AS max_block_num,
+    commission
+FROM native_rewards
+WHERE reward_type IN ('REWARD_TYPE_STAKING', 'REWARD_TYPE_VOTING');
@@ -0,0 +1,78 @@
+-- Unified Validator Lifecycle Actions --
+CREATE TABLE IF NOT EXISTS validator_actions AS base_events
+COMMENT 'Unified validator lifecycle actions (vote account creation, identity, commission, withdrawals)';
+ALTER TABLE validator_actions
+    ADD COLUMN IF NOT EXISTS action               LowCardinality(String) COMMENT 'Action type (initialize, update_identity, update_commission, withdraw)',
+    ADD COLUMN IF NOT EXISTS vote_account         FixedString(44) COMMENT 'Vote account',
+    ADD COLUMN IF NOT EXISTS node_pubkey          FixedString(44) COMMENT 'Validator identity (if applicable)',
+    ADD COLUMN IF NOT EXISTS commission           Nullable(UInt8) COMMENT 'Commission percentage (if applicable)',
+    ADD COLUMN IF NOT EXISTS amount               UInt64 DEFAULT 0 COMMENT 'Amount in lamports (if applicable)',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_action            (action)            TYPE set(8)                 GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_vote_account      (vote_account)      TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_node_pubkey       (node_pubkey)       TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Initialize Account = initialize
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_vote_initialize_account
+TO validator_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'initialize'        AS action,
+    vote_account,
+    node_pubkey,
+    commission,
+    0                   AS amount
+FROM native_vote_initialize_account;
+
+-- Update Validator Identity = update_identity
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_vote_update_validator_identity
+TO validator_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'update_identity'   AS action,
+    vote_account,
+    node_pubkey,
+    NULL                AS commission,
+    0                   AS amount
+FROM native_vote_update_validator_identity;
+
+-- Update Commission = update_commission
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_vote_update_commission
+TO validator_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'update_commission' AS action,
+    vote_account,
+    ''                  AS node_pubkey,
+    commission,
+    0                   AS amount
+FROM native_vote_update_commission;
+
+-- Withdraw = withdraw
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_vote_withdraw
+TO validator_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'withdraw'          AS action,
+    vote_account,
+    ''                  AS node_pubkey,
+    NULL                AS commission,
+    lamports            AS amount
+FROM native_vote_withdraw;
@@ -0,0 +1,37 @@
+mod native_rewards;
+mod native_vote;
+
+use common::db::set_clock;
+use proto::pb;
+use substreams::{errors::Error, pb::substreams::Clock};
+use substreams_database_change::pb::sf::substreams::sink::database::v1::DatabaseChanges;
+
+#[substreams::handlers::map]
+pub fn db_out(
+    params: String,
+    clock: Clock,
+    native_vote_events: pb::solana::native::vote::v1::Events,
+    native_rewards_events: pb::solana::native::rewards::v1::Events,
+) -> Result<DatabaseChanges, Error> {
+    let mut tables = substreams_database_change::tables::Tables::new();
+
+    // Per-slot votes are the bulk of Vote program activity, only keep them when explicitly requested
+    let include_votes = parse_bool_param(&params, "include_votes");
+
+    native_vote::process_events(&mut tables, &clock, &native_vote_events, include_votes);
+    native_rewards::process_events(&mut tables, &clock, &native_rewards_events);
+
+    if tables.all_row_count() > 0 {
+        set_clock(&clock, tables.create_row("blocks", [("block_num", clock.number.to_string())]));
+    }
+
+    Ok(tables.to_database_changes())
+}
+
+// Params are formatted as `key=value` pairs separated by `&`, e.g. `include_votes=true`
+fn parse_bool_param(params: &str, key: &str) -> bool {
+    params
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .any(|(k, v)| k.trim() == key && v.trim().eq_ignore_ascii_case("true"))
+}
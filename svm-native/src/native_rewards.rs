@@ -0,0 +1,24 @@
+use common::db::set_clock;
+use proto::pb::solana::native::rewards::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    let leader = base58::encode(&events.leader);
+
+    for (reward_index, reward) in events.rewards.iter().enumerate() {
+        let key = [("block_hash", clock.id.to_string()), ("reward_index", reward_index.to_string())];
+        let row = tables
+            .create_row("native_rewards", key)
+            .set("leader", &leader)
+            .set("account", base58::encode(&reward.account))
+            .set("reward_type", reward.reward_type().as_str_name())
+            .set("lamports", reward.lamports)
+            .set("post_balance", reward.post_balance);
+        if let Some(commission) = reward.commission {
+            row.set("commission", commission);
+        }
+        set_clock(clock, row);
+    }
+}
@@ -0,0 +1,157 @@
+use common::db::{common_key_v2, set_clock};
+use proto::pb::solana::native::vote::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::{Row, Tables};
+use substreams_solana::base58;
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events, include_votes: bool) {
+    for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        for (instruction_index, instruction) in tx.instructions.iter().enumerate() {
+            match &instruction.instruction {
+                Some(pb::instruction::Instruction::InitializeAccount(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_initialize_account", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("node_pubkey", base58::encode(&event.node_pubkey))
+                        .set("authorized_voter", base58::encode(&event.authorized_voter))
+                        .set("authorized_withdrawer", base58::encode(&event.authorized_withdrawer))
+                        .set("commission", event.commission);
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::Withdraw(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_withdraw", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("destination", base58::encode(&event.destination))
+                        .set("lamports", event.lamports)
+                        .set("withdraw_authority", base58::encode(&event.withdraw_authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::UpdateValidatorIdentity(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_update_validator_identity", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("node_pubkey", base58::encode(&event.node_pubkey))
+                        .set("authorized_withdrawer", base58::encode(&event.authorized_withdrawer));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::UpdateCommission(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_update_commission", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("commission", event.commission)
+                        .set("authorized_withdrawer", base58::encode(&event.authorized_withdrawer));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::Vote(event)) => {
+                    if !include_votes {
+                        continue;
+                    }
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_vote", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("vote_authority", base58::encode(&event.vote_authority))
+                        .set("slots_raw", event.slots.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(","))
+                        .set("hash", base58::encode(&event.hash));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::VoteSwitch(event)) => {
+                    if !include_votes {
+                        continue;
+                    }
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_vote", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("vote_authority", base58::encode(&event.vote_authority))
+                        .set("slots_raw", event.slots.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(","))
+                        .set("hash", base58::encode(&event.hash))
+                        .set("proof_hash", base58::encode(&event.proof_hash));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::CompactUpdateVoteState(event)) => {
+                    if !include_votes {
+                        continue;
+                    }
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_compact_update_vote_state", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("vote_authority", base58::encode(&event.vote_authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::TowerSync(event)) => {
+                    if !include_votes {
+                        continue;
+                    }
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_tower_sync", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("vote_authority", base58::encode(&event.vote_authority))
+                        .set("slots_raw", event.slots.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(","))
+                        .set("root_raw", event.root.map(|root| root.to_string()).unwrap_or_default())
+                        .set("hash", base58::encode(&event.hash))
+                        .set("vote_timestamp_raw", event.timestamp.map(|timestamp| timestamp.to_string()).unwrap_or_default())
+                        .set("block_id", base58::encode(&event.block_id));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::TowerSyncSwitch(event)) => {
+                    if !include_votes {
+                        continue;
+                    }
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_vote_tower_sync", key)
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("vote_authority", base58::encode(&event.vote_authority))
+                        .set("slots_raw", event.slots.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(","))
+                        .set("root_raw", event.root.map(|root| root.to_string()).unwrap_or_default())
+                        .set("hash", base58::encode(&event.hash))
+                        .set("vote_timestamp_raw", event.timestamp.map(|timestamp| timestamp.to_string()).unwrap_or_default())
+                        .set("block_id", base58::encode(&event.block_id))
+                        .set("proof_hash", base58::encode(&event.proof_hash));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                None => {}
+            }
+        }
+    }
+}
+
+fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
+    row.set("signature", base58::encode(&tx.signature))
+        .set("fee_payer", base58::encode(&tx.fee_payer))
+        .set("signers_raw", tx.signers.iter().map(base58::encode).collect::<Vec<_>>().join(","))
+        .set("fee", tx.fee)
+        .set("compute_units_consumed", tx.compute_units_consumed);
+}
+
+fn set_instruction(instruction: &pb::Instruction, row: &mut Row) {
+    row.set("program_id", base58::encode(&instruction.program_id))
+        .set("stack_height", instruction.stack_height)
+        .set("is_root", instruction.is_root);
+}
@@ -0,0 +1,154 @@
+-- Drift Order Action --
+CREATE TABLE IF NOT EXISTS drift_order_action AS base_events
+COMMENT 'Drift v2 order actions (OrderActionRecord), amounts in Drift precisions (base 1e9, quote & price 1e6)';
+ALTER TABLE drift_order_action
+    ADD COLUMN IF NOT EXISTS action                                             LowCardinality(String) COMMENT 'Order action (place, cancel, fill, trigger, expire)',
+    ADD COLUMN IF NOT EXISTS action_explanation                                 UInt8 COMMENT 'OrderActionExplanation variant',
+    ADD COLUMN IF NOT EXISTS market_index                                       UInt16 COMMENT 'Perp or spot market index',
+    ADD COLUMN IF NOT EXISTS market_type                                        LowCardinality(String) COMMENT 'Market type (perp, spot)',
+    ADD COLUMN IF NOT EXISTS filler                                             FixedString(44) COMMENT 'Filler (keeper) user account, empty when not filled by a keeper',
+    ADD COLUMN IF NOT EXISTS filler_reward                                      UInt64 COMMENT 'Filler reward in quote',
+    ADD COLUMN IF NOT EXISTS fill_record_id                                     UInt64 COMMENT 'Fill record id of the market',
+    ADD COLUMN IF NOT EXISTS base_asset_amount_filled                           UInt64 COMMENT 'Base amount filled',
+    ADD COLUMN IF NOT EXISTS quote_asset_amount_filled                          UInt64 COMMENT 'Quote amount filled',
+    ADD COLUMN IF NOT EXISTS taker_fee                                          UInt64 COMMENT 'Taker fee in quote',
+    ADD COLUMN IF NOT EXISTS maker_fee                                          Int64 COMMENT 'Maker fee in quote, negative for rebates',
+    ADD COLUMN IF NOT EXISTS referrer_reward                                    UInt32 COMMENT 'Referrer reward in quote',
+    ADD COLUMN IF NOT EXISTS quote_asset_amount_surplus                         Int64 COMMENT 'Quote surplus of the fill against the AMM',
+    ADD COLUMN IF NOT EXISTS spot_fulfillment_method_fee                        UInt64 COMMENT 'Fee paid to the external spot fulfillment (Openbook, Phoenix)',
+    ADD COLUMN IF NOT EXISTS taker                                              FixedString(44) COMMENT 'Taker user account, empty when not exposed',
+    ADD COLUMN IF NOT EXISTS taker_order_id                                     UInt32,
+    ADD COLUMN IF NOT EXISTS taker_order_direction                              LowCardinality(String) COMMENT 'Taker order direction (long, short)',
+    ADD COLUMN IF NOT EXISTS taker_order_base_asset_amount                      UInt64,
+    ADD COLUMN IF NOT EXISTS taker_order_cumulative_base_asset_amount_filled    UInt64,
+    ADD COLUMN IF NOT EXISTS taker_order_cumulative_quote_asset_amount_filled   UInt64,
+    ADD COLUMN IF NOT EXISTS maker                                              FixedString(44) COMMENT 'Maker user account, empty when filled against the AMM',
+    ADD COLUMN IF NOT EXISTS maker_order_id                                     UInt32,
+    ADD COLUMN IF NOT EXISTS maker_order_direction                              LowCardinality(String) COMMENT 'Maker order direction (long, short)',
+    ADD COLUMN IF NOT EXISTS maker_order_base_asset_amount                      UInt64,
+    ADD COLUMN IF NOT EXISTS maker_order_cumulative_base_asset_amount_filled    UInt64,
+    ADD COLUMN IF NOT EXISTS maker_order_cumulative_quote_asset_amount_filled   UInt64,
+    ADD COLUMN IF NOT EXISTS oracle_price                                       Int64 COMMENT 'Oracle price',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_action          (action)            TYPE set(8)                 GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_market          (market_type, market_index) TYPE set(256)       GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_taker           (taker)             TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_maker           (maker)             TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Drift Funding Rate --
+CREATE TABLE IF NOT EXISTS drift_funding_rate AS base_events
+COMMENT 'Drift v2 perp funding rate updates (FundingRateRecord), funding rates in 1e9 precision';
+ALTER TABLE drift_funding_rate
+    ADD COLUMN IF NOT EXISTS record_id                              UInt64 COMMENT 'Funding rate record id of the market',
+    ADD COLUMN IF NOT EXISTS market_index                           UInt16 COMMENT 'Perp market index',
+    ADD COLUMN IF NOT EXISTS funding_rate                           Int64 COMMENT 'Funding rate (quote per base)',
+    ADD COLUMN IF NOT EXISTS funding_rate_long                      Int128 COMMENT 'Funding rate paid by longs',
+    ADD COLUMN IF NOT EXISTS funding_rate_short                     Int128 COMMENT 'Funding rate received by shorts',
+    ADD COLUMN IF NOT EXISTS cumulative_funding_rate_long           Int128,
+    ADD COLUMN IF NOT EXISTS cumulative_funding_rate_short          Int128,
+    ADD COLUMN IF NOT EXISTS oracle_price_twap                      Int64 COMMENT 'Oracle price TWAP',
+    ADD COLUMN IF NOT EXISTS mark_price_twap                        UInt64 COMMENT 'Mark price TWAP',
+    ADD COLUMN IF NOT EXISTS period_revenue                         Int64 COMMENT 'AMM funding revenue of the period',
+    ADD COLUMN IF NOT EXISTS base_asset_amount_with_amm             Int128 COMMENT 'Net user position against the AMM',
+    ADD COLUMN IF NOT EXISTS base_asset_amount_with_unsettled_lp    Int128,
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_market_index    (market_index)      TYPE set(256)               GRANULARITY 1;
+
+-- Drift Liquidation --
+CREATE TABLE IF NOT EXISTS drift_liquidation AS base_events
+COMMENT 'Drift v2 liquidations (LiquidationRecord)';
+ALTER TABLE drift_liquidation
+    ADD COLUMN IF NOT EXISTS liquidation_type                                     LowCardinality(String) COMMENT 'Liquidation type (liquidate_perp, liquidate_spot, liquidate_borrow_for_perp_pnl, liquidate_perp_pnl_for_deposit, perp_bankruptcy, spot_bankruptcy)',
+    ADD COLUMN IF NOT EXISTS user                                                 FixedString(44) COMMENT 'Liquidated user account',
+    ADD COLUMN IF NOT EXISTS liquidator                                           FixedString(44) COMMENT 'Liquidator user account',
+    ADD COLUMN IF NOT EXISTS margin_requirement                                   UInt128 COMMENT 'Margin requirement of the user',
+    ADD COLUMN IF NOT EXISTS total_collateral                                     Int128 COMMENT 'Total collateral of the user',
+    ADD COLUMN IF NOT EXISTS margin_freed                                         UInt64 COMMENT 'Margin freed by the liquidation',
+    ADD COLUMN IF NOT EXISTS liquidation_id                                       UInt16 COMMENT 'Liquidation id of the user',
+    ADD COLUMN IF NOT EXISTS bankrupt                                             Bool COMMENT 'User is bankrupt',
+    ADD COLUMN IF NOT EXISTS canceled_order_ids                                   String COMMENT 'Comma-separated ids of the user orders cancelled by the liquidation',
+    ADD COLUMN IF NOT EXISTS perp_market_index                                    UInt16 COMMENT 'Perp market (liquidate_perp)',
+    ADD COLUMN IF NOT EXISTS perp_oracle_price                                    Int64,
+    ADD COLUMN IF NOT EXISTS perp_base_asset_amount                               Int64 COMMENT 'Base amount transferred to the liquidator',
+    ADD COLUMN IF NOT EXISTS perp_quote_asset_amount                              Int64 COMMENT 'Quote amount transferred to the liquidator',
+    ADD COLUMN IF NOT EXISTS perp_fill_record_id                                  UInt64,
+    ADD COLUMN IF NOT EXISTS perp_liquidator_fee                                  UInt64,
+    ADD COLUMN IF NOT EXISTS perp_if_fee                                          UInt64 COMMENT 'Insurance fund fee',
+    ADD COLUMN IF NOT EXISTS spot_asset_market_index                              UInt16 COMMENT 'Spot asset market (liquidate_spot)',
+    ADD COLUMN IF NOT EXISTS spot_asset_price                                     Int64,
+    ADD COLUMN IF NOT EXISTS spot_asset_transfer                                  UInt128 COMMENT 'Asset transferred to the liquidator',
+    ADD COLUMN IF NOT EXISTS spot_liability_market_index                          UInt16 COMMENT 'Spot liability market (liquidate_spot)',
+    ADD COLUMN IF NOT EXISTS spot_liability_price                                 Int64,
+    ADD COLUMN IF NOT EXISTS spot_liability_transfer                              UInt128 COMMENT 'Liability repaid by the liquidator',
+    ADD COLUMN IF NOT EXISTS spot_if_fee                                          UInt64 COMMENT 'Insurance fund fee',
+    ADD COLUMN IF NOT EXISTS borrow_pnl_perp_market_index                         UInt16 COMMENT 'Perp market of the pnl (liquidate_borrow_for_perp_pnl)',
+    ADD COLUMN IF NOT EXISTS borrow_pnl_oracle_price                              Int64,
+    ADD COLUMN IF NOT EXISTS borrow_pnl_pnl_transfer                              UInt128 COMMENT 'Pnl transferred to the liquidator',
+    ADD COLUMN IF NOT EXISTS borrow_pnl_liability_market_index                    UInt16 COMMENT 'Spot liability market (liquidate_borrow_for_perp_pnl)',
+    ADD COLUMN IF NOT EXISTS borrow_pnl_liability_price                           Int64,
+    ADD COLUMN IF NOT EXISTS borrow_pnl_liability_transfer                        UInt128 COMMENT 'Liability repaid by the liquidator',
+    ADD COLUMN IF NOT EXISTS pnl_deposit_perp_market_index                        UInt16 COMMENT 'Perp market of the pnl (liquidate_perp_pnl_for_deposit)',
+    ADD COLUMN IF NOT EXISTS pnl_deposit_oracle_price                             Int64,
+    ADD COLUMN IF NOT EXISTS pnl_deposit_pnl_transfer                             UInt128 COMMENT 'Pnl repaid by the liquidator',
+    ADD COLUMN IF NOT EXISTS pnl_deposit_asset_market_index                       UInt16 COMMENT 'Spot asset market (liquidate_perp_pnl_for_deposit)',
+    ADD COLUMN IF NOT EXISTS pnl_deposit_asset_price                              Int64,
+    ADD COLUMN IF NOT EXISTS pnl_deposit_asset_transfer                           UInt128 COMMENT 'Asset transferred to the liquidator',
+    ADD COLUMN IF NOT EXISTS perp_bankruptcy_market_index                         UInt16 COMMENT 'Perp market (perp_bankruptcy)',
+    ADD COLUMN IF NOT EXISTS perp_bankruptcy_pnl                                  Int128 COMMENT 'Negative pnl of the bankrupt user',
+    ADD COLUMN IF NOT EXISTS perp_bankruptcy_if_payment                           UInt128 COMMENT 'Insurance fund payment',
+    ADD COLUMN IF NOT EXISTS perp_bankruptcy_clawback_user                        FixedString(44) COMMENT 'User clawed back, empty if none',
+    ADD COLUMN IF NOT EXISTS perp_bankruptcy_clawback_user_payment                UInt128,
+    ADD COLUMN IF NOT EXISTS perp_bankruptcy_cumulative_funding_rate_delta        Int128 COMMENT 'Loss socialized through the funding rate',
+    ADD COLUMN IF NOT EXISTS spot_bankruptcy_market_index                         UInt16 COMMENT 'Spot market (spot_bankruptcy)',
+    ADD COLUMN IF NOT EXISTS spot_bankruptcy_borrow_amount                        UInt128 COMMENT 'Borrow of the bankrupt user',
+    ADD COLUMN IF NOT EXISTS spot_bankruptcy_if_payment                           UInt128 COMMENT 'Insurance fund payment',
+    ADD COLUMN IF NOT EXISTS spot_bankruptcy_cumulative_deposit_interest_delta    UInt128 COMMENT 'Loss socialized through the deposit interest',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_liquidation_type    (liquidation_type)  TYPE set(8)                 GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_user                (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_liquidator          (liquidator)        TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Drift Deposit --
+CREATE TABLE IF NOT EXISTS drift_deposit AS base_events
+COMMENT 'Drift v2 spot market deposits & withdrawals (DepositRecord)';
+ALTER TABLE drift_deposit
+    ADD COLUMN IF NOT EXISTS user_authority                         FixedString(44) COMMENT 'Authority of the user account',
+    ADD COLUMN IF NOT EXISTS user                                   FixedString(44) COMMENT 'User account',
+    ADD COLUMN IF NOT EXISTS direction                              LowCardinality(String) COMMENT 'Direction (deposit, withdraw)',
+    ADD COLUMN IF NOT EXISTS deposit_record_id                      UInt64,
+    ADD COLUMN IF NOT EXISTS amount                                 UInt64 COMMENT 'Amount in token decimals',
+    ADD COLUMN IF NOT EXISTS market_index                           UInt16 COMMENT 'Spot market index',
+    ADD COLUMN IF NOT EXISTS oracle_price                           Int64,
+    ADD COLUMN IF NOT EXISTS market_deposit_balance                 UInt128 COMMENT 'Market deposit balance after',
+    ADD COLUMN IF NOT EXISTS market_withdraw_balance                UInt128 COMMENT 'Market borrow balance after',
+    ADD COLUMN IF NOT EXISTS market_cumulative_deposit_interest     UInt128,
+    ADD COLUMN IF NOT EXISTS market_cumulative_borrow_interest      UInt128,
+    ADD COLUMN IF NOT EXISTS total_deposits_after                   UInt64 COMMENT 'User total deposits after',
+    ADD COLUMN IF NOT EXISTS total_withdraws_after                  UInt64 COMMENT 'User total withdrawals after',
+    ADD COLUMN IF NOT EXISTS explanation                            UInt8 COMMENT 'DepositExplanation variant',
+    ADD COLUMN IF NOT EXISTS transfer_user                          FixedString(44) COMMENT 'Counterparty of transfers between user accounts',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user_authority  (user_authority)    TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_market_index    (market_index)      TYPE set(256)               GRANULARITY 1;
+
+-- Drift Settle PnL --
+CREATE TABLE IF NOT EXISTS drift_settle_pnl AS base_events
+COMMENT 'Drift v2 perp PnL settlements (SettlePnlRecord)';
+ALTER TABLE drift_settle_pnl
+    ADD COLUMN IF NOT EXISTS user                       FixedString(44) COMMENT 'User account',
+    ADD COLUMN IF NOT EXISTS market_index               UInt16 COMMENT 'Perp market index',
+    ADD COLUMN IF NOT EXISTS pnl                        Int128 COMMENT 'PnL settled in quote',
+    ADD COLUMN IF NOT EXISTS base_asset_amount          Int64 COMMENT 'Position size',
+    ADD COLUMN IF NOT EXISTS quote_asset_amount_after   Int64,
+    ADD COLUMN IF NOT EXISTS quote_entry_amount         Int64,
+    ADD COLUMN IF NOT EXISTS settle_price               Int64,
+    ADD COLUMN IF NOT EXISTS explanation                UInt8 COMMENT 'SettlePnlExplanation variant',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_user            (user)              TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_market_index    (market_index)      TYPE set(256)               GRANULARITY 1;
@@ -0,0 +1,51 @@
+-- Perp funding rates --
+CREATE TABLE IF NOT EXISTS perp_funding_rates (
+    timestamp                       DateTime('UTC', 0) COMMENT 'beginning of the bar',
+
+    -- OrderBy --
+    program_id                      LowCardinality(FixedString(44)),
+    market_index                    UInt16,
+
+    -- Aggregate --
+    funding_rate                    AggregateFunction(argMax, Int64, UInt64) COMMENT 'latest funding rate of the window (1e9)',
+    funding_rate_long               AggregateFunction(argMax, Int128, UInt64) COMMENT 'latest funding rate paid by longs (1e9)',
+    funding_rate_short              AggregateFunction(argMax, Int128, UInt64) COMMENT 'latest funding rate received by shorts (1e9)',
+    cumulative_funding_rate_long    AggregateFunction(argMax, Int128, UInt64),
+    cumulative_funding_rate_short   AggregateFunction(argMax, Int128, UInt64),
+    oracle_price_twap               AggregateFunction(argMax, Int64, UInt64) COMMENT 'latest oracle price TWAP (1e6)',
+    mark_price_twap                 AggregateFunction(argMax, UInt64, UInt64) COMMENT 'latest mark price TWAP (1e6)',
+
+    -- summing --
+    period_revenue                  SimpleAggregateFunction(sum, Int128) COMMENT 'AMM funding revenue in the window (1e6)',
+    updates                         SimpleAggregateFunction(sum, UInt64) COMMENT 'number of funding rate updates in the window',
+
+    -- indexes --
+    INDEX idx_timestamp         (timestamp)         TYPE minmax                 GRANULARITY 1,
+    INDEX idx_program_id        (program_id)        TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_market_index      (market_index)      TYPE set(256)               GRANULARITY 1
+)
+ENGINE = AggregatingMergeTree
+ORDER BY (timestamp, program_id, market_index)
+COMMENT 'Funding rates of perp markets, aggregated by hour';
+
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_drift_perp_funding_rates
+TO perp_funding_rates
+AS
+WITH
+    toUInt64(to_version(block_num, transaction_index, instruction_index)) AS version
+SELECT
+    toStartOfHour(drift_funding_rate.timestamp)    AS timestamp,
+    program_id, market_index,
+
+    argMaxState(funding_rate, version)                      AS funding_rate,
+    argMaxState(funding_rate_long, version)                 AS funding_rate_long,
+    argMaxState(funding_rate_short, version)                AS funding_rate_short,
+    argMaxState(cumulative_funding_rate_long, version)      AS cumulative_funding_rate_long,
+    argMaxState(cumulative_funding_rate_short, version)     AS cumulative_funding_rate_short,
+    argMaxState(oracle_price_twap, version)                 AS oracle_price_twap,
+    argMaxState(mark_price_twap, version)                   AS mark_price_twap,
+
+    sum(toInt128(period_revenue))                           AS period_revenue,
+    count()                                                 AS updates
+FROM drift_funding_rate
+GROUP BY timestamp, program_id, market_index;
@@ -0,0 +1,66 @@
+-- Perp market fills --
+CREATE TABLE IF NOT EXISTS perp_market_fills (
+    timestamp               DateTime('UTC', 0) COMMENT 'beginning of the bar',
+
+    -- OrderBy --
+    program_id              LowCardinality(FixedString(44)),
+    market_index            UInt16,
+
+    -- Aggregate --
+    open                    AggregateFunction(argMin, Float64, UInt64),
+    high                    SimpleAggregateFunction(max, Float64),
+    low                     SimpleAggregateFunction(min, Float64),
+    close                   AggregateFunction(argMax, Float64, UInt64),
+
+    -- volume --
+    base_volume             SimpleAggregateFunction(sum, UInt128) COMMENT 'base amount filled in the window (1e9)',
+    quote_volume            SimpleAggregateFunction(sum, UInt128) COMMENT 'quote amount filled in the window (1e6)',
+    taker_fees              SimpleAggregateFunction(sum, UInt128) COMMENT 'taker fees in the window (1e6)',
+    maker_fees              SimpleAggregateFunction(sum, Int128) COMMENT 'maker fees in the window, negative for rebates (1e6)',
+
+    -- universal --
+    takers                  AggregateFunction(uniq, FixedString(44)) COMMENT 'unique takers in the window',
+    makers                  AggregateFunction(uniq, FixedString(44)) COMMENT 'unique makers in the window, excluding the AMM',
+    fills                   SimpleAggregateFunction(sum, UInt64) COMMENT 'number of fills in the window',
+
+    -- indexes --
+    INDEX idx_timestamp         (timestamp)         TYPE minmax                 GRANULARITY 1,
+    INDEX idx_program_id        (program_id)        TYPE set(8)                 GRANULARITY 1,
+    INDEX idx_market_index      (market_index)      TYPE set(256)               GRANULARITY 1,
+    INDEX idx_quote_volume      (quote_volume)      TYPE minmax                 GRANULARITY 1,
+    INDEX idx_fills             (fills)             TYPE minmax                 GRANULARITY 1
+)
+ENGINE = AggregatingMergeTree
+ORDER BY (timestamp, program_id, market_index)
+COMMENT 'OHLCV of perp market fills, aggregated by hour';
+
+-- Drift perp fills, prices converted from quote (1e6) per base (1e9)
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_drift_perp_market_fills
+TO perp_market_fills
+AS
+WITH
+    toFloat64(quote_asset_amount_filled) / base_asset_amount_filled * 1e3 AS price,
+    toUInt64(to_version(block_num, transaction_index, instruction_index)) AS version
+SELECT
+    toStartOfHour(drift_order_action.timestamp)    AS timestamp,
+    program_id, market_index,
+
+    /* OHLC */
+    argMinState(price, version)                 AS open,
+    max(price)                                  AS high,
+    min(price)                                  AS low,
+    argMaxState(price, version)                 AS close,
+
+    /* volumes & fees */
+    sum(toUInt128(base_asset_amount_filled))    AS base_volume,
+    sum(toUInt128(quote_asset_amount_filled))   AS quote_volume,
+    sum(toUInt128(taker_fee))                   AS taker_fees,
+    sum(toInt128(maker_fee))                    AS maker_fees,
+
+    /* universal */
+    uniqState(taker)                            AS takers,
+    uniqStateIf(maker, maker != '')             AS makers,
+    count()                                     AS fills
+FROM drift_order_action
+WHERE action = 'fill' AND market_type = 'perp' AND base_asset_amount_filled > 0
+GROUP BY timestamp, program_id, market_index;
@@ -0,0 +1,249 @@
+use common::db::{common_key_v2, set_clock};
+use proto::pb::drift::v1 as pb;
+use substreams::pb::substreams::Clock;
+use substreams_database_change::tables::{Row, Tables};
+use substreams_solana::base58;
+
+pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
+    for (transaction_index, tx) in events.transactions.iter().enumerate() {
+        for (log_index, log) in tx.logs.iter().enumerate() {
+            let key = common_key_v2(clock, transaction_index, log_index);
+            let row = match &log.log {
+                Some(pb::log::Log::OrderAction(event)) => handle_order_action(tables.create_row("drift_order_action", key), event),
+                Some(pb::log::Log::FundingRate(event)) => handle_funding_rate(tables.create_row("drift_funding_rate", key), event),
+                Some(pb::log::Log::Liquidation(event)) => handle_liquidation(tables.create_row("drift_liquidation", key), event),
+                Some(pb::log::Log::Deposit(event)) => handle_deposit(tables.create_row("drift_deposit", key), event),
+                Some(pb::log::Log::SettlePnl(event)) => handle_settle_pnl(tables.create_row("drift_settle_pnl", key), event),
+                _ => continue,
+            };
+            set_log(log, row);
+            set_transaction(tx, row);
+            set_clock(clock, row);
+        }
+    }
+}
+
+fn handle_order_action<'a>(row: &'a mut Row, event: &pb::OrderActionEvent) -> &'a mut Row {
+    row.set("action", order_action(event.action))
+        .set("action_explanation", event.action_explanation)
+        .set("market_index", event.market_index)
+        .set("market_type", market_type(event.market_type))
+        .set("filler", encode_option(&event.filler))
+        .set("filler_reward", event.filler_reward.unwrap_or_default())
+        .set("fill_record_id", event.fill_record_id.unwrap_or_default())
+        .set("base_asset_amount_filled", event.base_asset_amount_filled.unwrap_or_default())
+        .set("quote_asset_amount_filled", event.quote_asset_amount_filled.unwrap_or_default())
+        .set("taker_fee", event.taker_fee.unwrap_or_default())
+        .set("maker_fee", event.maker_fee.unwrap_or_default())
+        .set("referrer_reward", event.referrer_reward.unwrap_or_default())
+        .set("quote_asset_amount_surplus", event.quote_asset_amount_surplus.unwrap_or_default())
+        .set("spot_fulfillment_method_fee", event.spot_fulfillment_method_fee.unwrap_or_default())
+        .set("taker", encode_option(&event.taker))
+        .set("taker_order_id", event.taker_order_id.unwrap_or_default())
+        .set("taker_order_direction", event.taker_order_direction.map(direction).unwrap_or_default())
+        .set("taker_order_base_asset_amount", event.taker_order_base_asset_amount.unwrap_or_default())
+        .set(
+            "taker_order_cumulative_base_asset_amount_filled",
+            event.taker_order_cumulative_base_asset_amount_filled.unwrap_or_default(),
+        )
+        .set(
+            "taker_order_cumulative_quote_asset_amount_filled",
+            event.taker_order_cumulative_quote_asset_amount_filled.unwrap_or_default(),
+        )
+        .set("maker", encode_option(&event.maker))
+        .set("maker_order_id", event.maker_order_id.unwrap_or_default())
+        .set("maker_order_direction", event.maker_order_direction.map(direction).unwrap_or_default())
+        .set("maker_order_base_asset_amount", event.maker_order_base_asset_amount.unwrap_or_default())
+        .set(
+            "maker_order_cumulative_base_asset_amount_filled",
+            event.maker_order_cumulative_base_asset_amount_filled.unwrap_or_default(),
+        )
+        .set(
+            "maker_order_cumulative_quote_asset_amount_filled",
+            event.maker_order_cumulative_quote_asset_amount_filled.unwrap_or_default(),
+        )
+        .set("oracle_price", event.oracle_price)
+}
+
+fn handle_funding_rate<'a>(row: &'a mut Row, event: &pb::FundingRateEvent) -> &'a mut Row {
+    row.set("record_id", event.record_id)
+        .set("market_index", event.market_index)
+        .set("funding_rate", event.funding_rate)
+        .set("funding_rate_long", &event.funding_rate_long)
+        .set("funding_rate_short", &event.funding_rate_short)
+        .set("cumulative_funding_rate_long", &event.cumulative_funding_rate_long)
+        .set("cumulative_funding_rate_short", &event.cumulative_funding_rate_short)
+        .set("oracle_price_twap", event.oracle_price_twap)
+        .set("mark_price_twap", event.mark_price_twap)
+        .set("period_revenue", event.period_revenue)
+        .set("base_asset_amount_with_amm", &event.base_asset_amount_with_amm)
+        .set("base_asset_amount_with_unsettled_lp", &event.base_asset_amount_with_unsettled_lp)
+}
+
+fn handle_liquidation<'a>(row: &'a mut Row, event: &pb::LiquidationEvent) -> &'a mut Row {
+    let canceled_order_ids: Vec<String> = event.canceled_order_ids.iter().map(u32::to_string).collect();
+    row.set("liquidation_type", liquidation_type(event.liquidation_type))
+        .set("user", base58::encode(&event.user))
+        .set("liquidator", base58::encode(&event.liquidator))
+        .set("margin_requirement", &event.margin_requirement)
+        .set("total_collateral", &event.total_collateral)
+        .set("margin_freed", event.margin_freed)
+        .set("liquidation_id", event.liquidation_id)
+        .set("bankrupt", event.bankrupt)
+        .set("canceled_order_ids", canceled_order_ids.join(","));
+    // Drift serializes every liquidation record, only the one matching the liquidation type is filled
+    match event.liquidation_type {
+        0 => {
+            if let Some(perp) = &event.liquidate_perp {
+                row.set("perp_market_index", perp.market_index)
+                    .set("perp_oracle_price", perp.oracle_price)
+                    .set("perp_base_asset_amount", perp.base_asset_amount)
+                    .set("perp_quote_asset_amount", perp.quote_asset_amount)
+                    .set("perp_fill_record_id", perp.fill_record_id)
+                    .set("perp_liquidator_fee", perp.liquidator_fee)
+                    .set("perp_if_fee", perp.if_fee);
+            }
+        }
+        1 => {
+            if let Some(spot) = &event.liquidate_spot {
+                row.set("spot_asset_market_index", spot.asset_market_index)
+                    .set("spot_asset_price", spot.asset_price)
+                    .set("spot_asset_transfer", &spot.asset_transfer)
+                    .set("spot_liability_market_index", spot.liability_market_index)
+                    .set("spot_liability_price", spot.liability_price)
+                    .set("spot_liability_transfer", &spot.liability_transfer)
+                    .set("spot_if_fee", spot.if_fee);
+            }
+        }
+        2 => {
+            if let Some(borrow) = &event.liquidate_borrow_for_perp_pnl {
+                row.set("borrow_pnl_perp_market_index", borrow.perp_market_index)
+                    .set("borrow_pnl_oracle_price", borrow.market_oracle_price)
+                    .set("borrow_pnl_pnl_transfer", &borrow.pnl_transfer)
+                    .set("borrow_pnl_liability_market_index", borrow.liability_market_index)
+                    .set("borrow_pnl_liability_price", borrow.liability_price)
+                    .set("borrow_pnl_liability_transfer", &borrow.liability_transfer);
+            }
+        }
+        3 => {
+            if let Some(deposit) = &event.liquidate_perp_pnl_for_deposit {
+                row.set("pnl_deposit_perp_market_index", deposit.perp_market_index)
+                    .set("pnl_deposit_oracle_price", deposit.market_oracle_price)
+                    .set("pnl_deposit_pnl_transfer", &deposit.pnl_transfer)
+                    .set("pnl_deposit_asset_market_index", deposit.asset_market_index)
+                    .set("pnl_deposit_asset_price", deposit.asset_price)
+                    .set("pnl_deposit_asset_transfer", &deposit.asset_transfer);
+            }
+        }
+        4 => {
+            if let Some(bankruptcy) = &event.perp_bankruptcy {
+                row.set("perp_bankruptcy_market_index", bankruptcy.market_index)
+                    .set("perp_bankruptcy_pnl", &bankruptcy.pnl)
+                    .set("perp_bankruptcy_if_payment", &bankruptcy.if_payment)
+                    .set("perp_bankruptcy_clawback_user", encode_option(&bankruptcy.clawback_user))
+                    .set(
+                        "perp_bankruptcy_clawback_user_payment",
+                        bankruptcy.clawback_user_payment.as_deref().unwrap_or("0"),
+                    )
+                    .set("perp_bankruptcy_cumulative_funding_rate_delta", &bankruptcy.cumulative_funding_rate_delta);
+            }
+        }
+        5 => {
+            if let Some(bankruptcy) = &event.spot_bankruptcy {
+                row.set("spot_bankruptcy_market_index", bankruptcy.market_index)
+                    .set("spot_bankruptcy_borrow_amount", &bankruptcy.borrow_amount)
+                    .set("spot_bankruptcy_if_payment", &bankruptcy.if_payment)
+                    .set(
+                        "spot_bankruptcy_cumulative_deposit_interest_delta",
+                        &bankruptcy.cumulative_deposit_interest_delta,
+                    );
+            }
+        }
+        _ => {}
+    }
+    row
+}
+
+fn handle_deposit<'a>(row: &'a mut Row, event: &pb::DepositEvent) -> &'a mut Row {
+    row.set("user_authority", base58::encode(&event.user_authority))
+        .set("user", base58::encode(&event.user))
+        .set("direction", if event.direction == 0 { "deposit" } else { "withdraw" })
+        .set("deposit_record_id", event.deposit_record_id)
+        .set("amount", event.amount)
+        .set("market_index", event.market_index)
+        .set("oracle_price", event.oracle_price)
+        .set("market_deposit_balance", &event.market_deposit_balance)
+        .set("market_withdraw_balance", &event.market_withdraw_balance)
+        .set("market_cumulative_deposit_interest", &event.market_cumulative_deposit_interest)
+        .set("market_cumulative_borrow_interest", &event.market_cumulative_borrow_interest)
+        .set("total_deposits_after", event.total_deposits_after)
+        .set("total_withdraws_after", event.total_withdraws_after)
+        .set("explanation", event.explanation)
+        .set("transfer_user", encode_option(&event.transfer_user))
+}
+
+fn handle_settle_pnl<'a>(row: &'a mut Row, event: &pb::SettlePnlEvent) -> &'a mut Row {
+    row.set("user", base58::encode(&event.user))
+        .set("market_index", event.market_index)
+        .set("pnl", &event.pnl)
+        .set("base_asset_amount", event.base_asset_amount)
+        .set("quote_asset_amount_after", event.quote_asset_amount_after)
+        .set("quote_entry_amount", event.quote_entry_amount)
+        .set("settle_price", event.settle_price)
+        .set("explanation", event.explanation)
+}
+
+fn encode_option(account: &Option<Vec<u8>>) -> String {
+    account.as_ref().map(base58::encode).unwrap_or_default()
+}
+
+fn order_action(action: u32) -> &'static str {
+    match action {
+        0 => "place",
+        1 => "cancel",
+        2 => "fill",
+        3 => "trigger",
+        4 => "expire",
+        _ => "",
+    }
+}
+
+fn market_type(market_type: u32) -> &'static str {
+    match market_type {
+        0 => "spot",
+        1 => "perp",
+        _ => "",
+    }
+}
+
+fn direction(direction: u32) -> &'static str {
+    match direction {
+        0 => "long",
+        1 => "short",
+        _ => "",
+    }
+}
+
+fn liquidation_type(liquidation_type: u32) -> &'static str {
+    match liquidation_type {
+        0 => "liquidate_perp",
+        1 => "liquidate_spot",
+        2 => "liquidate_borrow_for_perp_pnl",
+        3 => "liquidate_perp_pnl_for_deposit",
+        4 => "perp_bankruptcy",
+        5 => "spot_bankruptcy",
+        _ => "",
+    }
+}
+
+fn set_log(log: &pb::Log, row: &mut Row) {
+    row.set("program_id", base58::encode(&log.program_id)).set("stack_height", log.invoke_depth);
+}
+
+fn set_transaction(tx: &pb::Transaction, row: &mut Row) {
+    row.set("signature", base58::encode(&tx.signature))
+        .set("fee_payer", base58::encode(&tx.fee_payer))
+        .set("signers_raw", tx.signers.iter().map(base58::encode).collect::<Vec<_>>().join(","))
+        .set("fee", tx.fee)
+        .set("compute_units_consumed", tx.compute_units_consumed);
+}
@@ -3,6 +3,7 @@ CREATE TABLE IF NOT EXISTS native_stake_initialize AS base_events
 COMMENT 'Native Stake Program: initialize stake account';
 ALTER TABLE native_stake_initialize
     ADD COLUMN IF NOT EXISTS is_root              Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS instruction          LowCardinality(String) COMMENT 'Instruction variant (initialize, initialize_checked)',
     ADD COLUMN IF NOT EXISTS stake_account        FixedString(44) COMMENT 'Stake account',
     ADD COLUMN IF NOT EXISTS staker               FixedString(44) COMMENT 'Staker authority',
     ADD COLUMN IF NOT EXISTS withdrawer           FixedString(44) COMMENT 'Withdrawer authority',
@@ -56,3 +57,72 @@ ALTER TABLE native_stake_split
     ADD COLUMN IF NOT EXISTS split_stake_account  FixedString(44) COMMENT 'New split stake account',
     ADD COLUMN IF NOT EXISTS lamports             UInt64 COMMENT 'Amount to split',
     ADD COLUMN IF NOT EXISTS stake_authority      FixedString(44) COMMENT 'Stake authority';
+
+-- Native Stake Authorize (Authorize, AuthorizeChecked, AuthorizeWithSeed, AuthorizeCheckedWithSeed) --
+CREATE TABLE IF NOT EXISTS native_stake_authorize AS base_events
+COMMENT 'Native Stake Program: change staker or withdrawer authority';
+ALTER TABLE native_stake_authorize
+    ADD COLUMN IF NOT EXISTS is_root              Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS instruction          LowCardinality(String) COMMENT 'Instruction variant (authorize, authorize_checked, authorize_with_seed, authorize_checked_with_seed)',
+    ADD COLUMN IF NOT EXISTS stake_account        FixedString(44) COMMENT 'Stake account',
+    ADD COLUMN IF NOT EXISTS new_authority        FixedString(44) COMMENT 'New authority',
+    ADD COLUMN IF NOT EXISTS stake_authorize      LowCardinality(String) COMMENT 'Authority type (STAKE_AUTHORIZE_STAKER, STAKE_AUTHORIZE_WITHDRAWER)',
+    ADD COLUMN IF NOT EXISTS authority            FixedString(44) COMMENT 'Current authority (base key for seed variants)',
+    ADD COLUMN IF NOT EXISTS authority_seed       String DEFAULT '' COMMENT 'Authority seed (seed variants only)',
+    ADD COLUMN IF NOT EXISTS authority_owner      String DEFAULT '' COMMENT 'Authority owner program (seed variants only)',
+    ADD COLUMN IF NOT EXISTS custodian            FixedString(44) COMMENT 'Lockup custodian',
+
+    -- indexes --
+    ADD INDEX IF NOT EXISTS idx_stake_account     (stake_account)     TYPE bloom_filter(0.005)    GRANULARITY 1,
+    ADD INDEX IF NOT EXISTS idx_new_authority     (new_authority)     TYPE bloom_filter(0.005)    GRANULARITY 1;
+
+-- Native Stake Set Lockup (SetLockup, SetLockupChecked) --
+CREATE TABLE IF NOT EXISTS native_stake_set_lockup AS base_events
+COMMENT 'Native Stake Program: set stake account lockup';
+ALTER TABLE native_stake_set_lockup
+    ADD COLUMN IF NOT EXISTS is_root              Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS instruction          LowCardinality(String) COMMENT 'Instruction variant (set_lockup, set_lockup_checked)',
+    ADD COLUMN IF NOT EXISTS stake_account        FixedString(44) COMMENT 'Stake account',
+    ADD COLUMN IF NOT EXISTS lockup_unix_timestamp Int64 DEFAULT 0 COMMENT 'New lockup timestamp',
+    ADD COLUMN IF NOT EXISTS lockup_epoch         UInt64 DEFAULT 0 COMMENT 'New lockup epoch',
+    ADD COLUMN IF NOT EXISTS lockup_custodian     FixedString(44) COMMENT 'New lockup custodian',
+    ADD COLUMN IF NOT EXISTS authority            FixedString(44) COMMENT 'Lockup custodian or withdraw authority';
+
+-- Native Stake Deactivate Delinquent --
+CREATE TABLE IF NOT EXISTS native_stake_deactivate_delinquent AS base_events
+COMMENT 'Native Stake Program: deactivate stake delegated to a delinquent validator';
+ALTER TABLE native_stake_deactivate_delinquent
+    ADD COLUMN IF NOT EXISTS is_root                  Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS stake_account            FixedString(44) COMMENT 'Stake account',
+    ADD COLUMN IF NOT EXISTS delinquent_vote_account  FixedString(44) COMMENT 'Delinquent vote account',
+    ADD COLUMN IF NOT EXISTS reference_vote_account   FixedString(44) COMMENT 'Reference vote account';
+
+-- Native Stake Redelegate --
+CREATE TABLE IF NOT EXISTS native_stake_redelegate AS base_events
+COMMENT 'Native Stake Program: redelegate stake to another validator';
+ALTER TABLE native_stake_redelegate
+    ADD COLUMN IF NOT EXISTS is_root                      Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS stake_account                FixedString(44) COMMENT 'Delegated stake account',
+    ADD COLUMN IF NOT EXISTS uninitialized_stake_account  FixedString(44) COMMENT 'Stake account receiving the redelegated stake',
+    ADD COLUMN IF NOT EXISTS vote_account                 FixedString(44) COMMENT 'Validator vote account',
+    ADD COLUMN IF NOT EXISTS stake_authority              FixedString(44) COMMENT 'Stake authority';
+
+-- Native Stake Move Stake --
+CREATE TABLE IF NOT EXISTS native_stake_move_stake AS base_events
+COMMENT 'Native Stake Program: move active stake between stake accounts';
+ALTER TABLE native_stake_move_stake
+    ADD COLUMN IF NOT EXISTS is_root                      Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS source_stake_account         FixedString(44) COMMENT 'Source stake account',
+    ADD COLUMN IF NOT EXISTS destination_stake_account    FixedString(44) COMMENT 'Destination stake account',
+    ADD COLUMN IF NOT EXISTS lamports                     UInt64 COMMENT 'Amount of stake moved',
+    ADD COLUMN IF NOT EXISTS stake_authority              FixedString(44) COMMENT 'Stake authority';
+
+-- Native Stake Move Lamports --
+CREATE TABLE IF NOT EXISTS native_stake_move_lamports AS base_events
+COMMENT 'Native Stake Program: move excess lamports between stake accounts';
+ALTER TABLE native_stake_move_lamports
+    ADD COLUMN IF NOT EXISTS is_root                      Bool COMMENT 'Is root instruction',
+    ADD COLUMN IF NOT EXISTS source_stake_account         FixedString(44) COMMENT 'Source stake account',
+    ADD COLUMN IF NOT EXISTS destination_stake_account    FixedString(44) COMMENT 'Destination stake account',
+    ADD COLUMN IF NOT EXISTS lamports                     UInt64 COMMENT 'Amount of lamports moved',
+    ADD COLUMN IF NOT EXISTS stake_authority              FixedString(44) COMMENT 'Stake authority';
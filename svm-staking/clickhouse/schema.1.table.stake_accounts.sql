@@ -0,0 +1,36 @@
+-- Stake Accounts (current state) --
+CREATE TABLE IF NOT EXISTS stake_accounts (
+    -- block --
+    block_num                   UInt32,
+    block_hash                  FixedString(44),
+    timestamp                   DateTime(0, 'UTC'),
+
+    -- stake account --
+    stake_account               FixedString(44) COMMENT 'Stake account',
+    vote_account                FixedString(44) COMMENT 'Validator vote account the stake is delegated to',
+    staker                      FixedString(44) COMMENT 'Staker authority',
+    withdrawer                  FixedString(44) COMMENT 'Withdrawer authority',
+    deactivating                Bool COMMENT 'Stake has been deactivated',
+    lamports                    UInt64 COMMENT 'Last known native balance in lamports',
+
+    -- indexes --
+    INDEX idx_vote_account      (vote_account)      TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_staker            (staker)            TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_withdrawer        (withdrawer)        TYPE bloom_filter(0.005)    GRANULARITY 1,
+    INDEX idx_lamports          (lamports)          TYPE minmax                 GRANULARITY 1
+)
+ENGINE = ReplacingMergeTree(block_num)
+ORDER BY (stake_account)
+COMMENT 'Native stake accounts current state (single row per stake account)';
+
+-- Delegated stake per validator --
+CREATE OR REPLACE VIEW validator_delegated_stake AS
+SELECT
+    vote_account,
+    count()         AS stake_accounts,
+    sum(lamports)   AS delegated_lamports,
+    max(block_num)  AS block_num,
+    max(timestamp)  AS timestamp
+FROM stake_accounts FINAL
+WHERE vote_account != '' AND NOT deactivating AND lamports > 0
+GROUP BY vote_account;
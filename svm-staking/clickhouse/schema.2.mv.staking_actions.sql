@@ -3,7 +3,7 @@ CREATE TABLE IF NOT EXISTS staking_actions AS base_events
 COMMENT 'Unified staking actions across all protocols';
 ALTER TABLE staking_actions
     ADD COLUMN IF NOT EXISTS protocol             LowCardinality(String) COMMENT 'Protocol name (native_stake, marinade)',
-    ADD COLUMN IF NOT EXISTS action               LowCardinality(String) COMMENT 'Action type (stake, unstake, withdraw, add_liquidity)',
+    ADD COLUMN IF NOT EXISTS action               LowCardinality(String) COMMENT 'Action type (stake, unstake, withdraw, move_stake, add_liquidity)',
     ADD COLUMN IF NOT EXISTS account              FixedString(44) COMMENT 'User/owner account',
     ADD COLUMN IF NOT EXISTS amount               UInt64 DEFAULT 0 COMMENT 'Amount in lamports',
     ADD COLUMN IF NOT EXISTS validator            FixedString(44) COMMENT 'Validator vote account (if applicable)',
@@ -67,6 +67,54 @@ SELECT
     ''                  AS validator
 FROM native_stake_withdraw;
 
+-- Deactivate Delinquent = unstake
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_stake_deactivate_delinquent
+TO staking_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'native_stake'              AS protocol,
+    'unstake'                   AS action,
+    fee_payer                   AS account,
+    0                           AS amount,
+    delinquent_vote_account     AS validator
+FROM native_stake_deactivate_delinquent;
+
+-- Redelegate = stake
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_stake_redelegate
+TO staking_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'native_stake'      AS protocol,
+    'stake'             AS action,
+    stake_authority     AS account,
+    0                   AS amount,
+    vote_account        AS validator
+FROM native_stake_redelegate;
+
+-- Move Stake = move_stake
+CREATE MATERIALIZED VIEW IF NOT EXISTS mv_native_stake_move_stake
+TO staking_actions AS
+SELECT
+    block_num, block_hash, timestamp,
+    transaction_index, instruction_index,
+    signature, fee_payer, signers_raw, fee, compute_units_consumed,
+    program_id, stack_height,
+
+    'native_stake'      AS protocol,
+    'move_stake'        AS action,
+    stake_authority     AS account,
+    lamports            AS amount,
+    ''                  AS validator
+FROM native_stake_move_stake;
+
 /* ──────────────────────────────────────────────────────────────────────────
    Marinade → staking_actions
    ────────────────────────────────────────────────────────────────────────── */
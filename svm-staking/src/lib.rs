@@ -1,21 +1,47 @@
 mod marinade;
 mod native_stake;
+mod stake_accounts;
 
 use common::db::set_clock;
 use proto::pb;
+use substreams::store::{DeltaString, Deltas, StoreGet, StoreGetString, StoreNew, StoreSetString};
 use substreams::{errors::Error, pb::substreams::Clock};
 use substreams_database_change::pb::sf::substreams::sink::database::v1::DatabaseChanges;
 
+#[substreams::handlers::store]
+pub fn store_stake_accounts(
+    clock: Clock,
+    native_stake_events: pb::solana::native::stake::v1::Events,
+    native_token_events: pb::solana::native::token::v1::Events,
+    store: StoreSetString,
+) {
+    stake_accounts::store_events(&clock, &native_stake_events, &native_token_events, &store);
+}
+
+#[substreams::handlers::store]
+pub fn store_stake_account_splits(
+    clock: Clock,
+    native_stake_events: pb::solana::native::stake::v1::Events,
+    stake_accounts_store: StoreGetString,
+    store: StoreSetString,
+) {
+    stake_accounts::store_splits(&clock, &native_stake_events, &stake_accounts_store, &store);
+}
+
 #[substreams::handlers::map]
 pub fn db_out(
     clock: Clock,
     marinade_events: pb::marinade::v1::Events,
     native_stake_events: pb::solana::native::stake::v1::Events,
+    stake_accounts_deltas: Deltas<DeltaString>,
+    stake_accounts_store: StoreGetString,
+    stake_account_splits_store: StoreGetString,
 ) -> Result<DatabaseChanges, Error> {
     let mut tables = substreams_database_change::tables::Tables::new();
 
     marinade::process_events(&mut tables, &clock, &marinade_events);
     native_stake::process_events(&mut tables, &clock, &native_stake_events);
+    stake_accounts::process_deltas(&mut tables, &clock, &stake_accounts_deltas, &stake_accounts_store, &stake_account_splits_store);
 
     if tables.all_row_count() > 0 {
         set_clock(&clock, tables.create_row("blocks", [("block_num", clock.number.to_string())]));
@@ -12,6 +12,7 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                     let key = common_key_v2(clock, transaction_index, instruction_index);
                     let row = tables
                         .create_row("native_stake_initialize", key)
+                        .set("instruction", "initialize")
                         .set("stake_account", base58::encode(&event.stake_account))
                         .set("staker", base58::encode(&event.staker))
                         .set("withdrawer", base58::encode(&event.withdrawer))
@@ -79,6 +80,157 @@ pub fn process_events(tables: &mut Tables, clock: &Clock, events: &pb::Events) {
                     set_transaction(tx, row);
                     set_clock(clock, row);
                 }
+                Some(pb::instruction::Instruction::InitializeChecked(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_initialize", key)
+                        .set("instruction", "initialize_checked")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("staker", base58::encode(&event.staker))
+                        .set("withdrawer", base58::encode(&event.withdrawer))
+                        // InitializeChecked always creates the account without lockup
+                        .set("lockup_unix_timestamp", 0i64)
+                        .set("lockup_epoch", 0u64)
+                        .set("lockup_custodian", "");
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::Authorize(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_authorize", key)
+                        .set("instruction", "authorize")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("new_authority", base58::encode(&event.new_authority))
+                        .set("stake_authorize", event.stake_authorize().as_str_name())
+                        .set("authority", base58::encode(&event.authority))
+                        .set("custodian", event.custodian.as_ref().map(base58::encode).unwrap_or_default());
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::AuthorizeChecked(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_authorize", key)
+                        .set("instruction", "authorize_checked")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("new_authority", base58::encode(&event.new_authority))
+                        .set("stake_authorize", event.stake_authorize().as_str_name())
+                        .set("authority", base58::encode(&event.authority))
+                        .set("custodian", event.custodian.as_ref().map(base58::encode).unwrap_or_default());
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::AuthorizeWithSeed(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_authorize", key)
+                        .set("instruction", "authorize_with_seed")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("new_authority", base58::encode(&event.new_authority))
+                        .set("stake_authorize", event.stake_authorize().as_str_name())
+                        .set("authority", base58::encode(&event.authority_base))
+                        .set("authority_seed", &event.authority_seed)
+                        .set("authority_owner", base58::encode(&event.authority_owner))
+                        .set("custodian", event.custodian.as_ref().map(base58::encode).unwrap_or_default());
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::AuthorizeCheckedWithSeed(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_authorize", key)
+                        .set("instruction", "authorize_checked_with_seed")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("new_authority", base58::encode(&event.new_authority))
+                        .set("stake_authorize", event.stake_authorize().as_str_name())
+                        .set("authority", base58::encode(&event.authority_base))
+                        .set("authority_seed", &event.authority_seed)
+                        .set("authority_owner", base58::encode(&event.authority_owner))
+                        .set("custodian", event.custodian.as_ref().map(base58::encode).unwrap_or_default());
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::SetLockup(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_set_lockup", key)
+                        .set("instruction", "set_lockup")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("lockup_unix_timestamp", event.lockup_unix_timestamp.unwrap_or_default())
+                        .set("lockup_epoch", event.lockup_epoch.unwrap_or_default())
+                        .set("lockup_custodian", event.lockup_custodian.as_ref().map(base58::encode).unwrap_or_default())
+                        .set("authority", base58::encode(&event.authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::SetLockupChecked(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_set_lockup", key)
+                        .set("instruction", "set_lockup_checked")
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("lockup_unix_timestamp", event.lockup_unix_timestamp.unwrap_or_default())
+                        .set("lockup_epoch", event.lockup_epoch.unwrap_or_default())
+                        .set("lockup_custodian", event.lockup_custodian.as_ref().map(base58::encode).unwrap_or_default())
+                        .set("authority", base58::encode(&event.authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::DeactivateDelinquent(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_deactivate_delinquent", key)
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("delinquent_vote_account", base58::encode(&event.delinquent_vote_account))
+                        .set("reference_vote_account", base58::encode(&event.reference_vote_account));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::Redelegate(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_redelegate", key)
+                        .set("stake_account", base58::encode(&event.stake_account))
+                        .set("uninitialized_stake_account", base58::encode(&event.uninitialized_stake_account))
+                        .set("vote_account", base58::encode(&event.vote_account))
+                        .set("stake_authority", base58::encode(&event.stake_authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::MoveStake(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_move_stake", key)
+                        .set("source_stake_account", base58::encode(&event.source_stake_account))
+                        .set("destination_stake_account", base58::encode(&event.destination_stake_account))
+                        .set("lamports", event.lamports)
+                        .set("stake_authority", base58::encode(&event.stake_authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
+                Some(pb::instruction::Instruction::MoveLamports(event)) => {
+                    let key = common_key_v2(clock, transaction_index, instruction_index);
+                    let row = tables
+                        .create_row("native_stake_move_lamports", key)
+                        .set("source_stake_account", base58::encode(&event.source_stake_account))
+                        .set("destination_stake_account", base58::encode(&event.destination_stake_account))
+                        .set("lamports", event.lamports)
+                        .set("stake_authority", base58::encode(&event.stake_authority));
+                    set_instruction(instruction, row);
+                    set_transaction(tx, row);
+                    set_clock(clock, row);
+                }
                 None => {}
             }
         }
@@ -0,0 +1,270 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use common::db::set_clock;
+use proto::pb::solana::native::stake::v1 as stake;
+use proto::pb::solana::native::token::v1 as token;
+use substreams::pb::substreams::Clock;
+use substreams::store::{DeltaString, Deltas, StoreGet, StoreGetString, StoreSet, StoreSetString};
+use substreams_database_change::tables::Tables;
+use substreams_solana::base58;
+
+// Each stake account field is stored under its own key: `<stake_account>:<field>`
+const VOTE_ACCOUNT: &str = "vote_account";
+const STAKER: &str = "staker";
+const WITHDRAWER: &str = "withdrawer";
+const DEACTIVATING: &str = "deactivating";
+const LAMPORTS: &str = "lamports";
+// Stake account this account was split (or redelegated) from
+const PARENT: &str = "parent";
+
+// Fields copied from the parent onto the split (or redelegated) stake account
+const INHERITED_FIELDS: [&str; 4] = [VOTE_ACCOUNT, STAKER, WITHDRAWER, DEACTIVATING];
+// Redelegate sets the vote account & activation of the new stake account itself
+const REDELEGATE_INHERITED_FIELDS: [&str; 2] = [STAKER, WITHDRAWER];
+
+struct StakeAccountWriter<'a> {
+    store: &'a StoreSetString,
+    ordinal: u64,
+    // Position of the instruction being processed, prefixed to the stored values
+    position: String,
+    // Stake accounts referenced in this block, used to pick up their native balances
+    touched: HashSet<Vec<u8>>,
+}
+
+impl StakeAccountWriter<'_> {
+    fn set(&mut self, account: &[u8], field: &str, value: String) {
+        self.touch(account);
+        self.store.set(self.ordinal, key(account, field), &versioned(&self.position, &value));
+        self.ordinal += 1;
+    }
+
+    fn touch(&mut self, account: &[u8]) {
+        self.touched.insert(account.to_vec());
+    }
+}
+
+pub fn store_events(clock: &Clock, stake_events: &stake::Events, token_events: &token::Events, store: &StoreSetString) {
+    let mut writer = StakeAccountWriter {
+        store,
+        ordinal: 0,
+        position: String::new(),
+        touched: HashSet::new(),
+    };
+
+    for (transaction_index, tx) in stake_events.transactions.iter().enumerate() {
+        for (instruction_index, instruction) in tx.instructions.iter().enumerate() {
+            writer.position = position(clock, transaction_index, instruction_index);
+            match &instruction.instruction {
+                Some(stake::instruction::Instruction::Initialize(event)) => {
+                    writer.set(&event.stake_account, STAKER, base58::encode(&event.staker));
+                    writer.set(&event.stake_account, WITHDRAWER, base58::encode(&event.withdrawer));
+                }
+                Some(stake::instruction::Instruction::InitializeChecked(event)) => {
+                    writer.set(&event.stake_account, STAKER, base58::encode(&event.staker));
+                    writer.set(&event.stake_account, WITHDRAWER, base58::encode(&event.withdrawer));
+                }
+                Some(stake::instruction::Instruction::Delegate(event)) => {
+                    writer.set(&event.stake_account, VOTE_ACCOUNT, base58::encode(&event.vote_account));
+                    writer.set(&event.stake_account, DEACTIVATING, false.to_string());
+                }
+                Some(stake::instruction::Instruction::Deactivate(event)) => {
+                    writer.set(&event.stake_account, DEACTIVATING, true.to_string());
+                }
+                Some(stake::instruction::Instruction::DeactivateDelinquent(event)) => {
+                    writer.set(&event.stake_account, DEACTIVATING, true.to_string());
+                }
+                Some(stake::instruction::Instruction::Authorize(event)) => {
+                    writer.set(
+                        &event.stake_account,
+                        authority_field(event.stake_authorize()),
+                        base58::encode(&event.new_authority),
+                    );
+                }
+                Some(stake::instruction::Instruction::AuthorizeChecked(event)) => {
+                    writer.set(
+                        &event.stake_account,
+                        authority_field(event.stake_authorize()),
+                        base58::encode(&event.new_authority),
+                    );
+                }
+                Some(stake::instruction::Instruction::AuthorizeWithSeed(event)) => {
+                    writer.set(
+                        &event.stake_account,
+                        authority_field(event.stake_authorize()),
+                        base58::encode(&event.new_authority),
+                    );
+                }
+                Some(stake::instruction::Instruction::AuthorizeCheckedWithSeed(event)) => {
+                    writer.set(
+                        &event.stake_account,
+                        authority_field(event.stake_authorize()),
+                        base58::encode(&event.new_authority),
+                    );
+                }
+                Some(stake::instruction::Instruction::Split(event)) => {
+                    writer.touch(&event.stake_account);
+                    writer.set(&event.split_stake_account, PARENT, base58::encode(&event.stake_account));
+                }
+                Some(stake::instruction::Instruction::Redelegate(event)) => {
+                    writer.set(&event.stake_account, DEACTIVATING, true.to_string());
+                    writer.set(&event.uninitialized_stake_account, PARENT, base58::encode(&event.stake_account));
+                    writer.set(&event.uninitialized_stake_account, VOTE_ACCOUNT, base58::encode(&event.vote_account));
+                    writer.set(&event.uninitialized_stake_account, DEACTIVATING, false.to_string());
+                }
+                Some(stake::instruction::Instruction::Merge(event)) => {
+                    // Merge drains and closes the source stake account, which is no longer delegated
+                    writer.set(&event.source_stake_account, VOTE_ACCOUNT, String::new());
+                    writer.set(&event.source_stake_account, LAMPORTS, 0.to_string());
+                    writer.touch(&event.destination_stake_account);
+                }
+                Some(stake::instruction::Instruction::MoveStake(event)) => {
+                    writer.touch(&event.source_stake_account);
+                    writer.touch(&event.destination_stake_account);
+                }
+                Some(stake::instruction::Instruction::MoveLamports(event)) => {
+                    writer.touch(&event.source_stake_account);
+                    writer.touch(&event.destination_stake_account);
+                }
+                Some(stake::instruction::Instruction::Withdraw(event)) => {
+                    writer.touch(&event.stake_account);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Keep the last post balance per account in the block
+    let mut post_balances: HashMap<&[u8], u64> = HashMap::new();
+    for tx in token_events.transactions.iter() {
+        for balance in tx.post_balances.iter() {
+            post_balances.insert(balance.account.as_slice(), balance.amount);
+        }
+    }
+    // Post balances are set at the end of the block
+    let position = position(clock, stake_events.transactions.len(), 0);
+    for account in writer.touched.iter() {
+        if let Some(lamports) = post_balances.get(account.as_slice()) {
+            store.set(writer.ordinal, key(account, LAMPORTS), &versioned(&position, &lamports.to_string()));
+            writer.ordinal += 1;
+        }
+    }
+}
+
+// Copies the parent's fields onto the split (or redelegated) stake account at split time.
+// Stores cannot read their own state, so the copies live in a store downstream of `store_events`.
+pub fn store_splits(clock: &Clock, stake_events: &stake::Events, accounts: &StoreGetString, store: &StoreSetString) {
+    let mut ordinal = 0;
+    // Fields copied earlier in this block, for accounts split again within the same block
+    let mut copied: HashMap<String, String> = HashMap::new();
+
+    for (transaction_index, tx) in stake_events.transactions.iter().enumerate() {
+        for (instruction_index, instruction) in tx.instructions.iter().enumerate() {
+            let (parent, child, staker, fields) = match &instruction.instruction {
+                // Split is signed by the staker of the parent account
+                Some(stake::instruction::Instruction::Split(event)) => (
+                    &event.stake_account,
+                    &event.split_stake_account,
+                    Some(&event.stake_authority),
+                    INHERITED_FIELDS.as_slice(),
+                ),
+                Some(stake::instruction::Instruction::Redelegate(event)) => (
+                    &event.stake_account,
+                    &event.uninitialized_stake_account,
+                    None,
+                    REDELEGATE_INHERITED_FIELDS.as_slice(),
+                ),
+                _ => continue,
+            };
+
+            let position = position(clock, transaction_index, instruction_index);
+            for &field in fields {
+                let value = match staker {
+                    Some(staker) if field == STAKER => Some(base58::encode(staker)),
+                    _ => parent_field(accounts, &copied, base58::encode(parent), field),
+                };
+                if let Some(value) = value {
+                    let key = key(child, field);
+                    let value = versioned(&position, &value);
+                    store.set(ordinal, &key, &value);
+                    ordinal += 1;
+                    copied.insert(key, value);
+                }
+            }
+        }
+    }
+}
+
+// Resolve a field of the parent at split time, walking up its own `parent` links when it was itself split without setting the field
+fn parent_field(accounts: &StoreGetString, copied: &HashMap<String, String>, account: String, field: &str) -> Option<String> {
+    let mut visited = HashSet::new();
+    let mut current = account;
+    while visited.insert(current.clone()) {
+        let key = format!("{}:{}", current, field);
+        if let Some(value) = latest([accounts.get_last(&key), copied.get(&key).cloned()]) {
+            return Some(value);
+        }
+        current = unversioned(&accounts.get_last(format!("{}:{}", current, PARENT))?).to_string();
+    }
+    None
+}
+
+pub fn process_deltas(tables: &mut Tables, clock: &Clock, deltas: &Deltas<DeltaString>, store: &StoreGetString, splits: &StoreGetString) {
+    // Sorted for deterministic row ordering
+    let accounts: BTreeSet<&str> = deltas.deltas.iter().map(|delta| substreams::key::first_segment(&delta.key)).collect();
+
+    for account in accounts {
+        let row = tables
+            .upsert_row("stake_accounts", account.to_string())
+            .set("stake_account", account)
+            .set("vote_account", get_field(store, splits, account, VOTE_ACCOUNT).unwrap_or_default())
+            .set("staker", get_field(store, splits, account, STAKER).unwrap_or_default())
+            .set("withdrawer", get_field(store, splits, account, WITHDRAWER).unwrap_or_default())
+            .set("deactivating", get_field(store, splits, account, DEACTIVATING).is_some_and(|v| v == "true"))
+            .set("lamports", get_lamports(store, account));
+        set_clock(clock, row);
+    }
+}
+
+// The latest of the field set on the stake account itself and the one copied from its parent
+fn get_field(store: &StoreGetString, splits: &StoreGetString, account: &str, field: &str) -> Option<String> {
+    let key = format!("{}:{}", account, field);
+    latest([store.get_last(&key), splits.get_last(&key)])
+}
+
+// Lamports are never inherited from the parent account
+fn get_lamports(store: &StoreGetString, account: &str) -> u64 {
+    store
+        .get_last(format!("{}:{}", account, LAMPORTS))
+        .and_then(|value| unversioned(&value).parse::<u64>().ok())
+        .unwrap_or_default()
+}
+
+// Values are prefixed with the position of the instruction setting them (`<block>:<transaction>:<instruction>|<value>`),
+// zero-padded so that the most recent value sorts last.
+fn position(clock: &Clock, transaction_index: usize, instruction_index: usize) -> String {
+    format!("{:012}:{:06}:{:06}", clock.number, transaction_index, instruction_index)
+}
+
+fn versioned(position: &str, value: &str) -> String {
+    format!("{}|{}", position, value)
+}
+
+fn unversioned(value: &str) -> &str {
+    value.split_once('|').map_or(value, |(_, value)| value)
+}
+
+// Most recent of the versioned values, without its position
+fn latest<const N: usize>(values: [Option<String>; N]) -> Option<String> {
+    values.into_iter().flatten().max().map(|value| unversioned(&value).to_string())
+}
+
+fn authority_field(stake_authorize: stake::StakeAuthorize) -> &'static str {
+    match stake_authorize {
+        stake::StakeAuthorize::Staker => STAKER,
+        stake::StakeAuthorize::Withdrawer => WITHDRAWER,
+    }
+}
+
+fn key(account: &[u8], field: &str) -> String {
+    format!("{}:{}", base58::encode(account), field)
+}